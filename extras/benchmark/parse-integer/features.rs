@@ -0,0 +1,132 @@
+//! Measure the cost of the parser features on representative datasets.
+//!
+//! Run under each feature combination to compare the fast and compact
+//! paths, and the contiguous and non-contiguous (digit separator) iterators:
+//!
+//! ```bash
+//! cargo bench --bench features
+//! cargo bench --bench features --features compact
+//! cargo bench --bench features --features format
+//! cargo bench --bench features --features format,compact
+//! ```
+
+use core::time::Duration;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use fastrand::Rng;
+use lexical_parse_integer::{FromLexical, FromLexicalWithOptions, Options};
+use lexical_util::format::STANDARD;
+
+// Default random data size.
+const COUNT: usize = 1000;
+
+// Use a fixed seed so throughput is comparable between feature combinations.
+const SEED: u64 = 0x5EED_1E71_CA1B_EAC5;
+
+#[cfg(feature = "format")]
+const UNDERSCORE: u128 = lexical_util::format::NumberFormatBuilder::new()
+    .digit_separator(core::num::NonZeroU8::new(b'_'))
+    .integer_internal_digit_separator(true)
+    .build_strict();
+
+const OPTIONS: Options = Options::new();
+
+// DATA
+
+fn random_u64() -> Vec<String> {
+    let mut rng = Rng::with_seed(SEED);
+    (0..COUNT).map(|_| rng.u64(..).to_string()).collect()
+}
+
+fn max_length_u64() -> Vec<String> {
+    let mut rng = Rng::with_seed(SEED);
+    (0..COUNT).map(|_| rng.u64(10_000_000_000_000_000_000..=u64::MAX).to_string()).collect()
+}
+
+/// Group the digits in threes, separated by underscores.
+#[cfg(feature = "format")]
+fn with_separators(data: &[String]) -> Vec<String> {
+    data.iter()
+        .map(|x| {
+            let mut grouped = String::with_capacity(x.len() + x.len() / 3);
+            for (index, c) in x.chars().enumerate() {
+                if index != 0 && (x.len() - index) % 3 == 0 {
+                    grouped.push('_');
+                }
+                grouped.push(c);
+            }
+            grouped
+        })
+        .collect()
+}
+
+fn bytes_len(data: &[String]) -> u64 {
+    data.iter().map(|x| x.len() as u64).sum()
+}
+
+// GENERATORS
+
+macro_rules! parse_generator {
+    ($group:ident, $name:expr, $data:expr) => {{
+        $group.throughput(Throughput::Bytes(bytes_len(&$data)));
+        $group.bench_function($name, |bench| {
+            bench.iter(|| {
+                $data.iter().for_each(|x| {
+                    black_box(u64::from_lexical(x.as_bytes()).unwrap());
+                })
+            })
+        });
+    }};
+    ($group:ident, $name:expr, $data:expr, $format:expr) => {{
+        $group.throughput(Throughput::Bytes(bytes_len(&$data)));
+        $group.bench_function($name, |bench| {
+            bench.iter(|| {
+                $data.iter().for_each(|x| {
+                    black_box(
+                        u64::from_lexical_with_options::<{ $format }>(x.as_bytes(), &OPTIONS)
+                            .unwrap(),
+                    );
+                })
+            })
+        });
+    }};
+}
+
+// BENCHES
+
+fn contiguous(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("features:contiguous");
+    group.measurement_time(Duration::from_secs(5));
+    let random_data = random_u64();
+    let max_data = max_length_u64();
+
+    parse_generator!(group, "parse_u64_random", random_data);
+    parse_generator!(group, "parse_u64_max_length", max_data);
+    parse_generator!(group, "parse_u64_random_options", random_data, STANDARD);
+    parse_generator!(group, "parse_u64_max_length_options", max_data, STANDARD);
+}
+
+#[cfg(feature = "format")]
+fn separators(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("features:separators");
+    group.measurement_time(Duration::from_secs(5));
+    let random_data = random_u64();
+    let max_data = max_length_u64();
+    let random_separators = with_separators(&random_data);
+    let max_separators = with_separators(&max_data);
+
+    // No separators present, but uses the non-contiguous skip iterator.
+    parse_generator!(group, "parse_u64_random_no_separators", random_data, UNDERSCORE);
+    parse_generator!(group, "parse_u64_max_length_no_separators", max_data, UNDERSCORE);
+    parse_generator!(group, "parse_u64_random_separators", random_separators, UNDERSCORE);
+    parse_generator!(group, "parse_u64_max_length_separators", max_separators, UNDERSCORE);
+}
+
+criterion_group!(contiguous_benches, contiguous);
+#[cfg(feature = "format")]
+criterion_group!(separators_benches, separators);
+
+#[cfg(feature = "format")]
+criterion_main!(contiguous_benches, separators_benches);
+#[cfg(not(feature = "format"))]
+criterion_main!(contiguous_benches);
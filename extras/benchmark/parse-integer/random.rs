@@ -4,7 +4,8 @@ mod input;
 use core::time::Duration;
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use lexical_parse_integer::FromLexical;
+use lexical_parse_integer::{options, FromLexical, FromLexicalWithOptions};
+use lexical_util::format::STANDARD;
 
 // Default random data size.
 const COUNT: usize = 1000;
@@ -65,6 +66,43 @@ macro_rules! bench_signed {
     };
 }
 
+// Compare the multi-digit (SWAR) and single-digit parsers for 16-20 digit
+// integers, where parsing 8 digits at a time has the largest impact.
+fn long_u64(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("random:long_u64");
+    group.measurement_time(Duration::from_secs(5));
+    let mut rng = fastrand::Rng::with_seed(fastrand::u64(..));
+    let data: Vec<String> =
+        (0..COUNT).map(|_| rng.u64(1_000_000_000_000_000..=u64::MAX).to_string()).collect();
+
+    group.bench_function("parse_u64_multi_digit", |bench| {
+        bench.iter(|| {
+            data.iter().for_each(|x| {
+                black_box(
+                    u64::from_lexical_with_options::<STANDARD>(
+                        x.as_bytes(),
+                        &options::LARGE_NUMBERS,
+                    )
+                    .unwrap(),
+                );
+            })
+        })
+    });
+    group.bench_function("parse_u64_single_digit", |bench| {
+        bench.iter(|| {
+            data.iter().for_each(|x| {
+                black_box(
+                    u64::from_lexical_with_options::<STANDARD>(
+                        x.as_bytes(),
+                        &options::SMALL_NUMBERS,
+                    )
+                    .unwrap(),
+                );
+            })
+        })
+    });
+}
+
 bench!(uniform, "random:uniform", input::RandomGen::Uniform);
 bench!(simple, "random:simple", input::RandomGen::Simple);
 bench!(large, "random:large", input::RandomGen::Large);
@@ -76,10 +114,12 @@ criterion_group!(simple_benches, simple);
 criterion_group!(large_benches, large);
 criterion_group!(simple_signed_benches, simple_signed);
 criterion_group!(large_signed_benches, large_signed);
+criterion_group!(long_u64_benches, long_u64);
 criterion_main!(
     uniform_benches,
     simple_benches,
     large_benches,
     simple_signed_benches,
-    large_signed_benches
+    large_signed_benches,
+    long_u64_benches
 );
@@ -0,0 +1,63 @@
+#[macro_use]
+mod input;
+
+use core::mem::MaybeUninit;
+use core::time::Duration;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use lexical_write_integer::compact::{compact_into, Compact};
+
+// Default random data size.
+const COUNT: usize = 1000;
+
+// GENERATOR
+
+macro_rules! generator {
+    ($group:ident, $type:literal, $iter:expr, $radix:literal) => {{
+        $group.bench_function(concat!("compact_", $type, "_radix", $radix), |bench| {
+            let mut buffer = [b'\x00'; 128];
+            bench.iter(|| {
+                $iter.for_each(|&x| {
+                    black_box(x.compact($radix, &mut buffer));
+                })
+            })
+        });
+        $group.bench_function(concat!("compact_into_", $type, "_radix", $radix), |bench| {
+            let mut buffer = [MaybeUninit::<u8>::uninit(); 128];
+            bench.iter(|| {
+                $iter.for_each(|&x| {
+                    black_box(compact_into(x, $radix, &mut buffer));
+                })
+            })
+        });
+    }};
+}
+
+// BENCHES
+
+macro_rules! bench {
+    ($fn:ident, $name:literal, $strategy:expr) => {
+        fn $fn(criterion: &mut Criterion) {
+            let mut group = criterion.benchmark_group($name);
+            group.measurement_time(Duration::from_secs(5));
+            let seed = fastrand::u64(..);
+
+            let u32_data = input::type_from_random::<u32>($strategy, COUNT, seed);
+            let u64_data = input::type_from_random::<u64>($strategy, COUNT, seed);
+            let u128_data = input::type_from_random::<u128>($strategy, COUNT, seed);
+
+            generator!(group, "u32", u32_data.iter(), 10);
+            generator!(group, "u64", u64_data.iter(), 10);
+            generator!(group, "u128", u128_data.iter(), 10);
+            generator!(group, "u64", u64_data.iter(), 2);
+            generator!(group, "u64", u64_data.iter(), 16);
+        }
+    };
+}
+
+bench!(uniform, "compact:uniform", input::RandomGen::Uniform);
+bench!(simple, "compact:simple", input::RandomGen::Simple);
+
+criterion_group!(uniform_benches, uniform);
+criterion_group!(simple_benches, simple);
+criterion_main!(uniform_benches, simple_benches);
@@ -48,7 +48,7 @@ macro_rules! is_invalid_digit_match {
         prop_assert!(result.is_err());
         let err = result.err().unwrap();
         prop_assert!(err.is_invalid_digit());
-        prop_assert!(matches!(*err.index().unwrap(), $p1 $(| $prest)*));
+        prop_assert!(matches!(err.index().unwrap(), $p1 $(| $prest)*));
     }};
 }
 
@@ -0,0 +1,26 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use lexical_parse_integer::FromLexical;
+use lexical_util::constants::BUFFER_SIZE;
+use lexical_write_integer::ToLexical;
+
+fn roundtrip(value: i128) {
+    let mut buffer = [b'0'; BUFFER_SIZE];
+    let digits = value.to_lexical(&mut buffer);
+    assert_eq!(i128::from_lexical(digits), Ok(value));
+}
+
+fuzz_target!(|data: &[u8]| {
+    match i128::from_lexical(data) {
+        Ok(value) => roundtrip(value),
+        Err(error) => {
+            if let Some(index) = error.index() {
+                assert!(index <= data.len());
+            }
+        },
+    }
+    if let Ok((value, count)) = i128::from_lexical_partial(data) {
+        assert!(count <= data.len());
+        roundtrip(value);
+    }
+});
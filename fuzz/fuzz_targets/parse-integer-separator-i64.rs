@@ -0,0 +1,57 @@
+#![no_main]
+use core::num;
+
+use libfuzzer_sys::fuzz_target;
+use lexical_parse_integer::{FromLexicalWithOptions, NumberFormatBuilder, Options};
+use lexical_util::constants::BUFFER_SIZE;
+use lexical_write_integer::ToLexical;
+
+const SEPARATOR: Option<num::NonZeroU8> = num::NonZeroU8::new(b'_');
+const INTERNAL: u128 = NumberFormatBuilder::new()
+    .digit_separator(SEPARATOR)
+    .integer_internal_digit_separator(true)
+    .build_strict();
+const LEADING: u128 = NumberFormatBuilder::rebuild(INTERNAL)
+    .integer_leading_digit_separator(true)
+    .build_strict();
+const TRAILING: u128 = NumberFormatBuilder::rebuild(INTERNAL)
+    .integer_trailing_digit_separator(true)
+    .build_strict();
+const ALL: u128 = NumberFormatBuilder::new()
+    .digit_separator(SEPARATOR)
+    .digit_separator_flags(true)
+    .build_strict();
+const OPTIONS: Options = Options::new();
+
+fn check<const FORMAT: u128>(data: &[u8]) {
+    let roundtrip = |value: i64| {
+        let mut buffer = [b'0'; BUFFER_SIZE];
+        let digits = value.to_lexical(&mut buffer);
+        assert_eq!(i64::from_lexical_with_options::<FORMAT>(digits, &OPTIONS), Ok(value));
+    };
+    match i64::from_lexical_with_options::<FORMAT>(data, &OPTIONS) {
+        Ok(value) => roundtrip(value),
+        Err(error) => {
+            if let Some(index) = error.index() {
+                assert!(index <= data.len());
+            }
+        },
+    }
+    if let Ok((value, count)) = i64::from_lexical_partial_with_options::<FORMAT>(data, &OPTIONS) {
+        assert!(count <= data.len());
+        roundtrip(value);
+    }
+}
+
+// Select the digit separator flags from the first byte, to fuzz each of
+// the skip iterators.
+fuzz_target!(|data: &[u8]| {
+    if let Some((&flags, data)) = data.split_first() {
+        match flags % 4 {
+            0 => check::<INTERNAL>(data),
+            1 => check::<LEADING>(data),
+            2 => check::<TRAILING>(data),
+            _ => check::<ALL>(data),
+        }
+    }
+});
@@ -1170,3 +1170,73 @@ pub fn parse_partial_with_options<N: FromLexicalWithOptions, const FORMAT: u128>
 ) -> Result<(N, usize)> {
     N::from_lexical_partial_with_options::<FORMAT>(bytes, options)
 }
+
+/// Lazily parse many complete numbers from an iterator of strings.
+///
+/// This applies [`parse`] to each input, returning an iterator of the
+/// per-element results, so invalid inputs do not stop parsing of the
+/// remaining elements.
+///
+/// * `inputs`  - Iterable of byte slices containing numeric strings.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "parse-integers")] {
+/// use lexical_core::Error;
+///
+/// let inputs: [&[u8]; 3] = [b"1", b"2a", b"3"];
+/// let values: Vec<_> = lexical_core::from_lexical_many::<u8, _>(inputs).collect();
+/// assert_eq!(values, [Ok(1), Err(Error::InvalidDigit(1)), Ok(3)]);
+/// # }
+/// ```
+#[inline]
+#[cfg(any(feature = "parse-floats", feature = "parse-integers"))]
+pub fn from_lexical_many<'a, N: FromLexical, I: IntoIterator<Item = &'a [u8]>>(
+    inputs: I,
+) -> impl Iterator<Item = Result<N>> {
+    inputs.into_iter().map(N::from_lexical)
+}
+
+/// Lazily parse many complete numbers from an iterator of strings with
+/// custom parsing options.
+///
+/// This applies [`parse_with_options`] to each input, returning an
+/// iterator of the per-element results. The options are borrowed for
+/// the lifetime of the iterator, so they are only built and validated
+/// once, and the format is validated at compile time.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `inputs`  - Iterable of byte slices containing numeric strings.
+/// * `options` - Options to customize number parsing.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(all(feature = "parse-floats", feature = "format"))] {
+/// use lexical_core::Error;
+///
+/// const JSON: u128 = lexical_core::format::JSON;
+/// const OPTIONS: lexical_core::ParseFloatOptions = lexical_core::ParseFloatOptions::new();
+/// let inputs: [&[u8]; 3] = [b"1.5", b"NaN", b"-2"];
+/// let values: Vec<_> =
+///     lexical_core::from_lexical_many_with_options::<f64, _, JSON>(inputs, &OPTIONS).collect();
+/// assert_eq!(values, [Ok(1.5), Err(Error::EmptyInteger(0)), Ok(-2.0)]);
+/// # }
+/// ```
+#[inline]
+#[cfg(any(feature = "parse-floats", feature = "parse-integers"))]
+pub fn from_lexical_many_with_options<
+    'a,
+    N: FromLexicalWithOptions,
+    I: IntoIterator<Item = &'a [u8]>,
+    const FORMAT: u128,
+>(
+    inputs: I,
+    options: &'a N::Options,
+) -> impl Iterator<Item = Result<N>> + 'a
+where
+    I::IntoIter: 'a,
+{
+    inputs.into_iter().map(move |bytes| N::from_lexical_with_options::<FORMAT>(bytes, options))
+}
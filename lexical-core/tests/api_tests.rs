@@ -54,6 +54,69 @@ fn string_to_float_test() {
     );
 }
 
+#[test]
+#[cfg(all(feature = "parse-floats", feature = "write-floats"))]
+fn signaling_nan_roundtrip_test() {
+    use lexical_core::{ParseFloatOptions, WriteFloatOptions};
+
+    const WRITE_OPTIONS: WriteFloatOptions = WriteFloatOptions::builder()
+        .snan_string(Some(b"sNaN"))
+        .qnan_string(Some(b"qNaN"))
+        .build_strict();
+    const PARSE_OPTIONS: ParseFloatOptions = ParseFloatOptions::builder()
+        .snan_string(Some(b"sNaN"))
+        .qnan_string(Some(b"qNaN"))
+        .build_strict();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+
+    let mut buffer = [b'0'; lexical_core::BUFFER_SIZE];
+    for bits in [0x7FF4_0000_0000_0000u64, f64::NAN.to_bits()] {
+        let float = f64::from_bits(bits);
+        let bytes =
+            lexical_core::write_with_options::<_, FORMAT>(float, &mut buffer, &WRITE_OPTIONS);
+        let parsed = lexical_core::parse_with_options::<f64, FORMAT>(bytes, &PARSE_OPTIONS);
+        assert_eq!(parsed.map(f64::to_bits), Ok(bits));
+    }
+
+    for bits in [0x7FA0_0000u32, f32::NAN.to_bits()] {
+        let float = f32::from_bits(bits);
+        let bytes =
+            lexical_core::write_with_options::<_, FORMAT>(float, &mut buffer, &WRITE_OPTIONS);
+        let parsed = lexical_core::parse_with_options::<f32, FORMAT>(bytes, &PARSE_OPTIONS);
+        assert_eq!(parsed.map(f32::to_bits), Ok(bits));
+    }
+}
+
+#[test]
+#[cfg(all(
+    feature = "parse-floats",
+    feature = "write-floats",
+    feature = "format",
+    feature = "power-of-two"
+))]
+fn c_hex_float_roundtrip_test() {
+    use lexical_core::{ParseFloatOptions, WriteFloatOptions};
+
+    const WRITE_FORMAT: u128 = lexical_core::format::C_HEX_STRING;
+    const PARSE_FORMAT: u128 = lexical_core::format::C_HEX_FLOAT;
+    const WRITE_OPTIONS: WriteFloatOptions = lexical_write_float::options::HEX_FLOAT;
+    const PARSE_OPTIONS: ParseFloatOptions = lexical_parse_float::options::HEX_FLOAT;
+
+    let mut buffer = [b'0'; lexical_core::BUFFER_SIZE];
+    let values = [12.0f64, 1.0, 0.25, 1.5, -3.25, 1e300, 5e-324, f64::MAX, f64::MIN_POSITIVE];
+    for value in values {
+        // The writer does not support base prefixes, so add it after the sign.
+        let digits =
+            lexical_core::write_with_options::<_, WRITE_FORMAT>(value, &mut buffer, &WRITE_OPTIONS);
+        let hex = match digits.split_first() {
+            Some((b'-', rest)) => [b"-0x", rest].concat(),
+            _ => [b"0x", &digits[..]].concat(),
+        };
+        let parsed = lexical_core::parse_with_options::<f64, PARSE_FORMAT>(&hex, &PARSE_OPTIONS);
+        assert_eq!(parsed, Ok(value));
+    }
+}
+
 /// Test that converting the specified value into a buffer of FORMATTED_SIZE
 /// yields the expected string
 #[cfg(feature = "write-integers")]
@@ -92,3 +155,58 @@ fn numeric_limit_string_tests() {
     test_format!(i64, i64::MIN, "-9223372036854775808");
     test_format!(i64, i64::MAX, "9223372036854775807");
 }
+
+#[test]
+#[cfg(feature = "parse-integers")]
+fn from_lexical_many_integer_test() {
+    use lexical_core::Error;
+
+    let inputs: [&[u8]; 6] = [b"12345", b"", b"-1", b"1a5", b"256", b"0"];
+    let values: Vec<lexical_core::Result<u8>> = lexical_core::from_lexical_many(inputs).collect();
+    assert_eq!(values, [
+        Err(Error::Overflow(3)),
+        Err(Error::Empty(0)),
+        Err(Error::InvalidDigit(0)),
+        Err(Error::InvalidDigit(1)),
+        Err(Error::Overflow(2)),
+        Ok(0),
+    ]);
+
+    let strings = ["1".to_string(), "-2".to_string(), "x".to_string()];
+    let values: Vec<lexical_core::Result<i32>> =
+        lexical_core::from_lexical_many(strings.iter().map(|x| x.as_bytes())).collect();
+    assert_eq!(values, [Ok(1), Ok(-2), Err(Error::InvalidDigit(0))]);
+
+    const OPTIONS: lexical_parse_integer::Options = lexical_core::ParseIntegerOptions::new();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let mut iter = lexical_core::from_lexical_many_with_options::<u16, _, FORMAT>(
+        [b"65535".as_slice(), b"65536", b"+7"],
+        &OPTIONS,
+    );
+    assert_eq!(iter.next(), Some(Ok(65535)));
+    assert_eq!(iter.next(), Some(Err(Error::Overflow(4))));
+    assert_eq!(iter.next(), Some(Ok(7)));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn from_lexical_many_float_test() {
+    use lexical_core::Error;
+
+    let inputs: [&[u8]; 4] = [b"1.5", b"inf", b"1e", b"-0.25"];
+    let values: Vec<lexical_core::Result<f64>> = lexical_core::from_lexical_many(inputs).collect();
+    assert_eq!(values, [Ok(1.5), Ok(f64::INFINITY), Err(Error::EmptyExponent(2)), Ok(-0.25)]);
+
+    const OPTIONS: lexical_parse_float::Options = lexical_core::ParseFloatOptions::new();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let values: Vec<lexical_core::Result<f32>> =
+        lexical_core::from_lexical_many_with_options::<_, _, FORMAT>(
+            [b"3.5".as_slice(), b".", b"NaN"],
+            &OPTIONS,
+        )
+        .collect();
+    assert_eq!(values[0], Ok(3.5));
+    assert_eq!(values[1], Err(Error::EmptyMantissa(1)));
+    assert!(values[2].as_ref().unwrap().is_nan());
+}
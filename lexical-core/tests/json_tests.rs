@@ -0,0 +1,80 @@
+#![cfg(all(feature = "parse-floats", feature = "parse-integers", feature = "format"))]
+
+use lexical_core::format::JSON;
+use lexical_core::{Error, ParseFloatOptions, ParseIntegerOptions};
+
+const FLOAT_OPTIONS: ParseFloatOptions = lexical_core::parse_float_options::JSON;
+const INTEGER_OPTIONS: ParseIntegerOptions = ParseIntegerOptions::new();
+
+fn parse_f64(bytes: &[u8]) -> Result<f64, Error> {
+    lexical_core::parse_with_options::<f64, JSON>(bytes, &FLOAT_OPTIONS)
+}
+
+fn parse_i64(bytes: &[u8]) -> Result<i64, Error> {
+    lexical_core::parse_with_options::<i64, JSON>(bytes, &INTEGER_OPTIONS)
+}
+
+#[test]
+fn json_accept_test() {
+    // JSONTestSuite/test_parsing/y_number*.json
+    assert_eq!(parse_f64(b"0"), Ok(0.0));
+    assert_eq!(parse_f64(b"-0"), Ok(-0.0));
+    assert_eq!(parse_f64(b"123"), Ok(123.0));
+    assert_eq!(parse_f64(b"-1"), Ok(-1.0));
+    assert_eq!(parse_f64(b"0.5"), Ok(0.5));
+    assert_eq!(parse_f64(b"-0.5"), Ok(-0.5));
+    assert_eq!(parse_f64(b"123.456789"), Ok(123.456789));
+    assert_eq!(parse_f64(b"0e1"), Ok(0.0));
+    assert_eq!(parse_f64(b"20e1"), Ok(200.0));
+    assert_eq!(parse_f64(b"1E22"), Ok(1e22));
+    assert_eq!(parse_f64(b"1E+2"), Ok(100.0));
+    assert_eq!(parse_f64(b"1E-2"), Ok(0.01));
+    assert_eq!(parse_f64(b"1e05"), Ok(1e5));
+    assert_eq!(parse_f64(b"-0.0e-0"), Ok(-0.0));
+    assert_eq!(parse_f64(b"123e-10000000"), Ok(0.0));
+
+    // Integers share the format, without a fraction or exponent.
+    assert_eq!(parse_i64(b"0"), Ok(0));
+    assert_eq!(parse_i64(b"-0"), Ok(0));
+    assert_eq!(parse_i64(b"123"), Ok(123));
+    assert_eq!(parse_i64(b"-123"), Ok(-123));
+    assert_eq!(parse_i64(b"-9223372036854775808"), Ok(i64::MIN));
+}
+
+#[test]
+fn json_reject_test() {
+    // JSONTestSuite/test_parsing/n_number*.json
+    assert_eq!(parse_f64(b""), Err(Error::Empty(0)));
+    assert_eq!(parse_f64(b"-"), Err(Error::EmptyMantissa(1)));
+    assert_eq!(parse_f64(b"01"), Err(Error::InvalidLeadingZeros(0)));
+    assert_eq!(parse_f64(b"-01"), Err(Error::InvalidLeadingZeros(1)));
+    assert_eq!(parse_f64(b"00.5"), Err(Error::InvalidLeadingZeros(0)));
+    assert_eq!(parse_f64(b"+1"), Err(Error::InvalidPositiveSign(0)));
+    assert_eq!(parse_f64(b".5"), Err(Error::EmptyInteger(0)));
+    assert_eq!(parse_f64(b"-.5"), Err(Error::EmptyInteger(1)));
+    assert_eq!(parse_f64(b"1."), Err(Error::EmptyFraction(2)));
+    assert_eq!(parse_f64(b"2.e3"), Err(Error::EmptyFraction(2)));
+    assert_eq!(parse_f64(b"1e"), Err(Error::EmptyExponent(2)));
+    assert_eq!(parse_f64(b"1e+"), Err(Error::EmptyExponent(3)));
+    assert_eq!(parse_f64(b"1.0E"), Err(Error::EmptyExponent(4)));
+    assert_eq!(parse_f64(b"0x1"), Err(Error::InvalidDigit(1)));
+    assert_eq!(parse_f64(b"1_000"), Err(Error::InvalidDigit(1)));
+    assert_eq!(parse_f64(b"12a"), Err(Error::InvalidDigit(2)));
+    assert_eq!(parse_f64(b" 1"), Err(Error::EmptyInteger(0)));
+    assert_eq!(parse_f64(b"1 "), Err(Error::InvalidDigit(1)));
+    assert_eq!(parse_f64(b"- 1"), Err(Error::EmptyInteger(1)));
+    assert_eq!(parse_f64(b"NaN"), Err(Error::EmptyInteger(0)));
+    assert_eq!(parse_f64(b"Infinity"), Err(Error::EmptyInteger(0)));
+    assert_eq!(parse_f64(b"-Infinity"), Err(Error::EmptyInteger(1)));
+
+    assert_eq!(parse_i64(b""), Err(Error::Empty(0)));
+    assert_eq!(parse_i64(b"-"), Err(Error::EmptyMantissa(1)));
+    assert_eq!(parse_i64(b"01"), Err(Error::InvalidLeadingZeros(0)));
+    assert_eq!(parse_i64(b"-01"), Err(Error::InvalidLeadingZeros(1)));
+    assert_eq!(parse_i64(b"+1"), Err(Error::InvalidPositiveSign(0)));
+    assert_eq!(parse_i64(b".5"), Err(Error::InvalidDigit(0)));
+    assert_eq!(parse_i64(b"1."), Err(Error::InvalidDigit(1)));
+    assert_eq!(parse_i64(b"1e5"), Err(Error::InvalidDigit(1)));
+    assert_eq!(parse_i64(b"1_000"), Err(Error::InvalidDigit(1)));
+    assert_eq!(parse_i64(b"NaN"), Err(Error::InvalidDigit(0)));
+}
@@ -0,0 +1,90 @@
+#![cfg(all(feature = "parse-floats", feature = "write-floats"))]
+
+mod util;
+
+use core::num::{NonZeroI32, NonZeroUsize};
+
+use lexical_core::{ParseFloatOptions, WriteFloatOptions};
+#[cfg(any(feature = "format", feature = "power-of-two"))]
+use lexical_core::NumberFormatBuilder;
+use util::{float_boundaries, float_random, float_roundtrip, XorShift};
+
+const RANDOM_COUNT: usize = 2000;
+
+/// Round-trip the boundary and random values for every write option that
+/// changes the digits written: trimming, exponent breaks and padding.
+fn roundtrip_matrix<const FORMAT: u128>(
+    radix: u32,
+    write_options: &WriteFloatOptions,
+    parse_options: &ParseFloatOptions,
+) {
+    let all_write_options = [
+        write_options.clone(),
+        write_options.rebuild().trim_floats(true).build_strict(),
+        write_options
+            .rebuild()
+            .positive_exponent_break(NonZeroI32::new(3))
+            .negative_exponent_break(NonZeroI32::new(-3))
+            .build_strict(),
+        write_options.rebuild().min_significant_digits(NonZeroUsize::new(40)).build_strict(),
+        write_options
+            .rebuild()
+            .trim_floats(true)
+            .min_significant_digits(NonZeroUsize::new(40))
+            .build_strict(),
+    ];
+
+    let mut rng = XorShift::new(0x853C_49E6_748F_EA9B);
+    let f32_values = [float_boundaries::<f32>(radix), float_random(&mut rng, RANDOM_COUNT)];
+    let f64_values = [float_boundaries::<f64>(radix), float_random(&mut rng, RANDOM_COUNT)];
+    for write_options in all_write_options.iter() {
+        for &value in f32_values.iter().flatten() {
+            float_roundtrip::<_, FORMAT>(value, write_options, parse_options);
+        }
+        for &value in f64_values.iter().flatten() {
+            float_roundtrip::<_, FORMAT>(value, write_options, parse_options);
+        }
+    }
+}
+
+#[test]
+fn decimal_roundtrip_test() {
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let write_options = WriteFloatOptions::new();
+    let parse_options = ParseFloatOptions::new();
+    roundtrip_matrix::<FORMAT>(10, &write_options, &parse_options);
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn hex_float_roundtrip_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(16)
+        .exponent_base(core::num::NonZeroU8::new(2))
+        .exponent_radix(core::num::NonZeroU8::new(10))
+        .build_strict();
+    let write_options = lexical_core::write_float_options::HEX_FLOAT;
+    let parse_options = lexical_core::parse_float_options::HEX_FLOAT;
+    roundtrip_matrix::<FORMAT>(16, &write_options, &parse_options);
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn binary_roundtrip_test() {
+    const FORMAT: u128 = NumberFormatBuilder::from_radix(2);
+    let write_options = WriteFloatOptions::new();
+    let parse_options = ParseFloatOptions::new();
+    roundtrip_matrix::<FORMAT>(2, &write_options, &parse_options);
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn digit_separator_roundtrip_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(core::num::NonZeroU8::new(b'_'))
+        .internal_digit_separator(true)
+        .build_strict();
+    let write_options = WriteFloatOptions::new();
+    let parse_options = ParseFloatOptions::new();
+    roundtrip_matrix::<FORMAT>(10, &write_options, &parse_options);
+}
@@ -0,0 +1,84 @@
+#![allow(dead_code, unused_imports)]
+
+#[cfg(all(feature = "parse-floats", feature = "write-floats"))]
+use core::fmt::Debug;
+
+#[cfg(all(feature = "parse-floats", feature = "write-floats"))]
+use lexical_core::{
+    FromLexicalWithOptions, ParseFloatOptions, ToLexicalWithOptions, WriteFloatOptions,
+};
+#[cfg(all(feature = "parse-floats", feature = "write-floats"))]
+use lexical_util::num::{AsCast, Float, Integer};
+
+/// Simple, deterministic xorshift generator for random float bits.
+pub struct XorShift(u64);
+
+impl XorShift {
+    pub const fn new(seed: u64) -> Self {
+        Self(seed | 1)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+/// Write a float and parse it back, asserting the bits are identical.
+#[cfg(all(feature = "parse-floats", feature = "write-floats"))]
+pub fn float_roundtrip<T, const FORMAT: u128>(
+    value: T,
+    write_options: &WriteFloatOptions,
+    parse_options: &ParseFloatOptions,
+) where
+    T: Float
+        + Debug
+        + ToLexicalWithOptions<Options = WriteFloatOptions>
+        + FromLexicalWithOptions<Options = ParseFloatOptions>,
+{
+    let mut buffer = [b'0'; 1024];
+    let bytes = lexical_core::write_with_options::<_, FORMAT>(value, &mut buffer, write_options);
+    let parsed = lexical_core::parse_with_options::<T, FORMAT>(bytes, parse_options);
+    assert_eq!(parsed.map(T::to_bits), Ok(value.to_bits()), "{:?}", core::str::from_utf8(bytes));
+}
+
+/// Get the special and boundary values for a float in a given radix.
+///
+/// This includes signed zeros, subnormals, the extremes, and powers of the
+/// radix with their neighbors, which covers the exponent breaks.
+#[cfg(all(feature = "parse-floats", feature = "write-floats"))]
+pub fn float_boundaries<T: Float>(radix: u32) -> Vec<T> {
+    let mut values = vec![
+        T::ZERO,
+        -T::ZERO,
+        T::from_bits(T::Unsigned::ONE),
+        T::from_bits(T::MANTISSA_MASK),
+        T::from_bits(T::HIDDEN_BIT_MASK),
+        T::MAX,
+        T::MIN,
+        T::from_bits(T::INFINITY_BITS),
+        T::from_bits(T::NEGATIVE_INFINITY_BITS),
+    ];
+    let radix = T::from_u64(radix as u64);
+    let mut large = T::ONE;
+    let mut small = T::ONE;
+    for _ in 0..24 {
+        for power in [large, small] {
+            values.extend_from_slice(&[power, power.prev(), power.next(), -power]);
+        }
+        large *= radix;
+        small /= radix;
+    }
+    values
+}
+
+/// Get random, non-NaN floats from the random bits.
+#[cfg(all(feature = "parse-floats", feature = "write-floats"))]
+pub fn float_random<T: Float>(rng: &mut XorShift, count: usize) -> Vec<T> {
+    (0..count)
+        .map(|_| T::from_bits(T::Unsigned::as_cast(rng.next_u64())))
+        .filter(|x| !x.is_nan())
+        .collect()
+}
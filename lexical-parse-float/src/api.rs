@@ -2,16 +2,22 @@
 
 #![doc(hidden)]
 
+#[cfg(feature = "unicode")]
+use lexical_util::algorithm::ltrim_unicode_whitespace_count;
+use lexical_util::algorithm::utf8_bom_count;
 #[cfg(feature = "f16")]
 use lexical_util::bf16::bf16;
-use lexical_util::error::Error;
+use lexical_util::error::{shift_error, Error};
 #[cfg(feature = "f16")]
 use lexical_util::f16::f16;
 use lexical_util::format::{is_valid_options_punctuation, NumberFormat, STANDARD};
+use lexical_util::result::Result;
 use lexical_util::{from_lexical, from_lexical_with_options};
 
+#[cfg(feature = "std")]
+use crate::options::ThreadLocalOptions;
 use crate::options::Options;
-use crate::parse::ParseFloat;
+use crate::parse::{classify_complete, NumberKind, ParseFloat};
 
 // API
 
@@ -56,7 +62,9 @@ macro_rules! float_from_lexical {
                 } else if !is_valid_options_punctuation(FORMAT, options.exponent(), options.decimal_point()) {
                     return Err(Error::InvalidPunctuation);
                 }
-                Self::parse_complete::<FORMAT>(bytes, options)
+                let skip = skip_count(bytes, options);
+                parse_magnitude_complete::<Self, FORMAT>(&bytes[skip..], options)
+                    .map_err(|error| shift_error(error, skip))
             }
 
             #[cfg_attr(not(feature = "compact"), inline)]
@@ -65,7 +73,10 @@ macro_rules! float_from_lexical {
                 options: &Self::Options,
             ) -> lexical_util::result::Result<(Self, usize)>
             {
-                Self::parse_partial::<FORMAT>(bytes, options)
+                let skip = skip_count(bytes, options);
+                parse_magnitude_partial::<Self, FORMAT>(&bytes[skip..], options)
+                    .map(|(value, count)| (value, count + skip))
+                    .map_err(|error| shift_error(error, skip))
             }
         }
     )*)
@@ -77,3 +88,340 @@ float_from_lexical! { f32 f64 }
 
 #[cfg(feature = "f16")]
 float_from_lexical! { bf16 f16 }
+
+/// Get the number of leading bytes to skip before parsing.
+#[inline(always)]
+fn skip_count(bytes: &[u8], options: &Options) -> usize {
+    let mut count = if options.skip_bom() { utf8_bom_count(bytes) } else { 0 };
+    #[cfg(feature = "unicode")]
+    if options.skip_unicode_whitespace() {
+        count += ltrim_unicode_whitespace_count(&bytes[count..]);
+    }
+    if bytes[count..].starts_with(options.ignore_prefix()) {
+        count += options.ignore_prefix().len();
+    }
+    count
+}
+
+// MAGNITUDE
+
+/// Get the power of ten for a magnitude word, if the byte is one.
+#[inline(always)]
+fn magnitude_exponent(byte: u8, options: &Options) -> Option<u8> {
+    let is_cased = options.case_sensitive_magnitude_words();
+    let word = options.magnitude_words().iter().find(|&&(suffix, _)| {
+        if is_cased {
+            suffix == byte
+        } else {
+            suffix.eq_ignore_ascii_case(&byte)
+        }
+    });
+    word.map(|&(_, exponent)| exponent)
+}
+
+/// Parse a partial float, followed by an optional magnitude word.
+#[inline(always)]
+fn parse_magnitude_partial<F: ParseFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<(F, usize)> {
+    let (value, count) = F::parse_partial::<FORMAT>(bytes, options)?;
+    if options.magnitude_words().is_empty() {
+        return Ok((value, count));
+    }
+    match bytes.get(count).and_then(|&c| magnitude_exponent(c, options)) {
+        Some(exponent) => {
+            // Re-parse the digits so the value is only rounded once.
+            let exponent = exponent.into();
+            let value = F::parse_complete_scaled::<FORMAT>(&bytes[..count], options, exponent)?;
+            Ok((value, count + 1))
+        },
+        None if options.required_magnitude_word() && count == bytes.len() => {
+            Err(Error::Empty(count))
+        },
+        None if options.required_magnitude_word() => Err(Error::InvalidDigit(count)),
+        None => Ok((value, count)),
+    }
+}
+
+/// Parse a complete float, followed by an optional magnitude word.
+#[inline(always)]
+fn parse_magnitude_complete<F: ParseFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<F> {
+    if options.magnitude_words().is_empty() {
+        return F::parse_complete::<FORMAT>(bytes, options);
+    }
+    let (value, count) = F::parse_partial::<FORMAT>(bytes, options)?;
+    match bytes.get(count).and_then(|&c| magnitude_exponent(c, options)) {
+        Some(exponent) if count + 1 == bytes.len() => {
+            F::parse_complete_scaled::<FORMAT>(&bytes[..count], options, exponent.into())
+        },
+        Some(_) => Err(Error::InvalidDigit(count + 1)),
+        // Use the complete parser for the error, which may not be an invalid digit.
+        None if count != bytes.len() => F::parse_complete::<FORMAT>(bytes, options),
+        None if options.required_magnitude_word() => Err(Error::Empty(count)),
+        None => Ok(value),
+    }
+}
+
+// DIGITS DROPPED
+
+/// A parsed float with the number of significant digits that were dropped.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ParsedFloat<F> {
+    /// The parsed float.
+    pub value: F,
+    /// The number of significant digits in the input beyond the precision of
+    /// the float, which only affect rounding. Leading and trailing zeros are
+    /// not significant.
+    pub digits_dropped: u32,
+}
+
+/// Parse a float and count the significant digits beyond its precision.
+///
+/// The precision of the float is the number of significant digits required
+/// to uniquely represent every float, which is `17` for decimal [`f64`] and
+/// `9` for decimal [`f32`]. Any further digits were rounded, which is useful
+/// for data-quality metrics. Special values never drop any digits.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::{parse_with_digits_dropped, Options};
+/// use lexical_parse_float::format::STANDARD;
+///
+/// const OPTIONS: Options = Options::new();
+/// let bytes = b"3.14159265358979323846264338327";
+/// let parsed = parse_with_digits_dropped::<f64, STANDARD>(bytes, &OPTIONS).unwrap();
+/// assert_eq!(parsed.value, 3.141592653589793);
+/// assert_eq!(parsed.digits_dropped, 13);
+///
+/// let parsed = parse_with_digits_dropped::<f64, STANDARD>(b"1.5", &OPTIONS).unwrap();
+/// assert_eq!(parsed.digits_dropped, 0);
+/// ```
+#[inline]
+pub fn parse_with_digits_dropped<F: ParseFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<ParsedFloat<F>> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    if !format.is_valid() {
+        return Err(format.error());
+    } else if !is_valid_options_punctuation(FORMAT, options.exponent(), options.decimal_point()) {
+        return Err(Error::InvalidPunctuation);
+    }
+    let (value, digits_dropped) = F::parse_complete_digits_dropped::<FORMAT>(bytes, options)?;
+    Ok(ParsedFloat {
+        value,
+        digits_dropped,
+    })
+}
+
+// EXACTNESS
+
+/// Parse a float and determine if it is exactly equal to the input.
+///
+/// The float is exact if no rounding occurred, that is, the exact value of
+/// the input is representable as the float. This compares the digits to
+/// the float using big-integer arithmetic, so it is slower than
+/// [`from_lexical`][FromLexical::from_lexical]. Special values are always
+/// exact, while values that overflow to infinity or underflow to zero are
+/// not.
+///
+/// * `bytes`   - Slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::from_lexical_exactness;
+///
+/// assert_eq!(from_lexical_exactness::<f64>(b"0.5"), Ok((0.5, true)));
+/// assert_eq!(from_lexical_exactness::<f64>(b"0.1"), Ok((0.1, false)));
+/// ```
+#[inline]
+pub fn from_lexical_exactness<F: ParseFloat>(bytes: &[u8]) -> Result<(F, bool)> {
+    F::parse_complete_exact::<STANDARD>(bytes, &DEFAULT_OPTIONS)
+}
+
+// THREAD-LOCAL DEFAULTS
+
+/// Parse a complete float with the default options for the current thread.
+///
+/// This is [`from_lexical`][FromLexical::from_lexical], but uses the
+/// options set by [`ThreadLocalOptions::set_default`] rather than the
+/// standard options, so the options only need to be configured once.
+///
+/// * `bytes`   - Slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::{from_lexical_default, Options, ThreadLocalOptions};
+///
+/// assert_eq!(from_lexical_default::<f64>(b"1.5"), Ok(1.5));
+/// let options = Options::builder().decimal_point(b',').build_strict();
+/// ThreadLocalOptions::set_default(options);
+/// assert_eq!(from_lexical_default::<f64>(b"1,5"), Ok(1.5));
+/// ```
+#[cfg(feature = "std")]
+#[inline]
+pub fn from_lexical_default<F>(bytes: &[u8]) -> Result<F>
+where
+    F: FromLexicalWithOptions<Options = Options>,
+{
+    ThreadLocalOptions::with_default(|options| {
+        F::from_lexical_with_options::<STANDARD>(bytes, options)
+    })
+}
+
+/// Parse a partial float with the default options for the current thread.
+///
+/// This is [`from_lexical_partial`][FromLexical::from_lexical_partial],
+/// but uses the options set by [`ThreadLocalOptions::set_default`] rather
+/// than the standard options.
+///
+/// * `bytes`   - Slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::{from_lexical_partial_default, Options, ThreadLocalOptions};
+///
+/// let options = Options::builder().decimal_point(b',').build_strict();
+/// ThreadLocalOptions::set_default(options);
+/// assert_eq!(from_lexical_partial_default::<f64>(b"1,5;2"), Ok((1.5, 3)));
+/// ```
+#[cfg(feature = "std")]
+#[inline]
+pub fn from_lexical_partial_default<F>(bytes: &[u8]) -> Result<(F, usize)>
+where
+    F: FromLexicalWithOptions<Options = Options>,
+{
+    ThreadLocalOptions::with_default(|options| {
+        F::from_lexical_partial_with_options::<STANDARD>(bytes, options)
+    })
+}
+
+// RANGE
+
+/// Get the number of leading spaces to skip around a range separator.
+#[inline(always)]
+fn space_count(bytes: &[u8]) -> usize {
+    bytes.iter().take_while(|&&c| c == b' ').count()
+}
+
+/// Parse a range of two floats separated by a byte, such as `1.5-2.5`.
+///
+/// This parses the start of the range, the separator, and the end of the
+/// range, returning both values and the number of bytes processed. Spaces
+/// around the separator are skipped. The conventional separator is `-`,
+/// which is never confused with a negative sign or the sign of an
+/// exponent, since the start of the range stops before the separator, so
+/// `-1--2` is the range from `-1` to `-2`. Since `.` is the decimal point,
+/// it is used as `..`, like Rust ranges, such as `1..2`.
+///
+/// If the separator is missing, this returns [`InvalidDigit`] at the
+/// byte where it was expected, or [`Empty`] at the end of the input.
+///
+/// * `bytes`   - Slice containing a numeric string.
+/// * `sep`     - The byte separating the start and end of the range.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::{from_lexical_range, Error};
+///
+/// assert_eq!(from_lexical_range::<f64>(b"1.5-2.5", b'-'), Ok((1.5, 2.5, 7)));
+/// assert_eq!(from_lexical_range::<f64>(b"-1 - -2", b'-'), Ok((-1.0, -2.0, 7)));
+/// assert_eq!(from_lexical_range::<f64>(b"1.5..2.5", b'.'), Ok((1.5, 2.5, 8)));
+/// assert_eq!(from_lexical_range::<f64>(b"1.5", b'-'), Err(Error::Empty(3)));
+/// ```
+///
+/// [`InvalidDigit`]: Error::InvalidDigit
+/// [`Empty`]: Error::Empty
+#[inline]
+pub fn from_lexical_range<F: ParseFloat>(bytes: &[u8], sep: u8) -> Result<(F, F, usize)> {
+    // The start of the range would consume the first `.` as a decimal point.
+    let (separator, limit): (&[u8], usize) = match sep {
+        b'.' => (b"..", bytes.windows(2).position(|x| x == b"..").unwrap_or(bytes.len())),
+        _ => (core::slice::from_ref(&sep), bytes.len()),
+    };
+    let (start, mut index) = F::parse_partial::<STANDARD>(&bytes[..limit], &DEFAULT_OPTIONS)?;
+    index += space_count(&bytes[index..]);
+    if !bytes[index..].starts_with(separator) {
+        return match index == bytes.len() {
+            true => Err(Error::Empty(index)),
+            false => Err(Error::InvalidDigit(index)),
+        };
+    }
+    index += separator.len();
+    index += space_count(&bytes[index..]);
+    let (end, count) = F::parse_partial::<STANDARD>(&bytes[index..], &DEFAULT_OPTIONS)
+        .map_err(|error| shift_error(error, index))?;
+    Ok((start, end, index + count))
+}
+
+/// Classify a number as an integer or float, without parsing its value.
+///
+/// This scans the number for a decimal point or exponent, which is useful
+/// for schema inference. Numbers without either are integers, even if too
+/// large for any integer type. Special values, such as `NaN`, are floats.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::{classify_number, NumberKind};
+/// use lexical_parse_float::format::STANDARD;
+///
+/// assert_eq!(classify_number::<STANDARD>(b"123"), NumberKind::Integer);
+/// assert_eq!(classify_number::<STANDARD>(b"1.5"), NumberKind::Float);
+/// assert_eq!(classify_number::<STANDARD>(b"1e3"), NumberKind::Float);
+/// assert_eq!(classify_number::<STANDARD>(b"1x"), NumberKind::Invalid);
+/// ```
+#[inline]
+pub fn classify_number<const FORMAT: u128>(bytes: &[u8]) -> NumberKind {
+    classify_number_with_options::<FORMAT>(bytes, &DEFAULT_OPTIONS)
+}
+
+/// Classify a number as an integer or float with custom options.
+///
+/// This uses the decimal point, exponent, and special strings from the
+/// options. See [`classify_number`] for more details.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::{classify_number_with_options, NumberKind, Options};
+/// use lexical_parse_float::format::STANDARD;
+///
+/// const OPTIONS: Options = Options::builder().decimal_point(b',').build_strict();
+/// let classify = |bytes| classify_number_with_options::<STANDARD>(bytes, &OPTIONS);
+/// assert_eq!(classify(b"1,5"), NumberKind::Float);
+/// assert_eq!(classify(b"1.5"), NumberKind::Invalid);
+/// ```
+#[inline]
+pub fn classify_number_with_options<const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> NumberKind {
+    let format = NumberFormat::<{ FORMAT }> {};
+    if !format.is_valid()
+        || !is_valid_options_punctuation(FORMAT, options.exponent(), options.decimal_point())
+    {
+        return NumberKind::Invalid;
+    }
+    classify_complete::<FORMAT>(bytes, options)
+}
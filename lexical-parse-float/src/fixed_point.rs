@@ -0,0 +1,155 @@
+//! Parse fixed-point numbers with a fixed number of fractional digits.
+//!
+//! This parses numbers such as `12.750` into the integral and fractional
+//! parts, such as seconds and nanoseconds for durations and timestamps,
+//! without any intermediate float and therefore without any rounding. The
+//! integral part reuses the integer parser.
+//!
+//! The fractional part is scaled to `scale` digits, so `12.75` with a scale
+//! of `3` is `(12, 750)`, and with a scale of `9` is `(12, 750000000)`. The
+//! value is always `integer + fraction / radix^scale`, so negative values
+//! round the integer part down: `-12.75` with a scale of `3` is `(-13, 250)`.
+
+use lexical_parse_integer::{FromLexicalWithOptions, Options as IntegerOptions};
+use lexical_util::digit::char_to_digit_const;
+use lexical_util::error::Error;
+use lexical_util::format::{is_valid_options_punctuation, NumberFormat, STANDARD};
+use lexical_util::iterator::{AsBytes, DigitsIter, Iter};
+use lexical_util::result::Result;
+
+use crate::options::Options;
+use crate::parse::skip_decimal_point;
+
+const DEFAULT_OPTIONS: Options = Options::new();
+const INTEGER_OPTIONS: IntegerOptions = IntegerOptions::new();
+
+/// Parse a decimal fixed-point number with `scale` fractional digits.
+///
+/// Returns the integral part and the fractional part, scaled to `scale`
+/// digits, so `12.75` with a scale of `3` is `(12, 750)`. More than `scale`
+/// fractional digits are rejected with [`Error::InvalidDigit`]. See
+/// [`parse_fixed_point_with_options`] for more details.
+///
+/// # Panics
+///
+/// Panics if `scale` is larger than `9`, since the fractional part would
+/// not fit in a [`u32`].
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::fixed_point::parse_fixed_point;
+///
+/// assert_eq!(parse_fixed_point(b"12.75", 3), Ok((12, 750)));
+/// assert_eq!(parse_fixed_point(b"12.75", 9), Ok((12, 750_000_000)));
+/// assert_eq!(parse_fixed_point(b"12", 3), Ok((12, 0)));
+/// assert!(parse_fixed_point(b"12.7501", 3).is_err());
+/// ```
+#[inline]
+pub fn parse_fixed_point(bytes: &[u8], scale: u32) -> Result<(i64, u32)> {
+    parse_fixed_point_with_options::<STANDARD>(bytes, scale, &DEFAULT_OPTIONS)
+}
+
+/// Parse a fixed-point number with `scale` fractional digits and options.
+///
+/// The integral part is parsed as an [`i64`], using the number format. The
+/// fractional part follows the decimal point from the options, or the
+/// middle dot if [`middle_dot_decimal_point`] is enabled, and has up
+/// to `scale` digits in the mantissa radix, which are right-padded with
+/// zeros. Any more fractional digits are rejected with
+/// [`Error::InvalidDigit`], since they cannot be represented.
+///
+/// The value is always `integer + fraction / radix^scale`, so negative
+/// values round the integral part down, and `-12.75` with a scale of `3`
+/// is `(-13, 250)`.
+///
+/// [`middle_dot_decimal_point`]: Options::middle_dot_decimal_point
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Slice containing a numeric string.
+/// * `scale`   - The number of fractional digits.
+/// * `options` - Options to customize number parsing.
+///
+/// # Panics
+///
+/// Panics if `radix^scale` does not fit in a [`u32`].
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::fixed_point::parse_fixed_point_with_options;
+/// use lexical_parse_float::format::STANDARD;
+/// use lexical_parse_float::Options;
+///
+/// const OPTIONS: Options = Options::builder().decimal_point(b',').build_strict();
+/// let parse = |bytes| parse_fixed_point_with_options::<STANDARD>(bytes, 6, &OPTIONS);
+/// assert_eq!(parse(b"1,5"), Ok((1, 500_000)));
+/// assert_eq!(parse(b"-1,5"), Ok((-2, 500_000)));
+/// ```
+#[inline]
+pub fn parse_fixed_point_with_options<const FORMAT: u128>(
+    bytes: &[u8],
+    scale: u32,
+    options: &Options,
+) -> Result<(i64, u32)> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    if !format.is_valid() {
+        return Err(format.error());
+    } else if !is_valid_options_punctuation(FORMAT, options.exponent(), options.decimal_point()) {
+        return Err(Error::InvalidPunctuation);
+    }
+    let radix = format.mantissa_radix();
+    let denominator = match radix.checked_pow(scale) {
+        Some(denominator) => denominator,
+        None => panic!("scale is too large for the fractional part"),
+    };
+
+    // INTEGER
+
+    let (integer, count) =
+        i64::from_lexical_partial_with_options::<FORMAT>(bytes, &INTEGER_OPTIONS)?;
+    if count == bytes.len() {
+        return Ok((integer, 0));
+    }
+    let mut byte = bytes[count..].bytes::<{ FORMAT }>();
+    if !skip_decimal_point(&mut byte, options) {
+        return Err(Error::InvalidDigit(count));
+    }
+
+    // FRACTION
+
+    // Accumulate the digits directly, since the fraction may have leading
+    // zeros. Only up to `scale` digits are accepted, so it cannot overflow.
+    let start = count + byte.cursor();
+    if start == bytes.len() && format.required_fraction_digits() {
+        return Err(Error::EmptyFraction(start));
+    }
+    let mut byte = bytes[start..].bytes::<{ FORMAT }>();
+    let mut iter = byte.fraction_iter();
+    let mut fraction = 0;
+    let mut digits = 0;
+    while let Some(&c) = iter.peek() {
+        match char_to_digit_const(c, radix) {
+            Some(digit) if digits < scale => fraction = fraction * radix + digit,
+            _ => break,
+        }
+        digits += 1;
+        iter.next();
+    }
+    let end = start + iter.cursor();
+    if end != bytes.len() {
+        return Err(Error::InvalidDigit(end));
+    }
+    let fraction = fraction * radix.pow(scale - digits);
+
+    // The fraction is always positive, so round negative values down.
+    let is_negative = bytes.first() == Some(&b'-');
+    if is_negative && fraction != 0 {
+        match integer.checked_sub(1) {
+            Some(integer) => Ok((integer, denominator - fraction)),
+            None => Err(Error::Underflow(count)),
+        }
+    } else {
+        Ok((integer, fraction))
+    }
+}
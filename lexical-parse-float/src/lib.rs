@@ -544,6 +544,7 @@ pub mod shared;
 pub mod bellerophon;
 pub mod bigint;
 pub mod binary;
+pub mod fixed_point;
 pub mod float;
 pub mod fpu;
 pub mod lemire;
@@ -579,6 +580,20 @@ pub use lexical_util::format::{self, NumberFormat, NumberFormatBuilder};
 pub use lexical_util::options::ParseOptions;
 pub use lexical_util::result::Result;
 
-pub use self::api::{FromLexical, FromLexicalWithOptions};
+pub use self::api::{
+    classify_number,
+    classify_number_with_options,
+    from_lexical_exactness,
+    from_lexical_range,
+    parse_with_digits_dropped,
+    FromLexical,
+    FromLexicalWithOptions,
+    ParsedFloat,
+};
 #[doc(inline)]
 pub use self::options::{Options, OptionsBuilder};
+pub use self::parse::NumberKind;
+#[cfg(feature = "std")]
+pub use self::api::{from_lexical_default, from_lexical_partial_default};
+#[cfg(feature = "std")]
+pub use self::options::ThreadLocalOptions;
@@ -187,7 +187,11 @@
 
 #![allow(clippy::must_use_candidate)]
 
-use lexical_util::ascii::{is_valid_ascii, is_valid_letter_slice};
+use core::num::NonZeroUsize;
+#[cfg(feature = "std")]
+use std::cell::RefCell;
+
+use lexical_util::ascii::{is_valid_ascii, is_valid_letter_slice, is_valid_prefix_slice};
 use lexical_util::error::Error;
 use lexical_util::options::{self, ParseOptions};
 use lexical_util::result::Result;
@@ -195,6 +199,9 @@ use lexical_util::result::Result;
 /// Maximum length for a special string.
 pub const MAX_SPECIAL_STRING_LENGTH: usize = 50;
 
+/// Magnitude words for millions, billions and trillions, as `M`, `B` and `T`.
+pub const MAGNITUDE_WORDS: &[(u8, u8)] = &[(b'M', 6), (b'B', 9), (b'T', 12)];
+
 /// Builder for [`Options`].
 ///
 /// This enables extensive control over how the float is parsed, from
@@ -219,16 +226,48 @@ pub struct OptionsBuilder {
     /// Disable the use of arbitrary-precision arithmetic, and always
     /// return the results from the fast or intermediate path algorithms.
     lossy: bool,
+    /// Maximum number of significant digits used by the slow algorithm.
+    max_slow_digits: Option<NonZeroUsize>,
     /// Character to designate the exponent component of a float.
     exponent: u8,
     /// Character to separate the integer from the fraction components.
     decimal_point: u8,
     /// String representation of Not A Number, aka `NaN`.
     nan_string: Option<&'static [u8]>,
+    /// String representation of a signaling `NaN`.
+    snan_string: Option<&'static [u8]>,
+    /// String representation of a quiet `NaN`.
+    qnan_string: Option<&'static [u8]>,
     /// Short string representation of `Infinity`.
     inf_string: Option<&'static [u8]>,
     /// Long string representation of `Infinity`.
     infinity_string: Option<&'static [u8]>,
+    /// Parse a decimal point without any digits, such as `.`, as zero.
+    allow_lone_decimal_point: bool,
+    /// Skip a leading UTF-8 byte order mark before parsing.
+    skip_bom: bool,
+    /// Skip leading Unicode whitespace before parsing.
+    skip_unicode_whitespace: bool,
+    /// Literal prefix, such as a currency symbol, to skip before parsing.
+    ignore_prefix: &'static [u8],
+    /// Accept the Unicode minus sign, `−`, as the exponent sign.
+    unicode_exponent_sign: bool,
+    /// Accept the middle dot, `·`, as the decimal point.
+    middle_dot_decimal_point: bool,
+    /// If the NaN strings must match in exact casing.
+    case_sensitive_nan: bool,
+    /// If the infinity strings must match in exact casing.
+    case_sensitive_inf: bool,
+    /// Suffixes for the magnitude of the value, and their power of ten.
+    magnitude_words: &'static [(u8, u8)],
+    /// If the magnitude words must match in exact casing.
+    case_sensitive_magnitude_words: bool,
+    /// If a magnitude word is required after the number.
+    required_magnitude_word: bool,
+    /// Reject fractions that end with redundant zeros, such as `1.50`.
+    no_trailing_fraction_zeros: bool,
+    /// Allow a fraction of a single zero, such as `1.0`, with trailing zeros disabled.
+    allow_zero_fraction: bool,
 }
 
 impl OptionsBuilder {
@@ -237,11 +276,27 @@ impl OptionsBuilder {
     pub const fn new() -> Self {
         Self {
             lossy: false,
+            max_slow_digits: None,
             exponent: b'e',
             decimal_point: b'.',
             nan_string: Some(b"NaN"),
+            snan_string: None,
+            qnan_string: None,
             inf_string: Some(b"inf"),
             infinity_string: Some(b"infinity"),
+            allow_lone_decimal_point: false,
+            skip_bom: false,
+            skip_unicode_whitespace: false,
+            ignore_prefix: &[],
+            unicode_exponent_sign: false,
+            middle_dot_decimal_point: false,
+            case_sensitive_nan: false,
+            case_sensitive_inf: false,
+            magnitude_words: &[],
+            case_sensitive_magnitude_words: false,
+            required_magnitude_word: false,
+            no_trailing_fraction_zeros: false,
+            allow_zero_fraction: false,
         }
     }
 
@@ -264,6 +319,24 @@ impl OptionsBuilder {
         self.lossy
     }
 
+    /// Get the maximum number of significant digits used by the slow
+    /// algorithm.
+    ///
+    /// Defaults to [`None`], which uses every digit that can affect the
+    /// result.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::options::Options;
+    ///
+    /// assert_eq!(Options::builder().get_max_slow_digits(), None);
+    /// ```
+    #[inline(always)]
+    pub const fn get_max_slow_digits(&self) -> Option<NonZeroUsize> {
+        self.max_slow_digits
+    }
+
     /// Get the character to designate the exponent component of a float.
     ///
     /// Any non-control character is valid, but `\t` to `\r` are also valid.
@@ -316,6 +389,46 @@ impl OptionsBuilder {
         self.nan_string
     }
 
+    /// Get the string representation for a signaling `NaN`.
+    ///
+    /// All characters must be valid ASCII letters (`A-Z` or `a-z`). If set,
+    /// this is parsed as a signaling NaN rather than the quiet
+    /// [`NaN`][f64::NAN]. Defaults to `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_snan_string(), None);
+    /// ```
+    #[inline(always)]
+    pub const fn get_snan_string(&self) -> Option<&'static [u8]> {
+        self.snan_string
+    }
+
+    /// Get the string representation for a quiet `NaN`.
+    ///
+    /// All characters must be valid ASCII letters (`A-Z` or `a-z`). If set,
+    /// this is parsed as a quiet [`NaN`][f64::NAN], in addition to
+    /// [`nan_string`]. Defaults to `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_qnan_string(), None);
+    /// ```
+    ///
+    /// [`nan_string`]: Self::nan_string
+    #[inline(always)]
+    pub const fn get_qnan_string(&self) -> Option<&'static [u8]> {
+        self.qnan_string
+    }
+
     /// Get the short string representation for `Infinity`.
     ///
     /// The first character must start with `I` or `i` and all characters must
@@ -354,6 +467,253 @@ impl OptionsBuilder {
         self.infinity_string
     }
 
+    /// Get if a decimal point without any digits parses as zero.
+    ///
+    /// If enabled, a lone decimal point, such as `.` or `-.`, is parsed as
+    /// zero rather than returning [`EmptyMantissa`]. A decimal point with an
+    /// exponent but no digits, such as `.e5`, is always an error. Defaults
+    /// to [`false`].
+    ///
+    /// [`EmptyMantissa`]: lexical_util::error::Error::EmptyMantissa
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::options::Options;
+    ///
+    /// assert_eq!(Options::builder().get_allow_lone_decimal_point(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn get_allow_lone_decimal_point(&self) -> bool {
+        self.allow_lone_decimal_point
+    }
+
+    /// Get if a leading UTF-8 byte order mark (`EF BB BF`) is skipped.
+    ///
+    /// The bytes skipped are included in the number of bytes processed.
+    /// Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_skip_bom(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn get_skip_bom(&self) -> bool {
+        self.skip_bom
+    }
+
+    /// Get if leading Unicode whitespace is skipped.
+    ///
+    /// This skips any characters with the `White_Space` property encoded
+    /// as UTF-8, after any byte order mark, and the bytes skipped are
+    /// included in the number of bytes processed. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_skip_unicode_whitespace(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn get_skip_unicode_whitespace(&self) -> bool {
+        self.skip_unicode_whitespace
+    }
+
+    /// Get the literal prefix skipped before parsing.
+    ///
+    /// If the input starts with the prefix, after any byte order mark or
+    /// whitespace, the prefix is skipped before parsing the sign and digits,
+    /// and the bytes skipped are included in the number of bytes processed.
+    /// The prefix is matched as a literal byte sequence and is optional.
+    /// Defaults to an empty prefix, which skips nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_ignore_prefix(), b"");
+    /// ```
+    #[inline(always)]
+    pub const fn get_ignore_prefix(&self) -> &'static [u8] {
+        self.ignore_prefix
+    }
+
+    /// Get if the Unicode minus sign is accepted as the exponent sign.
+    ///
+    /// If set, `−` (U+2212), encoded as UTF-8, is parsed as a negative
+    /// exponent sign, the same as `-`. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_unicode_exponent_sign(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn get_unicode_exponent_sign(&self) -> bool {
+        self.unicode_exponent_sign
+    }
+
+    /// Get if the middle dot is accepted as the decimal point.
+    ///
+    /// If set, `·` (U+00B7), encoded as UTF-8, is parsed as a decimal point,
+    /// in addition to [`decimal_point`][Self::get_decimal_point]. Defaults
+    /// to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_middle_dot_decimal_point(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn get_middle_dot_decimal_point(&self) -> bool {
+        self.middle_dot_decimal_point
+    }
+
+    /// Get if the NaN strings must match in exact casing.
+    ///
+    /// This applies to [`nan_string`], [`snan_string`], and [`qnan_string`],
+    /// and is independent of the casing of the infinity strings. Defaults to
+    /// [`false`].
+    ///
+    /// [`nan_string`]: Self::get_nan_string
+    /// [`snan_string`]: Self::get_snan_string
+    /// [`qnan_string`]: Self::get_qnan_string
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_case_sensitive_nan(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn get_case_sensitive_nan(&self) -> bool {
+        self.case_sensitive_nan
+    }
+
+    /// Get if the infinity strings must match in exact casing.
+    ///
+    /// This applies to [`inf_string`] and [`infinity_string`], and is
+    /// independent of the casing of the NaN strings. Defaults to [`false`].
+    ///
+    /// [`inf_string`]: Self::get_inf_string
+    /// [`infinity_string`]: Self::get_infinity_string
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_case_sensitive_inf(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn get_case_sensitive_inf(&self) -> bool {
+        self.case_sensitive_inf
+    }
+
+    /// Get the suffixes for the magnitude of the value.
+    ///
+    /// Each suffix is paired with the power of ten it scales the value by.
+    /// Defaults to no suffixes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_magnitude_words(), &[]);
+    /// ```
+    #[inline(always)]
+    pub const fn get_magnitude_words(&self) -> &'static [(u8, u8)] {
+        self.magnitude_words
+    }
+
+    /// Get if the magnitude words must match in exact casing.
+    ///
+    /// Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_case_sensitive_magnitude_words(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn get_case_sensitive_magnitude_words(&self) -> bool {
+        self.case_sensitive_magnitude_words
+    }
+
+    /// Get if a magnitude word is required after the number.
+    ///
+    /// Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_required_magnitude_word(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn get_required_magnitude_word(&self) -> bool {
+        self.required_magnitude_word
+    }
+
+    /// Get if fractions with redundant trailing zeros are rejected.
+    ///
+    /// Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_no_trailing_fraction_zeros(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn get_no_trailing_fraction_zeros(&self) -> bool {
+        self.no_trailing_fraction_zeros
+    }
+
+    /// Get if a fraction of a single zero is allowed with trailing zeros
+    /// disabled.
+    ///
+    /// Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_allow_zero_fraction(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn get_allow_zero_fraction(&self) -> bool {
+        self.allow_zero_fraction
+    }
+
     // SETTERS
 
     /// Set if we disable the use of arbitrary-precision arithmetic.
@@ -378,6 +738,47 @@ impl OptionsBuilder {
         self
     }
 
+    /// Set the maximum number of significant digits used by the slow
+    /// algorithm.
+    ///
+    /// Floats that are too close to halfway between two machine floats for
+    /// the intermediate algorithms use arbitrary-precision arithmetic on up
+    /// to 768 significant digits for an `f64`, which is slow for inputs
+    /// with many digits. This limits the digits used: any digits past the
+    /// limit are truncated, and only round the truncated value up if any
+    /// are non-zero. This does not affect decimal and power-of-two floats
+    /// that are not ambiguous, or radixes without a finite representation,
+    /// such as radix 3.
+    ///
+    /// The result is correctly rounded unless the exact value is within
+    /// one unit in the last kept digit of a halfway point. If the limit is
+    /// at least 17 digits for an `f64`, or 9 digits for an `f32`, the
+    /// result is at most 1 ULP from the correctly rounded value. Defaults
+    /// to [`None`], which uses every digit that can affect the result.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::num::NonZeroUsize;
+    ///
+    /// use lexical_parse_float::{FromLexicalWithOptions, Options};
+    /// use lexical_parse_float::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .max_slow_digits(NonZeroUsize::new(40))
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.max_slow_digits(), NonZeroUsize::new(40));
+    /// let value = "9007199254740993.0000000000000000000000000000000000000000000001";
+    /// let result = f64::from_lexical_with_options::<STANDARD>(value.as_bytes(), &OPTIONS);
+    /// assert_eq!(result, Ok(9007199254740994.0));
+    /// ```
+    #[must_use]
+    #[inline(always)]
+    pub const fn max_slow_digits(mut self, max_slow_digits: Option<NonZeroUsize>) -> Self {
+        self.max_slow_digits = max_slow_digits;
+        self
+    }
+
     /// Set the character to designate the exponent component of a float.
     ///
     /// Any non-control character is valid, but `\t` to `\r` are also valid.
@@ -454,6 +855,75 @@ impl OptionsBuilder {
         self
     }
 
+    /// Set the string representation for a signaling `NaN`.
+    ///
+    /// All characters must be valid ASCII letters (`A-Z` or `a-z`). If set,
+    /// this is parsed as a signaling NaN rather than the quiet
+    /// [`NaN`][f64::NAN]. Defaults to `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::{FromLexicalWithOptions, Options};
+    /// use lexical_parse_float::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .snan_string(Some(b"sNaN"))
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.snan_string(), Some(b"sNaN".as_ref()));
+    ///
+    /// let value = f64::from_lexical_with_options::<STANDARD>(b"sNaN", &OPTIONS);
+    /// assert_eq!(value.map(f64::to_bits), Ok(0x7FF4_0000_0000_0000));
+    /// ```
+    ///
+    /// Panics
+    ///
+    /// Setting a value with more than 50 elements will panic at runtime. You
+    /// should always build the format using [`build_strict`] or checking
+    /// [`is_valid`] prior to using the format, to avoid unexpected panics.
+    ///
+    /// [`build_strict`]: Self::build_strict
+    /// [`is_valid`]: Self::is_valid
+    #[must_use]
+    #[inline(always)]
+    pub const fn snan_string(mut self, snan_string: Option<&'static [u8]>) -> Self {
+        self.snan_string = snan_string;
+        self
+    }
+
+    /// Set the string representation for a quiet `NaN`.
+    ///
+    /// All characters must be valid ASCII letters (`A-Z` or `a-z`). If set,
+    /// this is parsed as a quiet [`NaN`][f64::NAN], in addition to
+    /// [`nan_string`]. Defaults to `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::Options;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .qnan_string(Some(b"qNaN"))
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.qnan_string(), Some(b"qNaN".as_ref()));
+    /// ```
+    ///
+    /// Panics
+    ///
+    /// Setting a value with more than 50 elements will panic at runtime. You
+    /// should always build the format using [`build_strict`] or checking
+    /// [`is_valid`] prior to using the format, to avoid unexpected panics.
+    ///
+    /// [`nan_string`]: Self::nan_string
+    /// [`build_strict`]: Self::build_strict
+    /// [`is_valid`]: Self::is_valid
+    #[must_use]
+    #[inline(always)]
+    pub const fn qnan_string(mut self, qnan_string: Option<&'static [u8]>) -> Self {
+        self.qnan_string = qnan_string;
+        self
+    }
+
     /// Set the short string representation for `Infinity`.
     ///
     /// The first character must start with `I` or `i` and all characters must
@@ -488,37 +958,418 @@ impl OptionsBuilder {
         self
     }
 
-    /// Set the long string representation for `Infinity`.
+    /// Set the long string representation for `Infinity`.
+    ///
+    /// The first character must start with `I` or `i` and all characters must
+    /// be valid ASCII letters (`A-Z` or `a-z`). If set to `None`, then parsing
+    /// [`Infinity`][f64::INFINITY] returns an error. Defaults to `infinity`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::Options;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .infinity_string(Some(b"Infinity"))
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.infinity_string(), Some(b"Infinity".as_ref()));
+    /// ```
+    ///
+    /// Panics
+    ///
+    /// Setting a value with more than 50 elements or one that is shorter than
+    /// [`inf_string`] will panic at runtime. You should always build the format
+    /// using [`build_strict`] or checking [`is_valid`] prior to
+    /// using the format, to avoid unexpected panics.
+    ///
+    /// [`inf_string`]: Self::inf_string
+    /// [`build_strict`]: Self::build_strict
+    /// [`is_valid`]: Self::is_valid
+    #[must_use]
+    #[inline(always)]
+    pub const fn infinity_string(mut self, infinity_string: Option<&'static [u8]>) -> Self {
+        self.infinity_string = infinity_string;
+        self
+    }
+
+    /// Set if a decimal point without any digits parses as zero.
+    ///
+    /// If enabled, a lone decimal point, such as `.` or `-.`, is parsed as
+    /// zero rather than returning [`EmptyMantissa`]. A decimal point with an
+    /// exponent but no digits, such as `.e5`, is always an error. Defaults
+    /// to [`false`].
+    ///
+    /// [`EmptyMantissa`]: lexical_util::error::Error::EmptyMantissa
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::{FromLexicalWithOptions, Options};
+    /// use lexical_parse_float::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .allow_lone_decimal_point(true)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.allow_lone_decimal_point(), true);
+    /// assert_eq!(f64::from_lexical_with_options::<STANDARD>(b".", &OPTIONS), Ok(0.0));
+    /// ```
+    #[must_use]
+    #[inline(always)]
+    pub const fn allow_lone_decimal_point(mut self, allow_lone_decimal_point: bool) -> Self {
+        self.allow_lone_decimal_point = allow_lone_decimal_point;
+        self
+    }
+
+    /// Set if a leading UTF-8 byte order mark (`EF BB BF`) is skipped.
+    ///
+    /// The bytes skipped are included in the number of bytes processed.
+    /// Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::{FromLexicalWithOptions, Options};
+    /// use lexical_parse_float::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .skip_bom(true)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.skip_bom(), true);
+    /// let bytes = "\u{FEFF}42".as_bytes();
+    /// let result = f64::from_lexical_partial_with_options::<STANDARD>(bytes, &OPTIONS);
+    /// assert_eq!(result, Ok((42.0, 5)));
+    /// ```
+    #[inline(always)]
+    pub const fn skip_bom(mut self, skip_bom: bool) -> Self {
+        self.skip_bom = skip_bom;
+        self
+    }
+
+    /// Set if leading Unicode whitespace is skipped.
+    ///
+    /// This skips any characters with the `White_Space` property encoded
+    /// as UTF-8, after any byte order mark, and the bytes skipped are
+    /// included in the number of bytes processed. This requires the
+    /// `unicode` feature. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "unicode")] {
+    /// use lexical_parse_float::{FromLexicalWithOptions, Options};
+    /// use lexical_parse_float::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .skip_unicode_whitespace(true)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.skip_unicode_whitespace(), true);
+    /// let bytes = "\u{3000} 42".as_bytes();
+    /// assert_eq!(f64::from_lexical_with_options::<STANDARD>(bytes, &OPTIONS), Ok(42.0));
+    /// # }
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "unicode")]
+    pub const fn skip_unicode_whitespace(mut self, skip_unicode_whitespace: bool) -> Self {
+        self.skip_unicode_whitespace = skip_unicode_whitespace;
+        self
+    }
+
+    /// Set the literal prefix skipped before parsing.
+    ///
+    /// If the input starts with the prefix, after any byte order mark or
+    /// whitespace, the prefix is skipped before parsing the sign and digits,
+    /// and the bytes skipped are included in the number of bytes processed.
+    /// The prefix is matched as a literal byte sequence and is optional.
+    /// It must not contain any ASCII digits or signs, which would be
+    /// ambiguous with the number. Defaults to an empty prefix, which skips
+    /// nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::{FromLexicalWithOptions, Options};
+    /// use lexical_parse_float::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .ignore_prefix("€".as_bytes())
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.ignore_prefix(), "€".as_bytes());
+    /// let bytes = "€10.5".as_bytes();
+    /// let result = f64::from_lexical_partial_with_options::<STANDARD>(bytes, &OPTIONS);
+    /// assert_eq!(result, Ok((10.5, 7)));
+    /// ```
+    #[inline(always)]
+    pub const fn ignore_prefix(mut self, ignore_prefix: &'static [u8]) -> Self {
+        self.ignore_prefix = ignore_prefix;
+        self
+    }
+
+    /// Set if the Unicode minus sign is accepted as the exponent sign.
+    ///
+    /// If set, `−` (U+2212), encoded as UTF-8, is parsed as a negative
+    /// exponent sign, the same as `-`, as is common in scientific text. The
+    /// plus-minus sign, `±`, is not accepted, since it does not determine
+    /// the value. This requires the `unicode` feature. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "unicode")] {
+    /// use lexical_parse_float::{FromLexicalWithOptions, Options};
+    /// use lexical_parse_float::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .unicode_exponent_sign(true)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.unicode_exponent_sign(), true);
+    /// let bytes = "1.5e−3".as_bytes();
+    /// assert_eq!(f64::from_lexical_with_options::<STANDARD>(bytes, &OPTIONS), Ok(0.0015));
+    /// # }
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "unicode")]
+    pub const fn unicode_exponent_sign(mut self, unicode_exponent_sign: bool) -> Self {
+        self.unicode_exponent_sign = unicode_exponent_sign;
+        self
+    }
+
+    /// Set if the middle dot is accepted as the decimal point.
+    ///
+    /// If set, `·` (U+00B7), encoded as UTF-8, is parsed as a decimal point,
+    /// in addition to [`decimal_point`][Self::decimal_point], as is used in
+    /// some typographic conventions. Both bytes of the middle dot are
+    /// counted in the number of bytes processed. This requires the `unicode`
+    /// feature. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "unicode")] {
+    /// use lexical_parse_float::{FromLexicalWithOptions, Options};
+    /// use lexical_parse_float::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .middle_dot_decimal_point(true)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.middle_dot_decimal_point(), true);
+    /// let bytes = "3·14".as_bytes();
+    /// assert_eq!(f64::from_lexical_with_options::<STANDARD>(bytes, &OPTIONS), Ok(3.14));
+    /// # }
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "unicode")]
+    pub const fn middle_dot_decimal_point(mut self, middle_dot_decimal_point: bool) -> Self {
+        self.middle_dot_decimal_point = middle_dot_decimal_point;
+        self
+    }
+
+    /// Set if the NaN strings must match in exact casing.
+    ///
+    /// This applies to [`nan_string`], [`snan_string`], and [`qnan_string`],
+    /// and is independent of the casing of the infinity strings. Defaults to
+    /// [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::{FromLexicalWithOptions, Options};
+    /// use lexical_parse_float::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .case_sensitive_nan(true)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.case_sensitive_nan(), true);
+    /// assert!(f64::from_lexical_with_options::<STANDARD>(b"NaN", &OPTIONS).unwrap().is_nan());
+    /// assert!(f64::from_lexical_with_options::<STANDARD>(b"nan", &OPTIONS).is_err());
+    /// ```
+    ///
+    /// [`nan_string`]: Self::nan_string
+    /// [`snan_string`]: Self::snan_string
+    /// [`qnan_string`]: Self::qnan_string
+    #[inline(always)]
+    pub const fn case_sensitive_nan(mut self, case_sensitive_nan: bool) -> Self {
+        self.case_sensitive_nan = case_sensitive_nan;
+        self
+    }
+
+    /// Set if the infinity strings must match in exact casing.
+    ///
+    /// This applies to [`inf_string`] and [`infinity_string`], and is
+    /// independent of the casing of the NaN strings. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::{FromLexicalWithOptions, Options};
+    /// use lexical_parse_float::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .infinity_string(Some(b"Infinity"))
+    ///     .case_sensitive_inf(true)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.case_sensitive_inf(), true);
+    /// let result = f64::from_lexical_with_options::<STANDARD>(b"Infinity", &OPTIONS);
+    /// assert_eq!(result, Ok(f64::INFINITY));
+    /// assert!(f64::from_lexical_with_options::<STANDARD>(b"infinity", &OPTIONS).is_err());
+    /// ```
+    ///
+    /// [`inf_string`]: Self::inf_string
+    /// [`infinity_string`]: Self::infinity_string
+    #[inline(always)]
+    pub const fn case_sensitive_inf(mut self, case_sensitive_inf: bool) -> Self {
+        self.case_sensitive_inf = case_sensitive_inf;
+        self
+    }
+
+    /// Set the suffixes for the magnitude of the value.
+    ///
+    /// Each suffix is an ASCII letter paired with the power of ten, up to
+    /// `19`, that the parsed value is multiplied by, such as `B` for
+    /// billions in financial text. For decimal formats, the power is added
+    /// to the exponent before rounding, so `1.5B` parses the same as
+    /// `1.5e9`. A suffix of the exponent character
+    /// is invalid. Unrecognized suffixes are invalid digits, unless parsing
+    /// a partial number. See [`MAGNITUDE_WORDS`] for the common suffixes.
+    /// Defaults to no suffixes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::{FromLexicalWithOptions, Options};
+    /// use lexical_parse_float::format::STANDARD;
+    /// use lexical_parse_float::options::MAGNITUDE_WORDS;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .magnitude_words(MAGNITUDE_WORDS)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.magnitude_words(), MAGNITUDE_WORDS);
+    /// assert_eq!(f64::from_lexical_with_options::<STANDARD>(b"1.5B", &OPTIONS), Ok(1.5e9));
+    /// assert_eq!(f64::from_lexical_with_options::<STANDARD>(b"2t", &OPTIONS), Ok(2e12));
+    /// ```
+    ///
+    /// [`MAGNITUDE_WORDS`]: crate::options::MAGNITUDE_WORDS
+    #[inline(always)]
+    pub const fn magnitude_words(mut self, magnitude_words: &'static [(u8, u8)]) -> Self {
+        self.magnitude_words = magnitude_words;
+        self
+    }
+
+    /// Set if the magnitude words must match in exact casing.
+    ///
+    /// Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::{FromLexicalWithOptions, Options};
+    /// use lexical_parse_float::format::STANDARD;
+    /// use lexical_parse_float::options::MAGNITUDE_WORDS;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .magnitude_words(MAGNITUDE_WORDS)
+    ///     .case_sensitive_magnitude_words(true)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.case_sensitive_magnitude_words(), true);
+    /// assert_eq!(f64::from_lexical_with_options::<STANDARD>(b"3M", &OPTIONS), Ok(3e6));
+    /// assert!(f64::from_lexical_with_options::<STANDARD>(b"3m", &OPTIONS).is_err());
+    /// ```
+    #[inline(always)]
+    pub const fn case_sensitive_magnitude_words(
+        mut self,
+        case_sensitive_magnitude_words: bool,
+    ) -> Self {
+        self.case_sensitive_magnitude_words = case_sensitive_magnitude_words;
+        self
+    }
+
+    /// Set if a magnitude word is required after the number.
+    ///
+    /// This requires [`magnitude_words`] to be set. If the magnitude word
+    /// is missing, parsing fails with [`Empty`] at the end of the number, or
+    /// [`InvalidDigit`] at an unrecognized suffix. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::{Error, FromLexicalWithOptions, Options};
+    /// use lexical_parse_float::format::STANDARD;
+    /// use lexical_parse_float::options::MAGNITUDE_WORDS;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .magnitude_words(MAGNITUDE_WORDS)
+    ///     .required_magnitude_word(true)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.required_magnitude_word(), true);
+    /// assert_eq!(f64::from_lexical_with_options::<STANDARD>(b"2T", &OPTIONS), Ok(2e12));
+    /// let result = f64::from_lexical_with_options::<STANDARD>(b"2", &OPTIONS);
+    /// assert_eq!(result, Err(Error::Empty(1)));
+    /// ```
+    ///
+    /// [`magnitude_words`]: Self::magnitude_words
+    /// [`Empty`]: Error::Empty
+    /// [`InvalidDigit`]: Error::InvalidDigit
+    #[inline(always)]
+    pub const fn required_magnitude_word(mut self, required_magnitude_word: bool) -> Self {
+        self.required_magnitude_word = required_magnitude_word;
+        self
+    }
+
+    /// Set if fractions with redundant trailing zeros are rejected.
+    ///
+    /// This requires the minimal representation of the fraction, so
+    /// `1.5` is valid but `1.50` fails with [`InvalidTrailingZeros`] at
+    /// the first trailing zero. Use [`allow_zero_fraction`] to also accept
+    /// a single `.0`. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::{Error, FromLexicalWithOptions, Options};
+    /// use lexical_parse_float::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .no_trailing_fraction_zeros(true)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.no_trailing_fraction_zeros(), true);
+    /// assert_eq!(f64::from_lexical_with_options::<STANDARD>(b"1.5", &OPTIONS), Ok(1.5));
+    /// let result = f64::from_lexical_with_options::<STANDARD>(b"1.50", &OPTIONS);
+    /// assert_eq!(result, Err(Error::InvalidTrailingZeros(3)));
+    /// ```
+    ///
+    /// [`InvalidTrailingZeros`]: Error::InvalidTrailingZeros
+    /// [`allow_zero_fraction`]: Self::allow_zero_fraction
+    #[inline(always)]
+    pub const fn no_trailing_fraction_zeros(mut self, no_trailing_fraction_zeros: bool) -> Self {
+        self.no_trailing_fraction_zeros = no_trailing_fraction_zeros;
+        self
+    }
+
+    /// Set if a fraction of a single zero is allowed with trailing zeros
+    /// disabled.
     ///
-    /// The first character must start with `I` or `i` and all characters must
-    /// be valid ASCII letters (`A-Z` or `a-z`). If set to `None`, then parsing
-    /// [`Infinity`][f64::INFINITY] returns an error. Defaults to `infinity`.
+    /// This only has an effect with [`no_trailing_fraction_zeros`], and
+    /// accepts `1.0`, but not `1.00`. Defaults to [`false`].
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use lexical_parse_float::Options;
+    /// use lexical_parse_float::{Error, FromLexicalWithOptions, Options};
+    /// use lexical_parse_float::format::STANDARD;
     ///
     /// const OPTIONS: Options = Options::builder()
-    ///     .infinity_string(Some(b"Infinity"))
+    ///     .no_trailing_fraction_zeros(true)
+    ///     .allow_zero_fraction(true)
     ///     .build_strict();
-    /// assert_eq!(OPTIONS.infinity_string(), Some(b"Infinity".as_ref()));
+    /// assert_eq!(OPTIONS.allow_zero_fraction(), true);
+    /// assert_eq!(f64::from_lexical_with_options::<STANDARD>(b"1.0", &OPTIONS), Ok(1.0));
+    /// let result = f64::from_lexical_with_options::<STANDARD>(b"1.00", &OPTIONS);
+    /// assert_eq!(result, Err(Error::InvalidTrailingZeros(2)));
     /// ```
     ///
-    /// Panics
-    ///
-    /// Setting a value with more than 50 elements or one that is shorter than
-    /// [`inf_string`] will panic at runtime. You should always build the format
-    /// using [`build_strict`] or checking [`is_valid`] prior to
-    /// using the format, to avoid unexpected panics.
-    ///
-    /// [`inf_string`]: Self::inf_string
-    /// [`build_strict`]: Self::build_strict
-    /// [`is_valid`]: Self::is_valid
-    #[must_use]
+    /// [`no_trailing_fraction_zeros`]: Self::no_trailing_fraction_zeros
     #[inline(always)]
-    pub const fn infinity_string(mut self, infinity_string: Option<&'static [u8]>) -> Self {
-        self.infinity_string = infinity_string;
+    pub const fn allow_zero_fraction(mut self, allow_zero_fraction: bool) -> Self {
+        self.allow_zero_fraction = allow_zero_fraction;
         self
     }
 
@@ -548,6 +1399,16 @@ impl OptionsBuilder {
         }
     }
 
+    /// Determine if [`snan_string`] and [`qnan_string`] are valid.
+    ///
+    /// [`snan_string`]: Self::snan_string
+    /// [`qnan_string`]: Self::qnan_string
+    #[doc(hidden)]
+    #[inline(always)]
+    pub const fn nan_kind_str_is_valid(&self) -> bool {
+        nan_kind_is_valid(self.snan_string) && nan_kind_is_valid(self.qnan_string)
+    }
+
     /// Determine if [`inf_string`] is valid.
     ///
     /// [`inf_string`]: Self::inf_string
@@ -605,6 +1466,40 @@ impl OptionsBuilder {
         }
     }
 
+    /// Determine if [`magnitude_words`] is valid.
+    ///
+    /// [`magnitude_words`]: Self::magnitude_words
+    #[doc(hidden)]
+    #[inline(always)]
+    pub const fn magnitude_words_is_valid(&self) -> bool {
+        let words = self.magnitude_words;
+        if self.required_magnitude_word && words.is_empty() {
+            return false;
+        }
+        let mut index = 0;
+        while index < words.len() {
+            let (suffix, exponent) = words[index];
+            if !suffix.is_ascii_alphabetic() ||
+                exponent > 19 ||
+                suffix.eq_ignore_ascii_case(&self.exponent)
+            {
+                return false;
+            }
+            let mut other = 0;
+            while other < index {
+                let previous = words[other].0;
+                if previous == suffix ||
+                    (!self.case_sensitive_magnitude_words && previous.eq_ignore_ascii_case(&suffix))
+                {
+                    return false;
+                }
+                other += 1;
+            }
+            index += 1;
+        }
+        true
+    }
+
     /// Check if the builder state is valid.
     #[inline(always)]
     #[allow(clippy::if_same_then_else, clippy::needless_bool)] // reason = "more idiomatic"
@@ -615,10 +1510,16 @@ impl OptionsBuilder {
             false
         } else if !self.nan_str_is_valid() {
             false
+        } else if !self.nan_kind_str_is_valid() {
+            false
         } else if !self.inf_str_is_valid() {
             false
         } else if !self.infinity_string_is_valid() {
             false
+        } else if !self.magnitude_words_is_valid() {
+            false
+        } else if !is_valid_prefix_slice(self.ignore_prefix) {
+            false
         } else {
             true
         }
@@ -641,11 +1542,27 @@ impl OptionsBuilder {
     pub const fn build_unchecked(&self) -> Options {
         Options {
             lossy: self.lossy,
+            max_slow_digits: self.max_slow_digits,
             exponent: self.exponent,
             decimal_point: self.decimal_point,
             nan_string: self.nan_string,
+            snan_string: self.snan_string,
+            qnan_string: self.qnan_string,
             inf_string: self.inf_string,
             infinity_string: self.infinity_string,
+            allow_lone_decimal_point: self.allow_lone_decimal_point,
+            skip_bom: self.skip_bom,
+            skip_unicode_whitespace: self.skip_unicode_whitespace,
+            ignore_prefix: self.ignore_prefix,
+            unicode_exponent_sign: self.unicode_exponent_sign,
+            middle_dot_decimal_point: self.middle_dot_decimal_point,
+            case_sensitive_nan: self.case_sensitive_nan,
+            case_sensitive_inf: self.case_sensitive_inf,
+            magnitude_words: self.magnitude_words,
+            case_sensitive_magnitude_words: self.case_sensitive_magnitude_words,
+            required_magnitude_word: self.required_magnitude_word,
+            no_trailing_fraction_zeros: self.no_trailing_fraction_zeros,
+            allow_zero_fraction: self.allow_zero_fraction,
         }
     }
 
@@ -688,6 +1605,16 @@ impl OptionsBuilder {
             }
         }
 
+        if self.snan_string.is_some() || self.qnan_string.is_some() {
+            let snan = unwrap_str(self.snan_string);
+            let qnan = unwrap_str(self.qnan_string);
+            if snan.len() > MAX_SPECIAL_STRING_LENGTH || qnan.len() > MAX_SPECIAL_STRING_LENGTH {
+                return Err(Error::NanStringTooLong);
+            } else if !self.nan_kind_str_is_valid() {
+                return Err(Error::InvalidNanString);
+            }
+        }
+
         if self.inf_string.is_some() && self.infinity_string.is_none() {
             return Err(Error::InfinityStringTooShort);
         }
@@ -717,6 +1644,12 @@ impl OptionsBuilder {
             }
         }
 
+        if !self.magnitude_words_is_valid() {
+            return Err(Error::InvalidMagnitudeWords);
+        } else if !is_valid_prefix_slice(self.ignore_prefix) {
+            return Err(Error::InvalidIgnorePrefix);
+        }
+
         Ok(self.build_unchecked())
     }
 }
@@ -760,16 +1693,48 @@ pub struct Options {
     /// Disable the use of arbitrary-precision arithmetic, and always
     /// return the results from the fast or intermediate path algorithms.
     lossy: bool,
+    /// Maximum number of significant digits used by the slow algorithm.
+    max_slow_digits: Option<NonZeroUsize>,
     /// Character to designate the exponent component of a float.
     exponent: u8,
     /// Character to separate the integer from the fraction components.
     decimal_point: u8,
     /// String representation of Not A Number, aka `NaN`.
     nan_string: Option<&'static [u8]>,
+    /// String representation of a signaling `NaN`.
+    snan_string: Option<&'static [u8]>,
+    /// String representation of a quiet `NaN`.
+    qnan_string: Option<&'static [u8]>,
     /// Short string representation of `Infinity`.
     inf_string: Option<&'static [u8]>,
     /// Long string representation of `Infinity`.
     infinity_string: Option<&'static [u8]>,
+    /// Parse a decimal point without any digits, such as `.`, as zero.
+    allow_lone_decimal_point: bool,
+    /// Skip a leading UTF-8 byte order mark before parsing.
+    skip_bom: bool,
+    /// Skip leading Unicode whitespace before parsing.
+    skip_unicode_whitespace: bool,
+    /// Literal prefix, such as a currency symbol, to skip before parsing.
+    ignore_prefix: &'static [u8],
+    /// Accept the Unicode minus sign, `−`, as the exponent sign.
+    unicode_exponent_sign: bool,
+    /// Accept the middle dot, `·`, as the decimal point.
+    middle_dot_decimal_point: bool,
+    /// If the NaN strings must match in exact casing.
+    case_sensitive_nan: bool,
+    /// If the infinity strings must match in exact casing.
+    case_sensitive_inf: bool,
+    /// Suffixes for the magnitude of the value, and their power of ten.
+    magnitude_words: &'static [(u8, u8)],
+    /// If the magnitude words must match in exact casing.
+    case_sensitive_magnitude_words: bool,
+    /// If a magnitude word is required after the number.
+    required_magnitude_word: bool,
+    /// Reject fractions that end with redundant zeros, such as `1.50`.
+    no_trailing_fraction_zeros: bool,
+    /// Allow a fraction of a single zero, such as `1.0`, with trailing zeros disabled.
+    allow_zero_fraction: bool,
 }
 
 impl Options {
@@ -823,6 +1788,24 @@ impl Options {
         self.lossy
     }
 
+    /// Get the maximum number of significant digits used by the slow
+    /// algorithm.
+    ///
+    /// Defaults to [`None`], which uses every digit that can affect the
+    /// result.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::options::Options;
+    ///
+    /// assert_eq!(Options::new().max_slow_digits(), None);
+    /// ```
+    #[inline(always)]
+    pub const fn max_slow_digits(&self) -> Option<NonZeroUsize> {
+        self.max_slow_digits
+    }
+
     /// Get the character to designate the exponent component of a float.
     ///
     /// Any non-control character is valid, but `\t` to `\r` are also valid.
@@ -875,6 +1858,44 @@ impl Options {
         self.nan_string
     }
 
+    /// Get the string representation for a signaling `NaN`.
+    ///
+    /// All characters must be valid ASCII letters (`A-Z` or `a-z`). If set,
+    /// this is parsed as a signaling NaN rather than the quiet
+    /// [`NaN`][f64::NAN]. Defaults to `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::options::Options;
+    ///
+    /// assert_eq!(Options::new().snan_string(), None);
+    /// ```
+    #[inline(always)]
+    pub const fn snan_string(&self) -> Option<&'static [u8]> {
+        self.snan_string
+    }
+
+    /// Get the string representation for a quiet `NaN`.
+    ///
+    /// All characters must be valid ASCII letters (`A-Z` or `a-z`). If set,
+    /// this is parsed as a quiet [`NaN`][f64::NAN], in addition to
+    /// [`nan_string`]. Defaults to `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::options::Options;
+    ///
+    /// assert_eq!(Options::new().qnan_string(), None);
+    /// ```
+    ///
+    /// [`nan_string`]: Self::nan_string
+    #[inline(always)]
+    pub const fn qnan_string(&self) -> Option<&'static [u8]> {
+        self.qnan_string
+    }
+
     /// Get the short string representation for `Infinity`.
     ///
     /// The first character must start with `I` or `i` and all characters must
@@ -911,6 +1932,241 @@ impl Options {
         self.infinity_string
     }
 
+    /// Get if a decimal point without any digits parses as zero.
+    ///
+    /// If enabled, a lone decimal point, such as `.` or `-.`, is parsed as
+    /// zero rather than returning [`EmptyMantissa`]. A decimal point with an
+    /// exponent but no digits, such as `.e5`, is always an error. Defaults
+    /// to [`false`].
+    ///
+    /// [`EmptyMantissa`]: lexical_util::error::Error::EmptyMantissa
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::options::Options;
+    ///
+    /// assert_eq!(Options::new().allow_lone_decimal_point(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn allow_lone_decimal_point(&self) -> bool {
+        self.allow_lone_decimal_point
+    }
+
+    /// Get if a leading UTF-8 byte order mark (`EF BB BF`) is skipped.
+    ///
+    /// The bytes skipped are included in the number of bytes processed.
+    /// Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::options::Options;
+    ///
+    /// assert_eq!(Options::new().skip_bom(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn skip_bom(&self) -> bool {
+        self.skip_bom
+    }
+
+    /// Get if leading Unicode whitespace is skipped.
+    ///
+    /// This skips any characters with the `White_Space` property encoded
+    /// as UTF-8, after any byte order mark, and the bytes skipped are
+    /// included in the number of bytes processed. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::options::Options;
+    ///
+    /// assert_eq!(Options::new().skip_unicode_whitespace(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn skip_unicode_whitespace(&self) -> bool {
+        self.skip_unicode_whitespace
+    }
+
+    /// Get the literal prefix skipped before parsing.
+    ///
+    /// If the input starts with the prefix, after any byte order mark or
+    /// whitespace, the prefix is skipped before parsing the sign and digits,
+    /// and the bytes skipped are included in the number of bytes processed.
+    /// The prefix is matched as a literal byte sequence and is optional.
+    /// Defaults to an empty prefix, which skips nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::options::Options;
+    ///
+    /// assert_eq!(Options::new().ignore_prefix(), b"");
+    /// ```
+    #[inline(always)]
+    pub const fn ignore_prefix(&self) -> &'static [u8] {
+        self.ignore_prefix
+    }
+
+    /// Get if the Unicode minus sign is accepted as the exponent sign.
+    ///
+    /// If set, `−` (U+2212), encoded as UTF-8, is parsed as a negative
+    /// exponent sign, the same as `-`. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::options::Options;
+    ///
+    /// assert_eq!(Options::new().unicode_exponent_sign(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn unicode_exponent_sign(&self) -> bool {
+        self.unicode_exponent_sign
+    }
+
+    /// Get if the middle dot is accepted as the decimal point.
+    ///
+    /// If set, `·` (U+00B7), encoded as UTF-8, is parsed as a decimal point,
+    /// in addition to [`decimal_point`][Self::decimal_point]. Defaults to
+    /// [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::options::Options;
+    ///
+    /// assert_eq!(Options::new().middle_dot_decimal_point(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn middle_dot_decimal_point(&self) -> bool {
+        self.middle_dot_decimal_point
+    }
+
+    /// Get if the NaN strings must match in exact casing.
+    ///
+    /// This applies to [`nan_string`], [`snan_string`], and [`qnan_string`],
+    /// and is independent of the casing of the infinity strings. Defaults to
+    /// [`false`].
+    ///
+    /// [`nan_string`]: Self::nan_string
+    /// [`snan_string`]: Self::snan_string
+    /// [`qnan_string`]: Self::qnan_string
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::options::Options;
+    ///
+    /// assert_eq!(Options::new().case_sensitive_nan(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn case_sensitive_nan(&self) -> bool {
+        self.case_sensitive_nan
+    }
+
+    /// Get if the infinity strings must match in exact casing.
+    ///
+    /// This applies to [`inf_string`] and [`infinity_string`], and is
+    /// independent of the casing of the NaN strings. Defaults to [`false`].
+    ///
+    /// [`inf_string`]: Self::inf_string
+    /// [`infinity_string`]: Self::infinity_string
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::options::Options;
+    ///
+    /// assert_eq!(Options::new().case_sensitive_inf(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn case_sensitive_inf(&self) -> bool {
+        self.case_sensitive_inf
+    }
+
+    /// Get the suffixes for the magnitude of the value.
+    ///
+    /// Each suffix is paired with the power of ten it scales the value by.
+    /// Defaults to no suffixes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::options::Options;
+    ///
+    /// assert_eq!(Options::new().magnitude_words(), &[]);
+    /// ```
+    #[inline(always)]
+    pub const fn magnitude_words(&self) -> &'static [(u8, u8)] {
+        self.magnitude_words
+    }
+
+    /// Get if the magnitude words must match in exact casing.
+    ///
+    /// Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::options::Options;
+    ///
+    /// assert_eq!(Options::new().case_sensitive_magnitude_words(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn case_sensitive_magnitude_words(&self) -> bool {
+        self.case_sensitive_magnitude_words
+    }
+
+    /// Get if a magnitude word is required after the number.
+    ///
+    /// Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::options::Options;
+    ///
+    /// assert_eq!(Options::new().required_magnitude_word(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn required_magnitude_word(&self) -> bool {
+        self.required_magnitude_word
+    }
+
+    /// Get if fractions with redundant trailing zeros are rejected.
+    ///
+    /// Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::options::Options;
+    ///
+    /// assert_eq!(Options::new().no_trailing_fraction_zeros(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn no_trailing_fraction_zeros(&self) -> bool {
+        self.no_trailing_fraction_zeros
+    }
+
+    /// Get if a fraction of a single zero is allowed with trailing zeros
+    /// disabled.
+    ///
+    /// Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_float::options::Options;
+    ///
+    /// assert_eq!(Options::new().allow_zero_fraction(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn allow_zero_fraction(&self) -> bool {
+        self.allow_zero_fraction
+    }
+
     // SETTERS
 
     /// Set if we disable the use of arbitrary-precision arithmetic.
@@ -970,11 +2226,27 @@ impl Options {
     pub const fn rebuild(&self) -> OptionsBuilder {
         OptionsBuilder {
             lossy: self.lossy,
+            max_slow_digits: self.max_slow_digits,
             exponent: self.exponent,
             decimal_point: self.decimal_point,
             nan_string: self.nan_string,
+            snan_string: self.snan_string,
+            qnan_string: self.qnan_string,
             inf_string: self.inf_string,
             infinity_string: self.infinity_string,
+            allow_lone_decimal_point: self.allow_lone_decimal_point,
+            skip_bom: self.skip_bom,
+            skip_unicode_whitespace: self.skip_unicode_whitespace,
+            ignore_prefix: self.ignore_prefix,
+            unicode_exponent_sign: self.unicode_exponent_sign,
+            middle_dot_decimal_point: self.middle_dot_decimal_point,
+            case_sensitive_nan: self.case_sensitive_nan,
+            case_sensitive_inf: self.case_sensitive_inf,
+            magnitude_words: self.magnitude_words,
+            case_sensitive_magnitude_words: self.case_sensitive_magnitude_words,
+            required_magnitude_word: self.required_magnitude_word,
+            no_trailing_fraction_zeros: self.no_trailing_fraction_zeros,
+            allow_zero_fraction: self.allow_zero_fraction,
         }
     }
 }
@@ -993,6 +2265,65 @@ impl ParseOptions for Options {
     }
 }
 
+// THREAD-LOCAL DEFAULTS
+// ---------------------
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static DEFAULT_OPTIONS: RefCell<Options> = const { RefCell::new(Options::new()) };
+}
+
+/// Ambient, per-thread default options for parsing floats.
+///
+/// This allows configuring the options once and then using them from
+/// code that does not have access to the [`Options`], without threading
+/// them through every call. Each thread starts with [`Options::new`].
+///
+/// The defaults are used by [`from_lexical_default`] and
+/// [`from_lexical_partial_default`]. [`from_lexical`] always uses the
+/// standard options and never reads the thread-local defaults, so it is
+/// not slowed down by the lookup.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::{from_lexical_default, FromLexical, Options, ThreadLocalOptions};
+///
+/// let options = Options::builder().decimal_point(b',').build_strict();
+/// ThreadLocalOptions::set_default(options);
+/// assert_eq!(from_lexical_default::<f64>(b"1,5"), Ok(1.5));
+/// assert!(f64::from_lexical(b"1,5").is_err());
+/// ```
+///
+/// [`from_lexical`]: crate::FromLexical::from_lexical
+/// [`from_lexical_default`]: crate::from_lexical_default
+/// [`from_lexical_partial_default`]: crate::from_lexical_partial_default
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThreadLocalOptions;
+
+#[cfg(feature = "std")]
+impl ThreadLocalOptions {
+    /// Set the default options for the current thread.
+    ///
+    /// Returns the previous default options, which can be used to restore
+    /// them later.
+    #[inline]
+    pub fn set_default(options: Options) -> Options {
+        DEFAULT_OPTIONS.with(|default| default.replace(options))
+    }
+
+    /// Call `f` with the default options for the current thread.
+    ///
+    /// The options are copied before calling `f`, so `f` may change the
+    /// defaults without affecting the options it was given.
+    #[inline]
+    pub fn with_default<R, F: FnOnce(&Options) -> R>(f: F) -> R {
+        let options = DEFAULT_OPTIONS.with(|default| default.borrow().clone());
+        f(&options)
+    }
+}
+
 /// Unwrap `Option` as a const fn.
 #[inline(always)]
 const fn unwrap_str(option: Option<&'static [u8]>) -> &'static [u8] {
@@ -1002,6 +2333,17 @@ const fn unwrap_str(option: Option<&'static [u8]>) -> &'static [u8] {
     }
 }
 
+/// Determine if a signaling or quiet `NaN` string is valid.
+#[inline(always)]
+const fn nan_kind_is_valid(option: Option<&'static [u8]>) -> bool {
+    match option {
+        Some(nan) => {
+            !nan.is_empty() && nan.len() <= MAX_SPECIAL_STRING_LENGTH && is_valid_letter_slice(nan)
+        },
+        None => true,
+    }
+}
+
 // PRE-DEFINED CONSTANTS
 // ---------------------
 
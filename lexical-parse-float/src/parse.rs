@@ -9,7 +9,6 @@
 
 #![doc(hidden)]
 
-#[cfg(not(feature = "compact"))]
 use lexical_parse_integer::algorithm;
 #[cfg(feature = "f16")]
 use lexical_util::bf16::bf16;
@@ -32,7 +31,7 @@ use crate::lemire::lemire;
 use crate::number::Number;
 use crate::options::Options;
 use crate::shared;
-use crate::slow::slow_radix;
+use crate::slow::{is_exact, slow_radix};
 
 // API
 // ---
@@ -109,6 +108,41 @@ pub trait ParseFloat: LemireFloat {
         check_radix!(FORMAT);
         fast_path_partial::<Self, FORMAT>(bytes, options)
     }
+
+    /// Forward complete parser parameters to the backend, and count the
+    /// number of significant digits that exceed the precision of the float.
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn parse_complete_digits_dropped<const FORMAT: u128>(
+        bytes: &[u8],
+        options: &Options,
+    ) -> Result<(Self, u32)> {
+        check_radix!(FORMAT);
+        let (float, digits) = parse_complete_significant::<Self, FORMAT>(bytes, options)?;
+        Ok((float, digits.saturating_sub(max_significant_digits::<Self, FORMAT>())))
+    }
+
+    /// Forward complete parser parameters to the backend, and determine if
+    /// the parsed float is exactly equal to the input.
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn parse_complete_exact<const FORMAT: u128>(
+        bytes: &[u8],
+        options: &Options,
+    ) -> Result<(Self, bool)> {
+        check_radix!(FORMAT);
+        parse_complete_exact::<Self, FORMAT>(bytes, options)
+    }
+
+    /// Forward complete parser parameters to the backend, and scale the
+    /// value by a power of ten before rounding.
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn parse_complete_scaled<const FORMAT: u128>(
+        bytes: &[u8],
+        options: &Options,
+        exponent: i64,
+    ) -> Result<Self> {
+        check_radix!(FORMAT);
+        parse_complete_scaled::<Self, FORMAT>(bytes, options, exponent)
+    }
 }
 
 macro_rules! parse_float_impl {
@@ -152,6 +186,35 @@ macro_rules! parse_float_as_f32 {
                 let (float, count) = fast_path_partial::<f32, FORMAT>(bytes, options)?;
                 Ok((Self::from_f32(float), count))
             }
+
+            #[cfg_attr(not(feature = "compact"), inline(always))]
+            fn parse_complete_digits_dropped<const FORMAT: u128>(bytes: &[u8], options: &Options)
+                -> Result<(Self, u32)>
+            {
+                let (float, digits) = parse_complete_significant::<f32, FORMAT>(bytes, options)?;
+                let dropped = digits.saturating_sub(max_significant_digits::<Self, FORMAT>());
+                Ok((Self::from_f32(float), dropped))
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline(always))]
+            fn parse_complete_exact<const FORMAT: u128>(bytes: &[u8], options: &Options)
+                -> Result<(Self, bool)>
+            {
+                // Also need to check the narrowing conversion was exact.
+                let (float, is_exact) = parse_complete_exact::<f32, FORMAT>(bytes, options)?;
+                let value = Self::from_f32(float);
+                Ok((value, is_exact && (value.as_f32() == float || float.is_nan())))
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline(always))]
+            fn parse_complete_scaled<const FORMAT: u128>(
+                bytes: &[u8],
+                options: &Options,
+                exponent: i64,
+            ) -> Result<Self>
+            {
+                Ok(Self::from_f32(parse_complete_scaled::<f32, FORMAT>(bytes, options, exponent)?))
+            }
         }
     )*)
 }
@@ -185,15 +248,94 @@ pub fn parse_mantissa_sign<const FORMAT: u128>(byte: &mut Bytes<'_, FORMAT>) ->
 /// Parse the sign from the leading digits.
 #[cfg_attr(not(feature = "compact"), inline(always))]
 pub fn parse_exponent_sign<const FORMAT: u128>(byte: &mut Bytes<'_, FORMAT>) -> Result<bool> {
-    let format = NumberFormat::<{ FORMAT }> {};
-    parse_sign!(
-        byte,
-        true,
-        format.no_positive_exponent_sign(),
-        format.required_exponent_sign(),
-        InvalidPositiveExponentSign,
-        MissingExponentSign
-    )
+    algorithm::parse_exponent_sign(byte)
+}
+
+/// The UTF-8 encoding of the Unicode minus sign, `−` (U+2212).
+#[cfg(feature = "unicode")]
+const UNICODE_MINUS: &[u8] = "\u{2212}".as_bytes();
+
+/// Parse the exponent sign, accepting the Unicode minus sign if enabled.
+///
+/// The UTF-8 encoded `−` is translated to `-` before the exponent digits.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+#[cfg_attr(not(feature = "unicode"), allow(unused_variables))]
+pub fn parse_exponent_sign_with_options<const FORMAT: u128>(
+    byte: &mut Bytes<'_, FORMAT>,
+    options: &Options,
+) -> Result<bool> {
+    #[cfg(feature = "unicode")]
+    if options.unicode_exponent_sign() && byte.as_slice().starts_with(UNICODE_MINUS) {
+        // SAFETY: safe since the buffer starts with the minus sign.
+        unsafe { byte.step_by_unchecked(UNICODE_MINUS.len()) };
+        return Ok(true);
+    }
+    parse_exponent_sign(byte)
+}
+
+/// The UTF-8 encoding of the middle dot, `·` (U+00B7).
+#[cfg(feature = "unicode")]
+const MIDDLE_DOT: &[u8] = "\u{b7}".as_bytes();
+
+/// Skip the decimal point, accepting the middle dot if enabled.
+///
+/// Returns if a decimal point was skipped.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+pub fn skip_decimal_point<const FORMAT: u128>(
+    byte: &mut Bytes<'_, FORMAT>,
+    options: &Options,
+) -> bool {
+    #[cfg(feature = "unicode")]
+    if options.middle_dot_decimal_point() && byte.as_slice().starts_with(MIDDLE_DOT) {
+        // SAFETY: safe since the buffer starts with the middle dot.
+        unsafe { byte.step_by_unchecked(MIDDLE_DOT.len()) };
+        return true;
+    }
+    let has_decimal = byte.first_is_cased(options.decimal_point());
+    if has_decimal {
+        // SAFETY: byte cannot be empty due to `first_is`
+        unsafe { byte.step_unchecked() };
+    }
+    has_decimal
+}
+
+/// Find the first redundant trailing zero in the fraction digits.
+///
+/// `byte` must start at the fraction, and `count` is the number of
+/// fraction digits. Returns the index of the zero, or [`None`] if the
+/// fraction is minimal or trailing zeros are allowed.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn trailing_fraction_zeros<const FORMAT: u128>(
+    mut byte: Bytes<'_, FORMAT>,
+    count: usize,
+    options: &Options,
+) -> Option<usize> {
+    if !options.no_trailing_fraction_zeros() || (count == 1 && options.allow_zero_fraction()) {
+        return None;
+    }
+    let mut iter = byte.fraction_iter();
+    let mut index = None;
+    for _ in 0..count {
+        // NOTE: The cursor is just past the digit after we consume it.
+        index = match iter.next() {
+            Some(&b'0') => index.or(Some(iter.cursor() - 1)),
+            _ => None,
+        };
+    }
+    index
+}
+
+/// Get the error for a mantissa without any digits.
+///
+/// A buffer that only contains a sign returns [`Error::EmptyMantissa`],
+/// otherwise, this returns [`Error::Empty`].
+#[inline(always)]
+const fn empty_mantissa_error(bytes: &[u8], index: usize) -> Error {
+    if matches!(bytes, [b'+' | b'-']) {
+        Error::EmptyMantissa(index)
+    } else {
+        Error::Empty(index)
+    }
 }
 
 /// Utility to extract the result and handle any errors from parsing a `Number`.
@@ -254,7 +396,7 @@ pub fn parse_complete<F: LemireFloat, const FORMAT: u128>(
         if NumberFormat::<FORMAT>::REQUIRED_INTEGER_DIGITS
             || NumberFormat::<FORMAT>::REQUIRED_MANTISSA_DIGITS
         {
-            return Err(Error::Empty(byte.cursor()));
+            return Err(empty_mantissa_error(bytes, byte.cursor()));
         } else {
             return Ok(F::ZERO);
         }
@@ -263,25 +405,119 @@ pub fn parse_complete<F: LemireFloat, const FORMAT: u128>(
     // Parse our a small representation of our number.
     let num: Number<'_> =
         parse_number!(FORMAT, byte, is_negative, options, parse_complete_number, parse_special);
-    // Try the fast-path algorithm.
-    if let Some(value) = num.try_fast_path::<_, FORMAT>() {
-        return Ok(value);
+    Ok(number_to_float::<F, FORMAT>(num, options))
+}
+
+/// Parse a float from bytes using a complete parser, and count the number
+/// of significant digits.
+///
+/// Special values, such as NaN and Infinity, have no significant digits.
+#[inline(always)]
+#[allow(clippy::missing_inline_in_public_items)] // reason = "only public for testing"
+pub fn parse_complete_significant<F: LemireFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<(F, u32)> {
+    let mut byte = bytes.bytes::<{ FORMAT }>();
+    let is_negative = parse_mantissa_sign(&mut byte)?;
+    if byte.integer_iter().is_consumed() {
+        if NumberFormat::<FORMAT>::REQUIRED_INTEGER_DIGITS
+            || NumberFormat::<FORMAT>::REQUIRED_MANTISSA_DIGITS
+        {
+            return Err(empty_mantissa_error(bytes, byte.cursor()));
+        } else {
+            return Ok((F::ZERO, 0));
+        }
     }
-    // Now try the moderate path algorithm.
-    let mut fp = moderate_path::<F, FORMAT>(&num, options.lossy());
 
-    // Unable to correctly round the float using the fast or moderate algorithms.
-    // Fallback to a slower, but always correct algorithm. If we have
-    // lossy, we can't be here.
-    if fp.exp < 0 {
-        debug_assert!(!options.lossy(), "lossy algorithms never use slow algorithms");
-        // Undo the invalid extended float biasing.
-        fp.exp -= shared::INVALID_FP;
-        fp = slow_path::<F, FORMAT>(num, fp);
+    // Parse our a small representation of our number.
+    let num = match parse_complete_number::<FORMAT>(byte.clone(), is_negative, options) {
+        Ok(num) => num,
+        Err(e) => {
+            return match parse_special::<_, FORMAT>(byte, is_negative, options) {
+                Some(value) => Ok((value, 0)),
+                None => Err(e),
+            };
+        },
+    };
+    let digits = significant_digits::<FORMAT>(&num);
+    Ok((number_to_float::<F, FORMAT>(num, options), digits))
+}
+
+/// Parse a float from bytes using a complete parser, and determine if the
+/// parsed float is exactly equal to the input.
+///
+/// Special values, such as NaN and Infinity, are always exact.
+#[inline(always)]
+#[allow(clippy::missing_inline_in_public_items)] // reason = "only public for testing"
+pub fn parse_complete_exact<F: LemireFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<(F, bool)> {
+    let mut byte = bytes.bytes::<{ FORMAT }>();
+    let is_negative = parse_mantissa_sign(&mut byte)?;
+    if byte.integer_iter().is_consumed() {
+        if NumberFormat::<FORMAT>::REQUIRED_INTEGER_DIGITS
+            || NumberFormat::<FORMAT>::REQUIRED_MANTISSA_DIGITS
+        {
+            return Err(empty_mantissa_error(bytes, byte.cursor()));
+        } else {
+            return Ok((F::ZERO, true));
+        }
     }
 
-    // Convert to native float and return result.
-    Ok(to_native!(F, fp, is_negative))
+    // Parse our a small representation of our number.
+    let num = match parse_complete_number::<FORMAT>(byte.clone(), is_negative, options) {
+        Ok(num) => num,
+        Err(e) => {
+            return match parse_special::<_, FORMAT>(byte, is_negative, options) {
+                Some(value) => Ok((value, true)),
+                None => Err(e),
+            };
+        },
+    };
+    let digits = significant_digits::<FORMAT>(&num) as usize;
+    let float = number_to_float::<F, FORMAT>(num, options);
+    Ok((float, is_exact::<F, FORMAT>(num, float, digits)))
+}
+
+/// Parse a float from bytes using a complete parser, and scale it by a
+/// power of ten before rounding.
+///
+/// The power is added to the exponent of the parsed digits, so the value
+/// is correctly rounded. Since the exponent is only a power of ten for
+/// decimal formats, other formats multiply the parsed value instead.
+#[inline(always)]
+#[allow(clippy::missing_inline_in_public_items)] // reason = "only public for testing"
+pub fn parse_complete_scaled<F: LemireFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+    exponent: i64,
+) -> Result<F> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    if format.mantissa_radix() != 10 || format.exponent_base() != 10 {
+        let value = parse_complete::<F, FORMAT>(bytes, options)?;
+        let power = 10u64.pow(exponent as u32);
+        return Ok(F::as_cast(value.as_f64() * power as f64));
+    }
+
+    let mut byte = bytes.bytes::<{ FORMAT }>();
+    let is_negative = parse_mantissa_sign(&mut byte)?;
+    if byte.integer_iter().is_consumed() {
+        if NumberFormat::<FORMAT>::REQUIRED_INTEGER_DIGITS
+            || NumberFormat::<FORMAT>::REQUIRED_MANTISSA_DIGITS
+        {
+            return Err(empty_mantissa_error(bytes, byte.cursor()));
+        } else {
+            return Ok(F::ZERO);
+        }
+    }
+
+    // Parse our a small representation of our number, and scale it.
+    let mut num: Number<'_> =
+        parse_number!(FORMAT, byte, is_negative, options, parse_complete_number, parse_special);
+    num.exponent = num.exponent.saturating_add(exponent);
+    Ok(number_to_float::<F, FORMAT>(num, options))
 }
 
 /// Parse a float using only the fast path as a complete parser.
@@ -297,7 +533,7 @@ pub fn fast_path_complete<F: LemireFloat, const FORMAT: u128>(
         if NumberFormat::<FORMAT>::REQUIRED_INTEGER_DIGITS
             || NumberFormat::<FORMAT>::REQUIRED_MANTISSA_DIGITS
         {
-            return Err(Error::Empty(byte.cursor()));
+            return Err(empty_mantissa_error(bytes, byte.cursor()));
         } else {
             return Ok(F::ZERO);
         }
@@ -322,7 +558,7 @@ pub fn parse_partial<F: LemireFloat, const FORMAT: u128>(
         if NumberFormat::<FORMAT>::REQUIRED_INTEGER_DIGITS
             || NumberFormat::<FORMAT>::REQUIRED_MANTISSA_DIGITS
         {
-            return Err(Error::Empty(byte.cursor()));
+            return Err(empty_mantissa_error(bytes, byte.cursor()));
         } else {
             return Ok((F::ZERO, byte.cursor()));
         }
@@ -337,25 +573,7 @@ pub fn parse_partial<F: LemireFloat, const FORMAT: u128>(
         parse_partial_number,
         parse_partial_special
     );
-    // Try the fast-path algorithm.
-    if let Some(value) = num.try_fast_path::<_, FORMAT>() {
-        return Ok((value, count));
-    }
-    // Now try the moderate path algorithm.
-    let mut fp = moderate_path::<F, FORMAT>(&num, options.lossy());
-
-    // Unable to correctly round the float using the fast or moderate algorithms.
-    // Fallback to a slower, but always correct algorithm. If we have
-    // lossy, we can't be here.
-    if fp.exp < 0 {
-        debug_assert!(!options.lossy(), "lossy algorithms never use slow algorithms");
-        // Undo the invalid extended float biasing.
-        fp.exp -= shared::INVALID_FP;
-        fp = slow_path::<F, FORMAT>(num, fp);
-    }
-
-    // Convert to native float and return result.
-    Ok((to_native!(F, fp, is_negative), count))
+    Ok((number_to_float::<F, FORMAT>(num, options), count))
 }
 
 /// Parse a float using only the fast path as a partial parser.
@@ -371,7 +589,7 @@ pub fn fast_path_partial<F: LemireFloat, const FORMAT: u128>(
         if NumberFormat::<FORMAT>::REQUIRED_INTEGER_DIGITS
             || NumberFormat::<FORMAT>::REQUIRED_MANTISSA_DIGITS
         {
-            return Err(Error::Empty(byte.cursor()));
+            return Err(empty_mantissa_error(bytes, byte.cursor()));
         } else {
             return Ok((F::ZERO, byte.cursor()));
         }
@@ -457,15 +675,18 @@ pub fn moderate_path<F: LemireFloat, const FORMAT: u128>(
 
 /// Invoke the slow path.
 /// At this point, the float string has already been validated.
+///
+/// * `digit_limit` - The maximum number of significant digits to use.
 #[must_use]
 #[inline(always)]
 pub fn slow_path<F: LemireFloat, const FORMAT: u128>(
     num: Number,
     fp: ExtendedFloat80,
+    digit_limit: usize,
 ) -> ExtendedFloat80 {
     #[cfg(not(feature = "power-of-two"))]
     {
-        slow_radix::<F, FORMAT>(num, fp)
+        slow_radix::<F, FORMAT>(num, fp, digit_limit)
     }
 
     #[cfg(feature = "power-of-two")]
@@ -474,7 +695,7 @@ pub fn slow_path<F: LemireFloat, const FORMAT: u128>(
         if is_power_two!(format.mantissa_radix()) {
             slow_binary::<F, FORMAT>(num)
         } else {
-            slow_radix::<F, FORMAT>(num, fp)
+            slow_radix::<F, FORMAT>(num, fp, digit_limit)
         }
     }
 }
@@ -487,16 +708,31 @@ pub fn slow_path<F: LemireFloat, const FORMAT: u128>(
 /// This creates a representation of the float as the
 /// significant digits and the decimal exponent.
 #[cfg_attr(not(feature = "compact"), inline(always))]
+pub fn parse_number<'a, const FORMAT: u128, const IS_PARTIAL: bool>(
+    byte: Bytes<'a, FORMAT>,
+    is_negative: bool,
+    options: &Options,
+) -> Result<(Number<'a>, usize)> {
+    let (number, count, _) = parse_number_syntax::<FORMAT, IS_PARTIAL>(byte, is_negative, options)?;
+    Ok((number, count))
+}
+
+/// Parse a partial, non-special floating point number, and determine if
+/// it has an exponent.
+///
+/// This is shared by parsing and classifying numbers, so both always
+/// accept the same syntax.
+#[cfg_attr(not(feature = "compact"), inline(always))]
 #[allow(unused_mut)] // reason = "used when format is enabled"
 #[allow(clippy::unwrap_used)] // reason = "developer error if we incorrectly assume an overflow"
 #[allow(clippy::collapsible_if)] // reason = "more readable uncollapsed"
 #[allow(clippy::cast_possible_wrap)] // reason = "no hardware supports buffers >= i64::MAX"
 #[allow(clippy::too_many_lines)] // reason = "function is one logical entity"
-pub fn parse_number<'a, const FORMAT: u128, const IS_PARTIAL: bool>(
+fn parse_number_syntax<'a, const FORMAT: u128, const IS_PARTIAL: bool>(
     mut byte: Bytes<'a, FORMAT>,
     is_negative: bool,
     options: &Options,
-) -> Result<(Number<'a>, usize)> {
+) -> Result<(Number<'a>, usize, bool)> {
     //  NOTE:
     //      There are no satisfactory optimizations to reduce the number
     //      of multiplications for very long input strings, but this will
@@ -526,7 +762,6 @@ pub fn parse_number<'a, const FORMAT: u128, const IS_PARTIAL: bool>(
 
     // Config options
     let format = NumberFormat::<{ FORMAT }> {};
-    let decimal_point = options.decimal_point();
     let exponent_character = options.exponent();
     debug_assert!(format.is_valid(), "should have already checked for an invalid number format");
     debug_assert!(!byte.is_buffer_empty(), "should have previously checked for empty input");
@@ -541,19 +776,22 @@ pub fn parse_number<'a, const FORMAT: u128, const IS_PARTIAL: bool>(
     #[cfg(feature = "format")]
     {
         let base_prefix = format.base_prefix();
-        let mut iter = byte.integer_iter();
-        if base_prefix != 0 && iter.read_if_value_cased(b'0').is_some() {
-            // Check to see if the next character is the base prefix.
-            // We must have a format like `0x`, `0d`, `0o`.
-            // NOTE: The check for empty integer digits happens below so
-            // we don't need a redundant check here.
+        let mut prefix = byte.clone();
+        let mut iter = prefix.integer_iter();
+        // Check to see if the next character is the base prefix.
+        // We must have a format like `0x`, `0d`, `0o`. A `0` without the
+        // base prefix is a digit, so only consume it with the prefix.
+        // NOTE: The check for empty integer digits happens below so
+        // we don't need a redundant check here.
+        if base_prefix != 0
+            && iter.read_if_value_cased(b'0').is_some()
+            && iter.read_if_value(base_prefix, format.case_sensitive_base_prefix()).is_some()
+        {
             is_prefix = true;
-            if iter.read_if_value(base_prefix, format.case_sensitive_base_prefix()).is_some()
-                && iter.is_buffer_empty()
-                && format.required_integer_digits()
-            {
+            if iter.is_buffer_empty() && format.required_integer_digits() {
                 return Err(Error::EmptyInteger(iter.cursor()));
             }
+            byte = prefix;
         }
     }
 
@@ -612,10 +850,8 @@ pub fn parse_number<'a, const FORMAT: u128, const IS_PARTIAL: bool>(
     let mut implicit_exponent: i64;
     let int_end = n_digits as i64;
     let mut fraction_digits = None;
-    let has_decimal = byte.first_is_cased(decimal_point);
+    let has_decimal = skip_decimal_point(&mut byte, options);
     if has_decimal {
-        // SAFETY: byte cannot be empty due to `first_is`
-        unsafe { byte.step_unchecked() };
         let before = byte.clone();
         #[cfg(not(feature = "compact"))]
         parse_8digits::<_, FORMAT>(byte.fraction_iter(), &mut mantissa);
@@ -653,6 +889,9 @@ pub fn parse_number<'a, const FORMAT: u128, const IS_PARTIAL: bool>(
         if format.required_fraction_digits() && n_after_dot == 0 {
             return Err(Error::EmptyFraction(byte.cursor()));
         }
+        if let Some(index) = trailing_fraction_zeros(before, n_after_dot, options) {
+            return Err(Error::InvalidTrailingZeros(index));
+        }
     }
 
     // NOTE: Check if we have our exponent **BEFORE** checking if the
@@ -661,8 +900,11 @@ pub fn parse_number<'a, const FORMAT: u128, const IS_PARTIAL: bool>(
         .first_is(exponent_character, format.case_sensitive_exponent() && cfg!(feature = "format"));
 
     // check to see if we have any invalid leading zeros
+    // A lone decimal point, like `.`, is parsed as zero if enabled.
     n_digits += n_after_dot;
+    let is_lone_decimal_point = has_decimal && !has_exponent && options.allow_lone_decimal_point();
     if format.required_mantissa_digits()
+        && !is_lone_decimal_point
         && (n_digits == 0 || (cfg!(feature = "format") && byte.current_count() == 0))
     {
         let any_digits = start.clone().integer_iter().peek().is_some();
@@ -699,7 +941,7 @@ pub fn parse_number<'a, const FORMAT: u128, const IS_PARTIAL: bool>(
             }
         }
 
-        let is_negative_exponent = parse_exponent_sign(&mut byte)?;
+        let is_negative_exponent = parse_exponent_sign_with_options(&mut byte, options)?;
         let before = byte.current_count();
         parse_digits(byte.exponent_iter(), format.exponent_radix(), |digit| {
             if explicit_exponent < 0x10000000 {
@@ -755,6 +997,7 @@ pub fn parse_number<'a, const FORMAT: u128, const IS_PARTIAL: bool>(
                 fraction: fraction_digits,
             },
             end,
+            has_exponent,
         ));
     }
 
@@ -763,10 +1006,7 @@ pub fn parse_number<'a, const FORMAT: u128, const IS_PARTIAL: bool>(
     let mut zeros = start.clone();
     let mut zeros_integer = zeros.integer_iter();
     n_digits = n_digits.saturating_sub(zeros_integer.skip_zeros());
-    if zeros.first_is_cased(decimal_point) {
-        // SAFETY: safe since zeros cannot be empty due to `first_is`
-        unsafe { zeros.step_unchecked() };
-    }
+    skip_decimal_point(&mut zeros, options);
     let mut zeros_fraction = zeros.fraction_iter();
     n_digits = n_digits.saturating_sub(zeros_fraction.skip_zeros());
 
@@ -830,6 +1070,7 @@ pub fn parse_number<'a, const FORMAT: u128, const IS_PARTIAL: bool>(
             fraction: fraction_digits,
         },
         end,
+        has_exponent,
     ))
 }
 
@@ -842,6 +1083,38 @@ pub fn parse_partial_number<'a, const FORMAT: u128>(
     parse_number::<FORMAT, true>(byte, is_negative, options)
 }
 
+/// Convert the parsed number to a native float.
+///
+/// This tries the fast path, then the moderate path, and finally the
+/// slow, but always correct, path.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+pub fn number_to_float<F: LemireFloat, const FORMAT: u128>(
+    num: Number<'_>,
+    options: &Options,
+) -> F {
+    // Try the fast-path algorithm.
+    if let Some(value) = num.try_fast_path::<_, FORMAT>() {
+        return value;
+    }
+    // Now try the moderate path algorithm.
+    let is_negative = num.is_negative;
+    let mut fp = moderate_path::<F, FORMAT>(&num, options.lossy());
+
+    // Unable to correctly round the float using the fast or moderate algorithms.
+    // Fallback to a slower, but always correct algorithm. If we have
+    // lossy, we can't be here.
+    if fp.exp < 0 {
+        debug_assert!(!options.lossy(), "lossy algorithms never use slow algorithms");
+        // Undo the invalid extended float biasing.
+        fp.exp -= shared::INVALID_FP;
+        let digit_limit = options.max_slow_digits().map_or(usize::MAX, |x| x.get());
+        fp = slow_path::<F, FORMAT>(num, fp, digit_limit);
+    }
+
+    // Convert to native float and return result.
+    to_native!(F, fp, is_negative)
+}
+
 /// Try to parse a non-special floating point number.
 #[inline(always)]
 pub fn parse_complete_number<'a, const FORMAT: u128>(
@@ -862,6 +1135,58 @@ pub fn parse_complete_number<'a, const FORMAT: u128>(
 // DIGITS
 // ------
 
+/// Count the significant digits in the parsed number.
+///
+/// This excludes leading and trailing zeros, since they only scale the
+/// value and do not contribute to its precision.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+#[allow(clippy::cast_possible_truncation)] // reason = "saturates at u32::MAX"
+pub fn significant_digits<const FORMAT: u128>(num: &Number<'_>) -> u32 {
+    let mut count: usize = 0;
+    let mut trailing_zeros: usize = 0;
+    let mut integer = num.integer.bytes::<{ FORMAT }>();
+    count_significant_digits(integer.integer_iter(), &mut count, &mut trailing_zeros);
+    if let Some(fraction) = num.fraction {
+        let mut fraction = fraction.bytes::<{ FORMAT }>();
+        count_significant_digits(fraction.fraction_iter(), &mut count, &mut trailing_zeros);
+    }
+    count.min(u32::MAX as usize) as u32
+}
+
+/// Count the significant digits in the iterator, tracking any trailing zeros.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn count_significant_digits<'a>(
+    iter: impl Iterator<Item = &'a u8>,
+    count: &mut usize,
+    trailing_zeros: &mut usize,
+) {
+    for &c in iter {
+        if c != b'0' {
+            *count += *trailing_zeros + 1;
+            *trailing_zeros = 0;
+        } else if *count != 0 {
+            *trailing_zeros += 1;
+        }
+    }
+}
+
+/// Get the number of significant digits required to represent every float.
+///
+/// This is the number of digits in `2^(MANTISSA_SIZE+1)`, plus 1, which is
+/// `17` for decimal `f64` and `9` for decimal `f32`. Any further digits only
+/// affect rounding.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+pub fn max_significant_digits<F: LemireFloat, const FORMAT: u128>() -> u32 {
+    let radix = NumberFormat::<FORMAT>::MANTISSA_RADIX as u64;
+    let mut value = 1u64 << (F::MANTISSA_SIZE + 1);
+    let mut count = 1;
+    while value != 0 {
+        value /= radix;
+        count += 1;
+    }
+    count
+}
+
 /// Iteratively parse and consume digits from bytes.
 #[inline(always)]
 pub fn parse_digits<'a, Iter, Cb>(mut iter: Iter, radix: u32, mut cb: Cb)
@@ -953,27 +1278,79 @@ pub fn parse_u64_digits<'a, Iter, const FORMAT: u128>(
     }
 }
 
+/// Parse up to `n` significant digits into a mantissa.
+///
+/// For contiguous iterators, this takes at most `n` bytes with `take_n`
+/// and accumulates the leading digits, using the 8-digit optimizations
+/// if possible. Otherwise, such as with digit separators, this parses one
+/// digit at a time. Parsing stops at the first byte that is not a digit,
+/// and the bytes are advanced past the digits consumed. Returns the
+/// mantissa and the number of digits consumed.
+///
+/// # Preconditions
+///
+/// `n` must be small enough that `radix^n` does not overflow a `u64`,
+/// such as the value from [`u64_step`].
+#[cfg_attr(not(feature = "compact"), inline(always))]
+pub fn parse_up_to_n_digits<const FORMAT: u128>(
+    byte: &mut Bytes<'_, FORMAT>,
+    n: usize,
+) -> (u64, usize) {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let radix = format.radix();
+    let mut mantissa = 0_u64;
+    let mut iter = byte.integer_iter();
+    let start = iter.current_count();
+    if let Some(mut digits) = iter.take_n(n) {
+        let first = digits.cursor();
+        #[cfg(not(feature = "compact"))]
+        parse_8digits::<_, FORMAT>(digits.integer_iter(), &mut mantissa);
+        parse_digits(digits.integer_iter(), radix, |digit| {
+            mantissa = mantissa * radix as u64 + digit as u64;
+        });
+        // `take_n` consumes all `n` bytes, so only keep the digits parsed.
+        // SAFETY: safe, since the digits are a prefix of the bytes taken.
+        unsafe { iter.set_cursor(digits.cursor()) };
+        return (mantissa, digits.cursor() - first);
+    }
+
+    while iter.current_count() - start < n {
+        match iter.peek().and_then(|&c| char_to_digit_const(c, radix)) {
+            Some(digit) => mantissa = mantissa * radix as u64 + digit as u64,
+            None => break,
+        }
+        // SAFETY: safe, since `iter` cannot be empty due to `iter.peek()`.
+        unsafe { iter.step_unchecked() };
+        iter.increment_count();
+    }
+    (mantissa, iter.current_count() - start)
+}
+
 // SPECIAL
 // -------
 
 /// Determine if the input data matches the special string.
 /// If there's no match, returns 0. Otherwise, returns the byte's cursor.
+///
+/// The comparison is case-sensitive if `is_cased` is set, or the format
+/// requires case-sensitive special values.
 #[must_use]
 #[inline(always)]
-pub fn is_special_eq<const FORMAT: u128>(mut byte: Bytes<FORMAT>, string: &'static [u8]) -> usize {
+pub fn is_special_eq<const FORMAT: u128>(
+    mut byte: Bytes<FORMAT>,
+    string: &'static [u8],
+    is_cased: bool,
+) -> usize {
     let format = NumberFormat::<{ FORMAT }> {};
-    if cfg!(feature = "format") && format.case_sensitive_special() {
-        if shared::starts_with(byte.special_iter(), string.iter()) {
-            // Trim the iterator afterwards.
-            byte.special_iter().peek();
-            return byte.cursor();
-        }
-    } else if shared::starts_with_uncased(byte.special_iter(), string.iter()) {
+    let is_cased = is_cased || (cfg!(feature = "format") && format.case_sensitive_special());
+    let mut iter = byte.special_iter();
+    if iter.read_if_slice(string, is_cased) {
         // Trim the iterator afterwards.
-        byte.special_iter().peek();
-        return byte.cursor();
+        iter.peek();
+        iter.cursor()
+    } else {
+        0
     }
-    0
 }
 
 /// Parse a positive representation of a special, non-finite float.
@@ -993,9 +1370,27 @@ where
 
     let cursor = byte.cursor();
     let length = byte.buffer_length() - cursor;
+    let is_nan_cased = options.case_sensitive_nan();
+    let is_inf_cased = options.case_sensitive_inf();
+    if let Some(snan_string) = options.snan_string() {
+        if length >= snan_string.len() {
+            let count = is_special_eq::<FORMAT>(byte.clone(), snan_string, is_nan_cased);
+            if count != 0 {
+                return Some((F::signaling_nan(), count));
+            }
+        }
+    }
+    if let Some(qnan_string) = options.qnan_string() {
+        if length >= qnan_string.len() {
+            let count = is_special_eq::<FORMAT>(byte.clone(), qnan_string, is_nan_cased);
+            if count != 0 {
+                return Some((F::NAN, count));
+            }
+        }
+    }
     if let Some(nan_string) = options.nan_string() {
         if length >= nan_string.len() {
-            let count = is_special_eq::<FORMAT>(byte.clone(), nan_string);
+            let count = is_special_eq::<FORMAT>(byte.clone(), nan_string, is_nan_cased);
             if count != 0 {
                 return Some((F::NAN, count));
             }
@@ -1003,7 +1398,7 @@ where
     }
     if let Some(infinity_string) = options.infinity_string() {
         if length >= infinity_string.len() {
-            let count = is_special_eq::<FORMAT>(byte.clone(), infinity_string);
+            let count = is_special_eq::<FORMAT>(byte.clone(), infinity_string, is_inf_cased);
             if count != 0 {
                 return Some((F::INFINITY, count));
             }
@@ -1011,7 +1406,7 @@ where
     }
     if let Some(inf_string) = options.inf_string() {
         if length >= inf_string.len() {
-            let count = is_special_eq::<FORMAT>(byte.clone(), inf_string);
+            let count = is_special_eq::<FORMAT>(byte.clone(), inf_string, is_inf_cased);
             if count != 0 {
                 return Some((F::INFINITY, count));
             }
@@ -1058,3 +1453,48 @@ where
     }
     None
 }
+
+// CLASSIFY
+// --------
+
+/// The kind of number in a string, determined without parsing its value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NumberKind {
+    /// A number without a decimal point or exponent, such as `123`.
+    Integer,
+    /// A number with a decimal point or exponent, such as `1.5` or `1e3`,
+    /// or a special value, such as `NaN`.
+    Float,
+    /// An invalid number.
+    Invalid,
+}
+
+/// Classify a complete number as an integer or float from its syntax.
+///
+/// This uses the same syntax checks as parsing, without converting the
+/// digits to a float.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+#[allow(clippy::missing_inline_in_public_items)] // reason = "only public for testing"
+pub fn classify_complete<const FORMAT: u128>(bytes: &[u8], options: &Options) -> NumberKind {
+    let mut byte = bytes.bytes::<{ FORMAT }>();
+    let is_negative = match parse_mantissa_sign(&mut byte) {
+        Ok(is_negative) => is_negative,
+        Err(_) => return NumberKind::Invalid,
+    };
+    if byte.integer_iter().is_consumed() {
+        return NumberKind::Invalid;
+    }
+    match parse_number_syntax::<FORMAT, false>(byte.clone(), is_negative, options) {
+        Ok((number, count, has_exponent)) if count == bytes.len() => {
+            if number.fraction.is_some() || has_exponent {
+                NumberKind::Float
+            } else {
+                NumberKind::Integer
+            }
+        },
+        _ if parse_special::<f64, FORMAT>(byte, is_negative, options).is_some() => {
+            NumberKind::Float
+        },
+        _ => NumberKind::Invalid,
+    }
+}
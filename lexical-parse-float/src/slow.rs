@@ -44,11 +44,19 @@ use crate::shared;
 /// any value before or equal to `16777217.0` must be rounded down
 /// to `16777216.0`. These near-halfway conversions therefore may require
 /// a large number of digits to unambiguously determine how to round.
+///
+/// The `digit_limit` caps the number of significant digits used, to bound
+/// the time for inputs with many digits. Any digits past the limit only
+/// round the truncated value up if any are non-zero, so the result is
+/// correctly rounded unless the value is within one unit in the last kept
+/// digit of a halfway point. It does not apply to radixes without a finite
+/// representation.
 #[must_use]
 #[allow(clippy::unwrap_used)] // reason = "none is a developer error"
 pub fn slow_radix<F: RawFloat, const FORMAT: u128>(
     num: Number,
     fp: ExtendedFloat80,
+    digit_limit: usize,
 ) -> ExtendedFloat80 {
     // Ensure our preconditions are valid:
     //  1. The significant digits are not shifted into place.
@@ -74,6 +82,7 @@ pub fn slow_radix<F: RawFloat, const FORMAT: u128>(
     {
         if let Some(max_digits) = F::max_digits(format.radix()) {
             // Can use our finite number of digit algorithm.
+            let max_digits = cmp::min(max_digits, digit_limit);
             digit_comp::<F, FORMAT>(num, fp, sci_exp, max_digits)
         } else {
             // Fallback to infinite digits.
@@ -84,7 +93,7 @@ pub fn slow_radix<F: RawFloat, const FORMAT: u128>(
     #[cfg(not(feature = "radix"))]
     {
         // Can use our finite number of digit algorithm.
-        let max_digits = F::max_digits(format.radix()).unwrap();
+        let max_digits = cmp::min(F::max_digits(format.radix()).unwrap(), digit_limit);
         digit_comp::<F, FORMAT>(num, fp, sci_exp, max_digits)
     }
 }
@@ -724,6 +733,65 @@ pub fn compare_bytes<const FORMAT: u128>(
     cmp::Ordering::Equal
 }
 
+// EXACTNESS
+// ---------
+
+/// Determine if the significant digits are exactly representable as a float.
+///
+/// The float must be the result of parsing the digits, and `digits` must be
+/// the number of significant digits, without leading or trailing zeros. We
+/// have the real digits `m1 * b^N1` and the float `m2 * 2^N2`, and after
+/// factoring out the powers-of-two from `b`, we scale both to integers and
+/// compare them, similar to [`negative_digit_comp`]. Any overflow to infinity
+/// or underflow to zero is inexact.
+#[must_use]
+#[allow(clippy::unwrap_used)] // reason = "none is a developer error"
+#[allow(clippy::cast_possible_wrap)] // reason = "digits is at most max_digits"
+#[allow(clippy::missing_inline_in_public_items)] // reason = "only public for testing"
+pub fn is_exact<F: RawFloat, const FORMAT: u128>(num: Number, float: F, digits: usize) -> bool {
+    let format = NumberFormat::<FORMAT> {};
+    let radix = format.radix();
+    debug_assert!(radix == format.exponent_base(), "exponent base must be the radix");
+
+    if digits == 0 {
+        return float == F::ZERO;
+    } else if float.is_inf() || float == F::ZERO {
+        return false;
+    }
+
+    // Every float has a finite representation with at most `max_digits`
+    // digits for even radixes, and odd radixes can only exactly represent
+    // integers, since the reduced fraction would have an odd denominator.
+    let real_exp = scientific_exponent::<FORMAT>(&num) + 1 - digits as i32;
+    match F::max_digits(radix) {
+        Some(max_digits) if digits > max_digits => return false,
+        None if real_exp < 0 => return false,
+        _ => (),
+    }
+
+    // Factor out the powers-of-two from the radix, so we have
+    // `m1 * odd^N1 == m2 * 2^(N2 - shift * N1)`.
+    let (mut real_digits, count) = parse_mantissa::<FORMAT>(num, digits);
+    debug_assert!(count == digits, "must parse all the significant digits");
+    let theor = b(float);
+    let mut theor_digits = Bigint::from_u64(theor.mant);
+    let shift = radix.trailing_zeros() as i32;
+    let odd = radix >> shift;
+    if odd != 1 && real_exp > 0 {
+        real_digits.pow(odd, real_exp as u32).unwrap();
+    } else if odd != 1 && real_exp < 0 {
+        theor_digits.pow(odd, (-real_exp) as u32).unwrap();
+    }
+    let binary_exp = theor.exp - shift * real_exp;
+    if binary_exp > 0 {
+        theor_digits.pow(2, binary_exp as u32).unwrap();
+    } else if binary_exp < 0 {
+        real_digits.pow(2, (-binary_exp) as u32).unwrap();
+    }
+
+    real_digits.data == theor_digits.data
+}
+
 // SCALING
 // -------
 
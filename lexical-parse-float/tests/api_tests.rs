@@ -29,6 +29,63 @@ fn special_bytes_test() {
     assert!(f32::from_lexical_with_options::<FORMAT>(b"Infinity", &OPTIONS).unwrap().is_infinite());
 }
 
+#[test]
+fn special_strings_test() {
+    const OPTIONS: Options = Options::builder()
+        .nan_string(Some(b"none"))
+        .inf_string(Some(b"infty"))
+        .infinity_string(Some(b"infinite"))
+        .build_strict();
+
+    let parse = |bytes: &[u8]| f64::from_lexical_with_options::<STANDARD>(bytes, &OPTIONS);
+    assert!(parse(b"none").unwrap().is_nan());
+    assert!(parse(b"NONE").unwrap().is_nan());
+    assert_eq!(parse(b"infty"), Ok(f64::INFINITY));
+    assert_eq!(parse(b"-Infty"), Ok(f64::NEG_INFINITY));
+    assert_eq!(parse(b"infinite"), Ok(f64::INFINITY));
+    assert_eq!(parse(b"NaN"), Err(Error::InvalidDigit(0)));
+    assert_eq!(parse(b"inf"), Err(Error::InvalidDigit(0)));
+
+    // Special values are never partially consumed by the complete parser.
+    assert_eq!(f64::from_lexical(b"nana"), Err(Error::InvalidDigit(0)));
+    assert_eq!(f64::from_lexical(b"-nana"), Err(Error::InvalidDigit(1)));
+    assert_eq!(f64::from_lexical(b"infx"), Err(Error::InvalidDigit(0)));
+    assert_eq!(f64::from_lexical(b"infinit"), Err(Error::InvalidDigit(0)));
+    assert_eq!(f64::from_lexical(b"Infinityy"), Err(Error::InvalidDigit(0)));
+    assert_eq!(parse(b"infinites"), Err(Error::InvalidDigit(0)));
+
+    // The partial parser stops after the longest special value.
+    assert_eq!(f64::from_lexical_partial(b"infx"), Ok((f64::INFINITY, 3)));
+    assert_eq!(f64::from_lexical_partial(b"Infinityy"), Ok((f64::INFINITY, 8)));
+    assert_eq!(
+        f64::from_lexical_partial_with_options::<STANDARD>(b"infinite!", &OPTIONS),
+        Ok((f64::INFINITY, 8))
+    );
+    let (value, count) = f64::from_lexical_partial(b"nana").unwrap();
+    assert!(value.is_nan());
+    assert_eq!(count, 3);
+}
+
+#[test]
+fn signaling_nan_test() {
+    const OPTIONS: Options =
+        Options::builder().snan_string(Some(b"sNaN")).qnan_string(Some(b"qNaN")).build_strict();
+
+    let parse = |bytes: &[u8]| f64::from_lexical_with_options::<STANDARD>(bytes, &OPTIONS);
+    assert_eq!(parse(b"sNaN").map(f64::to_bits), Ok(0x7FF4_0000_0000_0000));
+    assert_eq!(parse(b"-sNaN").map(f64::to_bits), Ok(0xFFF4_0000_0000_0000));
+    assert_eq!(parse(b"qNaN").map(f64::to_bits), Ok(f64::NAN.to_bits()));
+    assert_eq!(parse(b"NaN").map(f64::to_bits), Ok(f64::NAN.to_bits()));
+
+    let parse = |bytes: &[u8]| f32::from_lexical_with_options::<STANDARD>(bytes, &OPTIONS);
+    assert_eq!(parse(b"sNaN").map(f32::to_bits), Ok(0x7FA0_0000));
+    assert_eq!(parse(b"qNaN").map(f32::to_bits), Ok(f32::NAN.to_bits()));
+
+    // Without the options, these are not valid special values.
+    assert!(f64::from_lexical(b"sNaN").is_err());
+    assert!(f64::from_lexical(b"qNaN").is_err());
+}
+
 #[test]
 #[cfg(feature = "power-of-two")]
 fn invalid_format_test() {
@@ -122,8 +179,15 @@ fn f32_decimal_test() {
     assert_eq!(Err(Error::EmptyMantissa(1)), f32::from_lexical(b".e-1"));
     assert_eq!(Err(Error::EmptyMantissa(0)), f32::from_lexical(b"e1"));
     assert_eq!(Err(Error::EmptyMantissa(0)), f32::from_lexical(b"e-1"));
-    assert_eq!(Err(Error::Empty(1)), f32::from_lexical(b"+"));
-    assert_eq!(Err(Error::Empty(1)), f32::from_lexical(b"-"));
+    assert_eq!(Err(Error::EmptyMantissa(1)), f32::from_lexical(b"+"));
+    assert_eq!(Err(Error::EmptyMantissa(1)), f32::from_lexical(b"-"));
+    assert_eq!(Err(Error::InvalidDigit(0)), f32::from_lexical(b"x"));
+    assert_eq!(Err(Error::InvalidDigit(1)), f32::from_lexical(b"+x"));
+    assert_eq!(Err(Error::InvalidDigit(1)), f32::from_lexical(b"-x"));
+    assert_eq!(Err(Error::InvalidDigit(1)), f32::from_lexical(b"1x"));
+    assert_eq!(Err(Error::Empty(0)), f32::from_lexical_partial(b""));
+    assert_eq!(Err(Error::EmptyMantissa(1)), f32::from_lexical_partial(b"-"));
+    assert_eq!(Err(Error::EmptyMantissa(1)), f32::from_lexical_partial(b"+x"));
 
     // Bug fix for Issue #8
     assert_eq!(Ok(5.002868148396374), f32::from_lexical(b"5.002868148396374"));
@@ -513,8 +577,8 @@ fn f64_decimal_test() {
     assert_eq!(Err(Error::EmptyMantissa(1)), f64::from_lexical(b"."));
     assert_eq!(Err(Error::EmptyMantissa(2)), f64::from_lexical(b"+."));
     assert_eq!(Err(Error::EmptyMantissa(2)), f64::from_lexical(b"-."));
-    assert_eq!(Err(Error::Empty(1)), f64::from_lexical(b"+"));
-    assert_eq!(Err(Error::Empty(1)), f64::from_lexical(b"-"));
+    assert_eq!(Err(Error::EmptyMantissa(1)), f64::from_lexical(b"+"));
+    assert_eq!(Err(Error::EmptyMantissa(1)), f64::from_lexical(b"-"));
 
     // Bug fix for Issue #8
     assert_eq!(Ok(5.002868148396374), f64::from_lexical(b"5.002868148396374"));
@@ -644,6 +708,45 @@ fn parse_binary_f64_test() {
     assert_eq!(Ok((2.2250738585072014e-308, 170)), parse_binary(b"1111111111111111111111111111111111111111111111111111111111111111111111000000000000000000000000000000000000000000000000000000000000000000000000000000000000000^-10010011011"));
 }
 
+#[test]
+#[cfg(feature = "power-of-two")]
+fn parse_hex_exponent_base_f64_test() {
+    use core::num;
+
+    // The exponent base differs from the mantissa radix, so these never use
+    // the fast path.
+    const BASE16_2_10: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(16)
+        .exponent_base(num::NonZeroU8::new(2))
+        .exponent_radix(num::NonZeroU8::new(10))
+        .build_strict();
+    const BASE16_2_16: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(16)
+        .exponent_base(num::NonZeroU8::new(2))
+        .exponent_radix(num::NonZeroU8::new(16))
+        .build_strict();
+    const OPTIONS: Options = Options::builder().exponent(b'p').build_strict();
+
+    assert_eq!(f64::from_lexical_with_options::<BASE16_2_10>(b"1.8", &OPTIONS), Ok(1.5));
+    assert_eq!(f64::from_lexical_with_options::<BASE16_2_10>(b"1p10", &OPTIONS), Ok(1024.0));
+    assert_eq!(f64::from_lexical_with_options::<BASE16_2_10>(b"8p-4", &OPTIONS), Ok(0.5));
+    assert_eq!(f64::from_lexical_with_options::<BASE16_2_10>(b"4.0p-1076", &OPTIONS), Ok(5e-324));
+
+    assert_eq!(f64::from_lexical_with_options::<BASE16_2_16>(b"1p10", &OPTIONS), Ok(65536.0));
+    assert_eq!(f64::from_lexical_with_options::<BASE16_2_16>(b"3.039pC", &OPTIONS), Ok(12345.0));
+    assert_eq!(
+        f64::from_lexical_with_options::<BASE16_2_16>(b"1.7E43C8800759Cp3E4", &OPTIONS),
+        Ok(1e300)
+    );
+    assert_eq!(f64::from_lexical_with_options::<BASE16_2_16>(b"4.0p-434", &OPTIONS), Ok(5e-324));
+
+    // Zero cannot be normalized, so it must be handled before the algorithm.
+    assert_eq!(f64::from_lexical_with_options::<BASE16_2_10>(b"0.0", &OPTIONS), Ok(0.0));
+    assert_eq!(f64::from_lexical_with_options::<BASE16_2_10>(b"0p10", &OPTIONS), Ok(0.0));
+    let zero = f64::from_lexical_with_options::<BASE16_2_10>(b"-0.0", &OPTIONS);
+    assert_eq!(zero.map(f64::to_bits), Ok((-0.0f64).to_bits()));
+}
+
 #[test]
 fn parse_f32_lossy_test() {
     const FORMAT: u128 = STANDARD;
@@ -714,6 +817,42 @@ fn f64_lossy_decimal_test() {
     );
 }
 
+#[test]
+fn f64_max_slow_digits_test() {
+    const FORMAT: u128 = STANDARD;
+    const DEFAULT: Options = Options::new();
+
+    // Near-halfway values, with 10000 significant digits.
+    let mut above = "9007199254740993.".to_string();
+    above.push_str(&"0".repeat(9983));
+    above.push('1');
+    let mut below = "9007199254740992.".to_string();
+    below.push_str(&"9".repeat(9984));
+    let mut denormal = "2.4703282292062327208828439643411068618252990130716238221279284125033775363510437593264991818081799618989828234772285886546332835517796989819938739800539093906315035659515570226392290858392449105184435931802849936536152500319370457678249219365623669863658480757001585769269903706311928279558551332927834338409351978015531246597263579574622766465272827220056374006485499977096599470454020828166226237857393450736339007967761930577506740176324673600968951340535537458516661134223766678604162159680461914467291840300530057530849048765391711386591646239524912623653881879636239373280423891018672348497668235089863388587925628302755995657524455507255189313690836254779186948667994968324049705821028513185451396213837722826145437693412532098591327667236328125".to_string();
+    denormal.push_str(&"0".repeat(10000 - denormal.len()));
+    denormal.push_str("1e-324");
+
+    for limit in [17, 18, 64, 768] {
+        let options =
+            Options::builder().max_slow_digits(core::num::NonZeroUsize::new(limit)).build_strict();
+        for value in [&above, &below, &denormal] {
+            let exact = f64::from_lexical_with_options::<FORMAT>(value.as_bytes(), &DEFAULT);
+            let limited = f64::from_lexical_with_options::<FORMAT>(value.as_bytes(), &options);
+            let (exact, limited) = (exact.unwrap().to_bits(), limited.unwrap().to_bits());
+            assert!(exact.abs_diff(limited) <= 1, "{limit} digits");
+        }
+    }
+    assert_eq!(Ok(9007199254740994.0), f64::from_lexical(above.as_bytes()));
+    assert_eq!(Ok(9007199254740992.0), f64::from_lexical(below.as_bytes()));
+    assert_eq!(Ok(5e-324), f64::from_lexical(denormal.as_bytes()));
+
+    // Digits past the limit still round the value up.
+    let options =
+        Options::builder().max_slow_digits(core::num::NonZeroUsize::new(17)).build_strict();
+    let result = f64::from_lexical_with_options::<FORMAT>(above.as_bytes(), &options);
+    assert_eq!(Ok(9007199254740994.0), result);
+}
+
 #[cfg(feature = "format")]
 const fn rebuild(format: u128) -> NumberFormatBuilder {
     NumberFormatBuilder::rebuild(format)
@@ -774,6 +913,33 @@ fn f64_special_test() {
     assert!(f64::from_lexical_with_options::<F5>(b"n_a_n_", &OPTIONS).is_err());
 }
 
+#[test]
+#[cfg(feature = "format")]
+fn case_sensitive_special_strings_test() {
+    const CASED: u128 = rebuild(STANDARD).case_sensitive_special(true).build_strict();
+    const UNCASED: u128 = STANDARD;
+    const OPTIONS: Options = Options::builder()
+        .nan_string(Some(b"None"))
+        .inf_string(Some(b"Inf"))
+        .infinity_string(Some(b"Infinity"))
+        .build_strict();
+
+    assert!(f64::from_lexical_with_options::<CASED>(b"None", &OPTIONS).unwrap().is_nan());
+    assert!(f64::from_lexical_with_options::<UNCASED>(b"None", &OPTIONS).unwrap().is_nan());
+    assert!(f64::from_lexical_with_options::<CASED>(b"none", &OPTIONS).is_err());
+    assert!(f64::from_lexical_with_options::<UNCASED>(b"none", &OPTIONS).unwrap().is_nan());
+
+    assert_eq!(f64::from_lexical_with_options::<CASED>(b"Inf", &OPTIONS), Ok(f64::INFINITY));
+    assert_eq!(f64::from_lexical_with_options::<CASED>(b"-Infinity", &OPTIONS), Ok(-f64::INFINITY));
+    assert!(f64::from_lexical_with_options::<CASED>(b"inf", &OPTIONS).is_err());
+    assert!(f64::from_lexical_with_options::<CASED>(b"INFINITY", &OPTIONS).is_err());
+    assert_eq!(f64::from_lexical_with_options::<UNCASED>(b"INFINITY", &OPTIONS), Ok(f64::INFINITY));
+
+    // A case mismatch in the longer string falls back to the partial parser.
+    let result = f64::from_lexical_partial_with_options::<CASED>(b"InfINITY", &OPTIONS);
+    assert_eq!(result, Ok((f64::INFINITY, 3)));
+}
+
 #[test]
 #[cfg(feature = "format")]
 fn case_sensitive_exponent_test() {
@@ -897,6 +1063,31 @@ fn f64_required_exponent_sign_test() {
     assert!(f64::from_lexical_with_options::<FORMAT>(b"3.0e-7", &OPTIONS).is_ok());
 }
 
+#[test]
+#[cfg(feature = "format")]
+fn f64_independent_signs_test() {
+    const OPTIONS: Options = Options::new();
+    const F1: u128 = rebuild(format::PERMISSIVE)
+        .required_exponent_sign(true)
+        .no_positive_mantissa_sign(true)
+        .build_strict();
+    let parse = |bytes: &[u8]| f64::from_lexical_with_options::<F1>(bytes, &OPTIONS);
+    assert_eq!(parse(b"3.0e+7"), Ok(3.0e7));
+    assert_eq!(parse(b"-3.0e-7"), Ok(-3.0e-7));
+    assert_eq!(parse(b"3.0e7"), Err(Error::MissingExponentSign(4)));
+    assert_eq!(parse(b"+3.0e+7"), Err(Error::InvalidPositiveSign(0)));
+
+    const F2: u128 = rebuild(format::PERMISSIVE)
+        .required_mantissa_sign(true)
+        .no_positive_exponent_sign(true)
+        .build_strict();
+    let parse = |bytes: &[u8]| f64::from_lexical_with_options::<F2>(bytes, &OPTIONS);
+    assert_eq!(parse(b"+3.0e7"), Ok(3.0e7));
+    assert_eq!(parse(b"-3.0e-7"), Ok(-3.0e-7));
+    assert_eq!(parse(b"+3.0e+7"), Err(Error::InvalidPositiveExponentSign(5)));
+    assert_eq!(parse(b"3.0e7"), Err(Error::MissingSign(0)));
+}
+
 #[test]
 #[cfg(feature = "format")]
 fn f64_no_exponent_without_fraction_test() {
@@ -950,6 +1141,31 @@ fn f64_integer_internal_digit_separator_test() {
     assert!(f64::from_lexical_with_options::<FORMAT>(b"31_.0e7", &OPTIONS).is_err());
 }
 
+#[test]
+#[cfg(feature = "format")]
+fn f64_lenient_grouping_test() {
+    const FORMAT: u128 = rebuild(format::STANDARD)
+        .digit_separator(num::NonZeroU8::new(b','))
+        .integer_digit_separator_flags(true)
+        .build_strict();
+    const OPTIONS: Options = Options::new();
+
+    // Group separators are skipped anywhere in the integer digits.
+    assert_eq!(f64::from_lexical_with_options::<FORMAT>(b"1,2,3,4", &OPTIONS), Ok(1234.0));
+    assert_eq!(f64::from_lexical_with_options::<FORMAT>(b"1,2,3,4.5", &OPTIONS), Ok(1234.5));
+    assert_eq!(f64::from_lexical_with_options::<FORMAT>(b"12,34,.5", &OPTIONS), Ok(1234.5));
+    assert!(f64::from_lexical_with_options::<FORMAT>(b"1234.5,6", &OPTIONS).is_err());
+
+    // This composes with a custom decimal point.
+    const SPACE: u128 = rebuild(format::STANDARD)
+        .digit_separator(num::NonZeroU8::new(b' '))
+        .integer_digit_separator_flags(true)
+        .build_strict();
+    const COMMA: Options = Options::builder().decimal_point(b',').build_strict();
+    assert_eq!(f64::from_lexical_with_options::<SPACE>(b"1 2 3 4,5", &COMMA), Ok(1234.5));
+    assert_eq!(f64::from_lexical_with_options::<SPACE>(b"1 234,5", &COMMA), Ok(1234.5));
+}
+
 #[test]
 #[cfg(feature = "format")]
 fn f64_fraction_internal_digit_separator_test() {
@@ -1208,6 +1424,39 @@ fn base_prefix_and_suffix_test() {
     assert!(f64::from_lexical_with_options::<FORMAT>(b"+0x3.0e+300h ", &OPTIONS).is_err());
 }
 
+#[test]
+#[cfg(all(feature = "power-of-two", feature = "format"))]
+fn c_hex_float_test() {
+    const FORMAT: u128 = format::C_HEX_FLOAT;
+    const OPTIONS: Options = lexical_parse_float::options::HEX_FLOAT;
+
+    let parse = |bytes: &[u8]| f64::from_lexical_with_options::<FORMAT>(bytes, &OPTIONS);
+    assert_eq!(parse(b"0x1.8p3"), Ok(12.0));
+    assert_eq!(parse(b"0X1.8P3"), Ok(12.0));
+    assert_eq!(parse(b"-0x1.8p3"), Ok(-12.0));
+    assert_eq!(parse(b"0x.8p1"), Ok(1.0));
+    assert_eq!(parse(b"0x1p-2"), Ok(0.25));
+    assert_eq!(parse(b"0x1.8"), Ok(1.5));
+    assert_eq!(parse(b"0xA"), Ok(10.0));
+    assert_eq!(parse(b"0x1.fffffffffffffp1023"), Ok(f64::MAX));
+    assert_eq!(parse(b"0x1p-1074"), Ok(5e-324));
+    assert_eq!(parse(b"0x1.8e3"), Ok(1.555419921875));
+    assert_eq!(parse(b"0"), Ok(0.0));
+    assert_eq!(parse(b"-0"), Ok(-0.0));
+    assert_eq!(parse(b"0p3"), Ok(0.0));
+    assert_eq!(parse(b"1p3"), Ok(8.0));
+
+    assert_eq!(parse(b"0x"), Err(Error::EmptyMantissa(2)));
+    assert_eq!(parse(b"0xp3"), Err(Error::EmptyMantissa(2)));
+    assert_eq!(parse(b"0x.p1"), Err(Error::EmptyMantissa(3)));
+    assert_eq!(parse(b"0x1.8p"), Err(Error::EmptyExponent(6)));
+    assert_eq!(
+        f64::from_lexical_partial_with_options::<FORMAT>(b"0x1.8p3;", &OPTIONS),
+        Ok((12.0, 7))
+    );
+    assert_eq!(f32::from_lexical_with_options::<FORMAT>(b"0x1.8p3", &OPTIONS), Ok(12.0));
+}
+
 #[test]
 #[cfg(feature = "format")]
 fn issue66_test() {
@@ -1235,3 +1484,465 @@ fn issue68_test() {
     assert_eq!(f32::INFINITY, f32::from_lexical_with_options::<FORMAT>(hex, &OPTIONS).unwrap());
     assert_eq!(f64::INFINITY, f64::from_lexical_with_options::<FORMAT>(hex, &OPTIONS).unwrap());
 }
+
+#[test]
+fn empty_and_invalid_digit_test() {
+    assert_eq!(Err(Error::Empty(0)), f64::from_lexical(b""));
+    assert_eq!(Err(Error::InvalidDigit(0)), f64::from_lexical(b"x"));
+    assert_eq!(Err(Error::EmptyMantissa(1)), f64::from_lexical(b"+"));
+    assert_eq!(Err(Error::EmptyMantissa(1)), f64::from_lexical(b"-"));
+
+    assert_eq!(Err(Error::Empty(0)), f64::from_lexical_partial(b""));
+    assert_eq!(Err(Error::EmptyMantissa(1)), f64::from_lexical_partial(b"+"));
+}
+
+#[test]
+fn lone_decimal_point_test() {
+    const FORMAT: u128 = STANDARD;
+    const STRICT: Options = Options::new();
+    const LENIENT: Options = Options::builder().allow_lone_decimal_point(true).build_strict();
+
+    let parse_strict = |bytes: &[u8]| f64::from_lexical_with_options::<FORMAT>(bytes, &STRICT);
+    assert_eq!(parse_strict(b"."), Err(Error::EmptyMantissa(1)));
+    assert_eq!(parse_strict(b"-."), Err(Error::EmptyMantissa(2)));
+    assert_eq!(parse_strict(b"e5"), Err(Error::EmptyMantissa(0)));
+    assert_eq!(parse_strict(b".e5"), Err(Error::EmptyMantissa(1)));
+    assert_eq!(parse_strict(b".5"), Ok(0.5));
+
+    let parse_lenient = |bytes: &[u8]| f64::from_lexical_with_options::<FORMAT>(bytes, &LENIENT);
+    assert_eq!(parse_lenient(b"."), Ok(0.0));
+    assert_eq!(parse_lenient(b"+."), Ok(0.0));
+    assert!(parse_lenient(b"-.").unwrap().is_sign_negative());
+    assert_eq!(parse_lenient(b"e5"), Err(Error::EmptyMantissa(0)));
+    assert_eq!(parse_lenient(b".e5"), Err(Error::EmptyMantissa(1)));
+    assert_eq!(parse_lenient(b".5"), Ok(0.5));
+    assert_eq!(parse_lenient(b".x"), Err(Error::InvalidDigit(1)));
+
+    let parse_partial =
+        |bytes: &[u8]| f32::from_lexical_partial_with_options::<FORMAT>(bytes, &LENIENT);
+    assert_eq!(parse_partial(b"."), Ok((0.0, 1)));
+    assert_eq!(parse_partial(b".,1"), Ok((0.0, 1)));
+    assert_eq!(parse_partial(b".e5"), Err(Error::EmptyMantissa(1)));
+}
+
+#[test]
+fn no_trailing_fraction_zeros_test() {
+    use lexical_parse_float::{classify_number_with_options, NumberKind};
+
+    const FORMAT: u128 = STANDARD;
+    const STRICT: Options = Options::builder().no_trailing_fraction_zeros(true).build_strict();
+    const ZERO: Options = Options::builder()
+        .no_trailing_fraction_zeros(true)
+        .allow_zero_fraction(true)
+        .build_strict();
+
+    let parse_strict = |bytes: &[u8]| f64::from_lexical_with_options::<FORMAT>(bytes, &STRICT);
+    assert_eq!(parse_strict(b"1.5"), Ok(1.5));
+    assert_eq!(parse_strict(b"1.05"), Ok(1.05));
+    assert_eq!(parse_strict(b"10"), Ok(10.0));
+    assert_eq!(parse_strict(b"1."), Ok(1.0));
+    assert_eq!(parse_strict(b"1.5e10"), Ok(1.5e10));
+    assert_eq!(parse_strict(b"1.50"), Err(Error::InvalidTrailingZeros(3)));
+    assert_eq!(parse_strict(b"-1.500"), Err(Error::InvalidTrailingZeros(4)));
+    assert_eq!(parse_strict(b"1.50e10"), Err(Error::InvalidTrailingZeros(3)));
+    assert_eq!(parse_strict(b"1.0"), Err(Error::InvalidTrailingZeros(2)));
+    assert_eq!(parse_strict(b"0.0"), Err(Error::InvalidTrailingZeros(2)));
+
+    let parse_zero = |bytes: &[u8]| f64::from_lexical_with_options::<FORMAT>(bytes, &ZERO);
+    assert_eq!(parse_zero(b"1.0"), Ok(1.0));
+    assert_eq!(parse_zero(b"0.0"), Ok(0.0));
+    assert_eq!(parse_zero(b"1.5"), Ok(1.5));
+    assert_eq!(parse_zero(b"1.00"), Err(Error::InvalidTrailingZeros(2)));
+    assert_eq!(parse_zero(b"1.50"), Err(Error::InvalidTrailingZeros(3)));
+
+    let parse_partial =
+        |bytes: &[u8]| f64::from_lexical_partial_with_options::<FORMAT>(bytes, &STRICT);
+    assert_eq!(parse_partial(b"1.5,0"), Ok((1.5, 3)));
+    assert_eq!(parse_partial(b"1.50,"), Err(Error::InvalidTrailingZeros(3)));
+
+    // Trailing zeros are valid by default.
+    assert_eq!(f64::from_lexical(b"1.50"), Ok(1.5));
+
+    let classify = |bytes| classify_number_with_options::<FORMAT>(bytes, &STRICT);
+    assert_eq!(classify(b"1.5"), NumberKind::Float);
+    assert_eq!(classify(b"1.50"), NumberKind::Invalid);
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn no_trailing_fraction_zeros_digit_separator_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(num::NonZeroU8::new(b'_'))
+        .digit_separator_flags(true)
+        .build_strict();
+    const OPTIONS: Options = Options::builder().no_trailing_fraction_zeros(true).build_strict();
+
+    let parse = |bytes: &[u8]| f64::from_lexical_with_options::<FORMAT>(bytes, &OPTIONS);
+    assert_eq!(parse(b"1.5_5"), Ok(1.55));
+    assert_eq!(parse(b"1.5_"), Ok(1.5));
+    assert_eq!(parse(b"1.5_0"), Err(Error::InvalidTrailingZeros(4)));
+    assert_eq!(parse(b"1.5__0_0"), Err(Error::InvalidTrailingZeros(5)));
+}
+
+#[test]
+fn skip_bom_test() {
+    const OPTIONS: Options = Options::builder().skip_bom(true).build_strict();
+    let parse = |bytes: &[u8]| f64::from_lexical_with_options::<STANDARD>(bytes, &OPTIONS);
+    assert_eq!(parse("\u{FEFF}42".as_bytes()), Ok(42.0));
+    assert_eq!(parse("\u{FEFF}-1.5e3".as_bytes()), Ok(-1500.0));
+    assert_eq!(parse(b"42"), Ok(42.0));
+    assert_eq!(parse("\u{FEFF}".as_bytes()), Err(Error::Empty(3)));
+    assert_eq!(parse("\u{FEFF}1.5x".as_bytes()), Err(Error::InvalidDigit(6)));
+    assert_eq!(parse("\u{FEFF}1e".as_bytes()), Err(Error::EmptyExponent(5)));
+
+    let parse = |bytes: &[u8]| f64::from_lexical_partial_with_options::<STANDARD>(bytes, &OPTIONS);
+    assert_eq!(parse("\u{FEFF}42".as_bytes()), Ok((42.0, 5)));
+    assert_eq!(parse("\u{FEFF}4.2,1".as_bytes()), Ok((4.2, 6)));
+    assert_eq!(parse(b"4.2,1"), Ok((4.2, 3)));
+
+    // Disabled by default.
+    assert_eq!(f64::from_lexical("\u{FEFF}42".as_bytes()), Err(Error::InvalidDigit(0)));
+}
+
+#[test]
+#[cfg(feature = "unicode")]
+fn skip_unicode_whitespace_test() {
+    const OPTIONS: Options =
+        Options::builder().skip_bom(true).skip_unicode_whitespace(true).build_strict();
+    let parse = |bytes: &[u8]| f64::from_lexical_partial_with_options::<STANDARD>(bytes, &OPTIONS);
+    assert_eq!(parse(b" \t1.5"), Ok((1.5, 5)));
+    assert_eq!(parse("\u{3000}-1.5".as_bytes()), Ok((-1.5, 7)));
+    assert_eq!(parse("\u{FEFF}\u{A0}NaN".as_bytes()).map(|(x, n)| (x.is_nan(), n)), Ok((true, 8)));
+    assert_eq!(parse("\u{2028}".as_bytes()), Err(Error::Empty(3)));
+}
+
+#[test]
+fn ignore_prefix_test() {
+    const OPTIONS: Options = Options::builder().ignore_prefix("€".as_bytes()).build_strict();
+    let parse = |bytes: &[u8]| f64::from_lexical_partial_with_options::<STANDARD>(bytes, &OPTIONS);
+    assert_eq!(parse("€10".as_bytes()), Ok((10.0, 5)));
+    assert_eq!(parse("€-1.5e3".as_bytes()), Ok((-1500.0, 9)));
+    assert_eq!(parse(b"10"), Ok((10.0, 2)));
+    assert_eq!(parse("€inf".as_bytes()), Ok((f64::INFINITY, 6)));
+
+    // The prefix is skipped once, and before the sign.
+    let parse = |bytes: &[u8]| f64::from_lexical_with_options::<STANDARD>(bytes, &OPTIONS);
+    assert_eq!(parse("€".as_bytes()), Err(Error::Empty(3)));
+    assert_eq!(parse("€€1".as_bytes()), Err(Error::InvalidDigit(3)));
+    assert_eq!(parse("-€1".as_bytes()), Err(Error::InvalidDigit(1)));
+    assert_eq!(parse("€1.5x".as_bytes()), Err(Error::InvalidDigit(6)));
+
+    // The prefix cannot contain digits or signs, which are ambiguous.
+    let error = Err(Error::InvalidIgnorePrefix);
+    assert_eq!(Options::builder().ignore_prefix(b"1").build(), error);
+    assert_eq!(Options::builder().ignore_prefix(b"$-").build(), error);
+    assert!(!Options::builder().ignore_prefix(b"+").is_valid());
+
+    // Disabled by default.
+    assert_eq!(f64::from_lexical("€10".as_bytes()), Err(Error::InvalidDigit(0)));
+}
+
+#[test]
+#[cfg(feature = "unicode")]
+fn unicode_exponent_sign_test() {
+    const OPTIONS: Options = Options::builder().unicode_exponent_sign(true).build_strict();
+    let parse = |bytes: &[u8]| f64::from_lexical_with_options::<STANDARD>(bytes, &OPTIONS);
+    assert_eq!(parse("1.5e\u{2212}3".as_bytes()), Ok(0.0015));
+    assert_eq!(parse("1.5e\u{2212}3".as_bytes()), parse(b"1.5e-3"));
+    assert_eq!(parse("-2E\u{2212}10".as_bytes()), Ok(-2e-10));
+    assert_eq!(parse(b"1.5e+3"), Ok(1500.0));
+    assert_eq!(parse("1.5e\u{2212}".as_bytes()), Err(Error::EmptyExponent(7)));
+    assert_eq!(parse("1.5e\u{B1}3".as_bytes()), Err(Error::EmptyExponent(4)));
+    assert_eq!(parse("\u{2212}1.5".as_bytes()), Err(Error::InvalidDigit(0)));
+
+    let parse = |bytes: &[u8]| f64::from_lexical_partial_with_options::<STANDARD>(bytes, &OPTIONS);
+    assert_eq!(parse("1.5e\u{2212}3,".as_bytes()), Ok((0.0015, 8)));
+
+    // Disabled by default.
+    let bytes = "1.5e\u{2212}3".as_bytes();
+    assert_eq!(f64::from_lexical(bytes), Err(Error::EmptyExponent(4)));
+}
+
+#[test]
+#[cfg(feature = "unicode")]
+fn middle_dot_decimal_point_test() {
+    const OPTIONS: Options = Options::builder().middle_dot_decimal_point(true).build_strict();
+    let parse = |bytes: &[u8]| f64::from_lexical_with_options::<STANDARD>(bytes, &OPTIONS);
+    assert_eq!(parse("3\u{B7}25".as_bytes()), Ok(3.25));
+    assert_eq!(parse(b"3.25"), Ok(3.25));
+    assert_eq!(parse("-0\u{B7}5e2".as_bytes()), Ok(-50.0));
+    assert_eq!(parse("\u{B7}5".as_bytes()), Ok(0.5));
+    assert_eq!(parse("0\u{B7}1234567890123456789012345".as_bytes()), Ok(0.12345678901234568));
+    assert_eq!(parse("3\u{B7}25\u{B7}".as_bytes()), Err(Error::InvalidDigit(5)));
+    assert_eq!(parse("3\u{B7}1x".as_bytes()), Err(Error::InvalidDigit(4)));
+
+    // The byte count includes both bytes of the middle dot.
+    let parse = |bytes: &[u8]| f64::from_lexical_partial_with_options::<STANDARD>(bytes, &OPTIONS);
+    assert_eq!(parse("3\u{B7}25,".as_bytes()), Ok((3.25, 5)));
+    assert_eq!(parse(b"3.25,"), Ok((3.25, 4)));
+
+    // Disabled by default.
+    let bytes = "3\u{B7}25".as_bytes();
+    assert_eq!(f64::from_lexical(bytes), Err(Error::InvalidDigit(1)));
+}
+
+#[test]
+fn magnitude_words_test() {
+    use lexical_parse_float::options::MAGNITUDE_WORDS;
+
+    const OPTIONS: Options = Options::builder().magnitude_words(MAGNITUDE_WORDS).build_strict();
+    let parse = |bytes: &[u8]| f64::from_lexical_with_options::<STANDARD>(bytes, &OPTIONS);
+    assert_eq!(parse(b"1.5B"), Ok(1.5e9));
+    assert_eq!(parse(b"2T"), Ok(2e12));
+    assert_eq!(parse(b"-2.5M"), Ok(-2.5e6));
+    assert_eq!(parse(b"3m"), Ok(3e6));
+    assert_eq!(parse(b"1e3B"), Ok(1e12));
+    assert_eq!(parse(b"42"), Ok(42.0));
+    assert_eq!(f32::from_lexical_with_options::<STANDARD>(b"1.5b", &OPTIONS), Ok(1.5e9));
+
+    // The power is applied before rounding, as with an exponent.
+    assert_eq!(parse(b"540.232460B"), parse(b"540.232460e9"));
+    assert_eq!(parse(b"540.232460B"), Ok(540232460000.0));
+    assert_eq!(parse(b"512.981929M"), Ok(512981929.0));
+
+    // Unrecognized or repeated suffixes are invalid digits.
+    assert_eq!(parse(b"1.5X"), Err(Error::InvalidDigit(3)));
+    assert_eq!(parse(b"1.5BB"), Err(Error::InvalidDigit(4)));
+    assert_eq!(parse(b"1.5B "), Err(Error::InvalidDigit(4)));
+    assert_eq!(parse(b"1e"), Err(Error::EmptyExponent(2)));
+    assert_eq!(parse(b"B"), Err(Error::EmptyMantissa(0)));
+
+    let parse = |bytes: &[u8]| f64::from_lexical_partial_with_options::<STANDARD>(bytes, &OPTIONS);
+    assert_eq!(parse(b"1.5B,"), Ok((1.5e9, 4)));
+    assert_eq!(parse(b"1.5X"), Ok((1.5, 3)));
+    assert_eq!(parse(b"540.232460B,"), Ok((540232460000.0, 11)));
+    assert_eq!(parse(b"512.981929M,"), Ok((512981929.0, 11)));
+
+    // Case-sensitive and required magnitude words.
+    const REQUIRED: Options = Options::builder()
+        .magnitude_words(MAGNITUDE_WORDS)
+        .case_sensitive_magnitude_words(true)
+        .required_magnitude_word(true)
+        .build_strict();
+    let parse = |bytes: &[u8]| f64::from_lexical_with_options::<STANDARD>(bytes, &REQUIRED);
+    assert_eq!(parse(b"1.5B"), Ok(1.5e9));
+    assert_eq!(parse(b"1.5b"), Err(Error::InvalidDigit(3)));
+    assert_eq!(parse(b"1.5"), Err(Error::Empty(3)));
+    let parse = |bytes: &[u8]| f64::from_lexical_partial_with_options::<STANDARD>(bytes, &REQUIRED);
+    assert_eq!(parse(b"1.5"), Err(Error::Empty(3)));
+    assert_eq!(parse(b"1.5x"), Err(Error::InvalidDigit(3)));
+
+    // Disabled by default.
+    assert_eq!(f64::from_lexical(b"1.5B"), Err(Error::InvalidDigit(3)));
+}
+
+#[test]
+fn parse_with_digits_dropped_test() {
+    use lexical_parse_float::{parse_with_digits_dropped, ParsedFloat};
+
+    const OPTIONS: Options = Options::new();
+    let parse = |bytes: &[u8]| parse_with_digits_dropped::<f64, STANDARD>(bytes, &OPTIONS);
+    let dropped = |bytes: &[u8]| parse(bytes).map(|x| x.digits_dropped);
+
+    // 30 significant digits, 13 more than the 17 for an `f64`.
+    let bytes = b"1.23456789012345678901234567891";
+    assert_eq!(
+        parse(bytes),
+        Ok(ParsedFloat {
+            value: 1.2345678901234568,
+            digits_dropped: 13,
+        })
+    );
+    assert_eq!(dropped(b"-123456789012345678901234567891e-20"), Ok(13));
+    assert_eq!(dropped(b"1.5"), Ok(0));
+    assert_eq!(dropped(b"0"), Ok(0));
+    assert_eq!(dropped(b"12345678901234567"), Ok(0));
+    assert_eq!(dropped(b"123456789012345678"), Ok(1));
+
+    // Leading and trailing zeros are not significant.
+    assert_eq!(dropped(b"0.000000000000000000000000000015"), Ok(0));
+    assert_eq!(dropped(b"1500000000000000000000000000000.0000"), Ok(0));
+    assert_eq!(dropped(b"1000000000000000000000000000001"), Ok(14));
+
+    // Special values never drop any digits.
+    assert_eq!(dropped(b"inf"), Ok(0));
+    assert!(parse(b"NaN").unwrap().value.is_nan());
+
+    // Errors are the same as a complete parser.
+    assert_eq!(parse(b"1.5x"), Err(Error::InvalidDigit(3)));
+    assert_eq!(parse(b""), Err(Error::Empty(0)));
+
+    // An `f32` has 9 significant digits.
+    let parsed = parse_with_digits_dropped::<f32, STANDARD>(b"1.2345678901", &OPTIONS);
+    assert_eq!(parsed.map(|x| x.digits_dropped), Ok(2));
+}
+
+#[test]
+fn from_lexical_exactness_test() {
+    use lexical_parse_float::from_lexical_exactness;
+
+    let exact = |bytes: &[u8]| from_lexical_exactness::<f64>(bytes).map(|x| x.1);
+    assert_eq!(from_lexical_exactness::<f64>(b"0.5"), Ok((0.5, true)));
+    assert_eq!(from_lexical_exactness::<f64>(b"0.1"), Ok((0.1, false)));
+    assert_eq!(from_lexical_exactness::<f64>(b"3"), Ok((3.0, true)));
+    assert_eq!(exact(b"-0.5"), Ok(true));
+    assert_eq!(exact(b"0.50000000000000000000000000000"), Ok(true));
+    assert_eq!(exact(b"1e22"), Ok(true));
+    assert_eq!(exact(b"1e23"), Ok(false));
+    assert_eq!(exact(b"9007199254740992"), Ok(true));
+    assert_eq!(exact(b"9007199254740993"), Ok(false));
+
+    // 2^-60 requires the big-integer comparison, as do its neighbors.
+    assert_eq!(exact(b"8.67361737988403547205962240695953369140625e-19"), Ok(true));
+    assert_eq!(exact(b"8.67361737988403547205962240695953369140626e-19"), Ok(false));
+    assert_eq!(exact(b"8.673617379884035472059622406959533691406249e-19"), Ok(false));
+
+    // Zeros are exact, but overflow and underflow are not.
+    assert_eq!(exact(b"0"), Ok(true));
+    assert_eq!(exact(b"-0.000e10"), Ok(true));
+    assert_eq!(exact(b"1e400"), Ok(false));
+    assert_eq!(exact(b"1e-400"), Ok(false));
+    assert_eq!(exact(b"5e-324"), Ok(false));
+
+    // Special values are always exact.
+    assert_eq!(exact(b"inf"), Ok(true));
+    assert_eq!(exact(b"NaN"), Ok(true));
+
+    // Errors are the same as a complete parser.
+    assert_eq!(exact(b"1.5x"), Err(Error::InvalidDigit(3)));
+    assert_eq!(exact(b""), Err(Error::Empty(0)));
+
+    // Exactness depends on the precision of the float.
+    assert_eq!(from_lexical_exactness::<f32>(b"16777217"), Ok((16777216.0, false)));
+    assert_eq!(exact(b"16777217"), Ok(true));
+}
+
+#[test]
+fn from_lexical_range_test() {
+    use lexical_parse_float::from_lexical_range;
+
+    assert_eq!(from_lexical_range::<f64>(b"1.5-2.5", b'-'), Ok((1.5, 2.5, 7)));
+    assert_eq!(from_lexical_range::<f64>(b"1.5 - 2.5", b'-'), Ok((1.5, 2.5, 9)));
+    assert_eq!(from_lexical_range::<f64>(b"-1.5--2.5", b'-'), Ok((-1.5, -2.5, 9)));
+    assert_eq!(from_lexical_range::<f64>(b"1e-3-1e3", b'-'), Ok((1e-3, 1e3, 8)));
+    assert_eq!(from_lexical_range::<f32>(b"1.5,2.5]", b','), Ok((1.5, 2.5, 7)));
+
+    // The decimal point is used as `..`.
+    assert_eq!(from_lexical_range::<f64>(b"1.5..2.5", b'.'), Ok((1.5, 2.5, 8)));
+    assert_eq!(from_lexical_range::<f64>(b"1..2", b'.'), Ok((1.0, 2.0, 4)));
+    assert_eq!(from_lexical_range::<f64>(b"1...5", b'.'), Ok((1.0, 0.5, 5)));
+    assert_eq!(from_lexical_range::<f64>(b"1.5.2.5", b'.'), Err(Error::InvalidDigit(3)));
+
+    // Malformed ranges.
+    assert_eq!(from_lexical_range::<f64>(b"1.5~2.5", b'-'), Err(Error::InvalidDigit(3)));
+    assert_eq!(from_lexical_range::<f64>(b"1.5", b'-'), Err(Error::Empty(3)));
+    assert_eq!(from_lexical_range::<f64>(b"1.5-", b'-'), Err(Error::Empty(4)));
+    assert_eq!(from_lexical_range::<f64>(b"1.5-x", b'-'), Err(Error::EmptyMantissa(4)));
+    assert_eq!(from_lexical_range::<f64>(b"-2.5", b'-'), Err(Error::Empty(4)));
+    assert_eq!(from_lexical_range::<f64>(b"", b'-'), Err(Error::Empty(0)));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn special_digit_separator_test() {
+    // Digit separators in the mantissa are independent of special values.
+    const MANTISSA: u128 = NumberFormatBuilder::new()
+        .digit_separator(num::NonZeroU8::new(b'_'))
+        .integer_internal_digit_separator(true)
+        .build_strict();
+    const SPECIAL: u128 = rebuild(MANTISSA).special_digit_separator(true).build_strict();
+    const OPTIONS: Options = Options::new();
+
+    assert_eq!(f64::from_lexical_with_options::<MANTISSA>(b"1_0", &OPTIONS), Ok(10.0));
+    assert_eq!(f64::from_lexical_with_options::<MANTISSA>(b"inf", &OPTIONS), Ok(f64::INFINITY));
+    assert_eq!(
+        f64::from_lexical_with_options::<MANTISSA>(b"i_nf", &OPTIONS),
+        Err(Error::InvalidDigit(0))
+    );
+    assert!(f64::from_lexical_with_options::<MANTISSA>(b"na_n", &OPTIONS).is_err());
+
+    assert_eq!(f64::from_lexical_with_options::<SPECIAL>(b"1_0", &OPTIONS), Ok(10.0));
+    assert_eq!(f64::from_lexical_with_options::<SPECIAL>(b"i_nf", &OPTIONS), Ok(f64::INFINITY));
+    assert!(f64::from_lexical_with_options::<SPECIAL>(b"na_n", &OPTIONS).unwrap().is_nan());
+}
+
+#[test]
+fn classify_number_test() {
+    use lexical_parse_float::{classify_number, classify_number_with_options, NumberKind};
+
+    let classify = classify_number::<STANDARD>;
+    assert_eq!(classify(b"123"), NumberKind::Integer);
+    assert_eq!(classify(b"-123"), NumberKind::Integer);
+    assert_eq!(classify(b"123456789012345678901234567890"), NumberKind::Integer);
+    assert_eq!(classify(b"1.5"), NumberKind::Float);
+    assert_eq!(classify(b"1."), NumberKind::Float);
+    assert_eq!(classify(b".5"), NumberKind::Float);
+    assert_eq!(classify(b"1e3"), NumberKind::Float);
+    assert_eq!(classify(b"1E-3"), NumberKind::Float);
+    assert_eq!(classify(b"NaN"), NumberKind::Float);
+    assert_eq!(classify(b"-inf"), NumberKind::Float);
+    assert_eq!(classify(b"1x"), NumberKind::Invalid);
+    assert_eq!(classify(b""), NumberKind::Invalid);
+    assert_eq!(classify(b"-"), NumberKind::Invalid);
+    assert_eq!(classify(b"."), NumberKind::Invalid);
+    assert_eq!(classify(b"1e"), NumberKind::Invalid);
+    assert_eq!(classify(b"1.5.5"), NumberKind::Invalid);
+    assert_eq!(classify(b"nana"), NumberKind::Invalid);
+
+    // The classification agrees with the parser.
+    for bytes in [&b"0"[..], b"+1", b"1.5e-3", b"e3", b"1e+", b"1,5", b"infinity", b" 1"] {
+        assert_eq!(classify(bytes) != NumberKind::Invalid, f64::from_lexical(bytes).is_ok());
+    }
+
+    const OPTIONS: Options =
+        Options::builder().decimal_point(b',').exponent(b'^').build_strict();
+    let classify = |bytes| classify_number_with_options::<STANDARD>(bytes, &OPTIONS);
+    assert_eq!(classify(b"1,5"), NumberKind::Float);
+    assert_eq!(classify(b"1^3"), NumberKind::Float);
+    assert_eq!(classify(b"1.5"), NumberKind::Invalid);
+    assert_eq!(classify(b"1e3"), NumberKind::Invalid);
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn classify_number_format_test() {
+    use lexical_parse_float::{classify_number, NumberKind};
+
+    const SEPARATOR: u128 = NumberFormatBuilder::new()
+        .digit_separator(num::NonZeroU8::new(b'_'))
+        .internal_digit_separator(true)
+        .build_strict();
+    assert_eq!(classify_number::<SEPARATOR>(b"1_000"), NumberKind::Integer);
+    assert_eq!(classify_number::<SEPARATOR>(b"1_000.0_1"), NumberKind::Float);
+    assert_eq!(classify_number::<SEPARATOR>(b"1__000"), NumberKind::Invalid);
+
+    const REQUIRED: u128 = rebuild(STANDARD)
+        .required_digits(true)
+        .no_exponent_without_fraction(true)
+        .build_strict();
+    assert_eq!(classify_number::<REQUIRED>(b"1.5e3"), NumberKind::Float);
+    assert_eq!(classify_number::<REQUIRED>(b"1."), NumberKind::Invalid);
+    assert_eq!(classify_number::<REQUIRED>(b".5"), NumberKind::Invalid);
+    assert_eq!(classify_number::<REQUIRED>(b"1e3"), NumberKind::Invalid);
+
+    assert_eq!(classify_number::<{ format::JSON }>(b"01"), NumberKind::Invalid);
+    assert_eq!(classify_number::<{ format::JSON }>(b"NaN"), NumberKind::Invalid);
+    assert_eq!(classify_number::<{ format::JSON }>(b"10"), NumberKind::Integer);
+}
+
+#[test]
+#[cfg(all(feature = "power-of-two", feature = "format"))]
+fn classify_number_prefix_test() {
+    use lexical_parse_float::{classify_number_with_options, NumberKind};
+
+    const FORMAT: u128 = format::C_HEX_FLOAT;
+    const OPTIONS: Options = lexical_parse_float::options::HEX_FLOAT;
+    let classify = |bytes| classify_number_with_options::<FORMAT>(bytes, &OPTIONS);
+    assert_eq!(classify(b"0x1A"), NumberKind::Integer);
+    assert_eq!(classify(b"0x1.8p3"), NumberKind::Float);
+    assert_eq!(classify(b"-0x1p-2"), NumberKind::Float);
+    assert_eq!(classify(b"0"), NumberKind::Integer);
+    assert_eq!(classify(b"0p3"), NumberKind::Float);
+    assert_eq!(classify(b"0x"), NumberKind::Invalid);
+    assert_eq!(classify(b"0x1G"), NumberKind::Invalid);
+}
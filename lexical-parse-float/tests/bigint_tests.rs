@@ -24,3 +24,20 @@ fn simple_test() {
     let expected = vec_from_u32(&[2755359744, 11]);
     assert!(x.data == expected, "failed");
 }
+
+#[test]
+#[cfg(feature = "radix")]
+fn pow_composite_radix_test() {
+    // Composite radixes have their powers of two removed, so only the
+    // odd factor uses the pre-computed large powers.
+    for radix in [6, 10, 12, 14, 15, 18, 20, 24, 28, 30, 36] {
+        let base = Bigint::from_u32(radix);
+        let mut expected = Bigint::from_u32(1);
+        for exp in 0..=300 {
+            let mut x = Bigint::from_u32(1);
+            assert_eq!(x.pow(radix, exp), Some(()));
+            assert!(x.data == expected.data, "radix {radix}, exp {exp}");
+            expected *= &base;
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use lexical_parse_float::fixed_point::{parse_fixed_point, parse_fixed_point_with_options};
+use lexical_parse_float::{Error, Options};
+use lexical_util::format::STANDARD;
+
+#[test]
+fn parse_fixed_point_test() {
+    assert_eq!(parse_fixed_point(b"12.75", 3), Ok((12, 750)));
+    assert_eq!(parse_fixed_point(b"12.750", 3), Ok((12, 750)));
+    assert_eq!(parse_fixed_point(b"12.75", 9), Ok((12, 750_000_000)));
+    assert_eq!(parse_fixed_point(b"12.000000001", 9), Ok((12, 1)));
+    assert_eq!(parse_fixed_point(b"12", 3), Ok((12, 0)));
+    assert_eq!(parse_fixed_point(b"12.", 3), Ok((12, 0)));
+    assert_eq!(parse_fixed_point(b"+12.5", 3), Ok((12, 500)));
+    assert_eq!(parse_fixed_point(b"0.05", 2), Ok((0, 5)));
+    assert_eq!(parse_fixed_point(b"7", 0), Ok((7, 0)));
+
+    // Negative values round the integral part down.
+    assert_eq!(parse_fixed_point(b"-12.75", 3), Ok((-13, 250)));
+    assert_eq!(parse_fixed_point(b"-12.0", 3), Ok((-12, 0)));
+    assert_eq!(parse_fixed_point(b"-0.5", 3), Ok((-1, 500)));
+    assert_eq!(parse_fixed_point(b"-9223372036854775808", 3), Ok((i64::MIN, 0)));
+    assert_eq!(parse_fixed_point(b"-9223372036854775808.5", 3), Err(Error::Underflow(20)));
+
+    // More fractional digits than the scale are rejected.
+    assert_eq!(parse_fixed_point(b"12.7501", 3), Err(Error::InvalidDigit(6)));
+    assert_eq!(parse_fixed_point(b"12.7500", 3), Err(Error::InvalidDigit(6)));
+    assert_eq!(parse_fixed_point(b"12.5", 0), Err(Error::InvalidDigit(3)));
+
+    // Invalid inputs.
+    assert_eq!(parse_fixed_point(b"", 3), Err(Error::Empty(0)));
+    assert_eq!(parse_fixed_point(b"12x", 3), Err(Error::InvalidDigit(2)));
+    assert_eq!(parse_fixed_point(b"12.x", 3), Err(Error::InvalidDigit(3)));
+    assert_eq!(parse_fixed_point(b"12.-5", 3), Err(Error::InvalidDigit(3)));
+    assert_eq!(parse_fixed_point(b"12.5x", 3), Err(Error::InvalidDigit(4)));
+    assert_eq!(parse_fixed_point(b"12.5.5", 3), Err(Error::InvalidDigit(4)));
+    assert_eq!(parse_fixed_point(b"12,5", 3), Err(Error::InvalidDigit(2)));
+    assert_eq!(parse_fixed_point(b"99999999999999999999.5", 3), Err(Error::Overflow(18)));
+}
+
+#[test]
+fn parse_fixed_point_options_test() {
+    const OPTIONS: Options = Options::builder().decimal_point(b',').build_strict();
+    let parse = |bytes| parse_fixed_point_with_options::<STANDARD>(bytes, 6, &OPTIONS);
+    assert_eq!(parse(b"1,5"), Ok((1, 500_000)));
+    assert_eq!(parse(b"-1,5"), Ok((-2, 500_000)));
+    assert_eq!(parse(b"1.5"), Err(Error::InvalidDigit(1)));
+}
+
+#[test]
+#[cfg(feature = "unicode")]
+fn parse_fixed_point_middle_dot_test() {
+    const OPTIONS: Options = Options::builder().middle_dot_decimal_point(true).build_strict();
+    let parse = |bytes: &[u8]| parse_fixed_point_with_options::<STANDARD>(bytes, 3, &OPTIONS);
+    assert_eq!(parse("3\u{B7}25".as_bytes()), Ok((3, 250)));
+    assert_eq!(parse(b"3.25"), Ok((3, 250)));
+    assert_eq!(parse("3\u{B7}2x".as_bytes()), Err(Error::InvalidDigit(4)));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn parse_fixed_point_format_test() {
+    use lexical_util::format::JSON;
+
+    // Leading zeros in the fraction are never leading zeros of the number.
+    const OPTIONS: Options = Options::new();
+    let parse = |bytes| parse_fixed_point_with_options::<JSON>(bytes, 3, &OPTIONS);
+    assert_eq!(parse(b"12.05"), Ok((12, 50)));
+    assert_eq!(parse(b"0.001"), Ok((0, 1)));
+    assert_eq!(parse(b"-0.5"), Ok((-1, 500)));
+    assert_eq!(parse(b"12."), Err(Error::EmptyFraction(3)));
+    assert_eq!(parse(b"12.0x"), Err(Error::InvalidDigit(4)));
+    assert_eq!(parse(b"01.5"), Err(Error::InvalidLeadingZeros(0)));
+}
+
+#[test]
+#[should_panic]
+fn parse_fixed_point_scale_test() {
+    _ = parse_fixed_point(b"1.5", 10);
+}
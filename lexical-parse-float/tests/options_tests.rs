@@ -1,4 +1,5 @@
 use lexical_parse_float::options::{Options, OptionsBuilder};
+use lexical_util::error::Error;
 
 #[test]
 fn invalid_exponent_test() {
@@ -43,6 +44,27 @@ fn invalid_nan_test() {
     assert!(builder.is_valid());
 }
 
+#[test]
+fn invalid_nan_kind_test() {
+    let mut builder = OptionsBuilder::default();
+    builder = builder.snan_string(Some(b"snaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaan"));
+    assert!(!builder.is_valid());
+    assert_eq!(builder.build(), Err(Error::NanStringTooLong));
+    builder = builder.snan_string(Some(b"s-nan"));
+    assert!(!builder.is_valid());
+    assert_eq!(builder.build(), Err(Error::InvalidNanString));
+    builder = builder.snan_string(Some(b""));
+    assert!(!builder.is_valid());
+    builder = builder.snan_string(Some(b"sNaN"));
+    assert!(builder.is_valid());
+    builder = builder.qnan_string(Some(b"qN4N"));
+    assert!(!builder.is_valid());
+    assert_eq!(builder.build(), Err(Error::InvalidNanString));
+    builder = builder.qnan_string(Some(b"qNaN"));
+    assert!(builder.is_valid());
+    assert!(builder.build().is_ok());
+}
+
 #[test]
 fn invalid_inf_test() {
     let mut builder = OptionsBuilder::default();
@@ -96,6 +118,8 @@ fn builder_test() {
     builder = builder.exponent(b'^');
     builder = builder.decimal_point(b',');
     builder = builder.nan_string(Some(b"nan"));
+    builder = builder.snan_string(Some(b"sNaN"));
+    builder = builder.qnan_string(Some(b"qNaN"));
     builder = builder.inf_string(Some(b"Infinity"));
     builder = builder.infinity_string(Some(b"Infiniiiiiity"));
 
@@ -103,6 +127,8 @@ fn builder_test() {
     assert_eq!(builder.get_exponent(), b'^');
     assert_eq!(builder.get_decimal_point(), b',');
     assert_eq!(builder.get_nan_string(), Some("nan".as_bytes()));
+    assert_eq!(builder.get_snan_string(), Some("sNaN".as_bytes()));
+    assert_eq!(builder.get_qnan_string(), Some("qNaN".as_bytes()));
     assert_eq!(builder.get_inf_string(), Some("Infinity".as_bytes()));
     assert_eq!(builder.get_infinity_string(), Some("Infiniiiiiity".as_bytes()));
 
@@ -133,3 +159,130 @@ fn options_test() {
     assert_eq!(Options::builder(), OptionsBuilder::new());
     assert_eq!(opts.rebuild().build(), Ok(opts));
 }
+
+#[test]
+fn skip_options_test() {
+    let builder = OptionsBuilder::new();
+    assert!(!builder.get_skip_bom());
+    assert!(!builder.get_skip_unicode_whitespace());
+    assert!(!builder.get_unicode_exponent_sign());
+    assert!(!builder.get_middle_dot_decimal_point());
+
+    let options = builder.skip_bom(true).build_strict();
+    assert!(options.skip_bom());
+    assert!(!options.skip_unicode_whitespace());
+    assert_eq!(options.rebuild().build().as_ref(), Ok(&options));
+
+    #[cfg(feature = "unicode")]
+    {
+        let options = options.rebuild().skip_unicode_whitespace(true).build_strict();
+        assert!(options.skip_unicode_whitespace());
+        assert_eq!(options.rebuild().build().as_ref(), Ok(&options));
+
+        let options = options.rebuild().unicode_exponent_sign(true).build_strict();
+        assert!(options.unicode_exponent_sign());
+        assert_eq!(options.rebuild().build().as_ref(), Ok(&options));
+
+        let options = options.rebuild().middle_dot_decimal_point(true).build_strict();
+        assert!(options.middle_dot_decimal_point());
+        assert_eq!(options.rebuild().build(), Ok(options));
+    }
+
+    let builder = OptionsBuilder::new();
+    assert_eq!(builder.get_ignore_prefix(), b"");
+    let options = builder.ignore_prefix("€".as_bytes()).build_strict();
+    assert_eq!(options.ignore_prefix(), "€".as_bytes());
+    assert_eq!(options.rebuild().build().as_ref(), Ok(&options));
+
+    let builder = OptionsBuilder::new();
+    assert_eq!(builder.get_max_slow_digits(), None);
+    let options = builder.max_slow_digits(core::num::NonZeroUsize::new(64)).build_strict();
+    assert_eq!(options.max_slow_digits(), core::num::NonZeroUsize::new(64));
+    assert_eq!(options.rebuild().build().as_ref(), Ok(&options));
+}
+
+#[test]
+fn magnitude_words_options_test() {
+    use lexical_parse_float::options::MAGNITUDE_WORDS;
+
+    let builder = OptionsBuilder::new();
+    assert!(builder.get_magnitude_words().is_empty());
+    assert!(!builder.get_case_sensitive_magnitude_words());
+    assert!(!builder.get_required_magnitude_word());
+
+    let options =
+        builder.magnitude_words(MAGNITUDE_WORDS).required_magnitude_word(true).build_strict();
+    assert_eq!(options.magnitude_words(), MAGNITUDE_WORDS);
+    assert!(options.required_magnitude_word());
+    assert_eq!(options.rebuild().build().as_ref(), Ok(&options));
+
+    let invalid = |words: &'static [(u8, u8)]| OptionsBuilder::new().magnitude_words(words).build();
+    assert_eq!(invalid(&[(b'1', 3)]), Err(Error::InvalidMagnitudeWords));
+    assert_eq!(invalid(&[(b'K', 20)]), Err(Error::InvalidMagnitudeWords));
+    assert_eq!(invalid(&[(b'E', 3)]), Err(Error::InvalidMagnitudeWords));
+    assert_eq!(invalid(&[(b'K', 3), (b'k', 3)]), Err(Error::InvalidMagnitudeWords));
+    assert!(!OptionsBuilder::new().required_magnitude_word(true).is_valid());
+
+    // Words differing only by case are valid if case-sensitive.
+    let builder = OptionsBuilder::new().magnitude_words(&[(b'M', 6), (b'm', 6)]);
+    assert!(!builder.is_valid());
+    assert!(builder.case_sensitive_magnitude_words(true).is_valid());
+}
+
+#[test]
+fn trailing_fraction_zeros_options_test() {
+    let builder = OptionsBuilder::new();
+    assert!(!builder.get_no_trailing_fraction_zeros());
+    assert!(!builder.get_allow_zero_fraction());
+
+    let options = builder.no_trailing_fraction_zeros(true).allow_zero_fraction(true).build_strict();
+    assert!(options.no_trailing_fraction_zeros());
+    assert!(options.allow_zero_fraction());
+    assert_eq!(options.rebuild().build().as_ref(), Ok(&options));
+}
+
+#[test]
+fn case_sensitive_special_options_test() {
+    let builder = OptionsBuilder::new();
+    assert!(!builder.get_case_sensitive_nan());
+    assert!(!builder.get_case_sensitive_inf());
+
+    let options = builder.case_sensitive_nan(true).build_strict();
+    assert!(options.case_sensitive_nan());
+    assert!(!options.case_sensitive_inf());
+    let options = options.rebuild().case_sensitive_inf(true).build_strict();
+    assert!(options.case_sensitive_nan());
+    assert!(options.case_sensitive_inf());
+    assert_eq!(options.rebuild().build().as_ref(), Ok(&options));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn thread_local_options_test() {
+    use lexical_parse_float::{
+        from_lexical_default, from_lexical_partial_default, FromLexical, ThreadLocalOptions,
+    };
+
+    fn parse(bytes: &[u8]) -> lexical_parse_float::Result<f64> {
+        from_lexical_default(bytes)
+    }
+
+    assert_eq!(parse(b"1.5"), Ok(1.5));
+    let comma = Options::builder().decimal_point(b',').build().unwrap();
+    let previous = ThreadLocalOptions::set_default(comma.clone());
+    assert_eq!(previous, Options::new());
+    assert_eq!(parse(b"1,5"), Ok(1.5));
+    assert_eq!(parse(b"1.5"), Err(Error::InvalidDigit(1)));
+    assert_eq!(from_lexical_partial_default::<f32>(b"1,5.2"), Ok((1.5, 3)));
+    assert_eq!(ThreadLocalOptions::with_default(|options| options.clone()), comma);
+
+    // The defaults are not used by `from_lexical`.
+    assert_eq!(f64::from_lexical(b"1.5"), Ok(1.5));
+
+    // Other threads keep the standard defaults.
+    let result = std::thread::spawn(|| parse(b"1.5")).join().unwrap();
+    assert_eq!(result, Ok(1.5));
+
+    assert_eq!(ThreadLocalOptions::set_default(previous), comma);
+    assert_eq!(parse(b"1.5"), Ok(1.5));
+}
@@ -192,19 +192,64 @@ fn parse_u64_digits_test() {
     assert_eq!(step, 0);
 }
 
+#[test]
+fn parse_up_to_n_digits_test() {
+    use lexical_util::iterator::Iter;
+
+    const FORMAT: u128 = STANDARD;
+    let mut byte = b"123456789".bytes::<{ FORMAT }>();
+    assert_eq!(parse::parse_up_to_n_digits(&mut byte, 5), (12345, 5));
+    assert_eq!(byte.cursor(), 5);
+    assert_eq!(parse::parse_up_to_n_digits(&mut byte, 5), (6789, 4));
+    assert_eq!(byte.cursor(), 9);
+    assert_eq!(parse::parse_up_to_n_digits(&mut byte, 5), (0, 0));
+
+    let mut byte = b"12345678901234567890123".bytes::<{ FORMAT }>();
+    assert_eq!(parse::parse_up_to_n_digits(&mut byte, u64_step(10)), (1234567890123456789, 19));
+
+    // Stops at the first byte that is not a digit.
+    let mut byte = b"12.5".bytes::<{ FORMAT }>();
+    assert_eq!(parse::parse_up_to_n_digits(&mut byte, 5), (12, 2));
+    assert_eq!(byte.cursor(), 2);
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn parse_up_to_n_digits_separator_test() {
+    use core::num;
+
+    use lexical_util::format::NumberFormatBuilder;
+    use lexical_util::iterator::Iter;
+
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(num::NonZeroU8::new(b'_'))
+        .integer_internal_digit_separator(true)
+        .build_strict();
+    let mut byte = b"1_234_567".bytes::<{ FORMAT }>();
+    assert_eq!(parse::parse_up_to_n_digits(&mut byte, 5), (12345, 5));
+    assert_eq!(byte.cursor(), 7);
+    assert_eq!(parse::parse_up_to_n_digits(&mut byte, 5), (67, 2));
+}
+
 #[test]
 fn is_special_eq_test() {
     const FORMAT: u128 = STANDARD;
 
     let digits = b"NaN";
     let byte = digits.bytes::<{ FORMAT }>();
-    assert_eq!(parse::is_special_eq::<FORMAT>(byte.clone(), b"nan"), 3);
+    assert_eq!(parse::is_special_eq::<FORMAT>(byte.clone(), b"nan", false), 3);
 
     let byte = digits.bytes::<{ FORMAT }>();
-    assert_eq!(parse::is_special_eq::<FORMAT>(byte.clone(), b"NaN"), 3);
+    assert_eq!(parse::is_special_eq::<FORMAT>(byte.clone(), b"NaN", false), 3);
 
     let byte = digits.bytes::<{ FORMAT }>();
-    assert_eq!(parse::is_special_eq::<FORMAT>(byte.clone(), b"inf"), 0);
+    assert_eq!(parse::is_special_eq::<FORMAT>(byte.clone(), b"inf", false), 0);
+
+    let byte = digits.bytes::<{ FORMAT }>();
+    assert_eq!(parse::is_special_eq::<FORMAT>(byte.clone(), b"nan", true), 0);
+
+    let byte = digits.bytes::<{ FORMAT }>();
+    assert_eq!(parse::is_special_eq::<FORMAT>(byte.clone(), b"NaN", true), 3);
 }
 
 #[test]
@@ -265,3 +310,30 @@ fn parse_parse_special_test() {
     let result = parse::parse_special::<f64, FORMAT>(byte, true, &OPTIONS);
     assert_eq!(result, None);
 }
+
+#[test]
+fn parse_case_sensitive_special_test() {
+    const FORMAT: u128 = STANDARD;
+
+    // Case-sensitive NaN, while infinity remains case-insensitive.
+    const OPTIONS: Options = Options::builder().case_sensitive_nan(true).build_strict();
+    let special =
+        |digits: &[u8]| parse::parse_special::<f64, FORMAT>(digits.bytes(), false, &OPTIONS);
+    assert!(special(b"NaN").unwrap().is_nan());
+    assert_eq!(special(b"nan"), None);
+    assert_eq!(special(b"NAN"), None);
+    assert_eq!(special(b"inf"), Some(f64::INFINITY));
+    assert_eq!(special(b"INF"), Some(f64::INFINITY));
+    assert_eq!(special(b"Infinity"), Some(f64::INFINITY));
+
+    // Case-sensitive infinity, while NaN remains case-insensitive.
+    const INF: Options = Options::builder()
+        .infinity_string(Some(b"Infinity"))
+        .case_sensitive_inf(true)
+        .build_strict();
+    let special = |digits: &[u8]| parse::parse_special::<f64, FORMAT>(digits.bytes(), false, &INF);
+    assert_eq!(special(b"Infinity"), Some(f64::INFINITY));
+    assert_eq!(special(b"infinity"), None);
+    assert_eq!(special(b"INF"), None);
+    assert!(special(b"nan").unwrap().is_nan());
+}
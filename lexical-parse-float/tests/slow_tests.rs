@@ -69,13 +69,13 @@ fn slow_radix_test() {
         mant: 1 << 63,
         exp: -63,
     };
-    let result = slow::slow_radix::<f64, FORMAT>(num.clone(), fp);
+    let result = slow::slow_radix::<f64, FORMAT>(num.clone(), fp, usize::MAX);
     assert_eq!(result.mant, 0);
     assert_eq!(result.exp, 0);
 
     // 5e-324, round-up.
     num.fraction = Some(b"47032822920623272088284396434110686182529901307162382212792841250337753635104375932649918180817996189898282347722858865463328355177969898199387398005390939063150356595155702263922908583924491051844359318028499365361525003193704576782492193656236698636584807570015857692699037063119282795585513329278343384093519780155312465972635795746227664652728272200563740064854999770965994704540208281662262378573934507363390079677619305775067401763246736009689513405355374585166611342237666786041621596804619144672918403005300575308490487653917113865916462395249126236538818796362393732804238910186723484976682350898633885879256283027559956575244555072551893136908362547791869486679949683240497058210285131854513962138377228261454376934125320985913276672363281251");
-    let result = slow::slow_radix::<f64, FORMAT>(num.clone(), fp);
+    let result = slow::slow_radix::<f64, FORMAT>(num.clone(), fp, usize::MAX);
     assert_eq!(result.mant, 1);
     assert_eq!(result.exp, 0);
 
@@ -92,13 +92,13 @@ fn slow_radix_test() {
         mant: 9223372036854776832,
         exp: 2035,
     };
-    let result = slow::slow_radix::<f64, FORMAT>(num.clone(), fp);
+    let result = slow::slow_radix::<f64, FORMAT>(num.clone(), fp, usize::MAX);
     assert_eq!(result.mant, 0);
     assert_eq!(result.exp, 2046);
 
     // 8.988465674311582e+307
     num.fraction = Some(b"98846567431158053656668072130502949627624141313081589739713427561540454154866937524136980060240969353498844031142021255416291053696845311086136572877053658847429381365898442381794745560514296474151486978574387976858590638908514073910088308747655630259515975825139366555781573480200663642101543165321617080321");
-    let result = slow::slow_radix::<f64, FORMAT>(num.clone(), fp);
+    let result = slow::slow_radix::<f64, FORMAT>(num.clone(), fp, usize::MAX);
     assert_eq!(result.mant, 1);
     assert_eq!(result.exp, 2046);
 }
@@ -0,0 +1,53 @@
+#![cfg(not(feature = "compact"))]
+
+use lexical_parse_float::bigint::Limb;
+use lexical_parse_float::limits::u64_power_limit;
+use lexical_parse_float::table::get_large_int_power;
+
+/// Calculate `radix^exp` as little-endian, 32-bit limbs.
+fn pow_u32_limbs(radix: u32, exp: u32) -> Vec<u32> {
+    let mut result = vec![1u32];
+    for _ in 0..exp {
+        let mut carry = 0u64;
+        for limb in result.iter_mut() {
+            let value = u64::from(*limb) * u64::from(radix) + carry;
+            *limb = value as u32;
+            carry = value >> 32;
+        }
+        if carry != 0 {
+            result.push(carry as u32);
+        }
+    }
+    result
+}
+
+/// Convert native limbs to little-endian, 32-bit limbs.
+fn as_u32_limbs(limbs: &[Limb]) -> Vec<u32> {
+    let mut result: Vec<u32> = limbs
+        .iter()
+        .flat_map(|&limb| {
+            let limb = u64::from(limb);
+            [limb as u32, (limb >> 32) as u32]
+        })
+        .collect();
+    while result.last() == Some(&0) {
+        result.pop();
+    }
+    result
+}
+
+fn check_large_power(radix: u32) {
+    let (large, step) = get_large_int_power(radix);
+    assert_eq!(step, 5 * u64_power_limit(radix), "radix {radix}");
+    assert_ne!(large.last(), Some(&0), "radix {radix} is not normalized");
+    assert_eq!(as_u32_limbs(large), pow_u32_limbs(radix, step), "radix {radix}");
+}
+
+#[test]
+fn large_power_test() {
+    check_large_power(5);
+    #[cfg(feature = "radix")]
+    for radix in (3..=35).step_by(2) {
+        check_large_power(radix);
+    }
+}
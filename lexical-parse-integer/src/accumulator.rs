@@ -0,0 +1,104 @@
+//! Parse integers into caller-provided accumulator types.
+//!
+//! This allows reusing the digit scanning logic for integer-like types that
+//! are not natively supported, such as 256-bit integers from external
+//! big-integer crates.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use lexical_parse_integer::accumulator::{Overflow, ParseAccumulator};
+//! use lexical_parse_integer::from_lexical_into;
+//!
+//! #[derive(Default)]
+//! struct Digits(Vec<u32>);
+//!
+//! impl ParseAccumulator for Digits {
+//!     fn mul_add_digit(&mut self, _: u32, digit: u32) -> Result<(), Overflow> {
+//!         self.0.push(digit);
+//!         Ok(())
+//!     }
+//! }
+//!
+//! let mut digits = Digits::default();
+//! assert_eq!(from_lexical_into(b"1234", &mut digits), Ok(()));
+//! assert_eq!(digits.0, [1, 2, 3, 4]);
+//! ```
+
+/// Error when an accumulator cannot represent the parsed value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Overflow;
+
+/// Integer-like type that digits can be accumulated into.
+///
+/// The digits are processed from most to least significant, so parsing
+/// `123` calls `mul_add_digit(10, 1)`, `mul_add_digit(10, 2)`, and then
+/// `mul_add_digit(10, 3)`. Negative values, which are only parsed if
+/// [`IS_SIGNED`] is set, call [`mul_sub_digit`] instead.
+///
+/// [`IS_SIGNED`]: Self::IS_SIGNED
+/// [`mul_sub_digit`]: Self::mul_sub_digit
+pub trait ParseAccumulator {
+    /// If the accumulator can represent negative values.
+    const IS_SIGNED: bool = false;
+
+    /// Multiply the current value by `radix` and add `digit` to it.
+    ///
+    /// Returns [`Overflow`] if the result cannot be represented.
+    fn mul_add_digit(&mut self, radix: u32, digit: u32) -> Result<(), Overflow>;
+
+    /// Multiply the current value by `radix` and subtract `digit` from it.
+    ///
+    /// Returns [`Overflow`] if the result cannot be represented, which is
+    /// always the case for unsigned accumulators.
+    #[inline(always)]
+    fn mul_sub_digit(&mut self, radix: u32, digit: u32) -> Result<(), Overflow> {
+        _ = (radix, digit);
+        Err(Overflow)
+    }
+}
+
+macro_rules! parse_accumulator_impl {
+    ($($t:ty)*) => ($(
+        impl ParseAccumulator for $t {
+            #[inline(always)]
+            fn mul_add_digit(&mut self, radix: u32, digit: u32) -> Result<(), Overflow> {
+                *self = self
+                    .checked_mul(radix as $t)
+                    .and_then(|x| x.checked_add(digit as $t))
+                    .ok_or(Overflow)?;
+                Ok(())
+            }
+        }
+    )*);
+}
+
+parse_accumulator_impl! { u8 u16 u32 u64 u128 usize }
+
+macro_rules! parse_signed_accumulator_impl {
+    ($($t:ty)*) => ($(
+        impl ParseAccumulator for $t {
+            const IS_SIGNED: bool = true;
+
+            #[inline(always)]
+            fn mul_add_digit(&mut self, radix: u32, digit: u32) -> Result<(), Overflow> {
+                *self = self
+                    .checked_mul(radix as $t)
+                    .and_then(|x| x.checked_add(digit as $t))
+                    .ok_or(Overflow)?;
+                Ok(())
+            }
+
+            #[inline(always)]
+            fn mul_sub_digit(&mut self, radix: u32, digit: u32) -> Result<(), Overflow> {
+                *self = self
+                    .checked_mul(radix as $t)
+                    .and_then(|x| x.checked_sub(digit as $t))
+                    .ok_or(Overflow)?;
+                Ok(())
+            }
+        }
+    )*);
+}
+
+parse_signed_accumulator_impl! { i8 i16 i32 i64 i128 isize }
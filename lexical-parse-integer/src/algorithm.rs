@@ -20,7 +20,7 @@
 //!    substantial performance hits.
 //! 2. Overflow checking on invalid digits for partial parsers, while just
 //!    returning invalid digits for complete parsers.
-//! 3. A format-aware sign parser.
+//! 3. A format-aware sign parser, including support for trailing signs.
 //! 4. Digit parsing algorithms which explicitly wrap on overflow, for no
 //!    additional overhead. This has major performance wins for **most**
 //!    real-world integers, so most valid input will be substantially faster.
@@ -112,6 +112,20 @@ macro_rules! into_error {
     }};
 }
 
+/// Return an error for a buffer without any digits.
+///
+/// If the buffer only contains a sign, this is [`Error::EmptyMantissa`],
+/// otherwise, it's [`Error::Empty`].
+macro_rules! into_empty_error {
+    ($bytes:ident, $index:expr) => {{
+        if matches!($bytes, [b'+' | b'-']) {
+            into_error!(EmptyMantissa, $index);
+        } else {
+            into_error!(Empty, $index);
+        }
+    }};
+}
+
 /// Handle an invalid digit if the format feature is enabled.
 ///
 /// This is because we can have special, non-digit characters near
@@ -217,18 +231,58 @@ macro_rules! parse_sign {
 
 /// Parse the sign from the leading digits.
 #[cfg_attr(not(feature = "compact"), inline(always))]
-pub fn parse_sign<T: Integer, const FORMAT: u128>(byte: &mut Bytes<'_, FORMAT>) -> Result<bool> {
+pub fn parse_sign<T: Integer, const FORMAT: u128>(
+    byte: &mut Bytes<'_, FORMAT>,
+    options: &Options,
+) -> Result<bool> {
     let format = NumberFormat::<FORMAT> {};
     parse_sign!(
         byte,
         T::IS_SIGNED,
-        format.no_positive_mantissa_sign(),
-        format.required_mantissa_sign(),
+        format.no_positive_mantissa_sign() || options.no_positive_sign(),
+        format.required_mantissa_sign() || options.required_sign(),
         InvalidPositiveSign,
         MissingSign
     )
 }
 
+/// Parse the sign from the leading exponent digits.
+///
+/// This uses the exponent sign flags of the format, which are independent
+/// of the mantissa sign flags used by `parse_sign`.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+pub fn parse_exponent_sign<const FORMAT: u128>(byte: &mut Bytes<'_, FORMAT>) -> Result<bool> {
+    let format = NumberFormat::<FORMAT> {};
+    parse_sign!(
+        byte,
+        true,
+        format.no_positive_exponent_sign(),
+        format.required_exponent_sign(),
+        InvalidPositiveExponentSign,
+        MissingExponentSign
+    )
+}
+
+/// Find the sign trailing the digits, if there is no leading sign.
+///
+/// Returns the index of the sign and if the value is negative. A trailing
+/// `+` is an error if positive signs are not allowed.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+pub fn parse_trailing_sign<const FORMAT: u128>(
+    bytes: &[u8],
+    index: Option<usize>,
+    options: &Options,
+) -> Result<Option<(usize, bool)>> {
+    let format = NumberFormat::<FORMAT> {};
+    let no_positive = format.no_positive_mantissa_sign() || options.no_positive_sign();
+    match (bytes.first(), index) {
+        (Some(&b'+' | &b'-'), _) | (_, None) => Ok(None),
+        (_, Some(index)) if bytes[index] == b'-' => Ok(Some((index, true))),
+        (_, Some(index)) if no_positive => Err(Error::InvalidPositiveSign(index)),
+        (_, Some(index)) => Ok(Some((index, false))),
+    }
+}
+
 // FOUR DIGITS
 
 /// Determine if 4 bytes, read raw from bytes, are 4 digits for the radix.
@@ -575,10 +629,18 @@ macro_rules! parse_digits_checked {
 /// * `into_ok` - Behavior when returning a valid value.
 /// * `invalid_digit` - Behavior when an invalid digit is found.
 /// * `no_multi_digit` - If to disable multi-digit optimizations.
-/// * `is_partial` - If the parser is a partial parser.
+/// * `options` - Options to dictate sign parsing.
+/// * `trailing_sign` - If a trailing sign was found, and if it was negative.
 #[rustfmt::skip]
 macro_rules! algorithm {
-($bytes:ident, $into_ok:ident, $invalid_digit:ident, $no_multi_digit:expr) => {{
+(
+    $bytes:ident,
+    $into_ok:ident,
+    $invalid_digit:ident,
+    $no_multi_digit:expr,
+    $options:ident,
+    $trailing_sign:expr
+) => {{
     // WARNING:
     // --------
     // None of this code can be changed for optimization reasons.
@@ -601,20 +663,27 @@ macro_rules! algorithm {
     // Removes conditional paths, to, which simplifies maintenance.
     // The skip version of the iterator automatically coalesces to
     // the no-skip iterator.
+    #[cfg(not(feature = "format"))]
     let mut byte = $bytes.bytes::<FORMAT>();
+    #[cfg(feature = "format")]
+    let mut byte = $bytes.bytes::<FORMAT>().with_digit_separators($options.digit_separators());
     let radix = NumberFormat::<FORMAT>::MANTISSA_RADIX;
 
-    let is_negative = parse_sign::<T, FORMAT>(&mut byte)?;
+    let is_negative = match $trailing_sign {
+        Some(is_negative) => is_negative,
+        None => parse_sign::<T, FORMAT>(&mut byte, $options)?,
+    };
     let mut iter = byte.integer_iter();
     if iter.is_buffer_empty() {
         // Our default format **ALWAYS** requires significant digits, however,
-        // we can have cases where we don
+        // we can have cases where we don. A sign without any digits is
+        // an empty mantissa, rather than an empty buffer.
         #[cfg(not(feature = "format"))]
-        into_error!(Empty, iter.cursor());
+        into_empty_error!($bytes, iter.cursor());
 
         #[cfg(feature = "format")]
         if required_digits!() {
-            into_error!(Empty, iter.cursor());
+            into_empty_error!($bytes, iter.cursor());
         } else {
             $into_ok!(T::ZERO, iter.cursor(), 0)
         }
@@ -677,12 +746,14 @@ macro_rules! algorithm {
     let cannot_overflow = iter.as_slice().len() <= overflow_digits;
 
     //  NOTE:
-    //      Don't add optimizations for 128-bit integers.
+    //      Don't add optimizations for 128-bit integers here.
     //      128-bit multiplication is rather efficient, it's only division
     //      that's very slow. Any shortcut optimizations increasing branching,
     //      and even if parsing a 64-bit integer is marginally faster, it
     //      culminates in **way** slower performance overall for simple
-    //      integers, and no improvement for large integers.
+    //      integers, and no improvement for large integers. Short inputs
+    //      are instead parsed as 64-bit integers before reaching this, in
+    //      `parse.rs`, which is a single branch on the input length.
     let mut value = T::ZERO;
     if cannot_overflow && is_negative {
         parse_digits_unchecked!(value, iter, wrapping_sub, start_index, $invalid_digit, $no_multi_digit, true);
@@ -704,7 +775,19 @@ pub fn algorithm_complete<T, const FORMAT: u128>(bytes: &[u8], options: &Options
 where
     T: Integer,
 {
-    algorithm!(bytes, into_ok_complete, invalid_digit_complete, options.get_no_multi_digit())
+    // A trailing sign can only be the last character.
+    let mut trailing_sign = None;
+    let mut bytes = bytes;
+    if T::IS_SIGNED && options.allow_trailing_sign() {
+        let index = bytes.len().checked_sub(1).filter(|&i| matches!(bytes[i], b'+' | b'-'));
+        trailing_sign = parse_trailing_sign::<FORMAT>(bytes, index, options)?;
+        if let Some((index, _)) = trailing_sign {
+            bytes = &bytes[..index];
+        }
+    }
+    let trailing_sign = trailing_sign.map(|(_, is_negative)| is_negative);
+    let no_multi_digit = options.get_no_multi_digit();
+    algorithm!(bytes, into_ok_complete, invalid_digit_complete, no_multi_digit, options, trailing_sign)
 }
 
 /// Algorithm for the partial parser.
@@ -716,5 +799,40 @@ pub fn algorithm_partial<T, const FORMAT: u128>(
 where
     T: Integer,
 {
-    algorithm!(bytes, into_ok_partial, invalid_digit_partial, options.get_no_multi_digit())
+    // A trailing sign must directly follow the digits, otherwise we
+    // parse the value without it.
+    if T::IS_SIGNED && options.allow_trailing_sign() {
+        let index = bytes.iter().position(|&c| matches!(c, b'+' | b'-'));
+        if let Some((index, is_negative)) = parse_trailing_sign::<FORMAT>(bytes, index, options)? {
+            let digits = &bytes[..index];
+            let (value, count) =
+                algorithm_partial_impl::<T, FORMAT>(digits, options, Some(is_negative))?;
+            if count == index {
+                return Ok((value, index + 1));
+            }
+        }
+
+        // Cannot have both a leading and a trailing sign.
+        let (value, count) = algorithm_partial_impl::<T, FORMAT>(bytes, options, None)?;
+        let is_sign = |c: Option<&u8>| matches!(c, Some(&b'+' | &b'-'));
+        if is_sign(bytes.first()) && is_sign(bytes.get(count)) {
+            return Err(Error::InvalidDigit(count));
+        }
+        return Ok((value, count));
+    }
+    algorithm_partial_impl::<T, FORMAT>(bytes, options, None)
+}
+
+/// Algorithm for the partial parser, with an optional trailing sign.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn algorithm_partial_impl<T, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+    trailing_sign: Option<bool>,
+) -> Result<(T, usize)>
+where
+    T: Integer,
+{
+    let no_multi_digit = options.get_no_multi_digit();
+    algorithm!(bytes, into_ok_partial, invalid_digit_partial, no_multi_digit, options, trailing_sign)
 }
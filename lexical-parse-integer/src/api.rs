@@ -2,11 +2,29 @@
 
 #![doc(hidden)]
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+#[cfg(feature = "unicode")]
+use lexical_util::algorithm::ltrim_unicode_whitespace_count;
+use lexical_util::algorithm::utf8_bom_count;
+use lexical_util::digit::{char_is_digit_const, char_to_digit_const};
+use lexical_util::error::{shift_error, Error};
 use lexical_util::format::{NumberFormat, STANDARD};
+use lexical_util::iterator::{AsBytes, Bytes, DigitsIter, Iter};
+use lexical_util::num::as_cast;
+use lexical_util::result::Result;
 use lexical_util::{from_lexical, from_lexical_with_options};
 
+use crate::accumulator::ParseAccumulator;
+use crate::algorithm::parse_exponent_sign;
+#[cfg(feature = "std")]
+use crate::options::ThreadLocalOptions;
 use crate::options::{Options, STANDARD as DEFAULT_OPTIONS};
 use crate::parse::ParseInteger;
+use crate::parse_sign;
 
 /// Implement `FromLexical` for numeric type.
 ///
@@ -44,8 +62,23 @@ macro_rules! integer_from_lexical {
                 let format = NumberFormat::<{ FORMAT }> {};
                 if !format.is_valid() {
                     return Err(format.error());
+                } else if !is_valid_suffixes::<FORMAT>(options) {
+                    return Err(Error::InvalidIgnoreSuffix);
                 }
-                Self::parse_complete::<FORMAT>(bytes, options)
+                let skip = skip_count(bytes, options);
+                let end = bytes.len() - trailing_whitespace_count(&bytes[skip..], options);
+                let end = end - trailing_suffix_count(&bytes[skip..end], options);
+                let parse = |bytes: &[u8]| {
+                    #[cfg(feature = "radix")]
+                    if let Some(indicator) = options.radix_indicator() {
+                        return parse_radix_indicator_complete::<Self>(bytes, indicator)
+                            .map(|value| (value, 0));
+                    }
+                    parse_exponent_complete::<Self, FORMAT>(bytes, options).map(|value| (value, 0))
+                };
+                parse_translated(&bytes[skip..end], options, parse)
+                    .map(|(value, _)| value)
+                    .map_err(|error| shift_error(error, skip))
             }
 
             #[cfg_attr(not(feature = "compact"), inline)]
@@ -57,8 +90,27 @@ macro_rules! integer_from_lexical {
                 let format = NumberFormat::<{ FORMAT }> {};
                 if !format.is_valid() {
                     return Err(format.error());
+                } else if !is_valid_suffixes::<FORMAT>(options) {
+                    return Err(Error::InvalidIgnoreSuffix);
                 }
-                Self::parse_partial::<FORMAT>(bytes, options)
+                let skip = skip_count(bytes, options);
+                let parse = |bytes: &[u8]| {
+                    #[cfg(feature = "radix")]
+                    if let Some(indicator) = options.radix_indicator() {
+                        return parse_radix_indicator_partial::<Self>(bytes, indicator);
+                    }
+                    parse_exponent_partial::<Self, FORMAT>(bytes, options)
+                };
+                parse_translated(&bytes[skip..], options, parse)
+                    .map(|(value, count)| match count {
+                        0 => (value, 0),
+                        _ => {
+                            let count = skip + count;
+                            let count = count + suffix_count(&bytes[count..], options);
+                            (value, count + whitespace_count(&bytes[count..], options))
+                        },
+                    })
+                    .map_err(|error| shift_error(error, skip))
             }
         }
     )*)
@@ -80,3 +132,1557 @@ integer_from_lexical! {
     i128 u128 ;
     isize usize ;
 }
+
+/// Parse a complete integer with the default options for the current thread.
+///
+/// This is [`from_lexical`][FromLexical::from_lexical], but uses the
+/// options set by [`ThreadLocalOptions::set_default`] rather than the
+/// standard options, so the options only need to be configured once.
+///
+/// * `bytes`   - Slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_integer::{from_lexical_default, Options, ThreadLocalOptions};
+///
+/// assert_eq!(from_lexical_default::<u32>(b"42"), Ok(42));
+/// let options = Options::builder().trim_whitespace(true).build_strict();
+/// ThreadLocalOptions::set_default(options);
+/// assert_eq!(from_lexical_default::<u32>(b" 42 "), Ok(42));
+/// ```
+#[cfg(feature = "std")]
+#[inline]
+pub fn from_lexical_default<T>(bytes: &[u8]) -> Result<T>
+where
+    T: FromLexicalWithOptions<Options = Options>,
+{
+    ThreadLocalOptions::with_default(|options| {
+        T::from_lexical_with_options::<STANDARD>(bytes, options)
+    })
+}
+
+/// Parse a partial integer with the default options for the current thread.
+///
+/// This is [`from_lexical_partial`][FromLexical::from_lexical_partial],
+/// but uses the options set by [`ThreadLocalOptions::set_default`] rather
+/// than the standard options.
+///
+/// * `bytes`   - Slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_integer::{from_lexical_partial_default, Options, ThreadLocalOptions};
+///
+/// let options = Options::builder().trim_whitespace(true).build_strict();
+/// ThreadLocalOptions::set_default(options);
+/// assert_eq!(from_lexical_partial_default::<u32>(b" 42 ,1"), Ok((42, 4)));
+/// ```
+#[cfg(feature = "std")]
+#[inline]
+pub fn from_lexical_partial_default<T>(bytes: &[u8]) -> Result<(T, usize)>
+where
+    T: FromLexicalWithOptions<Options = Options>,
+{
+    ThreadLocalOptions::with_default(|options| {
+        T::from_lexical_partial_with_options::<STANDARD>(bytes, options)
+    })
+}
+
+/// Parse exactly `n` digits from the start of a fixed-width field.
+///
+/// This parses the first `n` bytes as decimal digits, without a sign,
+/// returning the parsed value and the remaining, unparsed bytes. This
+/// is useful for fixed-width columns, such as dates in `YYYYMMDD` form.
+/// Returns [`InvalidDigit`] if any of the first `n` bytes is not a digit,
+/// or [`Empty`] with the number of available digits if there are fewer
+/// than `n` bytes.
+///
+/// This only uses the standard format, which is contiguous and therefore
+/// has no digit separators.
+///
+/// * `bytes`   - Slice containing a numeric string.
+/// * `n`       - The number of digits to parse.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_integer::from_lexical_n;
+///
+/// let (year, rest) = from_lexical_n::<u16>(b"20240115", 4).unwrap();
+/// let (month, rest) = from_lexical_n::<u8>(rest, 2).unwrap();
+/// let (day, rest) = from_lexical_n::<u8>(rest, 2).unwrap();
+/// assert_eq!((year, month, day), (2024, 1, 15));
+/// assert!(rest.is_empty());
+/// ```
+///
+/// [`InvalidDigit`]: Error::InvalidDigit
+/// [`Empty`]: Error::Empty
+#[inline]
+pub fn from_lexical_n<T: ParseInteger>(bytes: &[u8], n: usize) -> Result<(T, &[u8])> {
+    let mut byte = bytes.bytes::<STANDARD>();
+    let mut iter = byte.integer_iter();
+    // NOTE: The standard format is always contiguous, so this never fails.
+    let digits = match iter.take_n(n) {
+        Some(digits) => digits.get_buffer(),
+        None => return Err(Error::Empty(0)),
+    };
+    let radix = NumberFormat::<STANDARD>::MANTISSA_RADIX;
+    if let Some(index) = digits.iter().position(|&c| !char_is_digit_const(c, radix)) {
+        return Err(Error::InvalidDigit(index));
+    } else if digits.len() < n {
+        return Err(Error::Empty(digits.len()));
+    }
+
+    let value = T::parse_complete::<STANDARD>(digits, &DEFAULT_OPTIONS)?;
+    Ok((value, &bytes[digits.len()..]))
+}
+
+/// Parse a fixed-width field of decimal digits into packed BCD.
+///
+/// Each byte must be a decimal digit, and two digits are packed per output
+/// byte, with the first digit in the high nibble, as with binary-coded
+/// decimal. An odd number of digits is left-padded with a zero nibble.
+/// Returns the packed digits, starting at the first output byte, and the
+/// number of output bytes used. The remaining output bytes are zero.
+///
+/// Returns [`Empty`] if there are no digits, [`InvalidDigit`] if a byte is
+/// not a decimal digit, and [`Overflow`] if the digits do not fit in `N`
+/// bytes, at the index of the first digit that does not fit.
+///
+/// * `bytes`   - Slice containing the decimal digits.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_integer::{parse_bcd, Error};
+///
+/// assert_eq!(parse_bcd::<4>(b"12345"), Ok(([0x01, 0x23, 0x45, 0x00], 3)));
+/// assert_eq!(parse_bcd::<4>(b"1234"), Ok(([0x12, 0x34, 0x00, 0x00], 2)));
+/// assert_eq!(parse_bcd::<4>(b"12a4"), Err(Error::InvalidDigit(2)));
+/// ```
+///
+/// [`Empty`]: Error::Empty
+/// [`InvalidDigit`]: Error::InvalidDigit
+/// [`Overflow`]: Error::Overflow
+pub fn parse_bcd<const N: usize>(bytes: &[u8]) -> Result<([u8; N], usize)> {
+    let radix = NumberFormat::<STANDARD>::MANTISSA_RADIX;
+    if let Some(index) = bytes.iter().position(|&c| !char_is_digit_const(c, radix)) {
+        return Err(Error::InvalidDigit(index));
+    } else if bytes.is_empty() {
+        return Err(Error::Empty(0));
+    } else if bytes.len() > 2 * N {
+        return Err(Error::Overflow(2 * N));
+    }
+
+    // Pad an odd number of digits with a leading zero nibble.
+    let mut packed = [0u8; N];
+    let count = (bytes.len() + 1) / 2;
+    let padding = bytes.len() % 2;
+    for (index, &c) in bytes.iter().enumerate() {
+        let nibble = index + padding;
+        let digit = c - b'0';
+        packed[nibble / 2] |= if nibble % 2 == 0 { digit << 4 } else { digit };
+    }
+    Ok((packed, count))
+}
+
+/// Parse a partial integer starting at an index, to resume parsing a buffer.
+///
+/// This parses the bytes starting at `start`, as with [`from_lexical_partial`],
+/// and returns the parsed value and the index in `bytes` after the last
+/// byte processed, so the next token can be inspected and parsing can
+/// continue from there. Error indexes are also relative to the start of
+/// `bytes`. If `start` is past the end of the buffer, this returns [`Empty`]
+/// with the length of the buffer.
+///
+/// * `bytes`   - Slice containing a numeric string.
+/// * `start`   - The index in `bytes` to start parsing at.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_integer::{from_lexical_resumable, Error};
+///
+/// let bytes = b"12:345";
+/// let (hours, index) = from_lexical_resumable::<u8>(bytes, 0).unwrap();
+/// assert_eq!((hours, index), (12, 2));
+/// assert_eq!(bytes[index], b':');
+/// assert_eq!(from_lexical_resumable::<u16>(bytes, index + 1), Ok((345, 6)));
+/// assert_eq!(from_lexical_resumable::<u16>(bytes, 7), Err(Error::Empty(6)));
+/// ```
+///
+/// [`from_lexical_partial`]: FromLexical::from_lexical_partial
+/// [`Empty`]: Error::Empty
+#[inline]
+pub fn from_lexical_resumable<T: ParseInteger>(bytes: &[u8], start: usize) -> Result<(T, usize)> {
+    let rest = match bytes.get(start..) {
+        Some(rest) => rest,
+        None => return Err(Error::Empty(bytes.len())),
+    };
+    T::parse_partial::<STANDARD>(rest, &DEFAULT_OPTIONS)
+        .map(|(value, count)| (value, count + start))
+        .map_err(|error| shift_error(error, start))
+}
+
+/// Parse an integer that ends at the input end or a terminator byte.
+///
+/// This parses a partial integer, as with [`from_lexical_partial`], and
+/// returns the parsed value and the number of bytes processed, which is
+/// the index of the terminator. This is useful for numbers embedded in
+/// structured text, such as the `,`, `]` and `}` after values in JSON.
+/// Returns [`InvalidDigit`] if the integer is followed by a byte that is
+/// not in `terminators`, [`Empty`] if a terminator has no digits before
+/// it, or [`EmptyMantissa`] if there is only a sign before it.
+///
+/// * `bytes`       - Slice containing a numeric string.
+/// * `terminators` - The bytes that may end the integer.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_integer::{from_lexical_until, Error};
+///
+/// assert_eq!(from_lexical_until::<u32>(b"123,456", b",]}"), Ok((123, 3)));
+/// assert_eq!(from_lexical_until::<u32>(b"123", b",]}"), Ok((123, 3)));
+/// assert_eq!(from_lexical_until::<u32>(b"123x", b",]}"), Err(Error::InvalidDigit(3)));
+/// ```
+///
+/// [`from_lexical_partial`]: FromLexical::from_lexical_partial
+/// [`InvalidDigit`]: Error::InvalidDigit
+/// [`Empty`]: Error::Empty
+/// [`EmptyMantissa`]: Error::EmptyMantissa
+#[inline]
+pub fn from_lexical_until<T: ParseInteger>(
+    bytes: &[u8],
+    terminators: &[u8],
+) -> Result<(T, usize)> {
+    let (value, count) = T::parse_partial::<STANDARD>(bytes, &DEFAULT_OPTIONS)?;
+    match bytes.get(count) {
+        Some(c) if !terminators.contains(c) => Err(Error::InvalidDigit(count)),
+        Some(_) if count == 0 => Err(Error::Empty(0)),
+        Some(_) if !bytes[count - 1].is_ascii_digit() => Err(Error::EmptyMantissa(count)),
+        _ => Ok((value, count)),
+    }
+}
+
+/// Sign of a number, as parsed by [`split_sign`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Sign {
+    /// No sign or a `+` sign.
+    Positive,
+    /// A `-` sign.
+    Negative,
+}
+
+/// Parse an optional leading sign and return the bytes after it.
+///
+/// This validates the sign as the integer parsers do, using the format
+/// and the options for whether a `+` sign is allowed or a sign is
+/// required. A `-` sign is always accepted, so the caller can decide
+/// if negative values are valid. The returned [`Bytes`] is positioned
+/// after the sign, so the digits can be parsed with its iterators.
+///
+/// * `bytes`   - Slice containing a numeric string.
+/// * `options` - Options to specify number parsing.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_integer::{split_sign, Options, Sign};
+/// use lexical_parse_integer::format::STANDARD;
+/// use lexical_util::iterator::Iter;
+///
+/// let (sign, byte) = split_sign::<STANDARD>(b"-42", &Options::new()).unwrap();
+/// assert_eq!(sign, Sign::Negative);
+/// assert_eq!(byte.as_slice(), b"42");
+/// assert_eq!(byte.cursor(), 1);
+/// ```
+#[inline]
+pub fn split_sign<'a, const FORMAT: u128>(
+    bytes: &'a [u8],
+    options: &Options,
+) -> Result<(Sign, Bytes<'a, FORMAT>)> {
+    let format = NumberFormat::<FORMAT> {};
+    if !format.is_valid() {
+        return Err(format.error());
+    }
+    let mut byte = bytes.bytes::<FORMAT>();
+    let is_negative = parse_sign!(
+        byte,
+        true,
+        format.no_positive_mantissa_sign() || options.no_positive_sign(),
+        format.required_mantissa_sign() || options.required_sign(),
+        InvalidPositiveSign,
+        MissingSign
+    )?;
+    let sign = if is_negative { Sign::Negative } else { Sign::Positive };
+    Ok((sign, byte))
+}
+
+/// Parse an integer from a string with a radix known at runtime.
+///
+/// This parses an optional sign followed by digits in `radix`, which
+/// must be in the range `[2, 36]`, otherwise [`InvalidRadix`] is returned.
+/// Letters are case-insensitive digits for radixes above 10. This uses
+/// contiguous digits without a base prefix or digit separators, for when
+/// the radix cannot be a part of the const `FORMAT`.
+///
+/// * `bytes`   - Slice containing a numeric string.
+/// * `radix`   - The radix of the digits.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_integer::{from_lexical_radix, Error};
+///
+/// assert_eq!(from_lexical_radix::<u32>(b"ff", 16), Ok(255));
+/// assert_eq!(from_lexical_radix::<i32>(b"-101", 2), Ok(-5));
+/// assert_eq!(from_lexical_radix::<u32>(b"12", 2), Err(Error::InvalidDigit(1)));
+/// assert_eq!(from_lexical_radix::<u32>(b"12", 37), Err(Error::InvalidRadix));
+/// ```
+///
+/// [`InvalidRadix`]: Error::InvalidRadix
+#[cfg(feature = "radix")]
+pub fn from_lexical_radix<T: ParseInteger>(bytes: &[u8], radix: u32) -> Result<T> {
+    if !(2..=36).contains(&radix) {
+        return Err(Error::InvalidRadix);
+    }
+
+    let (is_negative, start) = parse_radix_sign::<T>(bytes)?;
+    if start == bytes.len() {
+        return Err(Error::EmptyMantissa(start));
+    }
+    let (value, end) = parse_radix_digits::<T>(bytes, start, radix, is_negative)?;
+    if end != bytes.len() {
+        return Err(Error::InvalidDigit(end));
+    }
+    Ok(value)
+}
+
+/// Parse a complete integer using a closure to decode each digit.
+///
+/// This is the most flexible way to parse a custom digit alphabet, such
+/// as one that accepts ambiguous characters as aliases for digits. The
+/// `mapper` returns the value of a digit, or [`None`] if the byte is not
+/// a digit. Values that are not below the radix are invalid digits. An
+/// optional `+` or `-` sign is parsed before the digits, and is never
+/// passed to the `mapper`. The radix must be from `2` to `36`, otherwise
+/// [`InvalidRadix`] is returned.
+///
+/// * `bytes`   - Slice containing a numeric string.
+/// * `radix`   - The radix of the digits.
+/// * `mapper`  - Closure to get the value of a digit.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_integer::{from_lexical_with_mapper, Error};
+///
+/// // Accept `O` and `l` as aliases for `0` and `1`.
+/// let mapper = |c: u8| match c {
+///     b'O' => Some(0),
+///     b'l' => Some(1),
+///     _ => (c as char).to_digit(10),
+/// };
+/// assert_eq!(from_lexical_with_mapper::<u32, _>(b"lO5", 10, mapper), Ok(105));
+/// assert_eq!(from_lexical_with_mapper::<u32, _>(b"l0x", 10, mapper), Err(Error::InvalidDigit(2)));
+/// ```
+///
+/// [`InvalidRadix`]: Error::InvalidRadix
+#[cfg(feature = "radix")]
+pub fn from_lexical_with_mapper<T, F>(bytes: &[u8], radix: u32, mapper: F) -> Result<T>
+where
+    T: ParseInteger,
+    F: Fn(u8) -> Option<u32>,
+{
+    if !(2..=36).contains(&radix) {
+        return Err(Error::InvalidRadix);
+    }
+
+    let (is_negative, start) = parse_radix_sign::<T>(bytes)?;
+    if start == bytes.len() {
+        return Err(Error::EmptyMantissa(start));
+    }
+    let (value, end) = parse_mapped_digits::<T, _>(bytes, start, radix, is_negative, mapper)?;
+    if end != bytes.len() {
+        return Err(Error::InvalidDigit(end));
+    }
+    Ok(value)
+}
+
+/// Parse the leading sign for a radix known at runtime.
+///
+/// Returns if the value is negative and the index after the sign.
+#[cfg(feature = "radix")]
+#[inline(always)]
+fn parse_radix_sign<T: ParseInteger>(bytes: &[u8]) -> Result<(bool, usize)> {
+    match bytes.first() {
+        None => Err(Error::Empty(0)),
+        Some(b'+') => Ok((false, 1)),
+        Some(b'-') if T::IS_SIGNED => Ok((true, 1)),
+        _ => Ok((false, 0)),
+    }
+}
+
+/// Parse digits starting at `start` with a radix known at runtime.
+///
+/// This stops at the first invalid digit, returning the parsed value
+/// and the index of the first unparsed byte.
+#[cfg(feature = "radix")]
+#[inline(always)]
+fn parse_radix_digits<T: ParseInteger>(
+    bytes: &[u8],
+    start: usize,
+    radix: u32,
+    is_negative: bool,
+) -> Result<(T, usize)> {
+    use lexical_util::digit::char_to_digit;
+
+    parse_mapped_digits(bytes, start, radix, is_negative, |c| char_to_digit(c, radix))
+}
+
+/// Parse digits starting at `start`, decoding each digit with `mapper`.
+///
+/// This stops at the first invalid digit, returning the parsed value
+/// and the index of the first unparsed byte.
+#[cfg(feature = "radix")]
+fn parse_mapped_digits<T, F>(
+    bytes: &[u8],
+    start: usize,
+    radix: u32,
+    is_negative: bool,
+    mapper: F,
+) -> Result<(T, usize)>
+where
+    T: ParseInteger,
+    F: Fn(u8) -> Option<u32>,
+{
+    let mut value = T::ZERO;
+    let base = T::as_cast(radix);
+    for (index, &c) in bytes.iter().enumerate().skip(start) {
+        let digit = match mapper(c) {
+            Some(digit) if digit < radix => T::as_cast(digit),
+            _ => return Ok((value, index)),
+        };
+        let shifted = value.checked_mul(base);
+        value = if is_negative {
+            match shifted.and_then(|x| x.checked_sub(digit)) {
+                Some(value) => value,
+                None => return Err(Error::Underflow(index)),
+            }
+        } else {
+            match shifted.and_then(|x| x.checked_add(digit)) {
+                Some(value) => value,
+                None => return Err(Error::Overflow(index)),
+            }
+        };
+    }
+    Ok((value, bytes.len()))
+}
+
+/// Parse a partial integer as a Rust literal, detecting the radix.
+///
+/// This uses the [`RUST_LITERAL`] format, with an optional `0x`, `0o`, or
+/// `0b` prefix for hexadecimal, octal and binary digits, respectively.
+/// Single underscores are accepted between the digits, and leading,
+/// trailing or consecutive underscores are rejected with
+/// [`InvalidDigit`]. Parsing stops at the first byte that is not a digit,
+/// such as a type suffix, and returns the parsed value and the number of
+/// bytes processed.
+///
+/// * `bytes`   - Slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_integer::{from_rust_literal_partial, Error};
+///
+/// assert_eq!(from_rust_literal_partial::<u32>(b"1_000u32"), Ok((1000, 5)));
+/// assert_eq!(from_rust_literal_partial::<u16>(b"0xFF_FFu16"), Ok((0xFFFF, 7)));
+/// assert_eq!(from_rust_literal_partial::<i8>(b"-0b1010i8"), Ok((-10, 7)));
+/// assert_eq!(from_rust_literal_partial::<u8>(b"1__0"), Err(Error::InvalidDigit(1)));
+/// assert_eq!(from_rust_literal_partial::<u8>(b"_1"), Err(Error::Empty(0)));
+/// ```
+///
+/// [`RUST_LITERAL`]: lexical_util::format::RUST_LITERAL
+/// [`InvalidDigit`]: Error::InvalidDigit
+#[cfg(all(feature = "format", feature = "power-of-two"))]
+pub fn from_rust_literal_partial<T: ParseInteger>(bytes: &[u8]) -> Result<(T, usize)> {
+    use core::num::NonZeroU8;
+
+    use lexical_util::format::{NumberFormatBuilder, RUST_LITERAL};
+
+    const fn prefixed(radix: u8, prefix: Option<NonZeroU8>) -> u128 {
+        NumberFormatBuilder::rebuild(RUST_LITERAL)
+            .radix(radix)
+            .base_prefix(prefix)
+            .case_sensitive_base_prefix(true)
+            .trailing_digit_separator(false)
+            .consecutive_digit_separator(false)
+            .build_strict()
+    }
+    const DECIMAL: u128 = prefixed(10, None);
+    const HEXADECIMAL: u128 = prefixed(16, NonZeroU8::new(b'x'));
+    const OCTAL: u128 = prefixed(8, NonZeroU8::new(b'o'));
+    const BINARY: u128 = prefixed(2, NonZeroU8::new(b'b'));
+
+    let digits = bytes.strip_prefix(b"-").unwrap_or(bytes);
+    let (value, count) = match digits {
+        [b'0', b'x', ..] => T::parse_partial::<HEXADECIMAL>(bytes, &DEFAULT_OPTIONS),
+        [b'0', b'o', ..] => T::parse_partial::<OCTAL>(bytes, &DEFAULT_OPTIONS),
+        [b'0', b'b', ..] => T::parse_partial::<BINARY>(bytes, &DEFAULT_OPTIONS),
+        _ => T::parse_partial::<DECIMAL>(bytes, &DEFAULT_OPTIONS),
+    }?;
+    // Parsing stops at a misplaced underscore, which is not a type suffix.
+    match bytes.get(count) {
+        Some(b'_') => Err(Error::InvalidDigit(count)),
+        _ => Ok((value, count)),
+    }
+}
+
+/// Parse a complete integer as a C literal, detecting the radix.
+///
+/// This uses the [`C_LITERAL`] options, with a `0x` or `0X` prefix for
+/// hexadecimal digits, and a leading `0` followed by more digits for
+/// octal digits. A lone `0` is decimal zero. Any unsigned or long
+/// suffix, such as `u`, `l`, or `ull`, is skipped. Digit separators
+/// are not accepted: see [`from_cxx_literal`] for the C++ variant.
+///
+/// * `bytes`   - Slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_integer::{from_c_literal, Error};
+///
+/// assert_eq!(from_c_literal::<u32>(b"010"), Ok(8));
+/// assert_eq!(from_c_literal::<u32>(b"0x1F"), Ok(31));
+/// assert_eq!(from_c_literal::<u64>(b"42ul"), Ok(42));
+/// assert_eq!(from_c_literal::<u32>(b"0"), Ok(0));
+/// assert_eq!(from_c_literal::<u32>(b"08"), Err(Error::InvalidDigit(1)));
+/// ```
+///
+/// [`C_LITERAL`]: crate::options::C_LITERAL
+#[cfg(all(feature = "format", feature = "power-of-two"))]
+pub fn from_c_literal<T>(bytes: &[u8]) -> Result<T>
+where
+    T: FromLexicalWithOptions<Options = Options>,
+{
+    use lexical_util::format::C_LITERAL;
+
+    const DECIMAL: u128 = c_literal_format(C_LITERAL, 10, 0);
+    const OCTAL: u128 = c_literal_format(C_LITERAL, 8, 0);
+    const HEXADECIMAL: u128 = c_literal_format(C_LITERAL, 16, b'x');
+
+    parse_c_literal::<T, DECIMAL, OCTAL, HEXADECIMAL>(bytes)
+}
+
+/// Parse a complete integer as a C++ literal, detecting the radix.
+///
+/// This is the same as [`from_c_literal`], but also accepts `'` digit
+/// separators between digits, as C++14 and later do. A separator directly
+/// after the `0x` prefix is not between digits, and is rejected.
+///
+/// * `bytes`   - Slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_integer::{from_cxx_literal, Error};
+///
+/// assert_eq!(from_cxx_literal::<u32>(b"1'000"), Ok(1000));
+/// assert_eq!(from_cxx_literal::<u32>(b"0x7'FFu"), Ok(0x7FF));
+/// assert_eq!(from_cxx_literal::<u32>(b"0'10"), Ok(8));
+/// assert_eq!(from_cxx_literal::<u32>(b"1''0"), Err(Error::InvalidDigit(1)));
+/// assert_eq!(from_cxx_literal::<u32>(b"0x'FF"), Err(Error::InvalidDigit(2)));
+/// ```
+#[cfg(all(feature = "format", feature = "power-of-two"))]
+pub fn from_cxx_literal<T>(bytes: &[u8]) -> Result<T>
+where
+    T: FromLexicalWithOptions<Options = Options>,
+{
+    use lexical_util::format::CXX_LITERAL;
+
+    const DECIMAL: u128 = c_literal_format(CXX_LITERAL, 10, 0);
+    const OCTAL: u128 = c_literal_format(CXX_LITERAL, 8, 0);
+    const HEXADECIMAL: u128 = c_literal_format(CXX_LITERAL, 16, b'x');
+
+    parse_c_literal::<T, DECIMAL, OCTAL, HEXADECIMAL>(bytes)
+}
+
+/// Create the format for C or C++ literal digits, with an optional prefix.
+#[cfg(all(feature = "format", feature = "power-of-two"))]
+const fn c_literal_format(format: u128, radix: u8, prefix: u8) -> u128 {
+    use core::num::NonZeroU8;
+
+    use lexical_util::format::NumberFormatBuilder;
+
+    NumberFormatBuilder::rebuild(format)
+        .radix(radix)
+        .base_prefix(NonZeroU8::new(prefix))
+        .build_strict()
+}
+
+/// Parse a C or C++ literal, selecting the format from the radix prefix.
+#[cfg(all(feature = "format", feature = "power-of-two"))]
+fn parse_c_literal<T, const DECIMAL: u128, const OCTAL: u128, const HEXADECIMAL: u128>(
+    bytes: &[u8],
+) -> Result<T>
+where
+    T: FromLexicalWithOptions<Options = Options>,
+{
+    use crate::options::C_LITERAL as OPTIONS;
+
+    let digits = bytes.strip_prefix(b"-").or_else(|| bytes.strip_prefix(b"+")).unwrap_or(bytes);
+    match digits {
+        // The separator after the prefix would otherwise be internal to `0x`.
+        [b'0', b'x' | b'X', b'\'', ..] => Err(Error::InvalidDigit(bytes.len() - digits.len() + 2)),
+        [b'0', b'x' | b'X', ..] => T::from_lexical_with_options::<HEXADECIMAL>(bytes, &OPTIONS),
+        [b'0', c, ..] if c.is_ascii_digit() || *c == b'\'' => {
+            T::from_lexical_with_options::<OCTAL>(bytes, &OPTIONS)
+        },
+        _ => T::from_lexical_with_options::<DECIMAL>(bytes, &OPTIONS),
+    }
+}
+
+/// Parse an integer with an explicit, leading radix, such as `16#FF`.
+///
+/// The radix is written in decimal and followed by the `indicator`,
+/// and must be in the range `[2, 36]`. The digits are contiguous,
+/// without a base prefix or digit separators, and at least 1 digit must
+/// follow the `indicator`. Returns the parsed value and the number of
+/// bytes processed.
+#[cfg(feature = "radix")]
+pub(crate) fn parse_radix_indicator_partial<T: ParseInteger>(
+    bytes: &[u8],
+    indicator: u8,
+) -> Result<(T, usize)> {
+    let (is_negative, start) = parse_radix_sign::<T>(bytes)?;
+    let (radix, radix_end) = match parse_radix_digits::<u32>(bytes, start, 10, false) {
+        Ok(value) => value,
+        Err(_) => return Err(Error::InvalidRadix),
+    };
+    match bytes.get(radix_end) {
+        Some(&c) if c == indicator && radix_end == start => {
+            return Err(Error::EmptyInteger(start))
+        },
+        Some(&c) if c == indicator => (),
+        Some(_) => return Err(Error::InvalidDigit(radix_end)),
+        None => return Err(Error::Empty(radix_end)),
+    }
+    if !(2..=36).contains(&radix) {
+        return Err(Error::InvalidRadix);
+    }
+    let start = radix_end + 1;
+    match parse_radix_digits::<T>(bytes, start, radix, is_negative)? {
+        (_, end) if end == start && end == bytes.len() => Err(Error::Empty(end)),
+        (_, end) if end == start => Err(Error::InvalidDigit(end)),
+        result => Ok(result),
+    }
+}
+
+/// Parse an integer with an explicit, leading radix, such as `16#FF`.
+///
+/// This requires all bytes to be processed.
+#[cfg(feature = "radix")]
+pub(crate) fn parse_radix_indicator_complete<T: ParseInteger>(
+    bytes: &[u8],
+    indicator: u8,
+) -> Result<T> {
+    let (value, end) = parse_radix_indicator_partial::<T>(bytes, indicator)?;
+    if end != bytes.len() {
+        return Err(Error::InvalidDigit(end));
+    }
+    Ok(value)
+}
+
+/// Determine if the byte is the exponent character.
+#[inline(always)]
+fn is_exponent<const FORMAT: u128>(byte: u8, options: &Options) -> bool {
+    if cfg!(feature = "format") && NumberFormat::<FORMAT>::CASE_SENSITIVE_EXPONENT {
+        byte == options.exponent()
+    } else {
+        byte.eq_ignore_ascii_case(&options.exponent())
+    }
+}
+
+/// Parse a complete integer followed by an optional exponent, such as `1e3`.
+#[inline(always)]
+fn parse_exponent_complete<T: ParseInteger, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<T> {
+    if !options.allow_integer_exponent() {
+        return T::parse_complete::<FORMAT>(bytes, options);
+    } else if char_is_digit_const(options.exponent(), NumberFormat::<FORMAT>::RADIX) {
+        return Err(Error::InvalidExponentSymbol);
+    }
+    match bytes.iter().position(|&c| is_exponent::<FORMAT>(c, options)) {
+        Some(index) => {
+            let value = T::parse_complete::<FORMAT>(&bytes[..index], options)?;
+            let (value, count) = scale_exponent::<T, FORMAT>(value, bytes, index)?;
+            if count != bytes.len() {
+                return Err(Error::InvalidDigit(count));
+            }
+            Ok(value)
+        },
+        None => T::parse_complete::<FORMAT>(bytes, options),
+    }
+}
+
+/// Parse a partial integer followed by an optional exponent, such as `1e3`.
+#[inline(always)]
+fn parse_exponent_partial<T: ParseInteger, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<(T, usize)> {
+    if !options.allow_integer_exponent() {
+        return T::parse_partial::<FORMAT>(bytes, options);
+    } else if char_is_digit_const(options.exponent(), NumberFormat::<FORMAT>::RADIX) {
+        return Err(Error::InvalidExponentSymbol);
+    }
+    let (value, count) = T::parse_partial::<FORMAT>(bytes, options)?;
+    match bytes.get(count) {
+        Some(&c) if is_exponent::<FORMAT>(c, options) => {
+            scale_exponent::<T, FORMAT>(value, bytes, count)
+        },
+        _ => Ok((value, count)),
+    }
+}
+
+/// Parse the exponent starting at `index` and scale the value by it.
+///
+/// Returns the scaled value and the number of bytes processed. It is an
+/// error if the scaled value is not an integer or does not fit the type.
+#[inline(always)]
+fn scale_exponent<T: ParseInteger, const FORMAT: u128>(
+    mut value: T,
+    bytes: &[u8],
+    index: usize,
+) -> Result<(T, usize)> {
+    let mut byte = bytes[index + 1..].bytes::<FORMAT>();
+    let is_negative =
+        parse_exponent_sign(&mut byte).map_err(|error| shift_error(error, index + 1))?;
+    let start = index + 1 + byte.cursor();
+    let radix = NumberFormat::<FORMAT>::EXPONENT_RADIX;
+    let mut exponent = 0u32;
+    let mut digits = 0;
+    let mut iter = byte.exponent_iter();
+    while let Some(&c) = iter.peek() {
+        match char_to_digit_const(c, radix) {
+            Some(digit) => exponent = exponent.saturating_mul(radix).saturating_add(digit),
+            None => break,
+        }
+        // SAFETY: iter cannot be empty due to `iter.peek()`.
+        unsafe { iter.step_unchecked() };
+        digits += 1;
+    }
+    if digits == 0 {
+        return Err(Error::EmptyExponent(start));
+    }
+    let end = index + 1 + byte.cursor();
+
+    // Scale by the exponent base until the value overflows or is inexact.
+    let base = T::as_cast(NumberFormat::<FORMAT>::EXPONENT_BASE);
+    let mut count = 0;
+    while value != T::ZERO && count < exponent {
+        if is_negative {
+            if value % base != T::ZERO {
+                return Err(Error::InvalidExponent(index));
+            }
+            value /= base;
+        } else {
+            value = match value.checked_mul(base) {
+                Some(value) => value,
+                None if value < T::ZERO => return Err(Error::Underflow(end - 1)),
+                None => return Err(Error::Overflow(end - 1)),
+            };
+        }
+        count += 1;
+    }
+    Ok((value, end))
+}
+
+/// Get the number of leading bytes to skip before parsing.
+#[inline(always)]
+fn skip_count(bytes: &[u8], options: &Options) -> usize {
+    let mut count = if options.skip_bom() { utf8_bom_count(bytes) } else { 0 };
+    #[cfg(feature = "unicode")]
+    if options.skip_unicode_whitespace() {
+        count += ltrim_unicode_whitespace_count(&bytes[count..]);
+    }
+    count += whitespace_count(&bytes[count..], options);
+    if bytes[count..].starts_with(options.ignore_prefix()) {
+        count += options.ignore_prefix().len();
+    }
+    count
+}
+
+/// Get the number of leading ASCII whitespace bytes to skip.
+#[inline(always)]
+fn whitespace_count(bytes: &[u8], options: &Options) -> usize {
+    match options.trim_whitespace() {
+        true => bytes.iter().take_while(|c| c.is_ascii_whitespace()).count(),
+        false => 0,
+    }
+}
+
+/// Get the number of trailing ASCII whitespace bytes to skip.
+#[inline(always)]
+fn trailing_whitespace_count(bytes: &[u8], options: &Options) -> usize {
+    match options.trim_whitespace() {
+        true => bytes.iter().rev().take_while(|c| c.is_ascii_whitespace()).count(),
+        false => 0,
+    }
+}
+
+/// Check no ignored suffix starts with a valid digit for the format.
+#[inline(always)]
+fn is_valid_suffixes<const FORMAT: u128>(options: &Options) -> bool {
+    let radix = NumberFormat::<FORMAT>::RADIX;
+    let mut suffixes = options.ignore_suffixes().iter();
+    suffixes.all(|suffix| suffix.first().map_or(false, |&c| !char_is_digit_const(c, radix)))
+}
+
+/// Get the length of the longest ignored suffix at the start of the bytes.
+#[inline(always)]
+fn suffix_count(bytes: &[u8], options: &Options) -> usize {
+    let suffixes = options.ignore_suffixes().iter();
+    suffixes.filter(|&&suffix| bytes.starts_with(suffix)).map(|s| s.len()).max().unwrap_or(0)
+}
+
+/// Get the length of the longest ignored suffix at the end of the bytes.
+#[inline(always)]
+fn trailing_suffix_count(bytes: &[u8], options: &Options) -> usize {
+    let suffixes = options.ignore_suffixes().iter();
+    suffixes.filter(|&&suffix| bytes.ends_with(suffix)).map(|s| s.len()).max().unwrap_or(0)
+}
+
+/// Parse the bytes, translating any Eastern Arabic digits to ASCII first.
+///
+/// The parser returns the value and the number of bytes processed, and
+/// any indexes are mapped back to the untranslated input.
+#[inline(always)]
+#[cfg_attr(not(all(feature = "unicode", feature = "alloc")), allow(unused_variables))]
+fn parse_translated<T>(
+    bytes: &[u8],
+    options: &Options,
+    parse: impl FnOnce(&[u8]) -> Result<(T, usize)>,
+) -> Result<(T, usize)> {
+    #[cfg(all(feature = "unicode", feature = "alloc"))]
+    if options.eastern_arabic_digits() && bytes.iter().any(|&c| c == 0xD9 || c == 0xDB) {
+        let mut translated = Vec::with_capacity(bytes.len());
+        let mut index = 0;
+        while index < bytes.len() {
+            match eastern_arabic_digit(&bytes[index..]) {
+                Some(digit) => {
+                    translated.push(digit);
+                    index += 2;
+                },
+                None => {
+                    translated.push(bytes[index]);
+                    index += 1;
+                },
+            }
+        }
+        let original = |count| eastern_arabic_index(bytes, count);
+        return parse(&translated).map(|(value, count)| (value, original(count))).map_err(
+            |error| match error.index() {
+                Some(index) => shift_error(error, original(index) - index),
+                None => error,
+            },
+        );
+    }
+    parse(bytes)
+}
+
+/// Get the ASCII digit for a leading Eastern Arabic or Persian digit.
+#[inline(always)]
+#[cfg(all(feature = "unicode", feature = "alloc"))]
+const fn eastern_arabic_digit(bytes: &[u8]) -> Option<u8> {
+    match bytes {
+        [0xD9, c @ 0xA0..=0xA9, ..] => Some(b'0' + *c - 0xA0),
+        [0xDB, c @ 0xB0..=0xB9, ..] => Some(b'0' + *c - 0xB0),
+        _ => None,
+    }
+}
+
+/// Get the index in the original bytes of an index in the translated bytes.
+#[inline(always)]
+#[cfg(all(feature = "unicode", feature = "alloc"))]
+fn eastern_arabic_index(bytes: &[u8], count: usize) -> usize {
+    let mut index = 0;
+    for _ in 0..count {
+        index += if eastern_arabic_digit(&bytes[index..]).is_some() { 2 } else { 1 };
+    }
+    index
+}
+
+/// Parse a decimal string into a fixed-point integer scaled by `radix^scale`.
+///
+/// This parses the integer digits using `FORMAT` and `options`, so any
+/// digit separators for grouping, such as `1,234`, are handled by the
+/// format. The integer digits may be followed by a `.` and at most `scale`
+/// fraction digits, without digit separators. A value wrapped in
+/// parentheses, such as `(1,234.56)`, is negative, as used for accounting.
+///
+/// Returns [`InvalidDigit`] if there are more than `scale` fraction digits,
+/// and [`Overflow`] or [`Underflow`] if the scaled value cannot fit in `T`.
+///
+/// * `FORMAT`  - Flags and characters designating the number grammar.
+/// * `bytes`   - Slice containing a numeric string.
+/// * `scale`   - The number of fraction digits in the scaled value.
+/// * `options` - Options to dictate number parsing.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_integer::{from_lexical_fixed, Error, Options};
+/// use lexical_parse_integer::format::STANDARD;
+///
+/// const OPTIONS: Options = Options::new();
+/// assert_eq!(from_lexical_fixed::<i64, STANDARD>(b"1234.5", 2, &OPTIONS), Ok(123450));
+/// assert_eq!(from_lexical_fixed::<i64, STANDARD>(b"(12.34)", 2, &OPTIONS), Ok(-1234));
+/// assert_eq!(
+///     from_lexical_fixed::<i64, STANDARD>(b"1.234", 2, &OPTIONS),
+///     Err(Error::InvalidDigit(4))
+/// );
+/// ```
+///
+/// [`InvalidDigit`]: Error::InvalidDigit
+/// [`Overflow`]: Error::Overflow
+/// [`Underflow`]: Error::Underflow
+pub fn from_lexical_fixed<T: ParseInteger, const FORMAT: u128>(
+    bytes: &[u8],
+    scale: u32,
+    options: &Options,
+) -> Result<T> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    if !format.is_valid() {
+        return Err(format.error());
+    }
+
+    // Handle accounting-style negative values, which cannot also have a sign.
+    let (is_parenthesized, start, inner) = match bytes {
+        [b'(', inner @ .., b')'] => (true, 1, inner),
+        _ => (false, 0, bytes),
+    };
+    if is_parenthesized && matches!(inner.first(), Some(b'+' | b'-')) {
+        return Err(Error::InvalidDigit(start));
+    }
+    let is_negative = is_parenthesized || inner.first() == Some(&b'-');
+
+    // Parse the integer digits, which may include digit separators.
+    let (mut integer, count) = match T::parse_partial::<FORMAT>(inner, options) {
+        Ok(value) => value,
+        Err(error) => return Err(shift_error(error, start)),
+    };
+    let end = start + count;
+    let overflow = if is_negative {
+        Error::Underflow(end)
+    } else {
+        Error::Overflow(end)
+    };
+    if is_parenthesized {
+        integer = T::ZERO.checked_sub(integer).ok_or(overflow)?;
+    }
+
+    // Parse the fraction digits, which must not exceed the scale.
+    let fraction_digits = match &inner[count..] {
+        [] => &[][..],
+        [b'.', rest @ ..] => rest,
+        _ => return Err(Error::InvalidDigit(end)),
+    };
+    let radix: u32 = format.mantissa_radix();
+    let power = T::as_cast(radix).checked_pow(scale).ok_or(overflow)?;
+    // NOTE: This cannot overflow, since it is less than `power`.
+    let mut fraction = T::ZERO;
+    for (index, &c) in fraction_digits.iter().enumerate() {
+        let digit = match char_to_digit_const(c, radix) {
+            Some(digit) if index < scale as usize => digit,
+            _ => return Err(Error::InvalidDigit(end + 1 + index)),
+        };
+        fraction = fraction * as_cast(radix) + as_cast(digit);
+    }
+    fraction *= T::as_cast(radix).pow(scale - fraction_digits.len() as u32);
+
+    // Scale and combine the components.
+    let value = integer.checked_mul(power).ok_or(overflow)?;
+    let value = if is_negative {
+        value.checked_sub(fraction)
+    } else {
+        value.checked_add(fraction)
+    };
+    value.ok_or(overflow)
+}
+
+/// Check if a byte matches an expected, lowercase ASCII byte.
+#[inline(always)]
+const fn is_bool_char(c: u8, expected: u8, case_sensitive: bool) -> bool {
+    c == expected || (!case_sensitive && c.to_ascii_lowercase() == expected)
+}
+
+/// Parse a boolean from `0` or `1`, using the default options.
+///
+/// Returns [`InvalidDigit`] at the offending byte for any other input,
+/// and [`Empty`] if the input is empty.
+///
+/// * `bytes`   - Slice containing a boolean string.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_integer::{bool_from_lexical, Error};
+///
+/// assert_eq!(bool_from_lexical(b"1"), Ok(true));
+/// assert_eq!(bool_from_lexical(b"0"), Ok(false));
+/// assert_eq!(bool_from_lexical(b"2"), Err(Error::InvalidDigit(0)));
+/// ```
+///
+/// [`InvalidDigit`]: Error::InvalidDigit
+/// [`Empty`]: Error::Empty
+#[inline]
+pub fn bool_from_lexical(bytes: &[u8]) -> Result<bool> {
+    bool_from_lexical_with_options(bytes, &DEFAULT_OPTIONS)
+}
+
+/// Parse a boolean from `0` or `1`, or `true` or `false` if enabled.
+///
+/// The words `true` and `false` are only accepted if
+/// [`bool_words`][Options::bool_words] is enabled, and are matched
+/// case-insensitively if [`case_sensitive_bool_words`] is disabled.
+/// Returns [`InvalidDigit`] at the offending byte for invalid input,
+/// and [`Empty`] if the input is empty or a truncated word.
+///
+/// * `bytes`   - Slice containing a boolean string.
+/// * `options` - Options to dictate boolean parsing.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_integer::{bool_from_lexical_with_options, Error, Options};
+///
+/// const OPTIONS: Options = Options::builder()
+///     .bool_words(true)
+///     .case_sensitive_bool_words(false)
+///     .build_strict();
+/// assert_eq!(bool_from_lexical_with_options(b"1", &OPTIONS), Ok(true));
+/// assert_eq!(bool_from_lexical_with_options(b"False", &OPTIONS), Ok(false));
+/// assert_eq!(
+///     bool_from_lexical_with_options(b"truth", &OPTIONS),
+///     Err(Error::InvalidDigit(3))
+/// );
+/// ```
+///
+/// [`case_sensitive_bool_words`]: Options::case_sensitive_bool_words
+/// [`InvalidDigit`]: Error::InvalidDigit
+/// [`Empty`]: Error::Empty
+pub fn bool_from_lexical_with_options(bytes: &[u8], options: &Options) -> Result<bool> {
+    let case_sensitive = options.case_sensitive_bool_words();
+    let (value, word): (bool, &[u8]) = match bytes {
+        [] => return Err(Error::Empty(0)),
+        [b'0'] => return Ok(false),
+        [b'1'] => return Ok(true),
+        [b'0' | b'1', ..] => return Err(Error::InvalidDigit(1)),
+        _ if !options.bool_words() => return Err(Error::InvalidDigit(0)),
+        [c, ..] if is_bool_char(*c, b't', case_sensitive) => (true, b"true"),
+        [c, ..] if is_bool_char(*c, b'f', case_sensitive) => (false, b"false"),
+        _ => return Err(Error::InvalidDigit(0)),
+    };
+
+    for (index, &expected) in word.iter().enumerate() {
+        match bytes.get(index) {
+            Some(&c) if is_bool_char(c, expected, case_sensitive) => (),
+            Some(_) => return Err(Error::InvalidDigit(index)),
+            None => return Err(Error::Empty(index)),
+        }
+    }
+    if bytes.len() > word.len() {
+        return Err(Error::InvalidDigit(word.len()));
+    }
+    Ok(value)
+}
+
+/// Parse a decimal string into a caller-provided accumulator.
+///
+/// This parses the sign and digits using the standard format, and calls
+/// [`mul_add_digit`] for each digit, from most to least significant, or
+/// [`mul_sub_digit`] if the value is negative. The digits are added to
+/// the current value of the accumulator, so it should usually start at
+/// zero. A `-` sign is only accepted if the accumulator is signed.
+///
+/// Returns [`Overflow`] or [`Underflow`] at the index of the digit if the
+/// accumulator cannot represent the value, and otherwise the same errors
+/// as [`from_lexical`][FromLexical::from_lexical].
+///
+/// * `bytes`   - Slice containing a numeric string.
+/// * `value`   - The accumulator to parse the digits into.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_integer::{from_lexical_into, Error};
+///
+/// let mut value = 0u32;
+/// assert_eq!(from_lexical_into(b"1234", &mut value), Ok(()));
+/// assert_eq!(value, 1234);
+///
+/// let mut value = 0u8;
+/// assert_eq!(from_lexical_into(b"1234", &mut value), Err(Error::Overflow(3)));
+/// ```
+///
+/// [`mul_add_digit`]: ParseAccumulator::mul_add_digit
+/// [`mul_sub_digit`]: ParseAccumulator::mul_sub_digit
+/// [`Overflow`]: Error::Overflow
+/// [`Underflow`]: Error::Underflow
+pub fn from_lexical_into<A: ParseAccumulator>(bytes: &[u8], value: &mut A) -> Result<()> {
+    let format = NumberFormat::<STANDARD> {};
+    let mut byte = bytes.bytes::<STANDARD>();
+    let is_negative = parse_sign!(
+        byte,
+        A::IS_SIGNED,
+        format.no_positive_mantissa_sign(),
+        format.required_mantissa_sign(),
+        InvalidPositiveSign,
+        MissingSign
+    )?;
+    let mut iter = byte.integer_iter();
+    if iter.is_buffer_empty() {
+        return match bytes {
+            [b'+' | b'-'] => Err(Error::EmptyMantissa(iter.cursor())),
+            _ => Err(Error::Empty(iter.cursor())),
+        };
+    }
+
+    let radix = format.mantissa_radix();
+    while let Some(&c) = iter.peek() {
+        let index = iter.cursor();
+        let digit = match char_to_digit_const(c, radix) {
+            Some(digit) => digit,
+            None => return Err(Error::InvalidDigit(index)),
+        };
+        if is_negative && value.mul_sub_digit(radix, digit).is_err() {
+            return Err(Error::Underflow(index));
+        } else if !is_negative && value.mul_add_digit(radix, digit).is_err() {
+            return Err(Error::Overflow(index));
+        }
+        iter.next();
+    }
+    Ok(())
+}
+
+/// Parse an integer with a trailing Luhn (mod-10) check digit.
+///
+/// The bytes must only contain decimal digits, with the final digit
+/// being the check digit, as used for credit card numbers and ISINs.
+/// Returns the value of the digits before the check digit, or
+/// [`ChecksumMismatch`] at the index of the check digit if the Luhn
+/// checksum is invalid. Returns [`Empty`] if there are no digits
+/// before the check digit.
+///
+/// * `bytes`   - Slice containing the digits and check digit.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_integer::{from_lexical_checked_mod10, Error};
+///
+/// assert_eq!(from_lexical_checked_mod10::<u64>(b"79927398713"), Ok(7992739871));
+/// assert_eq!(
+///     from_lexical_checked_mod10::<u64>(b"79927398710"),
+///     Err(Error::ChecksumMismatch(10))
+/// );
+/// ```
+///
+/// [`ChecksumMismatch`]: Error::ChecksumMismatch
+/// [`Empty`]: Error::Empty
+pub fn from_lexical_checked_mod10<T: ParseInteger>(bytes: &[u8]) -> Result<T> {
+    let digits = checksum_digits(bytes, 1)?;
+    // Double every second digit, starting from the one left of the check digit.
+    let sum = digits.rev().enumerate().fold(0, |sum, (index, digit)| {
+        let digit = if index % 2 == 1 {
+            digit * 2
+        } else {
+            digit
+        };
+        sum + digit / 10 + digit % 10
+    });
+    checksum_value(bytes, 1, sum % 10 == 0)
+}
+
+/// Parse an integer with trailing ISO 7064 mod-97 check digits.
+///
+/// The bytes must only contain decimal digits, with the final two digits
+/// being the check digits, as used for the numeric form of IBANs. The
+/// checksum is valid if all the digits, as an integer, have a remainder
+/// of 1 when divided by 97. Returns the value of the digits before the
+/// check digits, or [`ChecksumMismatch`] at the index of the first check
+/// digit if the checksum is invalid. Returns [`Empty`] if there are no
+/// digits before the check digits.
+///
+/// * `bytes`   - Slice containing the digits and check digits.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_integer::{from_lexical_checked_mod97, Error};
+///
+/// let value = from_lexical_checked_mod97::<u64>(b"123456789012345611");
+/// assert_eq!(value, Ok(1234567890123456));
+/// assert_eq!(
+///     from_lexical_checked_mod97::<u64>(b"123456789012345612"),
+///     Err(Error::ChecksumMismatch(16))
+/// );
+/// ```
+///
+/// [`ChecksumMismatch`]: Error::ChecksumMismatch
+/// [`Empty`]: Error::Empty
+pub fn from_lexical_checked_mod97<T: ParseInteger>(bytes: &[u8]) -> Result<T> {
+    let digits = checksum_digits(bytes, 2)?;
+    let remainder = digits.fold(0, |rem, digit| (rem * 10 + digit) % 97);
+    checksum_value(bytes, 2, remainder == 1)
+}
+
+/// Validate the digits for a checksum, returning their values.
+///
+/// This requires at least 1 digit before the `check_digits`.
+#[inline]
+fn checksum_digits(
+    bytes: &[u8],
+    check_digits: usize,
+) -> Result<impl DoubleEndedIterator<Item = u32> + '_> {
+    let radix = NumberFormat::<STANDARD>::MANTISSA_RADIX;
+    if let Some(index) = bytes.iter().position(|&c| !char_is_digit_const(c, radix)) {
+        return Err(Error::InvalidDigit(index));
+    } else if bytes.len() <= check_digits {
+        return Err(Error::Empty(bytes.len()));
+    }
+    Ok(bytes.iter().filter_map(move |&c| char_to_digit_const(c, radix)))
+}
+
+/// Parse the digits before the `check_digits` if the checksum is valid.
+#[inline]
+fn checksum_value<T: ParseInteger>(
+    bytes: &[u8],
+    check_digits: usize,
+    is_valid: bool,
+) -> Result<T> {
+    let end = bytes.len() - check_digits;
+    if !is_valid {
+        return Err(Error::ChecksumMismatch(end));
+    }
+    T::parse_complete::<STANDARD>(&bytes[..end], &DEFAULT_OPTIONS)
+}
+
+/// Iterator over the integers in a delimited buffer, such as `1,2,3,4`.
+///
+/// Each field between delimiters is parsed as a complete integer using
+/// `FORMAT` and the options, so fields may contain digit separators if the
+/// format allows them. The delimiter must not be a digit separator or a
+/// valid digit, otherwise [`InvalidPunctuation`] is the only item yielded.
+/// Errors are yielded for each invalid field, with indexes relative to the
+/// start of the buffer, and parsing continues at the next field.
+///
+/// Empty fields are errors, including the field after a trailing
+/// delimiter, unless [`skip_trailing_delimiter`] is set. An empty buffer
+/// has no fields.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_integer::{Error, ParseIntegers};
+/// use lexical_parse_integer::format::STANDARD;
+///
+/// let values: Vec<_> = ParseIntegers::<u32, STANDARD>::new(b"1,2,,4", b',').collect();
+/// assert_eq!(values, [Ok(1), Ok(2), Err(Error::Empty(4)), Ok(4)]);
+///
+/// let values: Vec<_> = ParseIntegers::<u32, STANDARD>::new(b"1,2,", b',')
+///     .skip_trailing_delimiter(true)
+///     .collect();
+/// assert_eq!(values, [Ok(1), Ok(2)]);
+/// ```
+///
+/// [`skip_trailing_delimiter`]: ParseIntegers::skip_trailing_delimiter
+/// [`InvalidPunctuation`]: Error::InvalidPunctuation
+#[derive(Debug, Clone)]
+pub struct ParseIntegers<'a, T: ParseInteger, const FORMAT: u128> {
+    /// The buffer containing all fields.
+    bytes: &'a [u8],
+    /// The start index of the next field.
+    index: usize,
+    /// The byte that separates fields.
+    delimiter: u8,
+    /// Options to dictate number parsing.
+    options: &'a Options,
+    /// Ignore the empty field after a trailing delimiter.
+    skip_trailing_delimiter: bool,
+    /// If all fields have been yielded.
+    is_done: bool,
+    /// Error for an invalid format or delimiter, yielded instead of fields.
+    error: Option<Error>,
+    _marker: PhantomData<T>,
+}
+
+impl<'a, T: ParseInteger, const FORMAT: u128> ParseIntegers<'a, T, FORMAT> {
+    /// Create a new iterator over the fields in `bytes`, using the default
+    /// options.
+    #[inline(always)]
+    pub fn new(bytes: &'a [u8], delimiter: u8) -> Self {
+        Self::with_options(bytes, delimiter, &DEFAULT_OPTIONS)
+    }
+
+    /// Create a new iterator over the fields in `bytes`, using custom
+    /// options.
+    #[inline(always)]
+    pub fn with_options(bytes: &'a [u8], delimiter: u8, options: &'a Options) -> Self {
+        let format = NumberFormat::<{ FORMAT }> {};
+        let error = if !format.is_valid() {
+            Some(format.error())
+        } else if !is_valid_delimiter::<FORMAT>(delimiter, options) {
+            Some(Error::InvalidPunctuation)
+        } else {
+            None
+        };
+        Self {
+            bytes,
+            index: 0,
+            delimiter,
+            options,
+            skip_trailing_delimiter: false,
+            is_done: bytes.is_empty() && error.is_none(),
+            error,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Set if the empty field after a trailing delimiter is skipped.
+    ///
+    /// Defaults to [`false`].
+    #[inline(always)]
+    pub fn skip_trailing_delimiter(mut self, skip_trailing_delimiter: bool) -> Self {
+        self.skip_trailing_delimiter = skip_trailing_delimiter;
+        self
+    }
+
+    /// Get the unparsed bytes, starting at the next field.
+    #[inline(always)]
+    pub fn remaining(&self) -> &'a [u8] {
+        if self.is_done {
+            &[]
+        } else {
+            &self.bytes[self.index..]
+        }
+    }
+}
+
+impl<T: ParseInteger, const FORMAT: u128> Iterator for ParseIntegers<'_, T, FORMAT> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_done {
+            return None;
+        }
+        if let Some(error) = self.error {
+            self.is_done = true;
+            return Some(Err(error));
+        }
+
+        // Find the end of the field with the format's own parser, so it
+        // always ends at the delimiter after the number. Empty and invalid
+        // fields are re-parsed up to the next delimiter for the error.
+        let start = self.index;
+        let rest = &self.bytes[start..];
+        let (result, length) = match T::parse_partial::<FORMAT>(rest, self.options) {
+            Ok((value, count))
+                if count != 0 && rest.get(count).map_or(true, |&c| c == self.delimiter) =>
+            {
+                (Ok(value), count)
+            },
+            _ => {
+                let length = rest.iter().position(|&c| c == self.delimiter).unwrap_or(rest.len());
+                (T::parse_complete::<FORMAT>(&rest[..length], self.options), length)
+            },
+        };
+        if length < rest.len() {
+            self.index += length + 1;
+        } else {
+            self.is_done = true;
+        }
+        if length == 0 && self.is_done && self.skip_trailing_delimiter {
+            return None;
+        }
+        Some(result.map_err(|e| shift_error(e, start)))
+    }
+}
+
+impl<T: ParseInteger, const FORMAT: u128> FusedIterator for ParseIntegers<'_, T, FORMAT> {
+}
+
+/// Check the delimiter is not a digit separator or a digit for the format.
+#[inline(always)]
+fn is_valid_delimiter<const FORMAT: u128>(delimiter: u8, options: &Options) -> bool {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let separator = format.digit_separator();
+    (separator == 0 || delimiter != separator)
+        && !options.digit_separators().contains(&delimiter)
+        && !char_is_digit_const(delimiter, format.mantissa_radix())
+}
+
+/// Parse exactly `N` delimited integers into an array, such as `255,128,0`.
+///
+/// Each field is parsed as with [`ParseIntegers`], without allocating,
+/// and the first error is returned. Returns [`Empty`] at the end of the
+/// input if there are fewer than `N` fields, and [`InvalidDigit`] at the
+/// delimiter after the `N`th field if there are more, which is the number
+/// of bytes used by the `N` fields.
+///
+/// * `bytes`       - Slice containing the delimited integers.
+/// * `delimiter`   - Byte that separates the fields.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_integer::{parse_array, Error};
+///
+/// assert_eq!(parse_array::<u8, 3>(b"255,128,0", b','), Ok([255, 128, 0]));
+/// assert_eq!(parse_array::<u8, 3>(b"255,128", b','), Err(Error::Empty(7)));
+/// assert_eq!(parse_array::<u8, 2>(b"255,128,0", b','), Err(Error::InvalidDigit(7)));
+/// ```
+///
+/// [`Empty`]: Error::Empty
+/// [`InvalidDigit`]: Error::InvalidDigit
+pub fn parse_array<T: ParseInteger, const N: usize>(bytes: &[u8], delimiter: u8) -> Result<[T; N]> {
+    let mut values = [T::ZERO; N];
+    let mut fields = ParseIntegers::<T, STANDARD>::new(bytes, delimiter);
+    for value in values.iter_mut() {
+        match fields.next() {
+            Some(field) => *value = field?,
+            None => return Err(Error::Empty(bytes.len())),
+        }
+    }
+    if !fields.is_done {
+        return Err(Error::InvalidDigit(fields.index.saturating_sub(1)));
+    }
+    Ok(values)
+}
+
+/// Issue found when validating an integer string.
+///
+/// Each issue is reported with the index of the offending byte by
+/// [`validate_lexical`].
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValidationIssue {
+    /// There are no digits after the sign and base prefix.
+    Empty,
+    /// Invalid character found where a digit was expected.
+    InvalidDigit,
+    /// Digit separator found where the format does not allow it.
+    MisplacedSeparator,
+    /// Additional sign found after the first sign.
+    MultipleSigns,
+    /// Positive sign found when the format does not allow it.
+    InvalidPositiveSign,
+    /// Missing sign when the format requires one.
+    MissingSign,
+}
+
+/// Validate an integer string, reporting every issue found.
+///
+/// Unlike parsing, this does not stop at the first error: the entire
+/// input is scanned, and each issue is returned with its index, in order.
+/// This is useful for user-facing validation, which should highlight all
+/// the invalid characters at once. An empty result means the string only
+/// contains a valid sign, base prefix and suffix, and digits, although
+/// the value may still overflow or be negative when parsing to a specific
+/// type.
+///
+/// * `bytes`   - Slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_integer::{validate_lexical, ValidationIssue};
+/// use lexical_parse_integer::format::STANDARD;
+///
+/// let issues = validate_lexical::<STANDARD>(b"1a2b");
+/// assert_eq!(issues, [(1, ValidationIssue::InvalidDigit), (3, ValidationIssue::InvalidDigit)]);
+///
+/// let issues = validate_lexical::<STANDARD>(b"+-12");
+/// assert_eq!(issues, [(1, ValidationIssue::MultipleSigns)]);
+///
+/// assert!(validate_lexical::<STANDARD>(b"-1234").is_empty());
+/// ```
+#[cfg(feature = "alloc")]
+pub fn validate_lexical<const FORMAT: u128>(bytes: &[u8]) -> Vec<(usize, ValidationIssue)> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let mut issues = Vec::new();
+
+    // Any signs: only the first is valid.
+    let mut start = 0;
+    while let Some(&c @ (b'+' | b'-')) = bytes.get(start) {
+        if start != 0 {
+            issues.push((start, ValidationIssue::MultipleSigns));
+        } else if c == b'+' && format.no_positive_mantissa_sign() {
+            issues.push((start, ValidationIssue::InvalidPositiveSign));
+        }
+        start += 1;
+    }
+    if start == 0 && format.required_mantissa_sign() {
+        issues.push((0, ValidationIssue::MissingSign));
+    }
+
+    // Skip the optional base prefix and suffix.
+    let prefix = format.base_prefix();
+    let is_prefix = |c: u8| {
+        c == prefix || (!format.case_sensitive_base_prefix() && c.eq_ignore_ascii_case(&prefix))
+    };
+    let has_prefix = bytes.get(start) == Some(&b'0')
+        && bytes.get(start + 1).map_or(false, |&c| is_prefix(c));
+    if prefix != 0 && has_prefix {
+        start += 2;
+    }
+    let mut end = bytes.len();
+    let suffix = format.base_suffix();
+    let is_suffix = |c: u8| {
+        c == suffix || (!format.case_sensitive_base_suffix() && c.eq_ignore_ascii_case(&suffix))
+    };
+    if suffix != 0 && end > start && is_suffix(bytes[end - 1]) {
+        end -= 1;
+    }
+
+    let digits = &bytes[start..end];
+    if digits.is_empty() {
+        issues.push((start, ValidationIssue::Empty));
+        return issues;
+    }
+
+    let radix = format.mantissa_radix();
+    let separator = format.digit_separator();
+    let is_digit = |c: &u8| char_is_digit_const(*c, radix);
+    let first = digits.iter().position(is_digit).unwrap_or(digits.len());
+    let last = digits.iter().rposition(is_digit).unwrap_or(0);
+    for (index, &c) in digits.iter().enumerate() {
+        if char_is_digit_const(c, radix) {
+            continue;
+        } else if separator == 0 || c != separator {
+            issues.push((start + index, ValidationIssue::InvalidDigit));
+            continue;
+        }
+
+        let is_allowed = if index < first {
+            format.integer_leading_digit_separator()
+        } else if index > last {
+            format.integer_trailing_digit_separator()
+        } else {
+            format.integer_internal_digit_separator()
+        };
+        let is_consecutive = index > 0 && digits[index - 1] == separator;
+        if !is_allowed || (is_consecutive && !format.integer_consecutive_digit_separator()) {
+            issues.push((start + index, ValidationIssue::MisplacedSeparator));
+        }
+    }
+    issues
+}
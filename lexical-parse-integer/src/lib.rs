@@ -78,6 +78,7 @@
 //! * `power-of-two` - Add support for parsing power-of-two integer strings.
 //! * `radix` - Add support for strings of any radix.
 //! * `compact` - Reduce code size at the cost of performance.
+//! * `alloc` - Add support for reporting every issue in an integer string.
 //! * `std` (Default) - Disable to allow use in a [`no_std`] environment.
 //!
 //! [`no_std`]: https://docs.rust-embedded.org/book/intro/no-std.html
@@ -193,6 +194,11 @@
 //! # }
 //! ```
 //!
+//! If the radix is only known at runtime, such as from a file header, use
+//! `from_lexical_radix`, which supports signs and contiguous digits. For
+//! custom digit alphabets, `from_lexical_with_mapper` decodes each digit
+//! with a closure.
+//!
 //! #### compact
 //!
 //! Reduce the generated code size at the cost of performance. This minimizes
@@ -200,6 +206,21 @@
 //! reducing the size of the generated binaries. However, this resulting
 //! performance of the generated code is much lower.
 //!
+//! #### alloc
+//!
+//! Enable [`validate_lexical`], which scans the entire string and reports
+//! every issue with its position, rather than stopping at the first error.
+//! This is useful for user-facing validation, such as of form fields.
+//!
+//! ```rust
+//! # #[cfg(feature = "alloc")] {
+//! use lexical_parse_integer::{format::STANDARD, validate_lexical, ValidationIssue};
+//!
+//! let issues = validate_lexical::<STANDARD>(b"1a2b");
+//! assert_eq!(issues, [(1, ValidationIssue::InvalidDigit), (3, ValidationIssue::InvalidDigit)]);
+//! # }
+//! ```
+//!
 //! #### std
 //!
 //! Enable use of the standard library. Currently, the standard library
@@ -220,6 +241,14 @@
 //! const generic support. Older versions of lexical support older Rust
 //! versions.
 //!
+//! # Platform Support
+//!
+//! [`usize`] and [`isize`] are parsed as the fixed-width integer with the
+//! same size as the target pointer, so `usize` overflows above `65535`
+//! on 16-bit targets, such as MSP430. The algorithms select their
+//! optimizations from the size of the integer type and not the target,
+//! and only assume `usize` is at least 16 bits, which Rust guarantees.
+//!
 //! # Algorithm
 //!
 //! The default implementations are highly optimized both for simple
@@ -247,6 +276,14 @@
 //! - [Comprehensive Benchmarks](https://github.com/Alexhuszagh/lexical-benchmarks)
 //!
 //! [`rust-1.63.0`]: https://blog.rust-lang.org/2022/08/11/Rust-1.63.0.html
+#![cfg_attr(
+    feature = "alloc",
+    doc = "[`validate_lexical`]: crate::validate_lexical"
+)]
+#![cfg_attr(
+    not(feature = "alloc"),
+    doc = "[`validate_lexical`]: https://docs.rs/lexical-parse-integer/latest/lexical_parse_integer/fn.validate_lexical.html"
+)]
 
 // FIXME: Implement clippy/allow reasons once we drop support for 1.80.0 and below
 // Clippy reasons were stabilized in 1.81.0.
@@ -283,6 +320,11 @@
     clippy::semicolon_inside_block,
 )]
 
+// Need an allocator for Vec.
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub mod accumulator;
 pub mod algorithm;
 pub mod options;
 pub mod parse;
@@ -295,6 +337,21 @@ pub use lexical_util::format::{self, NumberFormat, NumberFormatBuilder};
 pub use lexical_util::options::ParseOptions;
 pub use lexical_util::result::Result;
 
-pub use self::api::{FromLexical, FromLexicalWithOptions};
+pub use self::api::{
+    bool_from_lexical, bool_from_lexical_with_options, from_lexical_checked_mod10,
+    from_lexical_checked_mod97, from_lexical_fixed, from_lexical_into, from_lexical_n,
+    from_lexical_resumable, from_lexical_until, parse_array, parse_bcd, split_sign, FromLexical,
+    FromLexicalWithOptions, ParseIntegers, Sign,
+};
+#[cfg(all(feature = "format", feature = "power-of-two"))]
+pub use self::api::{from_c_literal, from_cxx_literal, from_rust_literal_partial};
+#[cfg(feature = "radix")]
+pub use self::api::{from_lexical_radix, from_lexical_with_mapper};
+#[cfg(feature = "std")]
+pub use self::api::{from_lexical_default, from_lexical_partial_default};
+#[cfg(feature = "alloc")]
+pub use self::api::{validate_lexical, ValidationIssue};
 #[doc(inline)]
 pub use self::options::{Options, OptionsBuilder};
+#[cfg(feature = "std")]
+pub use self::options::ThreadLocalOptions;
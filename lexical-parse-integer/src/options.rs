@@ -24,6 +24,11 @@
 //! assert_eq!(result, Ok(1234));
 //! ```
 
+#[cfg(feature = "std")]
+use std::cell::RefCell;
+
+use lexical_util::ascii::{is_valid_ascii, is_valid_prefix_slice};
+use lexical_util::error::Error;
 use lexical_util::options::ParseOptions;
 use lexical_util::result::Result;
 
@@ -53,64 +58,798 @@ pub struct OptionsBuilder {
     /// increased branching can decrease performance for simple
     /// strings by 5-20%. Choose based on your inputs.
     no_multi_digit: bool,
+    /// Allow parsing booleans from `true` and `false`.
+    bool_words: bool,
+    /// If the boolean words are case-sensitive.
+    case_sensitive_bool_words: bool,
+    /// If a positive sign before the integer is not allowed.
+    no_positive_sign: bool,
+    /// If a sign before or after the integer is required.
+    required_sign: bool,
+    /// Allow a sign after the digits, such as `123-`, for signed integers.
+    allow_trailing_sign: bool,
+    /// Character to separate an explicit, leading radix from the digits.
+    radix_indicator: Option<u8>,
+    /// Skip a leading UTF-8 byte order mark before parsing.
+    skip_bom: bool,
+    /// Skip leading Unicode whitespace before parsing.
+    skip_unicode_whitespace: bool,
+    /// Skip leading and trailing ASCII whitespace.
+    trim_whitespace: bool,
+    /// Literal prefix, such as a currency symbol, to skip before parsing.
+    ignore_prefix: &'static [u8],
+    /// Literal suffixes, such as units, to skip after parsing.
+    ignore_suffixes: &'static [&'static [u8]],
+    /// Additional digit separators accepted alongside the format's.
+    digit_separators: &'static [u8],
+    /// Accept Eastern Arabic and Persian digits as decimal digits.
+    eastern_arabic_digits: bool,
+    /// Character to designate the exponent of an integer.
+    exponent: u8,
+    /// Allow an exponent after the digits, such as `1e3`.
+    allow_integer_exponent: bool,
 }
 
 impl OptionsBuilder {
     /// Create new options builder with default options.
     #[inline(always)]
-    pub const fn new() -> Self {
-        Self {
-            no_multi_digit: true,
-        }
+    pub const fn new() -> Self {
+        Self {
+            no_multi_digit: true,
+            bool_words: false,
+            case_sensitive_bool_words: true,
+            no_positive_sign: false,
+            required_sign: false,
+            allow_trailing_sign: false,
+            radix_indicator: None,
+            skip_bom: false,
+            skip_unicode_whitespace: false,
+            trim_whitespace: false,
+            ignore_prefix: &[],
+            ignore_suffixes: &[],
+            digit_separators: &[],
+            eastern_arabic_digits: false,
+            exponent: b'e',
+            allow_integer_exponent: false,
+        }
+    }
+
+    // GETTERS
+
+    /// Get if we disable the use of multi-digit optimizations.
+    ///
+    /// Defaults to [`true`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_no_multi_digit(), true);
+    /// ```
+    #[inline(always)]
+    pub const fn get_no_multi_digit(&self) -> bool {
+        self.no_multi_digit
+    }
+
+    /// Get if booleans can be parsed from `true` and `false`.
+    ///
+    /// `0` and `1` are always valid booleans. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_bool_words(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn get_bool_words(&self) -> bool {
+        self.bool_words
+    }
+
+    /// Get if the boolean words `true` and `false` are case-sensitive.
+    ///
+    /// Defaults to [`true`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_case_sensitive_bool_words(), true);
+    /// ```
+    #[inline(always)]
+    pub const fn get_case_sensitive_bool_words(&self) -> bool {
+        self.case_sensitive_bool_words
+    }
+
+    /// Get if a positive sign before the integer is not allowed.
+    ///
+    /// Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_no_positive_sign(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn get_no_positive_sign(&self) -> bool {
+        self.no_positive_sign
+    }
+
+    /// Get if a sign before or after the integer is required.
+    ///
+    /// This applies to both positive and negative values. Defaults to
+    /// [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_required_sign(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn get_required_sign(&self) -> bool {
+        self.required_sign
+    }
+
+    /// Get if a sign after the digits, such as `123-`, is allowed.
+    ///
+    /// This only applies to signed integers, and a value cannot have both
+    /// a leading and a trailing sign. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_allow_trailing_sign(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn get_allow_trailing_sign(&self) -> bool {
+        self.allow_trailing_sign
+    }
+
+    /// Get the character to separate an explicit, leading radix from the
+    /// digits.
+    ///
+    /// If set, integers are written as `radix#digits`, such as `16#FF`,
+    /// where the radix is written in decimal and must be from `2` to `36`.
+    /// The digits are contiguous and ignore the number format. This
+    /// requires the `radix` feature. Defaults to [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_radix_indicator(), None);
+    /// ```
+    #[inline(always)]
+    pub const fn get_radix_indicator(&self) -> Option<u8> {
+        self.radix_indicator
+    }
+
+    /// Get if a leading UTF-8 byte order mark (`EF BB BF`) is skipped.
+    ///
+    /// The bytes skipped are included in the number of bytes processed.
+    /// Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_skip_bom(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn get_skip_bom(&self) -> bool {
+        self.skip_bom
+    }
+
+    /// Get if leading Unicode whitespace is skipped.
+    ///
+    /// This skips any characters with the `White_Space` property encoded
+    /// as UTF-8, after any byte order mark, and the bytes skipped are
+    /// included in the number of bytes processed. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_skip_unicode_whitespace(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn get_skip_unicode_whitespace(&self) -> bool {
+        self.skip_unicode_whitespace
+    }
+
+    /// Get if leading and trailing ASCII whitespace is skipped.
+    ///
+    /// This skips ASCII whitespace before the sign and after the digits
+    /// and any suffix, and the bytes skipped are included in the number of
+    /// bytes processed. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_trim_whitespace(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn get_trim_whitespace(&self) -> bool {
+        self.trim_whitespace
+    }
+
+    /// Get the literal prefix skipped before parsing.
+    ///
+    /// If the input starts with the prefix, after any byte order mark or
+    /// whitespace, the prefix is skipped before parsing the sign and digits,
+    /// and the bytes skipped are included in the number of bytes processed.
+    /// The prefix is matched as a literal byte sequence and is optional.
+    /// Defaults to an empty prefix, which skips nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_ignore_prefix(), b"");
+    /// ```
+    #[inline(always)]
+    pub const fn get_ignore_prefix(&self) -> &'static [u8] {
+        self.ignore_prefix
+    }
+
+    /// Get the literal suffixes skipped after parsing.
+    ///
+    /// If the bytes after the digits start with one of the suffixes, the
+    /// longest matching suffix is skipped and included in the number of
+    /// bytes processed. Defaults to no suffixes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert!(builder.get_ignore_suffixes().is_empty());
+    /// ```
+    #[inline(always)]
+    pub const fn get_ignore_suffixes(&self) -> &'static [&'static [u8]] {
+        self.ignore_suffixes
+    }
+
+    /// Get the additional digit separators accepted alongside the format's.
+    ///
+    /// Defaults to an empty set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_digit_separators(), b"");
+    /// ```
+    #[inline(always)]
+    pub const fn get_digit_separators(&self) -> &'static [u8] {
+        self.digit_separators
+    }
+
+    /// Get if Eastern Arabic and Persian digits are accepted.
+    ///
+    /// If enabled, the digits `U+0660` to `U+0669` and `U+06F0` to `U+06F9`
+    /// are parsed as `0` to `9`, and may be mixed with ASCII digits.
+    /// Indexes in errors and the number of bytes processed refer to the
+    /// original UTF-8 input. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_eastern_arabic_digits(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn get_eastern_arabic_digits(&self) -> bool {
+        self.eastern_arabic_digits
+    }
+
+    /// Get the character to designate the exponent of an integer.
+    ///
+    /// This is only used if [`allow_integer_exponent`] is enabled.
+    /// Defaults to `b'e'`.
+    ///
+    /// [`allow_integer_exponent`]: Self::get_allow_integer_exponent
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::Options;
+    ///
+    /// assert_eq!(Options::builder().get_exponent(), b'e');
+    /// ```
+    #[inline(always)]
+    pub const fn get_exponent(&self) -> u8 {
+        self.exponent
+    }
+
+    /// Get if an exponent is allowed after the digits, such as `1e3`.
+    ///
+    /// The value is scaled by the radix raised to the exponent, and it is
+    /// an error if the result is not an integer or does not fit the type.
+    /// Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::Options;
+    ///
+    /// assert_eq!(Options::builder().get_allow_integer_exponent(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn get_allow_integer_exponent(&self) -> bool {
+        self.allow_integer_exponent
+    }
+
+    // SETTERS
+
+    /// Set if we disable the use of multi-digit optimizations.
+    ///
+    /// Defaults to [`true`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::Options;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .no_multi_digit(false)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.get_no_multi_digit(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn no_multi_digit(mut self, no_multi_digit: bool) -> Self {
+        self.no_multi_digit = no_multi_digit;
+        self
+    }
+
+    /// Set if booleans can be parsed from `true` and `false`.
+    ///
+    /// `0` and `1` are always valid booleans. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::Options;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .bool_words(true)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.bool_words(), true);
+    /// ```
+    #[inline(always)]
+    pub const fn bool_words(mut self, bool_words: bool) -> Self {
+        self.bool_words = bool_words;
+        self
+    }
+
+    /// Set if the boolean words `true` and `false` are case-sensitive.
+    ///
+    /// Defaults to [`true`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::Options;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .case_sensitive_bool_words(false)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.case_sensitive_bool_words(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn case_sensitive_bool_words(mut self, case_sensitive_bool_words: bool) -> Self {
+        self.case_sensitive_bool_words = case_sensitive_bool_words;
+        self
+    }
+
+    /// Set if a positive sign before the integer is not allowed.
+    ///
+    /// Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::Options;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .no_positive_sign(true)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.no_positive_sign(), true);
+    /// ```
+    #[inline(always)]
+    pub const fn no_positive_sign(mut self, no_positive_sign: bool) -> Self {
+        self.no_positive_sign = no_positive_sign;
+        self
+    }
+
+    /// Set if a sign before or after the integer is required.
+    ///
+    /// This applies to both positive and negative values. Defaults to
+    /// [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::Options;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .required_sign(true)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.required_sign(), true);
+    /// ```
+    #[inline(always)]
+    pub const fn required_sign(mut self, required_sign: bool) -> Self {
+        self.required_sign = required_sign;
+        self
+    }
+
+    /// Set if a sign after the digits, such as `123-`, is allowed.
+    ///
+    /// This only applies to signed integers, and a value cannot have both
+    /// a leading and a trailing sign. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::Options;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .allow_trailing_sign(true)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.allow_trailing_sign(), true);
+    /// ```
+    #[inline(always)]
+    pub const fn allow_trailing_sign(mut self, allow_trailing_sign: bool) -> Self {
+        self.allow_trailing_sign = allow_trailing_sign;
+        self
+    }
+
+    /// Set the character to separate an explicit, leading radix from the
+    /// digits.
+    ///
+    /// If set, integers are written as `radix#digits`, such as `16#FF`,
+    /// where the radix is written in decimal and must be from `2` to `36`.
+    /// The digits are contiguous and ignore the number format, so digit
+    /// separators, base prefixes, and the sign flags of the format are not
+    /// used. The character must be ASCII punctuation other than a sign, and
+    /// this requires the `radix` feature. It cannot be combined with
+    /// [`digit_separators`], [`no_positive_sign`], [`required_sign`],
+    /// [`allow_trailing_sign`], or [`allow_integer_exponent`]. Defaults to
+    /// [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "radix")] {
+    /// use lexical_parse_integer::{FromLexicalWithOptions, Options};
+    /// use lexical_parse_integer::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .radix_indicator(Some(b'#'))
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.radix_indicator(), Some(b'#'));
+    /// assert_eq!(u32::from_lexical_with_options::<STANDARD>(b"16#FF", &OPTIONS), Ok(255));
+    /// # }
+    /// ```
+    ///
+    /// [`digit_separators`]: Self::digit_separators
+    /// [`no_positive_sign`]: Self::no_positive_sign
+    /// [`required_sign`]: Self::required_sign
+    /// [`allow_trailing_sign`]: Self::allow_trailing_sign
+    /// [`allow_integer_exponent`]: Self::allow_integer_exponent
+    #[inline(always)]
+    pub const fn radix_indicator(mut self, radix_indicator: Option<u8>) -> Self {
+        self.radix_indicator = radix_indicator;
+        self
+    }
+
+    /// Set if a leading UTF-8 byte order mark (`EF BB BF`) is skipped.
+    ///
+    /// The bytes skipped are included in the number of bytes processed.
+    /// Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::{FromLexicalWithOptions, Options};
+    /// use lexical_parse_integer::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .skip_bom(true)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.skip_bom(), true);
+    /// let bytes = "\u{FEFF}42".as_bytes();
+    /// let result = u32::from_lexical_partial_with_options::<STANDARD>(bytes, &OPTIONS);
+    /// assert_eq!(result, Ok((42, 5)));
+    /// ```
+    #[inline(always)]
+    pub const fn skip_bom(mut self, skip_bom: bool) -> Self {
+        self.skip_bom = skip_bom;
+        self
+    }
+
+    /// Set if leading Unicode whitespace is skipped.
+    ///
+    /// This skips any characters with the `White_Space` property encoded
+    /// as UTF-8, after any byte order mark, and the bytes skipped are
+    /// included in the number of bytes processed. This requires the
+    /// `unicode` feature. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "unicode")] {
+    /// use lexical_parse_integer::{FromLexicalWithOptions, Options};
+    /// use lexical_parse_integer::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .skip_unicode_whitespace(true)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.skip_unicode_whitespace(), true);
+    /// let bytes = "\u{3000} 42".as_bytes();
+    /// assert_eq!(u32::from_lexical_with_options::<STANDARD>(bytes, &OPTIONS), Ok(42));
+    /// # }
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "unicode")]
+    pub const fn skip_unicode_whitespace(mut self, skip_unicode_whitespace: bool) -> Self {
+        self.skip_unicode_whitespace = skip_unicode_whitespace;
+        self
+    }
+
+    /// Set if leading and trailing ASCII whitespace is skipped.
+    ///
+    /// This skips ASCII whitespace, as defined by
+    /// [`u8::is_ascii_whitespace`], before the sign and after the digits and
+    /// any suffix, so `"  42  "` is parsed as `42`. The bytes skipped are
+    /// included in the number of bytes processed, so a partial parse
+    /// consumes the trailing whitespace. Whitespace between the sign and the
+    /// digits, or between digits, is still an error. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::{Error, FromLexicalWithOptions, Options};
+    /// use lexical_parse_integer::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .trim_whitespace(true)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.trim_whitespace(), true);
+    /// assert_eq!(i32::from_lexical_with_options::<STANDARD>(b"  -42  ", &OPTIONS), Ok(-42));
+    /// let result = i32::from_lexical_partial_with_options::<STANDARD>(b" 42 \t,", &OPTIONS);
+    /// assert_eq!(result, Ok((42, 5)));
+    /// let result = i32::from_lexical_with_options::<STANDARD>(b"- 42", &OPTIONS);
+    /// assert_eq!(result, Err(Error::InvalidDigit(1)));
+    /// ```
+    #[inline(always)]
+    pub const fn trim_whitespace(mut self, trim_whitespace: bool) -> Self {
+        self.trim_whitespace = trim_whitespace;
+        self
+    }
+
+    /// Set the literal prefix skipped before parsing.
+    ///
+    /// If the input starts with the prefix, after any byte order mark or
+    /// whitespace, the prefix is skipped before parsing the sign and digits,
+    /// and the bytes skipped are included in the number of bytes processed.
+    /// The prefix is matched as a literal byte sequence and is optional.
+    /// It must not contain any ASCII digits or signs, which would be
+    /// ambiguous with the number. Defaults to an empty prefix, which skips
+    /// nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::{FromLexicalWithOptions, Options};
+    /// use lexical_parse_integer::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .ignore_prefix(b"$")
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.ignore_prefix(), b"$");
+    /// let result = i32::from_lexical_partial_with_options::<STANDARD>(b"$-5", &OPTIONS);
+    /// assert_eq!(result, Ok((-5, 3)));
+    /// assert_eq!(i32::from_lexical_with_options::<STANDARD>(b"-5", &OPTIONS), Ok(-5));
+    /// ```
+    #[inline(always)]
+    pub const fn ignore_prefix(mut self, ignore_prefix: &'static [u8]) -> Self {
+        self.ignore_prefix = ignore_prefix;
+        self
+    }
+
+    /// Set the literal suffixes skipped after parsing.
+    ///
+    /// If the bytes after the digits start with one of the suffixes, such
+    /// as units like `ms` or `KB`, the suffix is skipped and included in
+    /// the number of bytes processed. The longest matching suffix is used,
+    /// so `KB` takes precedence over `B`. When parsing a complete number,
+    /// any other trailing bytes are still an error. The suffixes are
+    /// matched as literal byte sequences and are optional. Defaults to
+    /// no suffixes.
+    ///
+    /// The suffixes must not be empty or start with a digit, which would
+    /// be ambiguous with the number. Suffixes that start with a letter
+    /// that is a valid digit in the radix of the number format, such as
+    /// `B` in hexadecimal, are rejected when parsing with
+    /// [`InvalidIgnoreSuffix`][crate::Error::InvalidIgnoreSuffix].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::{Error, FromLexicalWithOptions, Options};
+    /// use lexical_parse_integer::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .ignore_suffixes(&[b"B", b"KB"])
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.ignore_suffixes(), &[b"B" as &[u8], b"KB"]);
+    /// assert_eq!(u32::from_lexical_with_options::<STANDARD>(b"5KB", &OPTIONS), Ok(5));
+    /// let result = u32::from_lexical_partial_with_options::<STANDARD>(b"5B,", &OPTIONS);
+    /// assert_eq!(result, Ok((5, 2)));
+    /// let result = u32::from_lexical_with_options::<STANDARD>(b"5XB", &OPTIONS);
+    /// assert_eq!(result, Err(Error::InvalidDigit(1)));
+    /// ```
+    #[inline(always)]
+    pub const fn ignore_suffixes(mut self, ignore_suffixes: &'static [&'static [u8]]) -> Self {
+        self.ignore_suffixes = ignore_suffixes;
+        self
     }
 
-    // GETTERS
+    /// Set additional digit separators accepted alongside the format's.
+    ///
+    /// This allows accepting more than one grouping character, such as
+    /// both `_` and ` `. The separators are only used if the format has a
+    /// digit separator, and are accepted in the same locations. This
+    /// requires the `format` feature. Defaults to an empty set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "format")] {
+    /// use core::num::NonZeroU8;
+    ///
+    /// use lexical_parse_integer::{FromLexicalWithOptions, NumberFormatBuilder, Options};
+    ///
+    /// const FORMAT: u128 = NumberFormatBuilder::new()
+    ///     .digit_separator(NonZeroU8::new(b'_'))
+    ///     .internal_digit_separator(true)
+    ///     .build_strict();
+    /// const OPTIONS: Options = Options::builder()
+    ///     .digit_separators(b" ")
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.digit_separators(), b" ");
+    /// let result = u32::from_lexical_with_options::<FORMAT>(b"1_000 000", &OPTIONS);
+    /// assert_eq!(result, Ok(1000000));
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub const fn digit_separators(mut self, digit_separators: &'static [u8]) -> Self {
+        self.digit_separators = digit_separators;
+        self
+    }
 
-    /// Get if we disable the use of multi-digit optimizations.
+    /// Set if Eastern Arabic and Persian digits are accepted.
     ///
-    /// Defaults to [`true`].
+    /// If enabled, the digits `U+0660` to `U+0669` and `U+06F0` to `U+06F9`
+    /// are parsed as `0` to `9`, and may be mixed with ASCII digits.
+    /// Indexes in errors and the number of bytes processed refer to the
+    /// original UTF-8 input. This requires the `unicode` and `alloc`
+    /// features. Defaults to [`false`].
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use lexical_parse_integer::Options;
+    /// # #[cfg(all(feature = "unicode", feature = "alloc"))] {
+    /// use lexical_parse_integer::{FromLexicalWithOptions, Options};
+    /// use lexical_parse_integer::format::STANDARD;
     ///
-    /// let builder = Options::builder();
-    /// assert_eq!(builder.get_no_multi_digit(), true);
+    /// const OPTIONS: Options = Options::builder()
+    ///     .eastern_arabic_digits(true)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.eastern_arabic_digits(), true);
+    /// let bytes = "\u{661}\u{662}\u{663}".as_bytes();
+    /// assert_eq!(u32::from_lexical_with_options::<STANDARD>(bytes, &OPTIONS), Ok(123));
+    /// # }
     /// ```
     #[inline(always)]
-    pub const fn get_no_multi_digit(&self) -> bool {
-        self.no_multi_digit
+    #[cfg(all(feature = "unicode", feature = "alloc"))]
+    pub const fn eastern_arabic_digits(mut self, eastern_arabic_digits: bool) -> Self {
+        self.eastern_arabic_digits = eastern_arabic_digits;
+        self
     }
 
-    // SETTERS
+    /// Set the character to designate the exponent of an integer.
+    ///
+    /// This is only used if [`allow_integer_exponent`] is enabled, and is
+    /// case-insensitive unless the format has case-sensitive exponents.
+    /// Defaults to `b'e'`.
+    ///
+    /// [`allow_integer_exponent`]: Self::allow_integer_exponent
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::{FromLexicalWithOptions, Options};
+    /// use lexical_parse_integer::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .exponent(b'^')
+    ///     .allow_integer_exponent(true)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.exponent(), b'^');
+    /// assert_eq!(u32::from_lexical_with_options::<STANDARD>(b"5^2", &OPTIONS), Ok(500));
+    /// ```
+    #[inline(always)]
+    pub const fn exponent(mut self, exponent: u8) -> Self {
+        self.exponent = exponent;
+        self
+    }
 
-    /// Set if we disable the use of multi-digit optimizations.
+    /// Set if an exponent is allowed after the digits, such as `1e3`.
     ///
-    /// Defaults to [`true`].
+    /// The value is scaled by the radix raised to the exponent, and it is
+    /// an error if the result is not an integer or does not fit the type.
+    /// Defaults to [`false`].
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use lexical_parse_integer::Options;
+    /// use lexical_parse_integer::{Error, FromLexicalWithOptions, Options};
+    /// use lexical_parse_integer::format::STANDARD;
     ///
     /// const OPTIONS: Options = Options::builder()
-    ///     .no_multi_digit(false)
+    ///     .allow_integer_exponent(true)
     ///     .build_strict();
-    /// assert_eq!(OPTIONS.get_no_multi_digit(), false);
+    /// assert_eq!(OPTIONS.allow_integer_exponent(), true);
+    /// assert_eq!(u64::from_lexical_with_options::<STANDARD>(b"15e1", &OPTIONS), Ok(150));
+    /// assert_eq!(u64::from_lexical_with_options::<STANDARD>(b"1200E-2", &OPTIONS), Ok(12));
+    /// let result = u64::from_lexical_with_options::<STANDARD>(b"1e-1", &OPTIONS);
+    /// assert_eq!(result, Err(Error::InvalidExponent(1)));
     /// ```
     #[inline(always)]
-    pub const fn no_multi_digit(mut self, no_multi_digit: bool) -> Self {
-        self.no_multi_digit = no_multi_digit;
+    pub const fn allow_integer_exponent(mut self, allow_integer_exponent: bool) -> Self {
+        self.allow_integer_exponent = allow_integer_exponent;
         self
     }
 
     // BUILDERS
 
-    /// Check if the builder state is valid (always [`true`]).
+    /// Check if the builder state is valid.
     #[inline(always)]
     pub const fn is_valid(&self) -> bool {
-        true
+        self.build().is_ok()
     }
 
     /// Build the [`Options`] struct without validation.
@@ -128,10 +867,29 @@ impl OptionsBuilder {
     pub const fn build_unchecked(&self) -> Options {
         Options {
             no_multi_digit: self.no_multi_digit,
+            bool_words: self.bool_words,
+            case_sensitive_bool_words: self.case_sensitive_bool_words,
+            no_positive_sign: self.no_positive_sign,
+            required_sign: self.required_sign,
+            allow_trailing_sign: self.allow_trailing_sign,
+            radix_indicator: self.radix_indicator,
+            skip_bom: self.skip_bom,
+            skip_unicode_whitespace: self.skip_unicode_whitespace,
+            trim_whitespace: self.trim_whitespace,
+            ignore_prefix: self.ignore_prefix,
+            ignore_suffixes: self.ignore_suffixes,
+            digit_separators: self.digit_separators,
+            eastern_arabic_digits: self.eastern_arabic_digits,
+            exponent: self.exponent,
+            allow_integer_exponent: self.allow_integer_exponent,
         }
     }
 
-    /// Build the [`Options`] struct. This can never panic.
+    /// Build the [`Options`] struct, panicking if the builder is invalid.
+    ///
+    /// # Panics
+    ///
+    /// If the built options are not valid.
     #[inline(always)]
     pub const fn build_strict(&self) -> Options {
         match self.build() {
@@ -140,9 +898,63 @@ impl OptionsBuilder {
         }
     }
 
-    /// Build the [`Options`] struct. Always [`Ok`].
+    /// Build the [`Options`] struct.
+    ///
+    /// # Errors
+    ///
+    /// If the radix indicator is a digit, a sign, or not ASCII punctuation,
+    /// or the `radix` feature is not enabled, or if the radix indicator is
+    /// combined with digit separators, sign options, or an integer
+    /// exponent. If any digit separator is
+    /// alphanumeric, a sign, or not valid ASCII, or the `format` feature
+    /// is not enabled. If the exponent character is not valid ASCII or
+    /// is a sign. If the ignore prefix contains a digit or sign, or any
+    /// ignore suffix is empty or starts with an ASCII digit.
     #[inline(always)]
     pub const fn build(&self) -> Result<Options> {
+        if !is_valid_ascii(self.exponent) || matches!(self.exponent, b'+' | b'-') {
+            return Err(Error::InvalidExponentSymbol);
+        }
+        let separators = self.digit_separators;
+        if cfg!(not(feature = "format")) && !separators.is_empty() {
+            return Err(Error::InvalidDigitSeparator);
+        }
+        let mut index = 0;
+        while index < separators.len() {
+            let separator = separators[index];
+            if separator.is_ascii_alphanumeric() ||
+                matches!(separator, b'+' | b'-') ||
+                !is_valid_ascii(separator)
+            {
+                return Err(Error::InvalidDigitSeparator);
+            }
+            index += 1;
+        }
+        if let Some(indicator) = self.radix_indicator {
+            if cfg!(not(feature = "radix")) {
+                return Err(Error::InvalidRadix);
+            } else if !indicator.is_ascii_punctuation() || matches!(indicator, b'+' | b'-') {
+                return Err(Error::InvalidPunctuation);
+            } else if !separators.is_empty()
+                || self.no_positive_sign
+                || self.required_sign
+                || self.allow_trailing_sign
+                || self.allow_integer_exponent
+            {
+                return Err(Error::InvalidRadixIndicator);
+            }
+        }
+        if !is_valid_prefix_slice(self.ignore_prefix) {
+            return Err(Error::InvalidIgnorePrefix);
+        }
+        let suffixes = self.ignore_suffixes;
+        let mut index = 0;
+        while index < suffixes.len() {
+            if suffixes[index].is_empty() || suffixes[index][0].is_ascii_digit() {
+                return Err(Error::InvalidIgnoreSuffix);
+            }
+            index += 1;
+        }
         Ok(self.build_unchecked())
     }
 }
@@ -180,6 +992,36 @@ pub struct Options {
     /// increased branching can decrease performance for simple
     /// strings by 5-20%. Choose based on your inputs.
     no_multi_digit: bool,
+    /// Allow parsing booleans from `true` and `false`.
+    bool_words: bool,
+    /// If the boolean words are case-sensitive.
+    case_sensitive_bool_words: bool,
+    /// If a positive sign before the integer is not allowed.
+    no_positive_sign: bool,
+    /// If a sign before or after the integer is required.
+    required_sign: bool,
+    /// Allow a sign after the digits, such as `123-`, for signed integers.
+    allow_trailing_sign: bool,
+    /// Character to separate an explicit, leading radix from the digits.
+    radix_indicator: Option<u8>,
+    /// Skip a leading UTF-8 byte order mark before parsing.
+    skip_bom: bool,
+    /// Skip leading Unicode whitespace before parsing.
+    skip_unicode_whitespace: bool,
+    /// Skip leading and trailing ASCII whitespace.
+    trim_whitespace: bool,
+    /// Literal prefix, such as a currency symbol, to skip before parsing.
+    ignore_prefix: &'static [u8],
+    /// Literal suffixes, such as units, to skip after parsing.
+    ignore_suffixes: &'static [&'static [u8]],
+    /// Additional digit separators accepted alongside the format's.
+    digit_separators: &'static [u8],
+    /// Accept Eastern Arabic and Persian digits as decimal digits.
+    eastern_arabic_digits: bool,
+    /// Character to designate the exponent of an integer.
+    exponent: u8,
+    /// Allow an exponent after the digits, such as `1e3`.
+    allow_integer_exponent: bool,
 }
 
 impl Options {
@@ -199,7 +1041,7 @@ impl Options {
 
     // GETTERS
 
-    /// Check if the builder state is valid (always [`true`]).
+    /// Check if the options state is valid.
     #[inline(always)]
     pub const fn is_valid(&self) -> bool {
         self.rebuild().is_valid()
@@ -224,6 +1066,265 @@ impl Options {
         self.no_multi_digit
     }
 
+    /// Get if booleans can be parsed from `true` and `false`.
+    ///
+    /// `0` and `1` are always valid booleans. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::options::Options;
+    ///
+    /// assert_eq!(Options::new().bool_words(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn bool_words(&self) -> bool {
+        self.bool_words
+    }
+
+    /// Get if the boolean words `true` and `false` are case-sensitive.
+    ///
+    /// Defaults to [`true`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::options::Options;
+    ///
+    /// assert_eq!(Options::new().case_sensitive_bool_words(), true);
+    /// ```
+    #[inline(always)]
+    pub const fn case_sensitive_bool_words(&self) -> bool {
+        self.case_sensitive_bool_words
+    }
+
+    /// Get if a positive sign before the integer is not allowed.
+    ///
+    /// Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::options::Options;
+    ///
+    /// assert_eq!(Options::new().no_positive_sign(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn no_positive_sign(&self) -> bool {
+        self.no_positive_sign
+    }
+
+    /// Get if a sign before or after the integer is required.
+    ///
+    /// This applies to both positive and negative values. Defaults to
+    /// [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::options::Options;
+    ///
+    /// assert_eq!(Options::new().required_sign(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn required_sign(&self) -> bool {
+        self.required_sign
+    }
+
+    /// Get if a sign after the digits, such as `123-`, is allowed.
+    ///
+    /// This only applies to signed integers, and a value cannot have both
+    /// a leading and a trailing sign. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::options::Options;
+    ///
+    /// assert_eq!(Options::new().allow_trailing_sign(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn allow_trailing_sign(&self) -> bool {
+        self.allow_trailing_sign
+    }
+
+    /// Get the character to separate an explicit, leading radix from the
+    /// digits.
+    ///
+    /// If set, integers are written as `radix#digits`, such as `16#FF`,
+    /// where the radix is written in decimal and must be from `2` to `36`.
+    /// The digits are contiguous and ignore the number format. This
+    /// requires the `radix` feature. Defaults to [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::options::Options;
+    ///
+    /// assert_eq!(Options::new().radix_indicator(), None);
+    /// ```
+    #[inline(always)]
+    pub const fn radix_indicator(&self) -> Option<u8> {
+        self.radix_indicator
+    }
+
+    /// Get if a leading UTF-8 byte order mark (`EF BB BF`) is skipped.
+    ///
+    /// The bytes skipped are included in the number of bytes processed.
+    /// Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::options::Options;
+    ///
+    /// assert_eq!(Options::new().skip_bom(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn skip_bom(&self) -> bool {
+        self.skip_bom
+    }
+
+    /// Get if leading Unicode whitespace is skipped.
+    ///
+    /// This skips any characters with the `White_Space` property encoded
+    /// as UTF-8, after any byte order mark, and the bytes skipped are
+    /// included in the number of bytes processed. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::options::Options;
+    ///
+    /// assert_eq!(Options::new().skip_unicode_whitespace(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn skip_unicode_whitespace(&self) -> bool {
+        self.skip_unicode_whitespace
+    }
+
+    /// Get if leading and trailing ASCII whitespace is skipped.
+    ///
+    /// This skips ASCII whitespace before the sign and after the digits
+    /// and any suffix, and the bytes skipped are included in the number of
+    /// bytes processed. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::options::Options;
+    ///
+    /// assert_eq!(Options::new().trim_whitespace(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn trim_whitespace(&self) -> bool {
+        self.trim_whitespace
+    }
+
+    /// Get the literal prefix skipped before parsing.
+    ///
+    /// If the input starts with the prefix, after any byte order mark or
+    /// whitespace, the prefix is skipped before parsing the sign and digits,
+    /// and the bytes skipped are included in the number of bytes processed.
+    /// The prefix is matched as a literal byte sequence and is optional.
+    /// Defaults to an empty prefix, which skips nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::options::Options;
+    ///
+    /// assert_eq!(Options::new().ignore_prefix(), b"");
+    /// ```
+    #[inline(always)]
+    pub const fn ignore_prefix(&self) -> &'static [u8] {
+        self.ignore_prefix
+    }
+
+    /// Get the literal suffixes skipped after parsing.
+    ///
+    /// If the bytes after the digits start with one of the suffixes, the
+    /// longest matching suffix is skipped and included in the number of
+    /// bytes processed. Defaults to no suffixes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::options::Options;
+    ///
+    /// assert!(Options::new().ignore_suffixes().is_empty());
+    /// ```
+    #[inline(always)]
+    pub const fn ignore_suffixes(&self) -> &'static [&'static [u8]] {
+        self.ignore_suffixes
+    }
+
+    /// Get the additional digit separators accepted alongside the format's.
+    ///
+    /// Defaults to an empty set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::options::Options;
+    ///
+    /// assert_eq!(Options::new().digit_separators(), b"");
+    /// ```
+    #[inline(always)]
+    pub const fn digit_separators(&self) -> &'static [u8] {
+        self.digit_separators
+    }
+
+    /// Get if Eastern Arabic and Persian digits are accepted.
+    ///
+    /// If enabled, the digits `U+0660` to `U+0669` and `U+06F0` to `U+06F9`
+    /// are parsed as `0` to `9`, and may be mixed with ASCII digits.
+    /// Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::options::Options;
+    ///
+    /// assert_eq!(Options::new().eastern_arabic_digits(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn eastern_arabic_digits(&self) -> bool {
+        self.eastern_arabic_digits
+    }
+
+    /// Get the character to designate the exponent of an integer.
+    ///
+    /// Defaults to `b'e'`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::options::Options;
+    ///
+    /// assert_eq!(Options::new().exponent(), b'e');
+    /// ```
+    #[inline(always)]
+    pub const fn exponent(&self) -> u8 {
+        self.exponent
+    }
+
+    /// Get if an exponent is allowed after the digits, such as `1e3`.
+    ///
+    /// Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_parse_integer::options::Options;
+    ///
+    /// assert_eq!(Options::new().allow_integer_exponent(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn allow_integer_exponent(&self) -> bool {
+        self.allow_integer_exponent
+    }
+
     // SETTERS
 
     /// Set if we disable the use of multi-digit optimizations.
@@ -253,6 +1354,21 @@ impl Options {
     pub const fn rebuild(&self) -> OptionsBuilder {
         OptionsBuilder {
             no_multi_digit: self.no_multi_digit,
+            bool_words: self.bool_words,
+            case_sensitive_bool_words: self.case_sensitive_bool_words,
+            no_positive_sign: self.no_positive_sign,
+            required_sign: self.required_sign,
+            allow_trailing_sign: self.allow_trailing_sign,
+            radix_indicator: self.radix_indicator,
+            skip_bom: self.skip_bom,
+            skip_unicode_whitespace: self.skip_unicode_whitespace,
+            trim_whitespace: self.trim_whitespace,
+            ignore_prefix: self.ignore_prefix,
+            ignore_suffixes: self.ignore_suffixes,
+            digit_separators: self.digit_separators,
+            eastern_arabic_digits: self.eastern_arabic_digits,
+            exponent: self.exponent,
+            allow_integer_exponent: self.allow_integer_exponent,
         }
     }
 }
@@ -271,6 +1387,65 @@ impl ParseOptions for Options {
     }
 }
 
+// THREAD-LOCAL DEFAULTS
+// ---------------------
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static DEFAULT_OPTIONS: RefCell<Options> = const { RefCell::new(Options::new()) };
+}
+
+/// Ambient, per-thread default options for parsing integers.
+///
+/// This allows configuring the options once and then using them from
+/// code that does not have access to the [`Options`], without threading
+/// them through every call. Each thread starts with [`Options::new`].
+///
+/// The defaults are used by [`from_lexical_default`] and
+/// [`from_lexical_partial_default`]. [`from_lexical`] always uses the
+/// standard options and never reads the thread-local defaults, so it is
+/// not slowed down by the lookup.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_integer::{from_lexical_default, FromLexical, Options, ThreadLocalOptions};
+///
+/// let options = Options::builder().trim_whitespace(true).build_strict();
+/// ThreadLocalOptions::set_default(options);
+/// assert_eq!(from_lexical_default::<u32>(b" 42 "), Ok(42));
+/// assert!(u32::from_lexical(b" 42 ").is_err());
+/// ```
+///
+/// [`from_lexical`]: crate::FromLexical::from_lexical
+/// [`from_lexical_default`]: crate::from_lexical_default
+/// [`from_lexical_partial_default`]: crate::from_lexical_partial_default
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThreadLocalOptions;
+
+#[cfg(feature = "std")]
+impl ThreadLocalOptions {
+    /// Set the default options for the current thread.
+    ///
+    /// Returns the previous default options, which can be used to restore
+    /// them later.
+    #[inline]
+    pub fn set_default(options: Options) -> Options {
+        DEFAULT_OPTIONS.with(|default| default.replace(options))
+    }
+
+    /// Call `f` with the default options for the current thread.
+    ///
+    /// The options are copied before calling `f`, so `f` may change the
+    /// defaults without affecting the options it was given.
+    #[inline]
+    pub fn with_default<R, F: FnOnce(&Options) -> R>(f: F) -> R {
+        let options = DEFAULT_OPTIONS.with(|default| default.borrow().clone());
+        f(&options)
+    }
+}
+
 // PRE-DEFINED CONSTANTS
 // ---------------------
 
@@ -289,3 +1464,21 @@ pub const SMALL_NUMBERS: Options = Options::builder()
 pub const LARGE_NUMBERS: Options = Options::builder()
     .no_multi_digit(false)
     .build_strict();
+
+/// Unsigned and long suffixes for [`C`] integer literals, in any casing.
+///
+/// [`C`]: https://en.cppreference.com/w/c/language/integer_constant
+#[rustfmt::skip]
+pub const C_INTEGER_SUFFIXES: &[&[u8]] = &[
+    b"u", b"U", b"l", b"L", b"ll", b"LL",
+    b"ul", b"uL", b"Ul", b"UL", b"lu", b"lU", b"Lu", b"LU",
+    b"ull", b"uLL", b"Ull", b"ULL", b"llu", b"llU", b"LLu", b"LLU",
+];
+
+/// Options for [`C`] integer literals, skipping any [`C_INTEGER_SUFFIXES`].
+///
+/// [`C`]: https://en.cppreference.com/w/c/language/integer_constant
+#[rustfmt::skip]
+pub const C_LITERAL: Options = Options::builder()
+    .ignore_suffixes(C_INTEGER_SUFFIXES)
+    .build_strict();
@@ -3,6 +3,7 @@
 #![doc(hidden)]
 
 // Select the correct back-end.
+use lexical_util::format::NumberFormat;
 use lexical_util::num::Integer;
 use lexical_util::result::Result;
 
@@ -24,11 +25,59 @@ pub trait ParseInteger: Integer {
     }
 }
 
+/// Determine if the digits cannot overflow the narrower integer type.
+///
+/// The number of bytes includes any sign, base prefix, or digit separators,
+/// so this is a conservative approximation of the number of digits.
+#[inline(always)]
+fn is_narrow<T: Integer, const FORMAT: u128>(bytes: &[u8]) -> bool {
+    bytes.len() <= T::overflow_digits(NumberFormat::<FORMAT>::MANTISSA_RADIX)
+}
+
 macro_rules! parse_integer_impl {
     ($($t:ty)*) => ($(
         impl ParseInteger for $t {}
     )*)
 }
 
-parse_integer_impl! { u8 u16 u32 u64 u128 usize }
-parse_integer_impl! { i8 i16 i32 i64 i128 isize }
+parse_integer_impl! { u8 u16 u32 u64 usize }
+parse_integer_impl! { i8 i16 i32 i64 isize }
+
+/// Implement the parser for 128-bit integers with a narrower fast path.
+///
+/// 128-bit multiplication is slow on 32-bit targets, even if the value fits
+/// in 64 bits, so short inputs that cannot overflow the narrower type are
+/// parsed into it and then widened. This produces identical values, errors,
+/// and counts, since the narrower type cannot overflow.
+macro_rules! parse_integer_wide_impl {
+    ($($t:ident $narrow:ident ; )*) => ($(
+        impl ParseInteger for $t {
+            #[cfg_attr(not(feature = "compact"), inline(always))]
+            fn parse_complete<const FORMAT: u128>(bytes: &[u8], options: &Options) -> Result<Self> {
+                if is_narrow::<$narrow, FORMAT>(bytes) {
+                    algorithm_complete::<$narrow, { FORMAT }>(bytes, options).map(|x| x as $t)
+                } else {
+                    algorithm_complete::<_, { FORMAT }>(bytes, options)
+                }
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline(always))]
+            fn parse_partial<const FORMAT: u128>(
+                bytes: &[u8],
+                options: &Options,
+            ) -> Result<(Self, usize)> {
+                if is_narrow::<$narrow, FORMAT>(bytes) {
+                    algorithm_partial::<$narrow, { FORMAT }>(bytes, options)
+                        .map(|(x, count)| (x as $t, count))
+                } else {
+                    algorithm_partial::<_, { FORMAT }>(bytes, options)
+                }
+            }
+        }
+    )*)
+}
+
+parse_integer_wide_impl! {
+    u128 u64 ;
+    i128 i64 ;
+}
@@ -0,0 +1,111 @@
+use lexical_parse_integer::accumulator::{Overflow, ParseAccumulator};
+use lexical_parse_integer::{from_lexical_into, FromLexical};
+
+/// Toy 128-bit integer, stored as high and low 64-bit halves.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct U128Halves {
+    hi: u64,
+    lo: u64,
+}
+
+impl U128Halves {
+    fn to_u128(self) -> u128 {
+        (self.hi as u128) << 64 | self.lo as u128
+    }
+}
+
+impl ParseAccumulator for U128Halves {
+    fn mul_add_digit(&mut self, radix: u32, digit: u32) -> Result<(), Overflow> {
+        let lo = self.lo as u128 * radix as u128 + digit as u128;
+        let hi = self.hi as u128 * radix as u128 + (lo >> 64);
+        if hi > u64::MAX as u128 {
+            return Err(Overflow);
+        }
+        self.hi = hi as u64;
+        self.lo = lo as u64;
+        Ok(())
+    }
+}
+
+fn parse_halves(bytes: &[u8]) -> lexical_parse_integer::Result<u128> {
+    let mut value = U128Halves::default();
+    from_lexical_into(bytes, &mut value)?;
+    Ok(value.to_u128())
+}
+
+#[test]
+fn from_lexical_into_toy_test() {
+    let cases: [&[u8]; 20] = [
+        b"0",
+        b"1",
+        b"+1",
+        b"0001",
+        b"1234",
+        b"18446744073709551615",
+        b"18446744073709551616",
+        b"340282366920938463463374607431768211455",
+        b"+340282366920938463463374607431768211455",
+        b"340282366920938463463374607431768211456",
+        b"3402823669209384634633746074317682114550",
+        b"999999999999999999999999999999999999999",
+        b"",
+        b"+",
+        b"-",
+        b"-1",
+        b"1a",
+        b"12 ",
+        b" 12",
+        b"1.0",
+    ];
+    for bytes in cases {
+        assert_eq!(parse_halves(bytes), u128::from_lexical(bytes), "{:?}", bytes);
+    }
+}
+
+#[test]
+fn from_lexical_into_primitive_test() {
+    let mut value = 0u64;
+    assert_eq!(from_lexical_into(b"18446744073709551615", &mut value), Ok(()));
+    assert_eq!(value, u64::MAX);
+
+    let mut value = 0u64;
+    assert_eq!(
+        from_lexical_into(b"18446744073709551616", &mut value),
+        u64::from_lexical(b"18446744073709551616").map(|_| ())
+    );
+
+    // Digits are added to the existing value.
+    let mut value = 12u32;
+    assert_eq!(from_lexical_into(b"34", &mut value), Ok(()));
+    assert_eq!(value, 1234);
+}
+
+#[test]
+fn from_lexical_into_signed_test() {
+    fn parse(bytes: &[u8]) -> lexical_parse_integer::Result<i8> {
+        let mut value = 0i8;
+        from_lexical_into(bytes, &mut value)?;
+        Ok(value)
+    }
+
+    let cases: [&[u8]; 10] =
+        [b"-5", b"+5", b"-128", b"-129", b"127", b"128", b"-", b"--1", b"-1a", b""];
+    for bytes in cases {
+        assert_eq!(parse(bytes), i8::from_lexical(bytes), "{:?}", bytes);
+    }
+}
+
+#[test]
+fn mul_add_digit_test() {
+    let mut value = 25u8;
+    assert_eq!(value.mul_add_digit(10, 5), Ok(()));
+    assert_eq!(value, 255);
+    assert_eq!(value.mul_add_digit(10, 0), Err(Overflow));
+
+    let mut value = U128Halves {
+        hi: 0,
+        lo: u64::MAX,
+    };
+    assert_eq!(value.mul_add_digit(2, 1), Ok(()));
+    assert_eq!(value.to_u128(), (u64::MAX as u128) * 2 + 1);
+}
@@ -3,7 +3,8 @@
 mod util;
 
 use lexical_parse_integer::algorithm;
-use lexical_parse_integer::options::SMALL_NUMBERS;
+use lexical_parse_integer::options::{LARGE_NUMBERS, SMALL_NUMBERS};
+use lexical_util::error::Error;
 use lexical_util::format::STANDARD;
 use lexical_util::iterator::AsBytes;
 #[cfg(feature = "power-of-two")]
@@ -176,3 +177,59 @@ fn algorithm_128_test() {
     assert_eq!(parse_i128(b"+12345"), Ok((12345, 6)));
     assert_eq!(parse_i128(b"+123.45"), Ok((123, 4)));
 }
+
+#[test]
+fn algorithm_multi_digit_u64_boundaries_test() {
+    let parse_multi = |bytes: &[u8]| {
+        algorithm::algorithm_complete::<u64, STANDARD>(bytes, &LARGE_NUMBERS)
+    };
+    let parse_single = |bytes: &[u8]| {
+        algorithm::algorithm_complete::<u64, STANDARD>(bytes, &SMALL_NUMBERS)
+    };
+
+    // Check every power-of-10 boundary, which crosses 8-digit chunks.
+    let mut power: u64 = 1;
+    loop {
+        for value in [power - 1, power, power + 1] {
+            let string = value.to_string();
+            assert_eq!(parse_multi(string.as_bytes()), Ok(value));
+            assert_eq!(parse_single(string.as_bytes()), Ok(value));
+        }
+        match power.checked_mul(10) {
+            Some(next) => power = next,
+            None => break,
+        }
+    }
+
+    // Check the 16-20 digit range, near the overflow boundary.
+    let values = [
+        1_000_000_000_000_000,
+        9_999_999_999_999_999,
+        12_345_678_901_234_567,
+        99_999_999_999_999_999,
+        999_999_999_999_999_999,
+        9_999_999_999_999_999_999,
+        u64::MAX / 10,
+        u64::MAX - 1,
+        u64::MAX,
+    ];
+    for value in values {
+        let string = value.to_string();
+        assert_eq!(parse_multi(string.as_bytes()), Ok(value));
+        assert_eq!(parse_single(string.as_bytes()), Ok(value));
+    }
+
+    // Leading zeros use the multi-digit path without overflowing.
+    assert_eq!(parse_multi(b"0000000018446744073709551615"), Ok(u64::MAX));
+    assert_eq!(parse_single(b"0000000018446744073709551615"), Ok(u64::MAX));
+
+    // Overflow and invalid digits in or after an 8-digit chunk.
+    assert_eq!(parse_multi(b"18446744073709551616"), Err(Error::Overflow(19)));
+    assert_eq!(parse_single(b"18446744073709551616"), Err(Error::Overflow(19)));
+    assert_eq!(parse_multi(b"99999999999999999999"), Err(Error::Overflow(19)));
+    assert_eq!(parse_single(b"99999999999999999999"), Err(Error::Overflow(19)));
+    assert_eq!(parse_multi(b"1234567x90123456"), Err(Error::InvalidDigit(7)));
+    assert_eq!(parse_single(b"1234567x90123456"), Err(Error::InvalidDigit(7)));
+    assert_eq!(parse_multi(b"123456789012345x"), Err(Error::InvalidDigit(15)));
+    assert_eq!(parse_single(b"123456789012345x"), Err(Error::InvalidDigit(15)));
+}
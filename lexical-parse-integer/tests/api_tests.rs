@@ -1,10 +1,16 @@
 mod util;
 
-use lexical_parse_integer::{FromLexical, FromLexicalWithOptions, Options};
+use lexical_parse_integer::{
+    bool_from_lexical, bool_from_lexical_with_options, from_lexical_checked_mod10,
+    from_lexical_checked_mod97, from_lexical_fixed, from_lexical_n, from_lexical_resumable,
+    from_lexical_until, parse_array, parse_bcd, split_sign, FromLexical, FromLexicalWithOptions,
+    Options, ParseIntegers, Sign,
+};
 use lexical_util::error::Error;
 #[cfg(feature = "format")]
 use lexical_util::format::NumberFormatBuilder;
 use lexical_util::format::STANDARD;
+use lexical_util::iterator::Iter;
 #[cfg(feature = "power-of-two")]
 use util::from_radix;
 
@@ -139,6 +145,60 @@ fn i128_decimal_test() {
     assert_eq!(Err(Error::InvalidDigit(1)), i128::from_lexical(b"1a"));
 }
 
+#[test]
+fn wide_u64_boundary_test() {
+    // Short inputs are parsed as 64-bit integers, so check either side of
+    // the cutoff and the 64-bit limits.
+    assert_eq!(Ok(9999999999999999), u128::from_lexical(b"9999999999999999"));
+    assert_eq!(Ok(99999999999999999), u128::from_lexical(b"99999999999999999"));
+    assert_eq!(Ok(u64::MAX as u128), u128::from_lexical(b"18446744073709551615"));
+    assert_eq!(Ok(u64::MAX as u128 + 1), u128::from_lexical(b"18446744073709551616"));
+    assert_eq!(Err(Error::InvalidDigit(15)), u128::from_lexical(b"999999999999999a"));
+    assert_eq!(Err(Error::InvalidDigit(16)), u128::from_lexical(b"9999999999999999a"));
+    assert_eq!(Ok((9999999999999999, 16)), u128::from_lexical_partial(b"9999999999999999a"));
+
+    assert_eq!(Ok(-99999999999999), i128::from_lexical(b"-99999999999999"));
+    assert_eq!(Ok(-999999999999999), i128::from_lexical(b"-999999999999999"));
+    assert_eq!(Ok(999999999999999), i128::from_lexical(b"999999999999999"));
+    assert_eq!(Ok(9999999999999999), i128::from_lexical(b"9999999999999999"));
+    assert_eq!(Ok(i64::MIN as i128), i128::from_lexical(b"-9223372036854775808"));
+    assert_eq!(Ok(i64::MIN as i128 - 1), i128::from_lexical(b"-9223372036854775809"));
+    assert_eq!(Ok(i64::MAX as i128 + 1), i128::from_lexical(b"9223372036854775808"));
+    assert_eq!(Err(Error::EmptyMantissa(1)), i128::from_lexical(b"-"));
+    assert_eq!(Err(Error::InvalidDigit(1)), i128::from_lexical(b"--1"));
+    assert_eq!(Ok((-1, 2)), i128::from_lexical_partial(b"-1-"));
+}
+
+#[test]
+#[cfg(target_pointer_width = "16")]
+fn size_decimal_test() {
+    assert_eq!(Ok(65535), usize::from_lexical(b"65535"));
+    assert_eq!(Err(Error::Overflow(4)), usize::from_lexical(b"65536"));
+    assert_eq!(Ok(-32768), isize::from_lexical(b"-32768"));
+    assert_eq!(Err(Error::Overflow(4)), isize::from_lexical(b"32768"));
+    assert_eq!(Err(Error::Underflow(5)), isize::from_lexical(b"-32769"));
+}
+
+#[test]
+#[cfg(target_pointer_width = "32")]
+fn size_decimal_test() {
+    assert_eq!(Ok(4294967295), usize::from_lexical(b"4294967295"));
+    assert_eq!(Err(Error::Overflow(9)), usize::from_lexical(b"4294967296"));
+    assert_eq!(Ok(-2147483648), isize::from_lexical(b"-2147483648"));
+    assert_eq!(Err(Error::Overflow(9)), isize::from_lexical(b"2147483648"));
+    assert_eq!(Err(Error::Underflow(10)), isize::from_lexical(b"-2147483649"));
+}
+
+#[test]
+#[cfg(target_pointer_width = "64")]
+fn size_decimal_test() {
+    assert_eq!(Ok(18446744073709551615), usize::from_lexical(b"18446744073709551615"));
+    assert_eq!(Err(Error::Overflow(19)), usize::from_lexical(b"18446744073709551616"));
+    assert_eq!(Ok(-9223372036854775808), isize::from_lexical(b"-9223372036854775808"));
+    assert_eq!(Err(Error::Overflow(18)), isize::from_lexical(b"9223372036854775808"));
+    assert_eq!(Err(Error::Underflow(19)), isize::from_lexical(b"-9223372036854775809"));
+}
+
 #[test]
 fn double_sign_test() {
     assert_eq!(Err(Error::InvalidDigit(1)), i16::from_lexical(b"+-0000"));
@@ -276,6 +336,108 @@ fn i32_integer_consecutive_digit_separator_test() {
     assert!(i32::from_lexical_with_options::<FORMAT>(b"31_", &OPTIONS).is_err());
 }
 
+#[test]
+#[cfg(feature = "format")]
+fn i32_lenient_grouping_test() {
+    const OPTIONS: Options = Options::new();
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(std::num::NonZeroU8::new(b','))
+        .integer_digit_separator_flags(true)
+        .build_strict();
+
+    // Group separators are skipped anywhere, without validating the grouping.
+    assert_eq!(i32::from_lexical_with_options::<FORMAT>(b"1,2,3,4", &OPTIONS), Ok(1234));
+    assert_eq!(i32::from_lexical_with_options::<FORMAT>(b"1,234", &OPTIONS), Ok(1234));
+    assert_eq!(i32::from_lexical_with_options::<FORMAT>(b"12,34", &OPTIONS), Ok(1234));
+    assert_eq!(i32::from_lexical_with_options::<FORMAT>(b"1,,234,", &OPTIONS), Ok(1234));
+    assert_eq!(i32::from_lexical_with_options::<FORMAT>(b"-,1,2,3,4", &OPTIONS), Ok(-1234));
+    assert_eq!(
+        i32::from_lexical_with_options::<FORMAT>(b",", &OPTIONS),
+        Err(Error::Empty(1))
+    );
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn i32_multiple_digit_separators_test() {
+    const OPTIONS: Options = Options::builder().digit_separators(b" ").build_strict();
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(std::num::NonZeroU8::new(b'_'))
+        .integer_internal_digit_separator(true)
+        .build_strict();
+
+    // Both separators are accepted in the same locations.
+    assert_eq!(i32::from_lexical_with_options::<FORMAT>(b"1_000 000", &OPTIONS), Ok(1000000));
+    assert_eq!(i32::from_lexical_with_options::<FORMAT>(b"1 000_000", &OPTIONS), Ok(1000000));
+    assert_eq!(i32::from_lexical_with_options::<FORMAT>(b"-1 2_3", &OPTIONS), Ok(-123));
+    assert!(i32::from_lexical_with_options::<FORMAT>(b" 123", &OPTIONS).is_err());
+    assert!(i32::from_lexical_with_options::<FORMAT>(b"123_", &OPTIONS).is_err());
+    assert!(i32::from_lexical_with_options::<FORMAT>(b"1,234", &OPTIONS).is_err());
+    assert_eq!(
+        i32::from_lexical_partial_with_options::<FORMAT>(b"1 2_3,4", &OPTIONS),
+        Ok((123, 5))
+    );
+
+    // Without the option, only the format's separator is accepted.
+    const DEFAULT: Options = Options::new();
+    assert_eq!(i32::from_lexical_with_options::<FORMAT>(b"1_000", &DEFAULT), Ok(1000));
+    assert!(i32::from_lexical_with_options::<FORMAT>(b"1 000", &DEFAULT).is_err());
+
+    // The extra separators are unused if the format has no separator.
+    assert!(i32::from_lexical_with_options::<STANDARD>(b"1 000", &OPTIONS).is_err());
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn i32_signed_digit_separator_test() {
+    const OPTIONS: Options = Options::new();
+    const INTERNAL: u128 = NumberFormatBuilder::new()
+        .digit_separator(std::num::NonZeroU8::new(b'_'))
+        .integer_internal_digit_separator(true)
+        .build_strict();
+    const LEADING: u128 = NumberFormatBuilder::new()
+        .digit_separator(std::num::NonZeroU8::new(b'_'))
+        .integer_internal_digit_separator(true)
+        .integer_leading_digit_separator(true)
+        .build_strict();
+
+    // The sign is consumed before classifying the digit separators.
+    assert_eq!(i32::from_lexical_with_options::<INTERNAL>(b"-1_000", &OPTIONS), Ok(-1000));
+    assert_eq!(i32::from_lexical_with_options::<INTERNAL>(b"+1_000", &OPTIONS), Ok(1000));
+    assert_eq!(
+        i32::from_lexical_partial_with_options::<INTERNAL>(b"-1_000", &OPTIONS),
+        Ok((-1000, 6))
+    );
+    assert_eq!(
+        i32::from_lexical_with_options::<INTERNAL>(b"-_1000", &OPTIONS),
+        Err(Error::InvalidDigit(1))
+    );
+    assert_eq!(
+        i32::from_lexical_with_options::<INTERNAL>(b"-1000_", &OPTIONS),
+        Err(Error::InvalidDigit(5))
+    );
+    assert_eq!(
+        u32::from_lexical_with_options::<INTERNAL>(b"-1_000", &OPTIONS),
+        Err(Error::InvalidDigit(0))
+    );
+
+    // A separator after the sign is leading, not internal.
+    assert_eq!(i32::from_lexical_with_options::<LEADING>(b"-_1000", &OPTIONS), Ok(-1000));
+    assert_eq!(i32::from_lexical_with_options::<LEADING>(b"-_1_000", &OPTIONS), Ok(-1000));
+    assert_eq!(
+        i32::from_lexical_partial_with_options::<LEADING>(b"-_1000", &OPTIONS),
+        Ok((-1000, 6))
+    );
+    assert_eq!(
+        i32::from_lexical_with_options::<LEADING>(b"-__1000", &OPTIONS),
+        Err(Error::InvalidDigit(1))
+    );
+    assert_eq!(
+        i32::from_lexical_with_options::<LEADING>(b"_-1000", &OPTIONS),
+        Err(Error::InvalidDigit(1))
+    );
+}
+
 #[test]
 #[cfg(feature = "format")]
 fn i32_json_no_leading_zero() {
@@ -357,3 +519,1011 @@ fn base_prefix_and_suffix_test() {
     assert!(i32::from_lexical_with_options::<FORMAT>(b"+h", &OPTIONS).is_err());
     assert!(i32::from_lexical_with_options::<FORMAT>(b"+0x", &OPTIONS).is_err());
 }
+
+#[test]
+fn empty_and_invalid_digit_test() {
+    assert_eq!(Err(Error::Empty(0)), u64::from_lexical(b""));
+    assert_eq!(Err(Error::Empty(0)), i64::from_lexical(b""));
+    assert_eq!(Err(Error::InvalidDigit(0)), u64::from_lexical(b"x"));
+    assert_eq!(Err(Error::InvalidDigit(0)), i64::from_lexical(b"x"));
+    assert_eq!(Err(Error::EmptyMantissa(1)), u64::from_lexical(b"+"));
+    assert_eq!(Err(Error::EmptyMantissa(1)), i64::from_lexical(b"+"));
+    assert_eq!(Err(Error::EmptyMantissa(1)), i64::from_lexical(b"-"));
+    assert_eq!(Err(Error::InvalidDigit(0)), u64::from_lexical(b"-"));
+    assert_eq!(Err(Error::InvalidDigit(1)), u64::from_lexical(b"+x"));
+    assert_eq!(Err(Error::InvalidDigit(1)), i64::from_lexical(b"+x"));
+    assert_eq!(Err(Error::InvalidDigit(1)), i64::from_lexical(b"-x"));
+    assert_eq!(Err(Error::InvalidDigit(1)), i64::from_lexical(b"1x"));
+
+    assert_eq!(Err(Error::Empty(0)), i64::from_lexical_partial(b""));
+    assert_eq!(Err(Error::EmptyMantissa(1)), i64::from_lexical_partial(b"+"));
+    assert_eq!(Err(Error::EmptyMantissa(1)), i64::from_lexical_partial(b"-"));
+    assert_eq!(Ok((0, 0)), i64::from_lexical_partial(b"x"));
+    assert_eq!(Ok((1, 1)), i64::from_lexical_partial(b"1x"));
+}
+
+#[test]
+fn from_lexical_n_test() {
+    let date = b"20240115";
+    let (year, rest) = from_lexical_n::<u16>(date, 4).unwrap();
+    assert_eq!(year, 2024);
+    assert_eq!(rest, b"0115");
+    let (month, rest) = from_lexical_n::<u8>(rest, 2).unwrap();
+    assert_eq!(month, 1);
+    assert_eq!(rest, b"15");
+    let (day, rest) = from_lexical_n::<u8>(rest, 2).unwrap();
+    assert_eq!(day, 15);
+    assert_eq!(rest, b"");
+
+    assert_eq!(Err(Error::Empty(0)), from_lexical_n::<u32>(b"123", 0));
+    assert_eq!(Err(Error::Empty(2)), from_lexical_n::<u32>(b"15", 4));
+    assert_eq!(Err(Error::Empty(0)), from_lexical_n::<u32>(b"", 1));
+    assert_eq!(Err(Error::InvalidDigit(2)), from_lexical_n::<u32>(b"20-4", 4));
+    assert_eq!(Err(Error::InvalidDigit(0)), from_lexical_n::<i32>(b"-123", 4));
+    assert_eq!(Err(Error::Overflow(2)), from_lexical_n::<u8>(b"2561", 4));
+}
+
+#[test]
+fn parse_bcd_test() {
+    assert_eq!(Ok(([0x01, 0x23, 0x45], 3)), parse_bcd::<3>(b"12345"));
+    assert_eq!(Ok(([0x12, 0x34, 0x56], 3)), parse_bcd::<3>(b"123456"));
+    assert_eq!(Ok(([0x09, 0x00, 0x00], 1)), parse_bcd::<3>(b"9"));
+    assert_eq!(Ok(([0x00, 0x07, 0x00], 2)), parse_bcd::<3>(b"007"));
+
+    assert_eq!(Err(Error::Empty(0)), parse_bcd::<3>(b""));
+    assert_eq!(Err(Error::InvalidDigit(2)), parse_bcd::<3>(b"12a45"));
+    assert_eq!(Err(Error::InvalidDigit(0)), parse_bcd::<3>(b"-12"));
+    assert_eq!(Err(Error::InvalidDigit(1)), parse_bcd::<3>(b"1 2"));
+    assert_eq!(Err(Error::Overflow(6)), parse_bcd::<3>(b"1234567"));
+}
+
+#[test]
+fn from_lexical_resumable_test() {
+    let bytes = b"-12:345";
+    let (minutes, index) = from_lexical_resumable::<i32>(bytes, 0).unwrap();
+    assert_eq!((minutes, index), (-12, 3));
+    assert_eq!(bytes[index], b':');
+    let (seconds, index) = from_lexical_resumable::<u32>(bytes, index + 1).unwrap();
+    assert_eq!((seconds, index), (345, 7));
+    assert_eq!(Err(Error::Empty(7)), from_lexical_resumable::<u32>(bytes, index));
+    assert_eq!(Err(Error::Empty(7)), from_lexical_resumable::<u32>(bytes, 8));
+
+    // Errors are relative to the start of the buffer.
+    assert_eq!(Err(Error::Empty(3)), from_lexical_resumable::<u32>(b"12:", 3));
+    assert_eq!(Err(Error::EmptyMantissa(4)), from_lexical_resumable::<i32>(b"12:-", 3));
+    assert_eq!(Err(Error::Overflow(5)), from_lexical_resumable::<u8>(b"12:256", 3));
+    assert_eq!(Ok((0, 3)), from_lexical_resumable::<u8>(b"12:x", 3));
+}
+
+#[test]
+fn from_lexical_until_test() {
+    assert_eq!(Ok((123, 3)), from_lexical_until::<u32>(b"123,456", b","));
+    assert_eq!(Ok((-123, 4)), from_lexical_until::<i32>(b"-123}", b",]}"));
+    assert_eq!(Ok((123, 3)), from_lexical_until::<u32>(b"123", b","));
+    assert_eq!(Err(Error::InvalidDigit(3)), from_lexical_until::<u32>(b"123x", b","));
+    assert_eq!(Err(Error::InvalidDigit(3)), from_lexical_until::<u32>(b"123]", b","));
+    assert_eq!(Err(Error::InvalidDigit(0)), from_lexical_until::<u32>(b"x", b","));
+    assert_eq!(Err(Error::Empty(0)), from_lexical_until::<u32>(b",", b","));
+    assert_eq!(Err(Error::Empty(0)), from_lexical_until::<u32>(b"", b","));
+    assert_eq!(Err(Error::EmptyMantissa(1)), from_lexical_until::<i32>(b"-,", b","));
+    assert_eq!(Err(Error::Overflow(2)), from_lexical_until::<u8>(b"256,", b","));
+}
+
+#[test]
+fn from_lexical_fixed_test() {
+    const OPTIONS: Options = Options::new();
+    let parse = |bytes: &[u8]| from_lexical_fixed::<i64, STANDARD>(bytes, 2, &OPTIONS);
+    assert_eq!(Ok(100000), parse(b"1000.00"));
+    assert_eq!(Ok(123456), parse(b"1234.56"));
+    assert_eq!(Ok(123450), parse(b"1234.5"));
+    assert_eq!(Ok(123400), parse(b"1234."));
+    assert_eq!(Ok(123400), parse(b"1234"));
+    assert_eq!(Ok(-123456), parse(b"-1234.56"));
+    assert_eq!(Ok(-50), parse(b"-0.50"));
+    assert_eq!(Ok(-123456), parse(b"(1234.56)"));
+    assert_eq!(Ok(-5), parse(b"(0.05)"));
+    assert_eq!(Err(Error::InvalidDigit(1)), parse(b"(-1.00)"));
+    assert_eq!(Err(Error::InvalidDigit(7)), parse(b"1234.567"));
+    assert_eq!(Err(Error::InvalidDigit(6)), parse(b"1234.5x"));
+    assert_eq!(Err(Error::InvalidDigit(2)), parse(b"(1x)"));
+    assert_eq!(Err(Error::InvalidDigit(0)), parse(b"(1.00"));
+    assert_eq!(Err(Error::Empty(1)), parse(b"()"));
+
+    let parse = |bytes: &[u8]| from_lexical_fixed::<i16, STANDARD>(bytes, 2, &OPTIONS);
+    assert_eq!(Ok(32767), parse(b"327.67"));
+    assert_eq!(Ok(-32768), parse(b"(327.68)"));
+    assert_eq!(Err(Error::Overflow(3)), parse(b"327.68"));
+    assert_eq!(Err(Error::Underflow(4)), parse(b"(327.69)"));
+    assert_eq!(Err(Error::Overflow(4)), parse(b"40000"));
+
+    let parse = |bytes: &[u8]| from_lexical_fixed::<u32, STANDARD>(bytes, 3, &OPTIONS);
+    assert_eq!(Ok(1500), parse(b"1.5"));
+    assert_eq!(Err(Error::Underflow(2)), parse(b"(1)"));
+    assert_eq!(Err(Error::Underflow(2)), parse(b"(0.5)"));
+    assert_eq!(Err(Error::Overflow(1)), from_lexical_fixed::<u8, STANDARD>(b"0.5", 3, &OPTIONS));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn from_lexical_fixed_grouping_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(core::num::NonZeroU8::new(b','))
+        .integer_internal_digit_separator(true)
+        .build_strict();
+    const OPTIONS: Options = Options::new();
+    let parse = |bytes: &[u8]| from_lexical_fixed::<i64, FORMAT>(bytes, 2, &OPTIONS);
+    assert_eq!(Ok(-123456), parse(b"(1,234.56)"));
+    assert_eq!(Ok(100000), parse(b"1,000.00"));
+    assert_eq!(Ok(-100000000), parse(b"-1,000,000.00"));
+    assert_eq!(Ok(-100000000), parse(b"(1,000,000)"));
+    assert_eq!(Err(Error::InvalidDigit(2)), parse(b"(1,,234.56)"));
+    assert_eq!(Err(Error::InvalidDigit(6)), parse(b"(1,234,.56)"));
+}
+
+#[test]
+fn bool_from_lexical_test() {
+    assert_eq!(Ok(false), bool_from_lexical(b"0"));
+    assert_eq!(Ok(true), bool_from_lexical(b"1"));
+    assert_eq!(Err(Error::Empty(0)), bool_from_lexical(b""));
+    assert_eq!(Err(Error::InvalidDigit(0)), bool_from_lexical(b"2"));
+    assert_eq!(Err(Error::InvalidDigit(1)), bool_from_lexical(b"10"));
+    assert_eq!(Err(Error::InvalidDigit(1)), bool_from_lexical(b"00"));
+    assert_eq!(Err(Error::InvalidDigit(0)), bool_from_lexical(b"+1"));
+    assert_eq!(Err(Error::InvalidDigit(0)), bool_from_lexical(b"true"));
+    assert_eq!(Err(Error::InvalidDigit(0)), bool_from_lexical(b"false"));
+    assert_eq!(Err(Error::InvalidDigit(0)), bool_from_lexical(b"True"));
+}
+
+#[test]
+fn bool_from_lexical_words_test() {
+    const OPTIONS: Options = Options::builder().bool_words(true).build_strict();
+    let parse = |bytes: &[u8]| bool_from_lexical_with_options(bytes, &OPTIONS);
+    assert_eq!(Ok(false), parse(b"0"));
+    assert_eq!(Ok(true), parse(b"1"));
+    assert_eq!(Ok(true), parse(b"true"));
+    assert_eq!(Ok(false), parse(b"false"));
+    assert_eq!(Err(Error::InvalidDigit(0)), parse(b"2"));
+    assert_eq!(Err(Error::InvalidDigit(0)), parse(b"True"));
+    assert_eq!(Err(Error::InvalidDigit(2)), parse(b"trUe"));
+    assert_eq!(Err(Error::InvalidDigit(0)), parse(b"FALSE"));
+    assert_eq!(Err(Error::InvalidDigit(4)), parse(b"truee"));
+    assert_eq!(Err(Error::InvalidDigit(5)), parse(b"false "));
+    assert_eq!(Err(Error::InvalidDigit(2)), parse(b"fa1se"));
+    assert_eq!(Err(Error::Empty(3)), parse(b"tru"));
+    assert_eq!(Err(Error::Empty(0)), parse(b""));
+
+    const INSENSITIVE: Options =
+        Options::builder().bool_words(true).case_sensitive_bool_words(false).build_strict();
+    let parse = |bytes: &[u8]| bool_from_lexical_with_options(bytes, &INSENSITIVE);
+    assert_eq!(Ok(false), parse(b"0"));
+    assert_eq!(Ok(true), parse(b"1"));
+    assert_eq!(Ok(true), parse(b"true"));
+    assert_eq!(Ok(true), parse(b"True"));
+    assert_eq!(Ok(true), parse(b"TRUE"));
+    assert_eq!(Ok(true), parse(b"tRuE"));
+    assert_eq!(Ok(false), parse(b"false"));
+    assert_eq!(Ok(false), parse(b"False"));
+    assert_eq!(Ok(false), parse(b"FALSE"));
+    assert_eq!(Err(Error::InvalidDigit(0)), parse(b"2"));
+    assert_eq!(Err(Error::InvalidDigit(0)), parse(b"yes"));
+    assert_eq!(Err(Error::InvalidDigit(3)), parse(b"TRUTH"));
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn from_lexical_radix_test() {
+    use lexical_parse_integer::from_lexical_radix;
+
+    assert_eq!(Ok(5), from_lexical_radix::<u32>(b"101", 2));
+    assert_eq!(Ok(-5), from_lexical_radix::<i32>(b"-101", 2));
+    assert_eq!(Ok(255), from_lexical_radix::<u8>(b"11111111", 2));
+    assert_eq!(Err(Error::InvalidDigit(1)), from_lexical_radix::<u32>(b"12", 2));
+
+    assert_eq!(Ok(511), from_lexical_radix::<u32>(b"777", 8));
+    assert_eq!(Ok(-8), from_lexical_radix::<i8>(b"-10", 8));
+    assert_eq!(Err(Error::InvalidDigit(2)), from_lexical_radix::<u32>(b"178", 8));
+
+    assert_eq!(Ok(1234), from_lexical_radix::<u32>(b"1234", 10));
+    assert_eq!(Ok(1234), from_lexical_radix::<u32>(b"+1234", 10));
+    assert_eq!(Ok(i64::MIN), from_lexical_radix::<i64>(b"-9223372036854775808", 10));
+    assert_eq!(Err(Error::InvalidDigit(2)), from_lexical_radix::<u32>(b"12a", 10));
+
+    assert_eq!(Ok(0xDEADBEEF), from_lexical_radix::<u32>(b"DEADBEEF", 16));
+    assert_eq!(Ok(0xDEADBEEF), from_lexical_radix::<u32>(b"deadbeef", 16));
+    assert_eq!(Ok(-0x80), from_lexical_radix::<i8>(b"-80", 16));
+    assert_eq!(Err(Error::InvalidDigit(1)), from_lexical_radix::<u32>(b"fg", 16));
+
+    assert_eq!(Ok(35), from_lexical_radix::<u32>(b"z", 36));
+    assert_eq!(Ok(46655), from_lexical_radix::<u32>(b"ZZZ", 36));
+    assert_eq!(Ok(u64::MAX), from_lexical_radix::<u64>(b"3w5e11264sgsf", 36));
+
+    // Each radix must match the const format parser.
+    for radix in [2, 8, 10, 16, 36] {
+        let digits: &[u8] = b"100000000001";
+        let expected = u64::from_str_radix(core::str::from_utf8(digits).unwrap(), radix);
+        assert_eq!(Ok(expected.unwrap()), from_lexical_radix::<u64>(digits, radix));
+    }
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn from_lexical_with_mapper_test() {
+    use lexical_parse_integer::from_lexical_with_mapper;
+
+    // Treat `O` as `0` and `l` as `1`, as in Crockford's base 32.
+    let mapper = |c: u8| match c {
+        b'O' | b'o' => Some(0),
+        b'l' | b'L' | b'I' | b'i' => Some(1),
+        _ => (c as char).to_digit(32),
+    };
+    let parse = |bytes: &[u8]| from_lexical_with_mapper::<i32, _>(bytes, 10, mapper);
+    assert_eq!(Ok(101), parse(b"lOl"));
+    assert_eq!(Ok(101), parse(b"101"));
+    assert_eq!(Ok(-10), parse(b"-lO"));
+    assert_eq!(Ok(10), parse(b"+lo"));
+    assert_eq!(Err(Error::Empty(0)), parse(b""));
+    assert_eq!(Err(Error::EmptyMantissa(1)), parse(b"-"));
+    assert_eq!(Err(Error::InvalidDigit(1)), parse(b"l-"));
+
+    // Values that are not below the radix are invalid digits.
+    assert_eq!(Err(Error::InvalidDigit(1)), parse(b"1a"));
+    let parse = |bytes: &[u8]| from_lexical_with_mapper::<u32, _>(bytes, 32, mapper);
+    assert_eq!(Ok(32 * 32 + 10), parse(b"lOa"));
+    assert_eq!(Ok(32 * 32 - 1), parse(b"vv"));
+    assert_eq!(Err(Error::InvalidDigit(0)), parse(b"w"));
+
+    // Overflow and underflow are detected.
+    assert_eq!(Ok(255), from_lexical_with_mapper::<u8, _>(b"255", 10, mapper));
+    assert_eq!(Err(Error::Overflow(2)), from_lexical_with_mapper::<u8, _>(b"256", 10, mapper));
+    assert_eq!(Err(Error::Underflow(3)), from_lexical_with_mapper::<i8, _>(b"-l29", 10, mapper));
+
+    // The radix must be supported.
+    assert_eq!(Err(Error::InvalidRadix), from_lexical_with_mapper::<u32, _>(b"0", 1, mapper));
+    assert_eq!(Err(Error::InvalidRadix), from_lexical_with_mapper::<u32, _>(b"0", 37, mapper));
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn from_lexical_radix_error_test() {
+    use lexical_parse_integer::from_lexical_radix;
+
+    assert_eq!(Err(Error::InvalidRadix), from_lexical_radix::<u32>(b"0", 0));
+    assert_eq!(Err(Error::InvalidRadix), from_lexical_radix::<u32>(b"0", 1));
+    assert_eq!(Err(Error::InvalidRadix), from_lexical_radix::<u32>(b"0", 37));
+    assert_eq!(Err(Error::Empty(0)), from_lexical_radix::<u32>(b"", 10));
+    assert_eq!(Err(Error::EmptyMantissa(1)), from_lexical_radix::<i32>(b"-", 10));
+    assert_eq!(Err(Error::EmptyMantissa(1)), from_lexical_radix::<u32>(b"+", 10));
+    assert_eq!(Err(Error::InvalidDigit(0)), from_lexical_radix::<u32>(b"-1", 10));
+    assert_eq!(Err(Error::Overflow(8)), from_lexical_radix::<u8>(b"100000000", 2));
+    assert_eq!(Err(Error::Overflow(2)), from_lexical_radix::<u8>(b"100", 16));
+    assert_eq!(Err(Error::Underflow(2)), from_lexical_radix::<i8>(b"-81", 16));
+    assert_eq!(
+        u64::from_lexical(b"18446744073709551616").map(|_| ()),
+        from_lexical_radix::<u64>(b"18446744073709551616", 10).map(|_| ())
+    );
+}
+
+#[test]
+fn no_positive_sign_test() {
+    const OPTIONS: Options = Options::builder().no_positive_sign(true).build_strict();
+    let parse = |bytes: &[u8]| i32::from_lexical_with_options::<STANDARD>(bytes, &OPTIONS);
+    assert_eq!(Ok(1), parse(b"1"));
+    assert_eq!(Ok(-1), parse(b"-1"));
+    assert_eq!(Err(Error::InvalidPositiveSign(0)), parse(b"+1"));
+    assert_eq!(
+        Err(Error::InvalidPositiveSign(0)),
+        u32::from_lexical_with_options::<STANDARD>(b"+1", &OPTIONS)
+    );
+}
+
+#[test]
+fn required_sign_test() {
+    const OPTIONS: Options = Options::builder().required_sign(true).build_strict();
+    let parse = |bytes: &[u8]| i32::from_lexical_with_options::<STANDARD>(bytes, &OPTIONS);
+    assert_eq!(Ok(1), parse(b"+1"));
+    assert_eq!(Ok(-1), parse(b"-1"));
+    assert_eq!(Err(Error::MissingSign(0)), parse(b"1"));
+    assert_eq!(Err(Error::MissingSign(0)), parse(b""));
+    assert_eq!(Ok((1, 2)), i32::from_lexical_partial_with_options::<STANDARD>(b"+1", &OPTIONS));
+    assert_eq!(
+        Err(Error::MissingSign(0)),
+        i32::from_lexical_partial_with_options::<STANDARD>(b"1", &OPTIONS)
+    );
+}
+
+#[test]
+fn allow_trailing_sign_test() {
+    const OPTIONS: Options = Options::builder().allow_trailing_sign(true).build_strict();
+    let parse = |bytes: &[u8]| i32::from_lexical_with_options::<STANDARD>(bytes, &OPTIONS);
+    assert_eq!(Ok(-5), parse(b"5-"));
+    assert_eq!(Ok(5), parse(b"5+"));
+    assert_eq!(Ok(-123), parse(b"123-"));
+    assert_eq!(Ok(-5), parse(b"-5"));
+    assert_eq!(Ok(5), parse(b"5"));
+    assert_eq!(Ok(i8::MIN), i8::from_lexical_with_options::<STANDARD>(b"128-", &OPTIONS));
+    assert_eq!(
+        Err(Error::Underflow(2)),
+        i8::from_lexical_with_options::<STANDARD>(b"129-", &OPTIONS)
+    );
+    assert_eq!(Err(Error::InvalidDigit(2)), parse(b"-5-"));
+    assert_eq!(Err(Error::InvalidDigit(2)), parse(b"+5+"));
+    assert_eq!(Err(Error::InvalidDigit(1)), parse(b"5-1"));
+    assert_eq!(Err(Error::InvalidDigit(1)), parse(b"5--"));
+    assert_eq!(Err(Error::EmptyMantissa(1)), parse(b"-"));
+
+    // Trailing signs are only valid for signed integers.
+    assert_eq!(
+        Err(Error::InvalidDigit(1)),
+        u32::from_lexical_with_options::<STANDARD>(b"5-", &OPTIONS)
+    );
+
+    // Disabled by default.
+    assert_eq!(Err(Error::InvalidDigit(1)), i32::from_lexical(b"5-"));
+}
+
+#[test]
+fn allow_trailing_sign_partial_test() {
+    const OPTIONS: Options = Options::builder().allow_trailing_sign(true).build_strict();
+    let parse = |bytes: &[u8]| i32::from_lexical_partial_with_options::<STANDARD>(bytes, &OPTIONS);
+    assert_eq!(Ok((-5, 2)), parse(b"5-"));
+    assert_eq!(Ok((-5, 2)), parse(b"5- 6"));
+    assert_eq!(Ok((5, 2)), parse(b"5+,"));
+    assert_eq!(Ok((5, 1)), parse(b"5 -"));
+    assert_eq!(Ok((5, 1)), parse(b"5"));
+    assert_eq!(Ok((-5, 2)), parse(b"-5"));
+    assert_eq!(Ok((-5, 2)), parse(b"-5 -"));
+    assert_eq!(Err(Error::InvalidDigit(2)), parse(b"-5-"));
+    assert_eq!(Err(Error::InvalidDigit(2)), parse(b"+5-"));
+}
+
+#[test]
+fn sign_options_combined_test() {
+    const OPTIONS: Options =
+        Options::builder().required_sign(true).allow_trailing_sign(true).build_strict();
+    let parse = |bytes: &[u8]| i32::from_lexical_with_options::<STANDARD>(bytes, &OPTIONS);
+    assert_eq!(Ok(-5), parse(b"5-"));
+    assert_eq!(Ok(5), parse(b"+5"));
+    assert_eq!(Err(Error::MissingSign(0)), parse(b"5"));
+
+    const NO_POSITIVE: Options =
+        Options::builder().no_positive_sign(true).allow_trailing_sign(true).build_strict();
+    let parse = |bytes: &[u8]| i32::from_lexical_with_options::<STANDARD>(bytes, &NO_POSITIVE);
+    assert_eq!(Ok(-5), parse(b"5-"));
+    assert_eq!(Err(Error::InvalidPositiveSign(1)), parse(b"5+"));
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn radix_indicator_test() {
+    const OPTIONS: Options = Options::builder().radix_indicator(Some(b'#')).build_strict();
+    let parse = |bytes: &[u8]| i64::from_lexical_with_options::<STANDARD>(bytes, &OPTIONS);
+    assert_eq!(Ok(255), parse(b"16#FF"));
+    assert_eq!(Ok(255), parse(b"16#ff"));
+    assert_eq!(Ok(10), parse(b"2#1010"));
+    assert_eq!(Ok(10), parse(b"02#1010"));
+    assert_eq!(Ok(35), parse(b"36#z"));
+    assert_eq!(Ok(1234), parse(b"10#1234"));
+    assert_eq!(Ok(-255), parse(b"-16#FF"));
+    assert_eq!(Ok(255), parse(b"+16#FF"));
+    assert_eq!(Err(Error::InvalidRadix), parse(b"37#1"));
+    assert_eq!(Err(Error::InvalidRadix), parse(b"1#1"));
+    assert_eq!(Err(Error::InvalidRadix), parse(b"0#1"));
+    assert_eq!(Err(Error::InvalidRadix), parse(b"99999999999#1"));
+    assert_eq!(Err(Error::InvalidDigit(2)), parse(b"16FF"));
+    assert_eq!(Err(Error::Empty(2)), parse(b"16"));
+    assert_eq!(Err(Error::EmptyInteger(0)), parse(b"#FF"));
+    assert_eq!(Err(Error::Empty(3)), parse(b"16#"));
+    assert_eq!(Err(Error::InvalidDigit(3)), parse(b"16#G"));
+    assert_eq!(Err(Error::InvalidDigit(5)), parse(b"2#1012"));
+    assert_eq!(Err(Error::InvalidDigit(4)), parse(b"16#F#F"));
+    assert_eq!(Err(Error::Empty(0)), parse(b""));
+    let parse = |bytes: &[u8]| u8::from_lexical_with_options::<STANDARD>(bytes, &OPTIONS);
+    assert_eq!(Ok(255), parse(b"16#FF"));
+    assert_eq!(Err(Error::Overflow(5)), parse(b"16#100"));
+    assert_eq!(Err(Error::InvalidDigit(0)), parse(b"-2#1"));
+
+    let parse = |bytes: &[u8]| i64::from_lexical_partial_with_options::<STANDARD>(bytes, &OPTIONS);
+    assert_eq!(Ok((255, 5)), parse(b"16#FF"));
+    assert_eq!(Ok((255, 5)), parse(b"16#FF, 2#1"));
+    assert_eq!(Ok((2, 4)), parse(b"2#102"));
+    assert_eq!(Err(Error::InvalidRadix), parse(b"37#1"));
+    assert_eq!(Err(Error::InvalidDigit(2)), parse(b"16FF"));
+
+    // A radix without any digits is not zero.
+    assert_eq!(Err(Error::Empty(3)), parse(b"16#"));
+    assert_eq!(Err(Error::InvalidDigit(3)), parse(b"16#xyz"));
+    assert_eq!(Err(Error::InvalidDigit(4)), parse(b"-16#,"));
+
+    // Disabled by default.
+    assert_eq!(Err(Error::InvalidDigit(2)), i64::from_lexical(b"16#FF"));
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn radix_indicator_options_test() {
+    let build = |indicator: u8| Options::builder().radix_indicator(Some(indicator)).build();
+    assert!(build(b'#').is_ok());
+    assert!(build(b'_').is_ok());
+    assert_eq!(Err(Error::InvalidPunctuation), build(b'1'));
+    assert_eq!(Err(Error::InvalidPunctuation), build(b'x'));
+    assert_eq!(Err(Error::InvalidPunctuation), build(b'-'));
+    assert_eq!(Err(Error::InvalidPunctuation), build(b' '));
+
+    // Options the radix indicator does not use are rejected.
+    let builder = Options::builder().radix_indicator(Some(b'#'));
+    let error = Err(Error::InvalidRadixIndicator);
+    assert_eq!(error, builder.clone().no_positive_sign(true).build());
+    assert_eq!(error, builder.clone().required_sign(true).build());
+    assert_eq!(error, builder.clone().allow_trailing_sign(true).build());
+    assert_eq!(error, builder.clone().allow_integer_exponent(true).build());
+    #[cfg(feature = "format")]
+    assert_eq!(error, builder.clone().digit_separators(b"_").build());
+
+    // The number format is ignored.
+    #[cfg(feature = "format")]
+    {
+        const OPTIONS: Options = Options::builder().radix_indicator(Some(b'#')).build_strict();
+        const FORMAT: u128 = NumberFormatBuilder::new()
+            .digit_separator(std::num::NonZeroU8::new(b'_'))
+            .integer_internal_digit_separator(true)
+            .required_mantissa_sign(true)
+            .build_strict();
+        let parse = |bytes: &[u8]| i64::from_lexical_with_options::<FORMAT>(bytes, &OPTIONS);
+        assert_eq!(Ok(255), parse(b"16#FF"));
+        assert_eq!(Err(Error::InvalidDigit(4)), parse(b"16#F_F"));
+    }
+}
+
+#[test]
+fn skip_bom_test() {
+    const OPTIONS: Options = Options::builder().skip_bom(true).build_strict();
+    let parse = |bytes: &[u8]| i32::from_lexical_with_options::<STANDARD>(bytes, &OPTIONS);
+    assert_eq!(Ok(42), parse("\u{FEFF}42".as_bytes()));
+    assert_eq!(Ok(-42), parse("\u{FEFF}-42".as_bytes()));
+    assert_eq!(Ok(42), parse(b"42"));
+    assert_eq!(Err(Error::Empty(3)), parse("\u{FEFF}".as_bytes()));
+    assert_eq!(Err(Error::InvalidDigit(4)), parse("\u{FEFF}4x".as_bytes()));
+    assert_eq!(Err(Error::InvalidDigit(3)), parse("\u{FEFF}\u{FEFF}42".as_bytes()));
+    assert_eq!(Err(Error::InvalidDigit(0)), parse(b"\xEF\xBB42"));
+    assert_eq!(Err(Error::Overflow(12)), parse("\u{FEFF}9999999999".as_bytes()));
+
+    let parse = |bytes: &[u8]| i32::from_lexical_partial_with_options::<STANDARD>(bytes, &OPTIONS);
+    assert_eq!(Ok((42, 5)), parse("\u{FEFF}42".as_bytes()));
+    assert_eq!(Ok((42, 5)), parse("\u{FEFF}42,1".as_bytes()));
+    assert_eq!(Ok((42, 2)), parse(b"42,1"));
+
+    // Nothing is consumed if no number follows the byte order mark.
+    assert_eq!(Ok((0, 0)), parse("\u{FEFF}x".as_bytes()));
+
+    // Disabled by default.
+    assert_eq!(Err(Error::InvalidDigit(0)), i32::from_lexical("\u{FEFF}42".as_bytes()));
+}
+
+#[test]
+#[cfg(feature = "unicode")]
+fn skip_unicode_whitespace_test() {
+    const OPTIONS: Options =
+        Options::builder().skip_bom(true).skip_unicode_whitespace(true).build_strict();
+    let parse = |bytes: &[u8]| i32::from_lexical_partial_with_options::<STANDARD>(bytes, &OPTIONS);
+    assert_eq!(Ok((42, 2)), parse(b"42"));
+    assert_eq!(Ok((42, 6)), parse(b" \t\r\n42"));
+    assert_eq!(Ok((42, 4)), parse("\u{A0}42".as_bytes()));
+    assert_eq!(Ok((-42, 9)), parse("\u{2003}\u{3000}-42".as_bytes()));
+    assert_eq!(Ok((42, 9)), parse("\u{FEFF}\u{202F} 42".as_bytes()));
+    assert_eq!(Ok((0, 0)), parse("\u{200B}42".as_bytes()));
+    assert_eq!(Err(Error::Empty(3)), parse("\u{3000}".as_bytes()));
+
+    // Whitespace is not skipped before the byte order mark.
+    assert_eq!(Ok((0, 0)), parse(" \u{FEFF}42".as_bytes()));
+}
+
+#[test]
+fn trim_whitespace_test() {
+    const OPTIONS: Options = Options::builder().trim_whitespace(true).build_strict();
+    let complete = |bytes: &[u8]| i32::from_lexical_with_options::<STANDARD>(bytes, &OPTIONS);
+    let parse = |bytes: &[u8]| i32::from_lexical_partial_with_options::<STANDARD>(bytes, &OPTIONS);
+
+    // Leading, trailing, and both.
+    assert_eq!(Ok(42), complete(b"  42"));
+    assert_eq!(Ok(42), complete(b"42 \t\r\n"));
+    assert_eq!(Ok(-42), complete(b"  -42  "));
+    assert_eq!(Ok(42), complete(b"42"));
+    assert_eq!(Ok((42, 4)), parse(b"\t 42"));
+    assert_eq!(Ok((42, 4)), parse(b"42  "));
+    assert_eq!(Ok((-42, 7)), parse(b"  -42  "));
+    assert_eq!(Ok((42, 4)), parse(b" 42 ,1"));
+
+    // Nothing is consumed if no number follows the whitespace.
+    assert_eq!(Ok((0, 0)), parse(b"  x"));
+
+    // Whitespace between the sign and digits, or between digits, is invalid.
+    assert_eq!(Err(Error::InvalidDigit(1)), complete(b"- 42"));
+    assert_eq!(Err(Error::InvalidDigit(3)), complete(b"  - 42"));
+    assert_eq!(Err(Error::InvalidDigit(1)), complete(b"4 2"));
+    assert_eq!(Ok((4, 2)), parse(b"4 2"));
+    assert_eq!(Err(Error::Empty(3)), complete(b"   "));
+
+    // Without the option, whitespace is an invalid digit.
+    assert_eq!(Err(Error::InvalidDigit(0)), i32::from_lexical(b" 42"));
+    assert_eq!(Err(Error::InvalidDigit(2)), i32::from_lexical(b"42 "));
+
+    // Trailing whitespace is skipped after any suffix.
+    const SUFFIX: Options =
+        Options::builder().trim_whitespace(true).ignore_suffixes(&[b"ms"]).build_strict();
+    let result = i32::from_lexical_with_options::<STANDARD>(b" 250ms ", &SUFFIX);
+    assert_eq!(Ok(250), result);
+    let result = i32::from_lexical_partial_with_options::<STANDARD>(b" 250ms  x", &SUFFIX);
+    assert_eq!(Ok((250, 8)), result);
+}
+
+#[test]
+fn ignore_prefix_test() {
+    const OPTIONS: Options = Options::builder().ignore_prefix(b"$").build_strict();
+    let parse = |bytes: &[u8]| i32::from_lexical_partial_with_options::<STANDARD>(bytes, &OPTIONS);
+    assert_eq!(Ok((1234, 5)), parse(b"$1234"));
+    assert_eq!(Ok((-5, 3)), parse(b"$-5"));
+    assert_eq!(Ok((5, 1)), parse(b"5"));
+    assert_eq!(Ok((-5, 2)), parse(b"-5"));
+
+    // The prefix is skipped once, and before the sign.
+    let parse = |bytes: &[u8]| i32::from_lexical_with_options::<STANDARD>(bytes, &OPTIONS);
+    assert_eq!(Err(Error::Empty(1)), parse(b"$"));
+    assert_eq!(Err(Error::InvalidDigit(1)), parse(b"$$5"));
+    assert_eq!(Err(Error::InvalidDigit(1)), parse(b"-$5"));
+    assert_eq!(Err(Error::InvalidDigit(3)), parse(b"$12$"));
+
+    // The prefix cannot contain digits or signs, which are ambiguous.
+    let error = Err(Error::InvalidIgnorePrefix);
+    assert_eq!(error, Options::builder().ignore_prefix(b"00").build());
+    assert_eq!(error, Options::builder().ignore_prefix(b"-").build());
+    assert!(!Options::builder().ignore_prefix(b"$+").is_valid());
+
+    // Disabled by default.
+    assert_eq!(Err(Error::InvalidDigit(0)), i32::from_lexical(b"$5"));
+}
+
+#[test]
+fn ignore_suffixes_test() {
+    const OPTIONS: Options = Options::builder().ignore_suffixes(&[b"B", b"KB"]).build_strict();
+    let parse = |bytes: &[u8]| u32::from_lexical_with_options::<STANDARD>(bytes, &OPTIONS);
+    assert_eq!(Ok(5), parse(b"5KB"));
+    assert_eq!(Ok(5), parse(b"5B"));
+    assert_eq!(Ok(5), parse(b"5"));
+    assert_eq!(Err(Error::InvalidDigit(1)), parse(b"5XB"));
+    assert_eq!(Err(Error::InvalidDigit(1)), parse(b"5BB"));
+    assert_eq!(Err(Error::InvalidDigit(1)), parse(b"5KBx"));
+    assert_eq!(Err(Error::Empty(0)), parse(b"KB"));
+
+    // The longest matching suffix is skipped when parsing a partial number.
+    let parse = |bytes: &[u8]| u32::from_lexical_partial_with_options::<STANDARD>(bytes, &OPTIONS);
+    assert_eq!(Ok((5, 3)), parse(b"5KB"));
+    assert_eq!(Ok((5, 2)), parse(b"5B"));
+    assert_eq!(Ok((5, 3)), parse(b"5KB,6"));
+    assert_eq!(Ok((5, 1)), parse(b"5XB"));
+    assert_eq!(Ok((0, 0)), parse(b"KB"));
+
+    // Suffixes compose with the prefix.
+    const BOTH: Options =
+        Options::builder().ignore_prefix(b"$").ignore_suffixes(&[b"ms"]).build_strict();
+    assert_eq!(Ok(100), i32::from_lexical_with_options::<STANDARD>(b"$100ms", &BOTH));
+    assert_eq!(Ok((100, 6)), i32::from_lexical_partial_with_options::<STANDARD>(b"$100ms", &BOTH));
+
+    // Suffixes cannot be empty or start with a digit, which are ambiguous.
+    let error = Err(Error::InvalidIgnoreSuffix);
+    assert_eq!(error, Options::builder().ignore_suffixes(&[b"ms", b""]).build());
+    assert_eq!(error, Options::builder().ignore_suffixes(&[b"0x"]).build());
+    assert!(!Options::builder().ignore_suffixes(&[b"5B"]).is_valid());
+
+    // Suffixes must not start with a digit in the radix.
+    #[cfg(feature = "power-of-two")]
+    {
+        const HEX: u128 = from_radix(16);
+        let result = u32::from_lexical_with_options::<HEX>(b"5KB", &OPTIONS);
+        assert_eq!(Err(Error::InvalidIgnoreSuffix), result);
+        let result = u32::from_lexical_partial_with_options::<HEX>(b"5KB", &OPTIONS);
+        assert_eq!(Err(Error::InvalidIgnoreSuffix), result);
+        assert_eq!(Ok(5), u32::from_lexical_with_options::<HEX>(b"5ms", &BOTH));
+    }
+
+    // Disabled by default.
+    assert_eq!(Err(Error::InvalidDigit(1)), u32::from_lexical(b"5B"));
+}
+
+#[test]
+#[cfg(all(feature = "unicode", feature = "alloc"))]
+fn eastern_arabic_digits_test() {
+    const OPTIONS: Options = Options::builder().eastern_arabic_digits(true).build_strict();
+    let parse = |s: &str| i32::from_lexical_with_options::<STANDARD>(s.as_bytes(), &OPTIONS);
+    assert_eq!(Ok(123), parse("\u{661}\u{662}\u{663}"));
+    assert_eq!(Ok(-9870), parse("-\u{6F9}\u{6F8}\u{6F7}\u{6F0}"));
+    assert_eq!(Ok(5), parse("5"));
+
+    // ASCII and Eastern Arabic digits may be mixed in a single number.
+    assert_eq!(Ok(123), parse("1\u{662}3"));
+    assert_eq!(Ok(1234), parse("\u{661}2\u{6F3}4"));
+
+    // Indexes refer to the original UTF-8 bytes.
+    assert_eq!(Err(Error::InvalidDigit(4)), parse("\u{661}\u{662}x"));
+    let truncated = &"1\u{662}\u{660}".as_bytes()[..4];
+    assert_eq!(
+        Err(Error::InvalidDigit(3)),
+        i32::from_lexical_with_options::<STANDARD>(truncated, &OPTIONS)
+    );
+    assert_eq!(Err(Error::Overflow(18)), parse("\u{669}".repeat(11).as_str()));
+    let parse =
+        |s: &str| i32::from_lexical_partial_with_options::<STANDARD>(s.as_bytes(), &OPTIONS);
+    assert_eq!(Ok((12, 4)), parse("\u{661}\u{662}."));
+    assert_eq!(Ok((-1, 3)), parse("-\u{661}x"));
+
+    // Disabled by default.
+    assert_eq!(Err(Error::InvalidDigit(0)), i32::from_lexical("\u{661}".as_bytes()));
+}
+
+#[test]
+fn allow_integer_exponent_test() {
+    const OPTIONS: Options = Options::builder().allow_integer_exponent(true).build_strict();
+    let parse = |bytes: &[u8]| u64::from_lexical_with_options::<STANDARD>(bytes, &OPTIONS);
+    assert_eq!(Ok(1000), parse(b"1e3"));
+    assert_eq!(Ok(150), parse(b"15e1"));
+    assert_eq!(Ok(150), parse(b"15E+1"));
+    assert_eq!(Ok(12), parse(b"1200e-2"));
+    assert_eq!(Ok(7), parse(b"7e0"));
+    assert_eq!(Ok(0), parse(b"0e100"));
+    assert_eq!(Ok(123), parse(b"123"));
+
+    // The scaled value must be an integer and fit the type.
+    assert_eq!(Err(Error::InvalidExponent(1)), parse(b"1e-1"));
+    assert_eq!(Err(Error::InvalidExponent(3)), parse(b"150e-2"));
+    assert_eq!(Err(Error::Overflow(4)), parse(b"1e100"));
+    assert_eq!(Err(Error::Overflow(3)), parse(b"2e19"));
+    assert_eq!(Ok(10000000000000000000), parse(b"1e19"));
+    assert_eq!(
+        Err(Error::Underflow(3)),
+        i8::from_lexical_with_options::<STANDARD>(b"-2e2", &OPTIONS)
+    );
+    assert_eq!(Ok(-100), i8::from_lexical_with_options::<STANDARD>(b"-1e2", &OPTIONS));
+
+    // The exponent needs digits, and nothing may follow it.
+    assert_eq!(Err(Error::EmptyExponent(2)), parse(b"1e"));
+    assert_eq!(Err(Error::EmptyExponent(3)), parse(b"1e-"));
+    assert_eq!(Err(Error::InvalidDigit(3)), parse(b"1e3x"));
+    assert_eq!(Err(Error::Empty(0)), parse(b"e3"));
+
+    let parse = |bytes: &[u8]| u64::from_lexical_partial_with_options::<STANDARD>(bytes, &OPTIONS);
+    assert_eq!(Ok((1000, 3)), parse(b"1e3,"));
+    assert_eq!(Ok((12, 2)), parse(b"12,e3"));
+    assert_eq!(Err(Error::EmptyExponent(2)), parse(b"1e,"));
+
+    // The exponent character must not be a digit in the radix.
+    #[cfg(feature = "power-of-two")]
+    {
+        const HEX: u128 = from_radix(16);
+        let result = u64::from_lexical_with_options::<HEX>(b"1e3", &OPTIONS);
+        assert_eq!(Err(Error::InvalidExponentSymbol), result);
+        const POWER: Options =
+            Options::builder().exponent(b'p').allow_integer_exponent(true).build_strict();
+        assert_eq!(Ok(0x1F0), u64::from_lexical_with_options::<HEX>(b"1Fp1", &POWER));
+    }
+
+    // Disabled by default.
+    assert_eq!(Err(Error::InvalidDigit(1)), u64::from_lexical(b"1e3"));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn integer_exponent_sign_test() {
+    const OPTIONS: Options = Options::builder().allow_integer_exponent(true).build_strict();
+    const F1: u128 = NumberFormatBuilder::new()
+        .required_exponent_sign(true)
+        .no_positive_mantissa_sign(true)
+        .build_strict();
+    let parse = |bytes: &[u8]| i64::from_lexical_with_options::<F1>(bytes, &OPTIONS);
+    assert_eq!(Ok(1000), parse(b"1e+3"));
+    assert_eq!(Ok(-12), parse(b"-1200e-2"));
+    assert_eq!(Err(Error::MissingExponentSign(2)), parse(b"1e3"));
+    assert_eq!(Err(Error::InvalidPositiveSign(0)), parse(b"+1e+3"));
+
+    const F2: u128 = NumberFormatBuilder::new()
+        .required_mantissa_sign(true)
+        .no_positive_exponent_sign(true)
+        .build_strict();
+    let parse = |bytes: &[u8]| i64::from_lexical_with_options::<F2>(bytes, &OPTIONS);
+    assert_eq!(Ok(1000), parse(b"+1e3"));
+    assert_eq!(Ok(-12), parse(b"-1200e-2"));
+    assert_eq!(Err(Error::InvalidPositiveExponentSign(3)), parse(b"+1e+3"));
+    assert_eq!(Err(Error::MissingSign(0)), parse(b"1e3"));
+
+    let parse = |bytes: &[u8]| i64::from_lexical_partial_with_options::<F2>(bytes, &OPTIONS);
+    assert_eq!(Ok((-1000, 4)), parse(b"-1e3,"));
+    assert_eq!(Err(Error::InvalidPositiveExponentSign(3)), parse(b"+1e+3,"));
+}
+
+#[test]
+fn parse_integers_test() {
+    let parse = |bytes| ParseIntegers::<i32, STANDARD>::new(bytes, b',').collect::<Vec<_>>();
+    assert_eq!(parse(b"1,2,3,4"), [Ok(1), Ok(2), Ok(3), Ok(4)]);
+    assert_eq!(parse(b"-1,+2,345"), [Ok(-1), Ok(2), Ok(345)]);
+    assert_eq!(parse(b"1234"), [Ok(1234)]);
+    assert_eq!(parse(b""), []);
+
+    // Empty fields.
+    assert_eq!(parse(b","), [Err(Error::Empty(0)), Err(Error::Empty(1))]);
+    assert_eq!(parse(b"1,,3"), [Ok(1), Err(Error::Empty(2)), Ok(3)]);
+    assert_eq!(parse(b",2"), [Err(Error::Empty(0)), Ok(2)]);
+
+    // Invalid fields continue at the next field.
+    assert_eq!(parse(b"1,2a,3"), [Ok(1), Err(Error::InvalidDigit(3)), Ok(3)]);
+    assert_eq!(parse(b"1, 2"), [Ok(1), Err(Error::InvalidDigit(2))]);
+    assert_eq!(parse(b"1,2147483648"), [Ok(1), Err(Error::Overflow(11))]);
+
+    // Delimiters that are valid digits are rejected.
+    let values: Vec<_> = ParseIntegers::<i32, STANDARD>::new(b"102", b'0').collect();
+    assert_eq!(values, [Err(Error::InvalidPunctuation)]);
+
+    let mut iter = ParseIntegers::<i32, STANDARD>::new(b"1,2", b',');
+    assert_eq!(iter.remaining(), b"1,2");
+    assert_eq!(iter.next(), Some(Ok(1)));
+    assert_eq!(iter.remaining(), b"2");
+    assert_eq!(iter.next(), Some(Ok(2)));
+    assert_eq!(iter.remaining(), b"");
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn split_sign_test() {
+    let options = Options::new();
+    let split = |bytes| {
+        split_sign::<STANDARD>(bytes, &options)
+            .map(|(sign, byte)| (sign, byte.cursor(), byte.as_slice()))
+    };
+    assert_eq!(split(b"-42"), Ok((Sign::Negative, 1, &b"42"[..])));
+    assert_eq!(split(b"+42"), Ok((Sign::Positive, 1, &b"42"[..])));
+    assert_eq!(split(b"42"), Ok((Sign::Positive, 0, &b"42"[..])));
+    assert_eq!(split(b""), Ok((Sign::Positive, 0, &b""[..])));
+    assert_eq!(split(b"--4"), Ok((Sign::Negative, 1, &b"-4"[..])));
+
+    // The remaining digits can be parsed with the iterators.
+    let (_, mut byte) = split_sign::<STANDARD>(b"-42", &options).unwrap();
+    assert_eq!(byte.integer_iter().copied().collect::<Vec<_>>(), b"42");
+
+    // The sign is validated by the options.
+    let options = Options::builder().no_positive_sign(true).build_strict();
+    let result = split_sign::<STANDARD>(b"+42", &options).map(|(sign, _)| sign);
+    assert_eq!(result, Err(Error::InvalidPositiveSign(0)));
+    let options = Options::builder().required_sign(true).build_strict();
+    let result = split_sign::<STANDARD>(b"42", &options).map(|(sign, _)| sign);
+    assert_eq!(result, Err(Error::MissingSign(0)));
+    let result = split_sign::<STANDARD>(b"-42", &options).map(|(sign, _)| sign);
+    assert_eq!(result, Ok(Sign::Negative));
+}
+
+#[test]
+fn parse_array_test() {
+    assert_eq!(parse_array::<u8, 3>(b"255,128,0", b','), Ok([255, 128, 0]));
+    assert_eq!(parse_array::<i32, 2>(b"-12 34", b' '), Ok([-12, 34]));
+    assert_eq!(parse_array::<u32, 1>(b"7", b','), Ok([7]));
+    assert_eq!(parse_array::<u32, 0>(b"", b','), Ok([]));
+
+    // Too few fields.
+    assert_eq!(parse_array::<u8, 3>(b"255,128", b','), Err(Error::Empty(7)));
+    assert_eq!(parse_array::<u8, 1>(b"", b','), Err(Error::Empty(0)));
+
+    // Too many fields, reporting the bytes used by the first `N`.
+    assert_eq!(parse_array::<u8, 2>(b"255,128,0", b','), Err(Error::InvalidDigit(7)));
+    assert_eq!(parse_array::<u8, 2>(b"1,2,", b','), Err(Error::InvalidDigit(3)));
+    assert_eq!(parse_array::<u8, 0>(b"1", b','), Err(Error::InvalidDigit(0)));
+
+    // Empty and invalid fields.
+    assert_eq!(parse_array::<u8, 3>(b"1,,3", b','), Err(Error::Empty(2)));
+    assert_eq!(parse_array::<u8, 3>(b"1,2,", b','), Err(Error::Empty(4)));
+    assert_eq!(parse_array::<u8, 3>(b",2,3", b','), Err(Error::Empty(0)));
+    assert_eq!(parse_array::<u8, 3>(b"1,256,3", b','), Err(Error::Overflow(4)));
+    assert_eq!(parse_array::<u8, 3>(b"1,2x,3", b','), Err(Error::InvalidDigit(3)));
+}
+
+#[test]
+fn parse_integers_trailing_delimiter_test() {
+    let values: Vec<_> = ParseIntegers::<u8, STANDARD>::new(b"1,2,", b',').collect();
+    assert_eq!(values, [Ok(1), Ok(2), Err(Error::Empty(4))]);
+
+    let parse = |bytes| {
+        ParseIntegers::<u8, STANDARD>::new(bytes, b',')
+            .skip_trailing_delimiter(true)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(parse(b"1,2,"), [Ok(1), Ok(2)]);
+    assert_eq!(parse(b"1,2"), [Ok(1), Ok(2)]);
+    assert_eq!(parse(b"1,,"), [Ok(1), Err(Error::Empty(2))]);
+    assert_eq!(parse(b","), [Err(Error::Empty(0))]);
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn parse_integers_separator_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(core::num::NonZeroU8::new(b'_'))
+        .integer_internal_digit_separator(true)
+        .build_strict();
+    const OPTIONS: Options = Options::new();
+
+    let parse = |bytes| {
+        ParseIntegers::<u64, FORMAT>::with_options(bytes, b';', &OPTIONS).collect::<Vec<_>>()
+    };
+    assert_eq!(parse(b"1_000;2_000_000;3"), [Ok(1000), Ok(2000000), Ok(3)]);
+    assert_eq!(parse(b"1_000;_2;3_"), [
+        Ok(1000),
+        Err(Error::InvalidDigit(6)),
+        Err(Error::InvalidDigit(10))
+    ]);
+    assert_eq!(parse(b"1__0;1"), [Err(Error::InvalidDigit(1)), Ok(1)]);
+
+    let values: Vec<_> = ParseIntegers::<u64, FORMAT>::new(b"1_000_2", b'_').collect();
+    assert_eq!(values, [Err(Error::InvalidPunctuation)]);
+    const SPACES: Options = Options::builder().digit_separators(b" ").build_strict();
+    let values: Vec<_> =
+        ParseIntegers::<u64, FORMAT>::with_options(b"1 000 2", b' ', &SPACES).collect();
+    assert_eq!(values, [Err(Error::InvalidPunctuation)]);
+}
+
+#[test]
+fn from_lexical_checked_mod10_test() {
+    assert_eq!(Ok(7992739871), from_lexical_checked_mod10::<u64>(b"79927398713"));
+    assert_eq!(Ok(453201511283036), from_lexical_checked_mod10::<u64>(b"4532015112830366"));
+    assert_eq!(Ok(0), from_lexical_checked_mod10::<u8>(b"00"));
+    assert_eq!(Ok(1), from_lexical_checked_mod10::<u8>(b"18"));
+    assert_eq!(
+        Err(Error::ChecksumMismatch(10)),
+        from_lexical_checked_mod10::<u64>(b"79927398710")
+    );
+    assert_eq!(
+        Err(Error::ChecksumMismatch(10)),
+        from_lexical_checked_mod10::<u64>(b"79927398731")
+    );
+    assert_eq!(Err(Error::Empty(0)), from_lexical_checked_mod10::<u64>(b""));
+    assert_eq!(Err(Error::Empty(1)), from_lexical_checked_mod10::<u64>(b"0"));
+    assert_eq!(Err(Error::InvalidDigit(0)), from_lexical_checked_mod10::<u64>(b"+18"));
+    assert_eq!(Err(Error::InvalidDigit(4)), from_lexical_checked_mod10::<u64>(b"7992-7398713"));
+    assert_eq!(Err(Error::Overflow(2)), from_lexical_checked_mod10::<u8>(b"2568"));
+}
+
+#[test]
+fn from_lexical_checked_mod97_test() {
+    assert_eq!(Ok(1234567890123456), from_lexical_checked_mod97::<u64>(b"123456789012345611"));
+    assert_eq!(Ok(1), from_lexical_checked_mod97::<u8>(b"195"));
+    assert_eq!(
+        Err(Error::ChecksumMismatch(16)),
+        from_lexical_checked_mod97::<u64>(b"123456789012345612")
+    );
+    assert_eq!(Err(Error::Empty(0)), from_lexical_checked_mod97::<u64>(b""));
+    assert_eq!(Err(Error::Empty(2)), from_lexical_checked_mod97::<u64>(b"01"));
+    assert_eq!(Err(Error::InvalidDigit(1)), from_lexical_checked_mod97::<u64>(b"1a97"));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn validate_lexical_test() {
+    use lexical_parse_integer::{validate_lexical, ValidationIssue};
+
+    assert_eq!(
+        validate_lexical::<STANDARD>(b"1a2b"),
+        [(1, ValidationIssue::InvalidDigit), (3, ValidationIssue::InvalidDigit)]
+    );
+    assert_eq!(validate_lexical::<STANDARD>(b"+-1-"), [
+        (1, ValidationIssue::MultipleSigns),
+        (3, ValidationIssue::InvalidDigit)
+    ]);
+    assert_eq!(validate_lexical::<STANDARD>(b""), [(0, ValidationIssue::Empty)]);
+    assert_eq!(validate_lexical::<STANDARD>(b"--"), [
+        (1, ValidationIssue::MultipleSigns),
+        (2, ValidationIssue::Empty)
+    ]);
+    assert_eq!(validate_lexical::<STANDARD>(b"1_0"), [(1, ValidationIssue::InvalidDigit)]);
+    assert!(validate_lexical::<STANDARD>(b"-1234").is_empty());
+    assert!(validate_lexical::<STANDARD>(b"+0001234").is_empty());
+}
+
+#[test]
+#[cfg(all(feature = "alloc", feature = "format", feature = "power-of-two"))]
+fn validate_lexical_format_test() {
+    use lexical_parse_integer::{validate_lexical, ValidationIssue};
+
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(std::num::NonZeroU8::new(b'_'))
+        .integer_internal_digit_separator(true)
+        .base_prefix(std::num::NonZeroU8::new(b'x'))
+        .no_positive_mantissa_sign(true)
+        .build_strict();
+
+    assert!(validate_lexical::<FORMAT>(b"1_234").is_empty());
+    assert!(validate_lexical::<FORMAT>(b"-0x1_234").is_empty());
+    assert_eq!(validate_lexical::<FORMAT>(b"_1__2_"), [
+        (0, ValidationIssue::MisplacedSeparator),
+        (3, ValidationIssue::MisplacedSeparator),
+        (5, ValidationIssue::MisplacedSeparator)
+    ]);
+    assert_eq!(validate_lexical::<FORMAT>(b"+1_x"), [
+        (0, ValidationIssue::InvalidPositiveSign),
+        (2, ValidationIssue::MisplacedSeparator),
+        (3, ValidationIssue::InvalidDigit)
+    ]);
+    assert_eq!(validate_lexical::<FORMAT>(b"0x"), [(2, ValidationIssue::Empty)]);
+
+    const SIGNED: u128 = NumberFormatBuilder::new().required_mantissa_sign(true).build_strict();
+    assert_eq!(validate_lexical::<SIGNED>(b"12"), [(0, ValidationIssue::MissingSign)]);
+    assert!(validate_lexical::<SIGNED>(b"+12").is_empty());
+}
+
+#[test]
+#[cfg(all(feature = "format", feature = "power-of-two"))]
+fn from_rust_literal_partial_test() {
+    use lexical_parse_integer::from_rust_literal_partial;
+
+    // Parsing stops at the type suffix.
+    assert_eq!(from_rust_literal_partial::<u32>(b"1_000u32"), Ok((1000, 5)));
+    assert_eq!(from_rust_literal_partial::<u16>(b"0xFF_FFu16"), Ok((0xFFFF, 7)));
+    assert_eq!(from_rust_literal_partial::<u8>(b"0o1_7u8"), Ok((0o17, 5)));
+    assert_eq!(from_rust_literal_partial::<i8>(b"-0x80i8"), Ok((i8::MIN, 5)));
+    assert_eq!(from_rust_literal_partial::<i32>(b"-0b1010"), Ok((-10, 7)));
+    assert_eq!(from_rust_literal_partial::<u64>(b"123"), Ok((123, 3)));
+
+    // Consecutive and trailing underscores are rejected.
+    assert_eq!(from_rust_literal_partial::<u32>(b"1__0"), Err(Error::InvalidDigit(1)));
+    assert_eq!(from_rust_literal_partial::<u32>(b"10_"), Err(Error::InvalidDigit(2)));
+    assert_eq!(from_rust_literal_partial::<u8>(b"0o17_u8"), Err(Error::InvalidDigit(4)));
+
+    // Leading underscores and prefixes without digits are invalid.
+    assert_eq!(from_rust_literal_partial::<u32>(b"_10"), Err(Error::Empty(0)));
+    assert_eq!(from_rust_literal_partial::<u32>(b"0x"), Err(Error::Empty(2)));
+    assert_eq!(from_rust_literal_partial::<u32>(b"+1"), Err(Error::InvalidPositiveSign(0)));
+    assert_eq!(from_rust_literal_partial::<u32>(b"-1"), Err(Error::Empty(0)));
+    assert_eq!(from_rust_literal_partial::<u8>(b"0x100u8"), Err(Error::Overflow(4)));
+
+    // Prefixes are case-sensitive, so this is a decimal `0` with a suffix.
+    assert_eq!(from_rust_literal_partial::<u32>(b"0XFF"), Ok((0, 1)));
+}
+
+#[test]
+#[cfg(all(feature = "format", feature = "power-of-two"))]
+fn from_c_literal_test() {
+    use lexical_parse_integer::{from_c_literal, from_cxx_literal};
+
+    // The radix is detected from the prefix or a leading zero.
+    assert_eq!(from_c_literal::<u32>(b"010"), Ok(8));
+    assert_eq!(from_c_literal::<u32>(b"0x1F"), Ok(31));
+    assert_eq!(from_c_literal::<u32>(b"0X1f"), Ok(31));
+    assert_eq!(from_c_literal::<u32>(b"123"), Ok(123));
+    assert_eq!(from_c_literal::<i32>(b"-017"), Ok(-15));
+    assert_eq!(from_c_literal::<i32>(b"-0x10"), Ok(-16));
+
+    // A lone zero is decimal, including with a suffix.
+    assert_eq!(from_c_literal::<u32>(b"0"), Ok(0));
+    assert_eq!(from_c_literal::<u32>(b"0u"), Ok(0));
+    assert_eq!(from_c_literal::<u32>(b"00"), Ok(0));
+
+    // Unsigned and long suffixes are skipped.
+    assert_eq!(from_c_literal::<u64>(b"42ul"), Ok(42));
+    assert_eq!(from_c_literal::<u64>(b"42ULL"), Ok(42));
+    assert_eq!(from_c_literal::<u64>(b"42llu"), Ok(42));
+    assert_eq!(from_c_literal::<u64>(b"0x2AL"), Ok(42));
+    assert_eq!(from_c_literal::<u64>(b"052u"), Ok(42));
+    assert_eq!(from_c_literal::<u64>(b"42lL"), Err(Error::InvalidDigit(2)));
+    assert_eq!(from_c_literal::<u64>(b"42uu"), Err(Error::InvalidDigit(2)));
+
+    // Invalid octal digits and digit separators are errors.
+    assert_eq!(from_c_literal::<u32>(b"08"), Err(Error::InvalidDigit(1)));
+    assert_eq!(from_c_literal::<u32>(b"1'000"), Err(Error::InvalidDigit(1)));
+    assert_eq!(from_c_literal::<u8>(b"0x100"), Err(Error::Overflow(4)));
+
+    // C++ accepts `'` digit separators between digits.
+    assert_eq!(from_cxx_literal::<u32>(b"1'000"), Ok(1000));
+    assert_eq!(from_cxx_literal::<u32>(b"1'000ul"), Ok(1000));
+    assert_eq!(from_cxx_literal::<u32>(b"0x7'FF"), Ok(0x7FF));
+    assert_eq!(from_cxx_literal::<u32>(b"0'10"), Ok(8));
+    assert_eq!(from_cxx_literal::<u32>(b"0"), Ok(0));
+    assert_eq!(from_cxx_literal::<u32>(b"1''0"), Err(Error::InvalidDigit(1)));
+    assert_eq!(from_cxx_literal::<u32>(b"1'"), Err(Error::InvalidDigit(1)));
+    assert_eq!(from_cxx_literal::<u32>(b"0x'FF"), Err(Error::InvalidDigit(2)));
+    assert_eq!(from_cxx_literal::<i32>(b"-0X'FF"), Err(Error::InvalidDigit(3)));
+    assert_eq!(from_c_literal::<u32>(b"0x'FF"), Err(Error::InvalidDigit(2)));
+}
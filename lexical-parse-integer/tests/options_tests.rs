@@ -1,4 +1,5 @@
 use lexical_parse_integer::options::{Options, OptionsBuilder};
+use lexical_util::error::Error;
 
 #[test]
 fn options_tests() {
@@ -15,3 +16,154 @@ fn options_tests() {
     assert!(OptionsBuilder::default().is_valid());
     assert_eq!(options.rebuild(), Options::builder());
 }
+
+#[test]
+fn bool_words_options_tests() {
+    let builder = OptionsBuilder::new();
+    assert!(!builder.get_bool_words());
+    assert!(builder.get_case_sensitive_bool_words());
+
+    let options = builder.bool_words(true).case_sensitive_bool_words(false).build_strict();
+    assert!(options.bool_words());
+    assert!(!options.case_sensitive_bool_words());
+    assert!(options.rebuild().get_bool_words());
+    assert!(!options.rebuild().get_case_sensitive_bool_words());
+}
+
+#[test]
+fn sign_options_tests() {
+    let builder = OptionsBuilder::new();
+    assert!(!builder.get_no_positive_sign());
+    assert!(!builder.get_required_sign());
+    assert!(!builder.get_allow_trailing_sign());
+
+    let options = builder
+        .no_positive_sign(true)
+        .required_sign(true)
+        .allow_trailing_sign(true)
+        .build_strict();
+    assert!(options.no_positive_sign());
+    assert!(options.required_sign());
+    assert!(options.allow_trailing_sign());
+    assert_eq!(options.rebuild().build_strict(), options);
+}
+
+#[test]
+fn skip_options_tests() {
+    let builder = OptionsBuilder::new();
+    assert!(!builder.get_skip_bom());
+    assert!(!builder.get_skip_unicode_whitespace());
+
+    let options = builder.skip_bom(true).build_strict();
+    assert!(options.skip_bom());
+    assert!(!options.skip_unicode_whitespace());
+    assert_eq!(options.rebuild().build_strict(), options);
+
+    #[cfg(feature = "unicode")]
+    {
+        let options = options.rebuild().skip_unicode_whitespace(true).build_strict();
+        assert!(options.skip_unicode_whitespace());
+        assert_eq!(options.rebuild().build_strict(), options);
+    }
+
+    let builder = OptionsBuilder::new();
+    assert!(!builder.get_trim_whitespace());
+    let options = builder.trim_whitespace(true).build_strict();
+    assert!(options.trim_whitespace());
+    assert_eq!(options.rebuild().build_strict(), options);
+
+    let builder = OptionsBuilder::new();
+    assert_eq!(builder.get_ignore_prefix(), b"");
+    let options = builder.ignore_prefix(b"$").build_strict();
+    assert_eq!(options.ignore_prefix(), b"$");
+    assert_eq!(options.rebuild().build_strict(), options);
+
+    let builder = OptionsBuilder::new();
+    assert!(builder.get_ignore_suffixes().is_empty());
+    let options = builder.ignore_suffixes(&[b"ms"]).build_strict();
+    assert_eq!(options.ignore_suffixes(), &[b"ms"]);
+    assert_eq!(options.rebuild().build_strict(), options);
+
+    assert!(!options.eastern_arabic_digits());
+    #[cfg(all(feature = "unicode", feature = "alloc"))]
+    {
+        let options = options.rebuild().eastern_arabic_digits(true).build_strict();
+        assert!(options.rebuild().get_eastern_arabic_digits());
+        assert_eq!(options.rebuild().build_strict(), options);
+    }
+}
+
+#[test]
+fn digit_separators_options_tests() {
+    let builder = OptionsBuilder::new();
+    assert_eq!(builder.get_digit_separators(), b"");
+
+    let builder = builder.digit_separators(b" ,");
+    assert_eq!(builder.get_digit_separators(), b" ,");
+    assert_eq!(builder.is_valid(), cfg!(feature = "format"));
+    if let Ok(options) = builder.build() {
+        assert_eq!(options.digit_separators(), b" ,");
+        assert_eq!(options.rebuild().build_strict(), options);
+    }
+
+    for invalid in [&b"1"[..], b"a", b"+", b" -", b"\x00"] {
+        let builder = OptionsBuilder::new().digit_separators(invalid);
+        assert_eq!(builder.build(), Err(Error::InvalidDigitSeparator));
+    }
+}
+
+#[test]
+fn exponent_options_tests() {
+    let builder = OptionsBuilder::new();
+    assert_eq!(builder.get_exponent(), b'e');
+    assert!(!builder.get_allow_integer_exponent());
+
+    let options = builder.exponent(b'^').allow_integer_exponent(true).build_strict();
+    assert_eq!(options.exponent(), b'^');
+    assert!(options.allow_integer_exponent());
+    assert_eq!(options.rebuild().build_strict(), options);
+
+    for invalid in [b'+', b'-', b'\x00', b'\xff'] {
+        let builder = OptionsBuilder::new().exponent(invalid);
+        assert_eq!(builder.build(), Err(Error::InvalidExponentSymbol));
+    }
+}
+
+#[test]
+#[cfg(not(feature = "radix"))]
+fn radix_indicator_options_tests() {
+    let builder = OptionsBuilder::new().radix_indicator(Some(b'#'));
+    assert!(!builder.is_valid());
+    assert!(builder.build().is_err());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn thread_local_options_test() {
+    use lexical_parse_integer::{
+        from_lexical_default, from_lexical_partial_default, FromLexical, ThreadLocalOptions,
+    };
+
+    fn parse(bytes: &[u8]) -> lexical_parse_integer::Result<i32> {
+        from_lexical_default(bytes)
+    }
+
+    assert_eq!(parse(b"42"), Ok(42));
+    assert_eq!(parse(b" 42"), Err(Error::InvalidDigit(0)));
+    let trim = Options::builder().trim_whitespace(true).build().unwrap();
+    let previous = ThreadLocalOptions::set_default(trim.clone());
+    assert_eq!(previous, Options::new());
+    assert_eq!(parse(b" 42 "), Ok(42));
+    assert_eq!(from_lexical_partial_default::<u8>(b" 42 ,1"), Ok((42, 4)));
+    assert_eq!(ThreadLocalOptions::with_default(|options| options.clone()), trim);
+
+    // The defaults are not used by `from_lexical`.
+    assert_eq!(i32::from_lexical(b" 42"), Err(Error::InvalidDigit(0)));
+
+    // Other threads keep the standard defaults.
+    let result = std::thread::spawn(|| parse(b" 42")).join().unwrap();
+    assert_eq!(result, Err(Error::InvalidDigit(0)));
+
+    assert_eq!(ThreadLocalOptions::set_default(previous), trim);
+    assert_eq!(parse(b" 42"), Err(Error::InvalidDigit(0)));
+}
@@ -29,6 +29,39 @@ pub fn ltrim_char_count(slc: &[u8], c: u8) -> usize {
     slc.iter().take_while(|&&si| si == c).count()
 }
 
+/// Count the bytes in a leading UTF-8 byte order mark (`EF BB BF`).
+#[inline(always)]
+#[cfg(any(feature = "parse-floats", feature = "parse-integers"))]
+pub const fn utf8_bom_count(slc: &[u8]) -> usize {
+    match slc {
+        [0xEF, 0xBB, 0xBF, ..] => 3,
+        _ => 0,
+    }
+}
+
+/// Count the number of leading bytes of Unicode whitespace in UTF-8.
+///
+/// This matches the characters with the `White_Space` property, such as
+/// ASCII whitespace, `U+00A0` (no-break space) and `U+3000` (ideographic
+/// space).
+#[inline]
+#[cfg(all(feature = "unicode", any(feature = "parse-floats", feature = "parse-integers")))]
+pub fn ltrim_unicode_whitespace_count(slc: &[u8]) -> usize {
+    let mut index = 0;
+    loop {
+        let width = match &slc[index..] {
+            [b' ' | b'\t' | b'\n' | b'\x0b' | b'\x0c' | b'\r', ..] => 1,
+            [0xC2, 0x85 | 0xA0, ..] => 2,
+            [0xE1, 0x9A, 0x80, ..] => 3,
+            [0xE2, 0x80, 0x80..=0x8A | 0xA8 | 0xA9 | 0xAF, ..] => 3,
+            [0xE2, 0x81, 0x9F, ..] => 3,
+            [0xE3, 0x80, 0x80, ..] => 3,
+            _ => return index,
+        };
+        index += width;
+    }
+}
+
 /// Check to see if parsing the float cannot possible overflow.
 ///
 /// This allows major optimizations for those types, since we can skip checked
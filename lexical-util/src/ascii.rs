@@ -45,3 +45,19 @@ pub const fn is_valid_letter_slice(slc: &[u8]) -> bool {
     }
     true
 }
+
+/// Determine if a slice has no ASCII digits or signs.
+///
+/// This is used for literal prefixes skipped before a number, which would
+/// otherwise be ambiguous with the leading sign and digits.
+#[inline(always)]
+pub const fn is_valid_prefix_slice(slc: &[u8]) -> bool {
+    let mut index = 0;
+    while index < slc.len() {
+        if slc[index].is_ascii_digit() || matches!(slc[index], b'+' | b'-') {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
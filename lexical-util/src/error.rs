@@ -19,10 +19,21 @@ pub enum Error {
     /// Integral underflow occurred during numeric parsing.
     Underflow(usize),
     /// Invalid digit found before string termination.
+    ///
+    /// The index is of the first invalid byte: `"x"` and `"1x"` fail
+    /// at 0 and 1, respectively, and a `-` parsed into an unsigned
+    /// integer fails at 0.
     InvalidDigit(usize),
     /// Empty byte array found.
+    ///
+    /// Returned when no digits were found after any skipped prefix, sign
+    /// or base prefix, and the index is where the digits were expected:
+    /// `""` fails at 0, and `"0x"` with a base prefix fails at 2.
     Empty(usize),
     /// Empty mantissa found.
+    ///
+    /// Returned when a sign is not followed by any digits, so `"+"`
+    /// and `"-"` fail at 1, the index just past the sign.
     EmptyMantissa(usize),
     /// Empty exponent found.
     EmptyExponent(usize),
@@ -44,6 +55,8 @@ pub enum Error {
     ExponentWithoutFraction(usize),
     /// Integer or integer component of float had invalid leading zeros.
     InvalidLeadingZeros(usize),
+    /// Fraction component of float had redundant trailing zeros.
+    InvalidTrailingZeros(usize),
     /// No exponent with required exponent notation.
     MissingExponent(usize),
     /// Integral sign was required(usize), but not found.
@@ -52,6 +65,8 @@ pub enum Error {
     InvalidPositiveSign(usize),
     /// Invalid negative sign for an unsigned type was found.
     InvalidNegativeSign(usize),
+    /// Check digit did not match the checksum of the digits.
+    ChecksumMismatch(usize),
 
     // NUMBER FORMAT ERRORS
     /// Invalid radix for the mantissa (significant) digits.
@@ -114,6 +129,22 @@ pub enum Error {
     InvalidNegativeExponentBreak,
     /// Invalid positive exponent break: break is below 0.
     InvalidPositiveExponentBreak,
+    /// Invalid padding character for the integer digits.
+    InvalidPadCharacter,
+    /// Invalid integer grouping: the separator is not a space or punctuation.
+    InvalidIntegerGrouping,
+    /// Invalid magnitude words: invalid or duplicate suffix, or too large.
+    InvalidMagnitudeWords,
+    /// Invalid ignore prefix: contains a digit or sign.
+    InvalidIgnorePrefix,
+    /// Invalid ignore suffix: empty or starts with a digit.
+    InvalidIgnoreSuffix,
+    /// Invalid radix indicator: combined with digit separator, sign, or exponent options.
+    InvalidRadixIndicator,
+
+    // WRITE ERRORS
+    /// The value has more digits than the fixed number of digits allowed.
+    FieldOverflow,
 
     // NOT AN ERROR
     /// An error did not actually occur, and the result was successful.
@@ -134,94 +165,146 @@ macro_rules! is_error_type {
     );
 }
 
-impl Error {
-    /// Get a description of the error in a const, panic friendly way.
-    #[inline]
-    pub const fn description(&self) -> &'static str {
-        match self {
-            // PARSE ERRORS
-            Self::Overflow(_) => "'numeric overflow occurred'",
-            Self::Underflow(_) => "'numeric underflow occurred'",
-            Self::InvalidDigit(_) => "'invalid digit found'",
-            Self::Empty(_) => "'the string to parse was empty'",
-            Self::EmptyMantissa(_) => "'no significant digits found'",
-            Self::EmptyExponent(_) => "'exponent notation found without an exponent'",
-            Self::EmptyInteger(_) => "'invalid float with no integer digits'",
-            Self::EmptyFraction(_) => "'invalid float with no fraction digits'",
-            Self::InvalidPositiveMantissaSign(_) => "'invalid `+` sign before significant digits'",
-            Self::MissingMantissaSign(_) => "'missing required `+/-` sign for significant digits'",
-            Self::InvalidExponent(_) => "'exponent found but not allowed'",
-            Self::InvalidPositiveExponentSign(_) => "'invalid `+` sign in exponent'",
-            Self::MissingExponentSign(_) => "'missing required `+/-` sign for exponent'",
-            Self::ExponentWithoutFraction(_) =>  "'invalid float containing exponent without fraction'",
-            Self::InvalidLeadingZeros(_) => "'invalid number with leading zeros before digits'",
-            Self::MissingExponent(_) => "'missing required exponent'",
-            Self::MissingSign(_) => "'missing required `+/-` sign for integer'",
-            Self::InvalidPositiveSign(_) => "'invalid `+` sign for an integer was found'",
-            Self::InvalidNegativeSign(_) => "'invalid `-` sign for an unsigned type was found'",
+/// Define the description and message of each error from a single text.
+///
+/// The description quotes the text, and the message prefixes it with the
+/// variant name, so the two can never diverge.
+macro_rules! error_messages {
+    ($($variant:ident $(($index:tt))? => $text:literal,)*) => {
+        impl Error {
+            /// Get a description of the error in a const, panic friendly way.
+            #[inline]
+            pub const fn description(&self) -> &'static str {
+                match self {
+                    $(Self::$variant $(($index))? => concat!("'", $text, "'"),)*
+                }
+            }
 
-            // NUMBER FORMAT ERRORS
-            Self::InvalidMantissaRadix => "'invalid radix for mantissa digits'",
-            Self::InvalidExponentBase => "'invalid exponent base'",
-            Self::InvalidExponentRadix => "'invalid radix for exponent digits'",
-            Self::InvalidDigitSeparator => "'invalid digit separator: must be ASCII and not a digit or a `+/-` sign'",
-            Self::InvalidDecimalPoint => "'invalid decimal point: must be ASCII and not a digit or a `+/-` sign'",
-            Self::InvalidExponentSymbol => "'invalid exponent symbol: must be ASCII and not a digit or a `+/-` sign'",
-            Self::InvalidBasePrefix => "'invalid base prefix character'",
-            Self::InvalidBaseSuffix => "'invalid base suffix character'",
-            Self::InvalidPunctuation => "'invalid punctuation: multiple characters overlap'",
-            Self::InvalidExponentFlags => "'exponent flags set while disabling exponent notation'",
-            Self::InvalidMantissaSign => "'disabled the `+` sign while requiring a sign for significant digits'",
-            Self::InvalidExponentSign => "'disabled the `+` sign while requiring a sign for exponent digits'",
-            Self::InvalidSpecial => "'special flags set while disabling special floats'",
-            Self::InvalidConsecutiveIntegerDigitSeparator => "'enabled consecutive digit separators in the integer without setting a valid location'",
-            Self::InvalidConsecutiveFractionDigitSeparator => "'enabled consecutive digit separators in the fraction without setting a valid location'",
-            Self::InvalidConsecutiveExponentDigitSeparator => "'enabled consecutive digit separators in the exponent without setting a valid location'",
-            Self::InvalidFlags => "'invalid flags enabled without the format feature'",
+            /// Get a static message for the error, without the index.
+            ///
+            /// This contains the variant name followed by a description of the
+            /// error, such as `"Overflow: numeric overflow occurred"`. Unlike the
+            /// [`Display`][fmt::Display] implementation, this does not require any
+            /// formatting, so it's cheap to log on embedded targets.
+            ///
+            /// # Examples
+            ///
+            /// ```rust
+            /// use lexical_util::error::Error;
+            ///
+            /// const MESSAGE: &str = Error::InvalidDigit(3).message();
+            /// assert_eq!(MESSAGE, "InvalidDigit: invalid digit found");
+            /// ```
+            #[inline]
+            pub const fn message(&self) -> &'static str {
+                match self {
+                    $(Self::$variant $(($index))? => concat!(stringify!($variant), ": ", $text),)*
+                }
+            }
+        }
+    };
+}
 
-            // OPTION ERRORS
-            Self::InvalidNanString => "'NaN string must started with `n`'",
-            Self::NanStringTooLong => "'NaN string is too long'",
-            Self::InvalidInfString => "'short infinity string must started with `i`'",
-            Self::InfStringTooLong => "'short infinity string is too long'",
-            Self::InvalidInfinityString => "'long infinity string must started with `i`'",
-            Self::InfinityStringTooLong => "'long infinity string is too long'",
-            Self::InfinityStringTooShort => "'long infinity string is too short'",
-            Self::InvalidFloatParseAlgorithm => "'invalid combination of float parse algorithms'",
-            Self::InvalidRadix => "'invalid radix for significant digits'",
-            Self::InvalidFloatPrecision => "'invalid float precision: min digits is larger than max digits'",
-            Self::InvalidNegativeExponentBreak => "'invalid negative exponent break: value is above 0'",
-            Self::InvalidPositiveExponentBreak => "'invalid positive exponent break: value is below 0'",
+error_messages! {
+    // PARSE ERRORS
+    Overflow(_) => "numeric overflow occurred",
+    Underflow(_) => "numeric underflow occurred",
+    InvalidDigit(_) => "invalid digit found",
+    Empty(_) => "no digits found after the prefix or sign",
+    EmptyMantissa(_) => "no significant digits found",
+    EmptyExponent(_) => "exponent notation found without an exponent",
+    EmptyInteger(_) => "invalid float with no integer digits",
+    EmptyFraction(_) => "invalid float with no fraction digits",
+    InvalidPositiveMantissaSign(_) => "invalid `+` sign before significant digits",
+    MissingMantissaSign(_) => "missing required `+/-` sign for significant digits",
+    InvalidExponent(_) => "exponent found but not allowed",
+    InvalidPositiveExponentSign(_) => "invalid `+` sign in exponent",
+    MissingExponentSign(_) => "missing required `+/-` sign for exponent",
+    ExponentWithoutFraction(_) => "invalid float containing exponent without fraction",
+    InvalidLeadingZeros(_) => "invalid number with leading zeros before digits",
+    InvalidTrailingZeros(_) => "invalid float with redundant trailing zeros in the fraction",
+    MissingExponent(_) => "missing required exponent",
+    MissingSign(_) => "missing required `+/-` sign for integer",
+    InvalidPositiveSign(_) => "invalid `+` sign for an integer was found",
+    InvalidNegativeSign(_) => "invalid `-` sign for an unsigned type was found",
+    ChecksumMismatch(_) => "check digit does not match the checksum",
 
-            // NOT AN ERROR
-            Self::Success => "'not actually an error'",
-        }
-    }
+    // NUMBER FORMAT ERRORS
+    InvalidMantissaRadix => "invalid radix for mantissa digits",
+    InvalidExponentBase => "invalid exponent base",
+    InvalidExponentRadix => "invalid radix for exponent digits",
+    InvalidDigitSeparator => "invalid digit separator: must be ASCII and not a digit or a `+/-` sign",
+    InvalidDecimalPoint => "invalid decimal point: must be ASCII and not a digit or a `+/-` sign",
+    InvalidExponentSymbol => "invalid exponent symbol: must be ASCII and not a digit or a `+/-` sign",
+    InvalidBasePrefix => "invalid base prefix character",
+    InvalidBaseSuffix => "invalid base suffix character",
+    InvalidPunctuation => "invalid punctuation: multiple characters overlap",
+    InvalidExponentFlags => "exponent flags set while disabling exponent notation",
+    InvalidMantissaSign => "disabled the `+` sign while requiring a sign for significant digits",
+    InvalidExponentSign => "disabled the `+` sign while requiring a sign for exponent digits",
+    InvalidSpecial => "special flags set while disabling special floats",
+    InvalidConsecutiveIntegerDigitSeparator => "enabled consecutive digit separators in the integer without setting a valid location",
+    InvalidConsecutiveFractionDigitSeparator => "enabled consecutive digit separators in the fraction without setting a valid location",
+    InvalidConsecutiveExponentDigitSeparator => "enabled consecutive digit separators in the exponent without setting a valid location",
+    InvalidFlags => "invalid flags enabled without the format feature",
 
+    // OPTION ERRORS
+    InvalidNanString => "NaN string must start with `n`",
+    NanStringTooLong => "NaN string is too long",
+    InvalidInfString => "short infinity string must start with `i`",
+    InfStringTooLong => "short infinity string is too long",
+    InvalidInfinityString => "long infinity string must start with `i`",
+    InfinityStringTooLong => "long infinity string is too long",
+    InfinityStringTooShort => "long infinity string is too short",
+    InvalidFloatParseAlgorithm => "invalid combination of float parse algorithms",
+    InvalidRadix => "invalid radix for significant digits",
+    InvalidFloatPrecision => "invalid float precision: min digits is larger than max digits",
+    InvalidNegativeExponentBreak => "invalid negative exponent break: value is above 0",
+    InvalidPositiveExponentBreak => "invalid positive exponent break: value is below 0",
+    InvalidPadCharacter => "invalid pad character for the integer digits",
+    InvalidIntegerGrouping => "invalid integer grouping: separator must be a space or non-sign punctuation",
+    InvalidMagnitudeWords => "invalid magnitude words: invalid or duplicate suffix, or exponent is above 19",
+    InvalidIgnorePrefix => "invalid ignore prefix: contains a digit or sign",
+    InvalidIgnoreSuffix => "invalid ignore suffix: empty or starts with a digit",
+    InvalidRadixIndicator => "invalid radix indicator: combined with digit separator, sign, or exponent options",
+
+    // WRITE ERRORS
+    FieldOverflow => "value has more digits than the fixed number of digits",
+
+    // NOT AN ERROR
+    Success => "not actually an error",
+}
+
+impl Error {
     /// Get the index for the parsing error.
+    ///
+    /// This is the position in the input where the error occurred, or
+    /// [`None`] if the error is not a parsing error.
     #[inline]
-    pub fn index(&self) -> Option<&usize> {
+    pub const fn index(&self) -> Option<usize> {
         match self {
             // PARSE ERRORS
-            Self::Overflow(index) => Some(index),
-            Self::Underflow(index) => Some(index),
-            Self::InvalidDigit(index) => Some(index),
-            Self::Empty(index) => Some(index),
-            Self::EmptyMantissa(index) => Some(index),
-            Self::EmptyExponent(index) => Some(index),
-            Self::EmptyInteger(index) => Some(index),
-            Self::EmptyFraction(index) => Some(index),
-            Self::InvalidPositiveMantissaSign(index) => Some(index),
-            Self::MissingMantissaSign(index) => Some(index),
-            Self::InvalidExponent(index) => Some(index),
-            Self::InvalidPositiveExponentSign(index) => Some(index),
-            Self::MissingExponentSign(index) => Some(index),
-            Self::ExponentWithoutFraction(index) => Some(index),
-            Self::InvalidLeadingZeros(index) => Some(index),
-            Self::MissingExponent(index) => Some(index),
-            Self::MissingSign(index) => Some(index),
-            Self::InvalidPositiveSign(index) => Some(index),
-            Self::InvalidNegativeSign(index) => Some(index),
+            Self::Overflow(index) => Some(*index),
+            Self::Underflow(index) => Some(*index),
+            Self::InvalidDigit(index) => Some(*index),
+            Self::Empty(index) => Some(*index),
+            Self::EmptyMantissa(index) => Some(*index),
+            Self::EmptyExponent(index) => Some(*index),
+            Self::EmptyInteger(index) => Some(*index),
+            Self::EmptyFraction(index) => Some(*index),
+            Self::InvalidPositiveMantissaSign(index) => Some(*index),
+            Self::MissingMantissaSign(index) => Some(*index),
+            Self::InvalidExponent(index) => Some(*index),
+            Self::InvalidPositiveExponentSign(index) => Some(*index),
+            Self::MissingExponentSign(index) => Some(*index),
+            Self::ExponentWithoutFraction(index) => Some(*index),
+            Self::InvalidLeadingZeros(index) => Some(*index),
+            Self::InvalidTrailingZeros(index) => Some(*index),
+            Self::MissingExponent(index) => Some(*index),
+            Self::MissingSign(index) => Some(*index),
+            Self::InvalidPositiveSign(index) => Some(*index),
+            Self::InvalidNegativeSign(index) => Some(*index),
+            Self::ChecksumMismatch(index) => Some(*index),
 
             // NUMBER FORMAT ERRORS
             Self::InvalidMantissaRadix => None,
@@ -255,6 +338,15 @@ impl Error {
             Self::InvalidFloatPrecision => None,
             Self::InvalidNegativeExponentBreak => None,
             Self::InvalidPositiveExponentBreak => None,
+            Self::InvalidPadCharacter => None,
+            Self::InvalidIntegerGrouping => None,
+            Self::InvalidMagnitudeWords => None,
+            Self::InvalidIgnorePrefix => None,
+            Self::InvalidIgnoreSuffix => None,
+            Self::InvalidRadixIndicator => None,
+
+            // WRITE ERRORS
+            Self::FieldOverflow => None,
 
             // NOT AN ERROR
             Self::Success => None,
@@ -276,10 +368,12 @@ impl Error {
     is_error_type!(is_missing_exponent_sign, MissingExponentSign(_));
     is_error_type!(is_exponent_without_fraction, ExponentWithoutFraction(_));
     is_error_type!(is_invalid_leading_zeros, InvalidLeadingZeros(_));
+    is_error_type!(is_invalid_trailing_zeros, InvalidTrailingZeros(_));
     is_error_type!(is_missing_exponent, MissingExponent(_));
     is_error_type!(is_missing_sign, MissingSign(_));
     is_error_type!(is_invalid_positive_sign, InvalidPositiveSign(_));
     is_error_type!(is_invalid_negative_sign, InvalidNegativeSign(_));
+    is_error_type!(is_checksum_mismatch, ChecksumMismatch(_));
     is_error_type!(is_invalid_mantissa_radix, InvalidMantissaRadix);
     is_error_type!(is_invalid_exponent_base, InvalidExponentBase);
     is_error_type!(is_invalid_exponent_radix, InvalidExponentRadix);
@@ -318,9 +412,51 @@ impl Error {
     is_error_type!(is_invalid_float_precision, InvalidFloatPrecision);
     is_error_type!(is_invalid_negative_exponent_break, InvalidNegativeExponentBreak);
     is_error_type!(is_invalid_positive_exponent_break, InvalidPositiveExponentBreak);
+    is_error_type!(is_invalid_pad_character, InvalidPadCharacter);
+    is_error_type!(is_invalid_integer_grouping, InvalidIntegerGrouping);
+    is_error_type!(is_invalid_magnitude_words, InvalidMagnitudeWords);
+    is_error_type!(is_invalid_ignore_prefix, InvalidIgnorePrefix);
+    is_error_type!(is_invalid_ignore_suffix, InvalidIgnoreSuffix);
+    is_error_type!(is_invalid_radix_indicator, InvalidRadixIndicator);
+    is_error_type!(is_field_overflow, FieldOverflow);
     is_error_type!(is_success, Success);
 }
 
+/// Shift the index of an error from parsing a subslice starting at `offset`.
+///
+/// Errors without an index are returned unchanged.
+#[inline(always)]
+pub const fn shift_error(error: Error, offset: usize) -> Error {
+    match error {
+        Error::Overflow(index) => Error::Overflow(index + offset),
+        Error::Underflow(index) => Error::Underflow(index + offset),
+        Error::InvalidDigit(index) => Error::InvalidDigit(index + offset),
+        Error::Empty(index) => Error::Empty(index + offset),
+        Error::EmptyMantissa(index) => Error::EmptyMantissa(index + offset),
+        Error::EmptyExponent(index) => Error::EmptyExponent(index + offset),
+        Error::EmptyInteger(index) => Error::EmptyInteger(index + offset),
+        Error::EmptyFraction(index) => Error::EmptyFraction(index + offset),
+        Error::InvalidPositiveMantissaSign(index) => {
+            Error::InvalidPositiveMantissaSign(index + offset)
+        },
+        Error::MissingMantissaSign(index) => Error::MissingMantissaSign(index + offset),
+        Error::InvalidExponent(index) => Error::InvalidExponent(index + offset),
+        Error::InvalidPositiveExponentSign(index) => {
+            Error::InvalidPositiveExponentSign(index + offset)
+        },
+        Error::MissingExponentSign(index) => Error::MissingExponentSign(index + offset),
+        Error::ExponentWithoutFraction(index) => Error::ExponentWithoutFraction(index + offset),
+        Error::InvalidLeadingZeros(index) => Error::InvalidLeadingZeros(index + offset),
+        Error::InvalidTrailingZeros(index) => Error::InvalidTrailingZeros(index + offset),
+        Error::MissingExponent(index) => Error::MissingExponent(index + offset),
+        Error::MissingSign(index) => Error::MissingSign(index + offset),
+        Error::InvalidPositiveSign(index) => Error::InvalidPositiveSign(index + offset),
+        Error::InvalidNegativeSign(index) => Error::InvalidNegativeSign(index + offset),
+        Error::ChecksumMismatch(index) => Error::ChecksumMismatch(index + offset),
+        _ => error,
+    }
+}
+
 /// Add an error message for parsing errors.
 macro_rules! write_parse_error {
     ($formatter:ident, $message:expr, $index:ident) => {
@@ -342,6 +478,13 @@ macro_rules! options_message {
     };
 }
 
+/// Add an error message for writing errors.
+macro_rules! write_message {
+    ($formatter:ident, $message:expr) => {
+        write!($formatter, "lexical write error: {}", $message)
+    };
+}
+
 impl fmt::Display for Error {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
         let description = self.description();
@@ -368,10 +511,12 @@ impl fmt::Display for Error {
                 write_parse_error!(formatter, description, index)
             },
             Self::InvalidLeadingZeros(index) => write_parse_error!(formatter, description, index),
+            Self::InvalidTrailingZeros(index) => write_parse_error!(formatter, description, index),
             Self::MissingExponent(index) => write_parse_error!(formatter, description, index),
             Self::MissingSign(index) => write_parse_error!(formatter, description, index),
             Self::InvalidPositiveSign(index) => write_parse_error!(formatter, description, index),
             Self::InvalidNegativeSign(index) => write_parse_error!(formatter, description, index),
+            Self::ChecksumMismatch(index) => write_parse_error!(formatter, description, index),
 
             // NUMBER FORMAT ERRORS
             Self::InvalidMantissaRadix => format_message!(formatter, description),
@@ -411,6 +556,15 @@ impl fmt::Display for Error {
             Self::InvalidFloatPrecision => options_message!(formatter, description),
             Self::InvalidNegativeExponentBreak => options_message!(formatter, description),
             Self::InvalidPositiveExponentBreak => options_message!(formatter, description),
+            Self::InvalidPadCharacter => options_message!(formatter, description),
+            Self::InvalidIntegerGrouping => options_message!(formatter, description),
+            Self::InvalidMagnitudeWords => options_message!(formatter, description),
+            Self::InvalidIgnorePrefix => options_message!(formatter, description),
+            Self::InvalidIgnoreSuffix => options_message!(formatter, description),
+            Self::InvalidRadixIndicator => options_message!(formatter, description),
+
+            // WRITE ERRORS
+            Self::FieldOverflow => write_message!(formatter, description),
 
             // NOT AN ERROR
             Self::Success => write!(formatter, "{description}"),
@@ -421,3 +575,31 @@ impl fmt::Display for Error {
 #[cfg(feature = "std")]
 impl error::Error for Error {
 }
+
+/// Error when a buffer is too small to write a number.
+///
+/// This contains the number of bytes required to write the number, so
+/// the buffer can be grown and the write retried.
+#[cfg(any(feature = "write-floats", feature = "write-integers"))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct NeedCapacity(pub usize);
+
+#[cfg(any(feature = "write-floats", feature = "write-integers"))]
+impl NeedCapacity {
+    /// Get the number of bytes required to write the number.
+    #[inline(always)]
+    pub const fn required(&self) -> usize {
+        self.0
+    }
+}
+
+#[cfg(any(feature = "write-floats", feature = "write-integers"))]
+impl fmt::Display for NeedCapacity {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "lexical write error: buffer requires {} bytes", self.0)
+    }
+}
+
+#[cfg(all(feature = "std", any(feature = "write-floats", feature = "write-integers")))]
+impl error::Error for NeedCapacity {
+}
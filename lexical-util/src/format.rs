@@ -144,6 +144,7 @@
     doc = "
 - [`C_HEX_LITERAL`]: Number format for a [`C`] literal hexadecimal floating-point number.
 - [`C_HEX_STRING`]: Number format for a [`C`] string hexadecimal floating-point number.
+- [`C_HEX_FLOAT`]: Number format to parse a [`C`] hexadecimal float with a `0x` prefix.
 "
 )]
 #![cfg_attr(
@@ -637,6 +638,7 @@ pub use crate::format_flags::*;
 pub use crate::not_feature_format::*;
 #[cfg(feature = "format")]
 pub use crate::prebuilt_formats::*;
+use crate::result::Result;
 
 /// Determine if the format packed struct is valid.
 #[inline(always)]
@@ -653,5 +655,25 @@ pub const fn format_error<const FORMAT: u128>() -> Error {
     NumberFormat::<FORMAT> {}.error()
 }
 
+/// Validate a packed number format known only at runtime.
+///
+/// This returns the same error as [`format_error`], but as a [`Result`],
+/// so user-supplied formats can be checked without panicking.
+///
+/// ```rust
+/// use lexical_util::error::Error;
+/// use lexical_util::format::{validate_format, STANDARD};
+///
+/// assert_eq!(validate_format(STANDARD), Ok(()));
+/// assert_eq!(validate_format(0), Err(Error::InvalidMantissaRadix));
+/// ```
+#[inline(always)]
+pub const fn validate_format(format: u128) -> Result<()> {
+    match format_error_impl(format) {
+        Error::Success => Ok(()),
+        error => Err(error),
+    }
+}
+
 /// Standard number format. This is identical to the Rust string format.
 pub const STANDARD: u128 = NumberFormatBuilder::new().build_strict();
@@ -3161,6 +3161,10 @@ impl NumberFormatBuilder {
     /// This enables leading, trailing, internal, and consecutive digit
     /// separators for the integer component. Defaults to [`false`].
     ///
+    /// This skips the digit separator anywhere in the integer digits, without
+    /// validating the grouping, so `1,2,3,4` and `1,234` both parse as `1234`
+    /// with a digit separator of `,`.
+    ///
     /// # Used For
     ///
     /// - Parse Float
@@ -399,6 +399,72 @@ pub trait DigitsIter<'a>: Iterator<Item = &'a u8> + Iter<'a> {
         }
     }
 
+    /// Check if the next elements match the given values with optional case
+    /// sensitivity, without consuming them.
+    ///
+    /// For non-contiguous iterators, this skips digit separators between
+    /// the elements. The cursor is always restored afterwards.
+    #[inline(always)]
+    fn peek_is_slice(&mut self, value: &[u8], is_cased: bool) -> bool {
+        let start = self.cursor();
+        let is_match = self.read_if_slice(value, is_cased);
+        // SAFETY: safe, since `start` was a valid cursor for the buffer.
+        unsafe { self.set_cursor(start) };
+        is_match
+    }
+
+    /// Read the next elements if they match the given values with optional
+    /// case sensitivity.
+    ///
+    /// For non-contiguous iterators, this skips digit separators between
+    /// the elements. If the values do not match, the cursor is restored and
+    /// nothing is consumed. Like [`read_if_value`], this does not increment
+    /// the count of digits returned.
+    ///
+    /// [`read_if_value`]: DigitsIter::read_if_value
+    #[inline(always)]
+    fn read_if_slice(&mut self, value: &[u8], is_cased: bool) -> bool {
+        let start = self.cursor();
+        for &expected in value {
+            if self.read_if_value(expected, is_cased).is_none() {
+                // SAFETY: safe, since `start` was a valid cursor for the buffer.
+                unsafe { self.set_cursor(start) };
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Read the next four digits as a little-endian, packed u32.
+    ///
+    /// See [`read_u64`] for more details.
+    ///
+    /// [`read_u64`]: DigitsIter::read_u64
+    #[inline(always)]
+    fn read_u32(&mut self) -> Option<u32> {
+        read_packed_digits(self, mem::size_of::<u32>()).map(|value| value as u32)
+    }
+
+    /// Read the next eight digits as a little-endian, packed u64.
+    ///
+    /// The digits are gathered in the order they are returned by the
+    /// iterator, skipping any digit separators, so the first digit is the
+    /// least-significant byte. For contiguous iterators, this is identical to
+    /// [`u64::from_le`] on [`peek_u64`], so `1_2_3_4_5_6_7_8` and `12345678`
+    /// both give `0x3837_3635_3433_3231`. This allows multi-digit
+    /// optimizations even with digit separators.
+    ///
+    /// If fewer than eight digits remain, or any of the next eight values is
+    /// not a digit, this returns [`None`] and the cursor is restored.
+    /// Otherwise, the digits are consumed and the count is incremented for
+    /// each digit.
+    ///
+    /// [`peek_u64`]: Iter::peek_u64
+    #[inline(always)]
+    fn read_u64(&mut self) -> Option<u64> {
+        read_packed_digits(self, mem::size_of::<u64>())
+    }
+
     /// Skip zeros from the start of the iterator
     #[inline(always)]
     fn skip_zeros(&mut self) -> usize {
@@ -412,3 +478,34 @@ pub trait DigitsIter<'a>: Iterator<Item = &'a u8> + Iter<'a> {
     /// Determine if the character is a digit.
     fn is_digit(&self, value: u8) -> bool;
 }
+
+/// Read up to eight digits as a little-endian, packed integer.
+///
+/// The cursor is restored if fewer than `count` digits remain.
+#[inline(always)]
+fn read_packed_digits<'a, T>(iter: &mut T, count: usize) -> Option<u64>
+where
+    T: DigitsIter<'a> + ?Sized,
+{
+    debug_assert!(count <= mem::size_of::<u64>());
+    let start = iter.cursor();
+    let mut value = 0u64;
+    for index in 0..count {
+        match iter.peek() {
+            Some(&digit) if iter.is_digit(digit) => {
+                value |= (digit as u64) << (8 * index);
+                // SAFETY: the slice cannot be empty because we peeked a value.
+                unsafe { iter.step_unchecked() };
+            },
+            _ => {
+                // SAFETY: safe, since `start` was a valid cursor for the buffer.
+                unsafe { iter.set_cursor(start) };
+                return None;
+            },
+        }
+    }
+    for _ in 0..count {
+        iter.increment_count();
+    }
+    Some(value)
+}
@@ -137,6 +137,8 @@ mod skip;
 #[cfg(any(feature = "write-floats", feature = "write-integers"))]
 pub use constants::{FormattedSize, BUFFER_SIZE};
 pub use error::Error;
+#[cfg(any(feature = "write-floats", feature = "write-integers"))]
+pub use error::NeedCapacity;
 pub use format::{NumberFormat, NumberFormatBuilder};
 #[cfg(any(feature = "parse-floats", feature = "parse-integers"))]
 pub use options::ParseOptions;
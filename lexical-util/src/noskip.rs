@@ -70,33 +70,25 @@ impl<'a, const __: u128> Bytes<'a, __> {
     /// Get iterator over integer digits.
     #[inline(always)]
     pub fn integer_iter<'b>(&'b mut self) -> DigitsIterator<'a, 'b, __> {
-        DigitsIterator {
-            byte: self,
-        }
+        DigitsIterator::new(self)
     }
 
     /// Get iterator over fraction digits.
     #[inline(always)]
     pub fn fraction_iter<'b>(&'b mut self) -> DigitsIterator<'a, 'b, __> {
-        DigitsIterator {
-            byte: self,
-        }
+        DigitsIterator::new(self)
     }
 
     /// Get iterator over exponent digits.
     #[inline(always)]
     pub fn exponent_iter<'b>(&'b mut self) -> DigitsIterator<'a, 'b, __> {
-        DigitsIterator {
-            byte: self,
-        }
+        DigitsIterator::new(self)
     }
 
     /// Get iterator over special floating point values.
     #[inline(always)]
     pub fn special_iter<'b>(&'b mut self) -> DigitsIterator<'a, 'b, __> {
-        DigitsIterator {
-            byte: self,
-        }
+        DigitsIterator::new(self)
     }
 }
 
@@ -162,17 +154,30 @@ unsafe impl<'a, const __: u128> Iter<'a> for Bytes<'a, __> {
 pub struct DigitsIterator<'a: 'b, 'b, const __: u128> {
     /// The internal byte object for the no-skip iterator.
     byte: &'b mut Bytes<'a, __>,
+    /// The cursor when the iterator was created.
+    start: usize,
 }
 
 impl<'a: 'b, 'b, const __: u128> DigitsIterator<'a, 'b, __> {
     /// Create a new digits iterator from the bytes underlying item.
     #[inline(always)]
     pub fn new(byte: &'b mut Bytes<'a, __>) -> Self {
+        let start = byte.index;
         Self {
             byte,
+            start,
         }
     }
 
+    /// Get the number of digits consumed by this iterator.
+    ///
+    /// Nothing is skipped, so this is every byte consumed since the
+    /// iterator was created.
+    #[inline(always)]
+    pub fn digits_consumed(&self) -> usize {
+        self.byte.index - self.start
+    }
+
     /// Take the first N digits from the iterator.
     ///
     /// This only takes the digits if we have a contiguous iterator.
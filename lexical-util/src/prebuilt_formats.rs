@@ -1007,6 +1007,25 @@ pub const C_HEX_LITERAL: u128 = C18_HEX_LITERAL;
 #[cfg(feature = "power-of-two")]
 pub const C_HEX_STRING: u128 = C18_HEX_STRING;
 
+// C HEX FLOAT [0134567MN]
+/// Number format to parse a [`C`] hexadecimal float with a `0x` prefix.
+///
+/// This parses the hexadecimal floats accepted by `strtod`, such as
+/// `0x1.8p3`, which has a hexadecimal mantissa and a binary exponent written
+/// in decimal. The `p` exponent character is set through the parse options,
+/// and the binary exponent is optional, so `0x1.8` is `1.5`. Unlike `strtod`,
+/// the `0x` prefix is optional and the digits are always hexadecimal.
+///
+/// [`C`]: https://en.cppreference.com/w/c
+#[rustfmt::skip]
+#[cfg(feature = "power-of-two")]
+pub const C_HEX_FLOAT: u128 = NumberFormatBuilder::new()
+    .mantissa_radix(16)
+    .exponent_base(num::NonZeroU8::new(2))
+    .exponent_radix(num::NonZeroU8::new(10))
+    .base_prefix(num::NonZeroU8::new(b'x'))
+    .build_strict();
+
 // C18 LITERAL [01345678MN]
 /// Number format for a [`C18`] literal floating-point number.
 ///
@@ -410,7 +410,8 @@ macro_rules! is_il {
         if slc.get(prev).map_or(false, |&x| $self.is_digit(x)) {
             slc.get(next).map_or(false, |&x| $self.is_digit(x))
         } else {
-            slc.get(prev).map_or(true, |&x| !$self.is_digit_separator(x))
+            slc.get(prev).map_or(true, |&x| !$self.is_digit_separator(x)) &&
+                slc.get(next).map_or(true, |&x| !$self.is_digit_separator(x))
         }
     }};
 
@@ -479,10 +480,12 @@ macro_rules! is_ilc {
     (@internal $self:ident, $index:expr) => {{
         // NOTE: The conditions here then are that:
         // - `index + 1` is a digit after consuming digit separators
+        // Since we've previously had a digit, this cannot be leading,
+        // so the end of the buffer is a trailing digit separator.
 
         let next = indexing!(@nextc $self, $index);
         let slc = $self.byte.slc;
-        slc.get(next).map_or(true, |&x| $self.is_digit(x))
+        slc.get(next).map_or(false, |&x| $self.is_digit(x))
     }};
 
     (@internal $self:ident) => {
@@ -494,19 +497,19 @@ macro_rules! is_ilc {
 ///
 /// # Examples
 ///
-/// - `__123`- valid
-/// - `+__123`- valid
-/// - `._123`- valid
+/// - `__123`- invalid
+/// - `+__123`- invalid
+/// - `._123`- invalid
 /// - `_+123`- valid
-/// - `_123`- valid
-/// - `+_123`- valid
+/// - `_123`- invalid
+/// - `+_123`- invalid
 /// - `+1_23`- valid
-/// - `+1__23`- valid
-/// - `+123_`- invalid
+/// - `+1__23`- invalid
+/// - `+123_`- valid
 /// - `+123__`- invalid
 /// - _: valid
 /// - _+: valid
-/// - 1_+: invalid
+/// - 1_+: valid
 ///
 /// # Preconditions
 ///
@@ -862,7 +865,10 @@ macro_rules! peek_1 {
 macro_rules! peek_n {
     ($self:ident, $is_skip:ident) => {{
         // This will consume consecutive digit separators.
+        // NOTE: Always bound by the buffer length, and not the backing slice,
+        // so sub-buffers from `take_n` never consume past their end.
         let mut index = $self.cursor();
+        let length = $self.buffer_length();
         let buffer = $self.get_buffer();
         let value = buffer.get(index)?;
         let is_digit_separator = $self.is_digit_separator(*value);
@@ -879,12 +885,12 @@ macro_rules! peek_n {
                 // a non-digit separator character. Don't need any complex checks
                 // here, since we've already done them above.
                 index += 1;
-                while index < buffer.len()
+                while index < length
                     && buffer.get(index).map_or(false, |&x| $self.is_digit_separator(x))
                 {
                     index += 1;
                 }
-                // SAFETY: Safe since `index <= buffer.len()`.
+                // SAFETY: Safe since `index <= self.buffer_length()`.
                 unsafe { $self.set_cursor(index) };
                 buffer.get(index)
             } else {
@@ -1057,6 +1063,8 @@ pub struct Bytes<'a, const FORMAT: u128> {
     /// The current count of exponent digits returned by the iterator.
     /// This is only used if the iterator is not contiguous.
     exponent_count: usize,
+    /// Additional digit separators accepted alongside the format's.
+    digit_separators: &'a [u8],
 }
 
 impl<'a, const FORMAT: u128> Bytes<'a, FORMAT> {
@@ -1069,6 +1077,7 @@ impl<'a, const FORMAT: u128> Bytes<'a, FORMAT> {
             integer_count: 0,
             fraction_count: 0,
             exponent_count: 0,
+            digit_separators: &[],
         }
     }
 
@@ -1091,31 +1100,59 @@ impl<'a, const FORMAT: u128> Bytes<'a, FORMAT> {
             integer_count: 0,
             fraction_count: 0,
             exponent_count: 0,
+            digit_separators: &[],
+        }
+    }
+
+    /// Set additional digit separators to accept alongside the format's.
+    ///
+    /// These are only used if the format has a digit separator, so formats
+    /// without one remain contiguous, and the format's digit separator is
+    /// always checked first.
+    #[inline(always)]
+    pub const fn with_digit_separators(mut self, digit_separators: &'a [u8]) -> Self {
+        self.digit_separators = digit_separators;
+        self
+    }
+
+    /// Determine if the character is a digit separator.
+    #[inline(always)]
+    pub const fn is_digit_separator(&self, value: u8) -> bool {
+        let digit_separator = NumberFormat::<{ FORMAT }>::DIGIT_SEPARATOR;
+        if digit_separator == 0 {
+            // Check at compile time if we have an invalid digit separator.
+            // b'\x00', or the NUL character, is this invalid value.
+            false
+        } else if value == digit_separator {
+            true
+        } else {
+            let mut index = 0;
+            while index < self.digit_separators.len() {
+                if self.digit_separators[index] == value {
+                    return true;
+                }
+                index += 1;
+            }
+            false
         }
     }
 
     /// Get iterator over integer digits.
     #[inline(always)]
     pub fn integer_iter<'b>(&'b mut self) -> IntegerDigitsIterator<'a, 'b, FORMAT> {
-        IntegerDigitsIterator {
-            byte: self,
-        }
+        IntegerDigitsIterator::new(self)
     }
 
     /// Get iterator over fraction digits.
     #[inline(always)]
     pub fn fraction_iter<'b>(&'b mut self) -> FractionDigitsIterator<'a, 'b, FORMAT> {
-        FractionDigitsIterator {
-            byte: self,
-        }
+        FractionDigitsIterator::new(self)
     }
 
     /// Get iterator over exponent digits.
     #[inline(always)]
     pub fn exponent_iter<'b>(&'b mut self) -> ExponentDigitsIterator<'a, 'b, FORMAT> {
-        ExponentDigitsIterator {
-            byte: self,
-        }
+        ExponentDigitsIterator::new(self)
     }
 
     /// Get iterator over special floating point values.
@@ -1143,11 +1180,11 @@ impl<'a, const FORMAT: u128> Bytes<'a, FORMAT> {
             // if the value is in the range `[0, 1]`.
             // We also need to make sure the **current** value
             // isn't a digit separator.
-            let format = NumberFormat::<{ FORMAT }> {};
             debug_assert!(self.as_slice().len() >= count);
             debug_assert!(count == 0 || count == 1);
             debug_assert!(
-                count == 0 || self.slc.get(self.index) != Some(&format.digit_separator())
+                count == 0 ||
+                    !self.slc.get(self.index).map_or(false, |&x| self.is_digit_separator(x))
             );
         }
         self.index += count;
@@ -1240,6 +1277,8 @@ macro_rules! skip_iterator {
         pub struct $iterator<'a: 'b, 'b, const FORMAT: u128> {
             /// The internal byte object for the skip iterator.
             byte: &'b mut Bytes<'a, FORMAT>,
+            /// The digit count when the iterator was created.
+            start: usize,
         }
     };
 }
@@ -1253,18 +1292,11 @@ macro_rules! is_sign {
 }
 
 macro_rules! is_digit_separator {
-    ($format:ident) => {
+    () => {
         /// Determine if the character is a digit separator.
-        pub const fn is_digit_separator(&self, value: u8) -> bool {
-            let format = NumberFormat::<{ $format }> {};
-            let digit_separator = format.digit_separator();
-            if digit_separator == 0 {
-                // Check at compile time if we have an invalid digit separator.
-                // b'\x00', or the NUL character, is this invalid value.
-                false
-            } else {
-                value == digit_separator
-            }
+        #[inline(always)]
+        pub fn is_digit_separator(&self, value: u8) -> bool {
+            self.byte.is_digit_separator(value)
         }
     };
 }
@@ -1274,14 +1306,28 @@ macro_rules! skip_iterator_impl {
     ($iterator:ident, $radix_cb:ident) => {
         impl<'a: 'b, 'b, const FORMAT: u128> $iterator<'a, 'b, FORMAT> {
             is_sign!();
-            is_digit_separator!(FORMAT);
+            is_digit_separator!();
 
             /// Create a new digits iterator from the bytes underlying item.
             #[inline(always)]
             pub fn new(byte: &'b mut Bytes<'a, FORMAT>) -> Self {
-                Self {
+                let mut iter = Self {
                     byte,
-                }
+                    start: 0,
+                };
+                iter.start = iter.current_count();
+                iter
+            }
+
+            /// Get the number of digits consumed by this iterator.
+            ///
+            /// Unlike the cursor, this excludes any skipped digit separators,
+            /// and only counts digits consumed since the iterator was created.
+            /// For contiguous iterators, nothing is skipped, so this is every
+            /// byte consumed.
+            #[inline(always)]
+            pub fn digits_consumed(&self) -> usize {
+                self.current_count() - self.start
             }
 
             /// Take the first N digits from the iterator.
@@ -1336,6 +1382,35 @@ macro_rules! skip_iterator_iterator_impl {
                 }
                 Some(value)
             }
+
+            /// Consume the iterator, returning the number of elements.
+            ///
+            /// If there are no digit separators remaining, nothing can be
+            /// skipped, so every remaining byte is returned and we can avoid
+            /// the skip logic in `next()`. Otherwise, we fall back to `next()`.
+            #[inline(always)]
+            fn count(mut self) -> usize {
+                let start = self.cursor();
+                let end = self.buffer_length();
+                let remaining = &self.get_buffer()[start..end];
+                if !Self::IS_CONTIGUOUS {
+                    if remaining.iter().any(|&c| self.is_digit_separator(c)) {
+                        let mut count = 0;
+                        while self.next().is_some() {
+                            count += 1;
+                        }
+                        return count;
+                    }
+                    for &c in remaining {
+                        if self.is_digit(c) {
+                            self.increment_count();
+                        }
+                    }
+                }
+                // SAFETY: safe, since `end` is the length of the buffer.
+                unsafe { self.set_cursor(end) };
+                end - start
+            }
         }
     };
 }
@@ -1541,15 +1616,16 @@ skip_iterator_bytesiter_impl!(
 // SPECIAL DIGITS ITERATOR
 // -----------------------
 
-skip_iterator!(
-    SpecialDigitsIterator,
-    "Iterator that skips over digit separators in special floats."
-);
+/// Iterator that skips over digit separators in special floats.
+pub struct SpecialDigitsIterator<'a: 'b, 'b, const FORMAT: u128> {
+    /// The internal byte object for the skip iterator.
+    byte: &'b mut Bytes<'a, FORMAT>,
+}
 skip_iterator_iterator_impl!(SpecialDigitsIterator);
 
 impl<'a: 'b, 'b, const FORMAT: u128> SpecialDigitsIterator<'a, 'b, FORMAT> {
     is_sign!();
-    is_digit_separator!(FORMAT);
+    is_digit_separator!();
 }
 
 unsafe impl<'a: 'b, 'b, const FORMAT: u128> Iter<'a> for SpecialDigitsIterator<'a, 'b, FORMAT> {
@@ -1575,10 +1651,12 @@ impl<'a: 'b, 'b, const FORMAT: u128> DigitsIter<'a> for SpecialDigitsIterator<'a
         }
     }
 
-    /// Determine if the character is a digit.
+    /// Determine if the character is a valid character in a special string.
+    ///
+    /// Special strings only contain ASCII letters, so this is independent
+    /// of the mantissa radix.
     #[inline(always)]
     fn is_digit(&self, value: u8) -> bool {
-        let format = NumberFormat::<{ FORMAT }> {};
-        char_is_digit_const(value, format.mantissa_radix())
+        value.is_ascii_alphabetic()
     }
 }
@@ -49,3 +49,13 @@ fn is_valid_letter_slice_test() {
     assert_eq!(ascii::is_valid_letter_slice(b" 09a"), false);
     assert_eq!(ascii::is_valid_letter_slice(b"aZAz"), true);
 }
+
+#[test]
+fn is_valid_prefix_slice_test() {
+    assert_eq!(ascii::is_valid_prefix_slice(b""), true);
+    assert_eq!(ascii::is_valid_prefix_slice(b"$"), true);
+    assert_eq!(ascii::is_valid_prefix_slice("\u{20ac}".as_bytes()), true);
+    assert_eq!(ascii::is_valid_prefix_slice(b"$1"), false);
+    assert_eq!(ascii::is_valid_prefix_slice(b"+"), false);
+    assert_eq!(ascii::is_valid_prefix_slice(b"-$"), false);
+}
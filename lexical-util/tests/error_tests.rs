@@ -0,0 +1,163 @@
+use core::fmt::{self, Write};
+
+use lexical_util::error::{shift_error, Error};
+
+/// Fixed-size writer, to format errors without allocation.
+struct StackWriter {
+    buffer: [u8; 128],
+    length: usize,
+}
+
+impl StackWriter {
+    const fn new() -> Self {
+        Self {
+            buffer: [0; 128],
+            length: 0,
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.buffer[..self.length]).unwrap()
+    }
+}
+
+impl Write for StackWriter {
+    fn write_str(&mut self, string: &str) -> fmt::Result {
+        let bytes = string.as_bytes();
+        let end = self.length + bytes.len();
+        if end > self.buffer.len() {
+            return Err(fmt::Error);
+        }
+        self.buffer[self.length..end].copy_from_slice(bytes);
+        self.length = end;
+        Ok(())
+    }
+}
+
+fn display(error: Error) -> StackWriter {
+    let mut writer = StackWriter::new();
+    write!(writer, "{}", error).unwrap();
+    writer
+}
+
+#[test]
+fn index_test() {
+    assert_eq!(Error::Overflow(3).index(), Some(3));
+    assert_eq!(Error::InvalidDigit(0).index(), Some(0));
+    assert_eq!(Error::EmptyExponent(12).index(), Some(12));
+    assert_eq!(Error::ChecksumMismatch(10).index(), Some(10));
+    assert_eq!(Error::InvalidDigitSeparator.index(), None);
+    assert_eq!(Error::InvalidRadix.index(), None);
+    assert_eq!(Error::Success.index(), None);
+
+    const INDEX: Option<usize> = Error::Underflow(5).index();
+    assert_eq!(INDEX, Some(5));
+}
+
+#[test]
+fn shift_error_test() {
+    assert_eq!(shift_error(Error::Overflow(3), 2), Error::Overflow(5));
+    assert_eq!(shift_error(Error::EmptyExponent(0), 4), Error::EmptyExponent(4));
+    assert_eq!(shift_error(Error::ChecksumMismatch(10), 1), Error::ChecksumMismatch(11));
+    assert_eq!(shift_error(Error::InvalidRadix, 2), Error::InvalidRadix);
+    assert_eq!(shift_error(Error::Success, 2), Error::Success);
+}
+
+#[test]
+fn message_test() {
+    #[rustfmt::skip]
+    let messages = [
+        (Error::Overflow(7), "Overflow: numeric overflow occurred"),
+        (Error::Underflow(7), "Underflow: numeric underflow occurred"),
+        (Error::InvalidDigit(7), "InvalidDigit: invalid digit found"),
+        (Error::Empty(7), "Empty: no digits found after the prefix or sign"),
+        (Error::EmptyMantissa(7), "EmptyMantissa: no significant digits found"),
+        (Error::EmptyExponent(7), "EmptyExponent: exponent notation found without an exponent"),
+        (Error::EmptyInteger(7), "EmptyInteger: invalid float with no integer digits"),
+        (Error::EmptyFraction(7), "EmptyFraction: invalid float with no fraction digits"),
+        (Error::InvalidPositiveMantissaSign(7), "InvalidPositiveMantissaSign: invalid `+` sign before significant digits"),
+        (Error::MissingMantissaSign(7), "MissingMantissaSign: missing required `+/-` sign for significant digits"),
+        (Error::InvalidExponent(7), "InvalidExponent: exponent found but not allowed"),
+        (Error::InvalidPositiveExponentSign(7), "InvalidPositiveExponentSign: invalid `+` sign in exponent"),
+        (Error::MissingExponentSign(7), "MissingExponentSign: missing required `+/-` sign for exponent"),
+        (Error::ExponentWithoutFraction(7), "ExponentWithoutFraction: invalid float containing exponent without fraction"),
+        (Error::InvalidLeadingZeros(7), "InvalidLeadingZeros: invalid number with leading zeros before digits"),
+        (Error::InvalidTrailingZeros(7), "InvalidTrailingZeros: invalid float with redundant trailing zeros in the fraction"),
+        (Error::MissingExponent(7), "MissingExponent: missing required exponent"),
+        (Error::MissingSign(7), "MissingSign: missing required `+/-` sign for integer"),
+        (Error::InvalidPositiveSign(7), "InvalidPositiveSign: invalid `+` sign for an integer was found"),
+        (Error::InvalidNegativeSign(7), "InvalidNegativeSign: invalid `-` sign for an unsigned type was found"),
+        (Error::ChecksumMismatch(7), "ChecksumMismatch: check digit does not match the checksum"),
+        (Error::InvalidMantissaRadix, "InvalidMantissaRadix: invalid radix for mantissa digits"),
+        (Error::InvalidExponentBase, "InvalidExponentBase: invalid exponent base"),
+        (Error::InvalidExponentRadix, "InvalidExponentRadix: invalid radix for exponent digits"),
+        (Error::InvalidDigitSeparator, "InvalidDigitSeparator: invalid digit separator: must be ASCII and not a digit or a `+/-` sign"),
+        (Error::InvalidDecimalPoint, "InvalidDecimalPoint: invalid decimal point: must be ASCII and not a digit or a `+/-` sign"),
+        (Error::InvalidExponentSymbol, "InvalidExponentSymbol: invalid exponent symbol: must be ASCII and not a digit or a `+/-` sign"),
+        (Error::InvalidBasePrefix, "InvalidBasePrefix: invalid base prefix character"),
+        (Error::InvalidBaseSuffix, "InvalidBaseSuffix: invalid base suffix character"),
+        (Error::InvalidPunctuation, "InvalidPunctuation: invalid punctuation: multiple characters overlap"),
+        (Error::InvalidExponentFlags, "InvalidExponentFlags: exponent flags set while disabling exponent notation"),
+        (Error::InvalidMantissaSign, "InvalidMantissaSign: disabled the `+` sign while requiring a sign for significant digits"),
+        (Error::InvalidExponentSign, "InvalidExponentSign: disabled the `+` sign while requiring a sign for exponent digits"),
+        (Error::InvalidSpecial, "InvalidSpecial: special flags set while disabling special floats"),
+        (Error::InvalidConsecutiveIntegerDigitSeparator, "InvalidConsecutiveIntegerDigitSeparator: enabled consecutive digit separators in the integer without setting a valid location"),
+        (Error::InvalidConsecutiveFractionDigitSeparator, "InvalidConsecutiveFractionDigitSeparator: enabled consecutive digit separators in the fraction without setting a valid location"),
+        (Error::InvalidConsecutiveExponentDigitSeparator, "InvalidConsecutiveExponentDigitSeparator: enabled consecutive digit separators in the exponent without setting a valid location"),
+        (Error::InvalidFlags, "InvalidFlags: invalid flags enabled without the format feature"),
+        (Error::InvalidNanString, "InvalidNanString: NaN string must start with `n`"),
+        (Error::NanStringTooLong, "NanStringTooLong: NaN string is too long"),
+        (Error::InvalidInfString, "InvalidInfString: short infinity string must start with `i`"),
+        (Error::InfStringTooLong, "InfStringTooLong: short infinity string is too long"),
+        (Error::InvalidInfinityString, "InvalidInfinityString: long infinity string must start with `i`"),
+        (Error::InfinityStringTooLong, "InfinityStringTooLong: long infinity string is too long"),
+        (Error::InfinityStringTooShort, "InfinityStringTooShort: long infinity string is too short"),
+        (Error::InvalidFloatParseAlgorithm, "InvalidFloatParseAlgorithm: invalid combination of float parse algorithms"),
+        (Error::InvalidRadix, "InvalidRadix: invalid radix for significant digits"),
+        (Error::InvalidFloatPrecision, "InvalidFloatPrecision: invalid float precision: min digits is larger than max digits"),
+        (Error::InvalidNegativeExponentBreak, "InvalidNegativeExponentBreak: invalid negative exponent break: value is above 0"),
+        (Error::InvalidPositiveExponentBreak, "InvalidPositiveExponentBreak: invalid positive exponent break: value is below 0"),
+        (Error::InvalidPadCharacter, "InvalidPadCharacter: invalid pad character for the integer digits"),
+        (Error::InvalidIntegerGrouping, "InvalidIntegerGrouping: invalid integer grouping: separator must be a space or non-sign punctuation"),
+        (Error::InvalidMagnitudeWords, "InvalidMagnitudeWords: invalid magnitude words: invalid or duplicate suffix, or exponent is above 19"),
+        (Error::InvalidIgnorePrefix, "InvalidIgnorePrefix: invalid ignore prefix: contains a digit or sign"),
+        (Error::InvalidIgnoreSuffix, "InvalidIgnoreSuffix: invalid ignore suffix: empty or starts with a digit"),
+        (Error::InvalidRadixIndicator, "InvalidRadixIndicator: invalid radix indicator: combined with digit separator, sign, or exponent options"),
+        (Error::FieldOverflow, "FieldOverflow: value has more digits than the fixed number of digits"),
+        (Error::Success, "Success: not actually an error"),
+    ];
+    for (error, message) in messages {
+        assert_eq!(error.message(), message);
+        assert!(message.ends_with(error.description().trim_matches('\'')));
+    }
+
+    const MESSAGE: &str = Error::EmptyExponent(4).message();
+    assert_eq!(MESSAGE, "EmptyExponent: exponent notation found without an exponent");
+}
+
+#[test]
+fn display_test() {
+    assert_eq!(
+        display(Error::InvalidDigit(3)).as_str(),
+        "lexical parse error: 'invalid digit found' at index 3"
+    );
+    assert_eq!(
+        display(Error::Overflow(19)).as_str(),
+        "lexical parse error: 'numeric overflow occurred' at index 19"
+    );
+    assert_eq!(
+        display(Error::InvalidMantissaRadix).as_str(),
+        "lexical number format error: 'invalid radix for mantissa digits'"
+    );
+    assert_eq!(
+        display(Error::InvalidRadix).as_str(),
+        "lexical options error: 'invalid radix for significant digits'"
+    );
+}
+
+#[test]
+#[should_panic(expected = "lexical parse error: 'invalid digit found' at index 3")]
+fn display_panic_test() {
+    let error = Error::InvalidDigit(3);
+    panic!("{}", error);
+}
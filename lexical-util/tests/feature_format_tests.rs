@@ -239,3 +239,37 @@ fn constants_test() {
     let _: u128 = format::MYSQL;
     let _: u128 = format::MONGODB;
 }
+
+#[test]
+fn validate_format_test() {
+    use lexical_util::error::Error;
+
+    let validate = |builder: format::NumberFormatBuilder| {
+        format::validate_format(builder.build_unchecked())
+    };
+    let builder = format::NumberFormatBuilder::new;
+    assert_eq!(validate(builder()), Ok(()));
+    assert_eq!(format::validate_format(format::IGNORE), Ok(()));
+    assert_eq!(format::validate_format(format::JSON), Ok(()));
+
+    let invalid = validate(builder().no_exponent_notation(true).required_exponent_notation(true));
+    assert_eq!(invalid, Err(Error::InvalidExponentFlags));
+    let invalid = validate(builder().no_positive_mantissa_sign(true).required_mantissa_sign(true));
+    assert_eq!(invalid, Err(Error::InvalidMantissaSign));
+    let invalid = validate(builder().no_positive_exponent_sign(true).required_exponent_sign(true));
+    assert_eq!(invalid, Err(Error::InvalidExponentSign));
+    let invalid = validate(builder().no_special(true).case_sensitive_special(true));
+    assert_eq!(invalid, Err(Error::InvalidSpecial));
+    let invalid = validate(
+        builder()
+            .digit_separator(num::NonZeroU8::new(b'1'))
+            .integer_internal_digit_separator(true),
+    );
+    assert_eq!(invalid, Err(Error::InvalidDigitSeparator));
+    let invalid = validate(
+        builder()
+            .digit_separator(num::NonZeroU8::new(b'_'))
+            .integer_consecutive_digit_separator(true),
+    );
+    assert_eq!(invalid, Err(Error::InvalidConsecutiveIntegerDigitSeparator));
+}
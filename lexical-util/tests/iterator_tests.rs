@@ -113,3 +113,245 @@ fn skip_iterator_test() {
     assert_eq!(iter.as_slice(), &digits[digits.len()..]);
     assert_eq!(iter.as_ptr(), digits[digits.len()..].as_ptr());
 }
+
+#[test]
+#[cfg(not(feature = "format"))]
+fn digits_iterator_slice_test() {
+    use lexical_util::format::STANDARD;
+
+    let digits = b"1e+5";
+    let mut byte = digits.bytes::<{ STANDARD }>();
+    let mut iter = byte.integer_iter();
+    assert!(iter.peek_is_slice(b"1e", true));
+    assert!(iter.peek_is_slice(b"1E", false));
+    assert!(!iter.peek_is_slice(b"1E", true));
+    assert!(!iter.peek_is_slice(b"1e+56", true));
+    assert!(iter.peek_is_slice(b"", true));
+    assert_eq!(iter.cursor(), 0);
+
+    assert!(!iter.read_if_slice(b"1e-", true));
+    assert_eq!(iter.cursor(), 0);
+    assert!(iter.read_if_slice(b"1E", false));
+    assert_eq!(iter.cursor(), 2);
+    assert_eq!(iter.peek(), Some(&b'+'));
+    assert!(iter.read_if_slice(b"+5", true));
+    assert!(iter.is_buffer_empty());
+    assert!(!iter.peek_is_slice(b"5", true));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn skip_iterator_slice_test() {
+    use core::num;
+
+    use lexical_util::format::NumberFormatBuilder;
+
+    pub const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(num::NonZeroU8::new(b'_'))
+        .digit_separator_flags(true)
+        .build_strict();
+
+    let digits = b"_1__2_3_";
+    let mut byte = digits.bytes::<{ FORMAT }>();
+    let mut iter = byte.integer_iter();
+    assert!(iter.peek_is_slice(b"12", true));
+    assert!(iter.peek_is_slice(b"123", true));
+    assert!(!iter.peek_is_slice(b"124", true));
+    assert!(!iter.peek_is_slice(b"1234", true));
+    assert_eq!(iter.cursor(), 0);
+    assert_eq!(iter.current_count(), 0);
+
+    assert!(!iter.read_if_slice(b"13", true));
+    assert_eq!(iter.cursor(), 0);
+    assert!(iter.read_if_slice(b"12", true));
+    assert_eq!(iter.cursor(), 5);
+    assert_eq!(iter.current_count(), 0);
+    assert!(!iter.read_if_slice(b"34", true));
+    assert_eq!(iter.cursor(), 5);
+    assert!(iter.read_if_slice(b"3", true));
+    assert_eq!(iter.peek(), None);
+    assert!(iter.is_buffer_empty());
+
+    let special = b"i_N_f";
+    let mut byte = special.bytes::<{ FORMAT }>();
+    let mut iter = byte.special_iter();
+    assert!(iter.peek_is_slice(b"inf", false));
+    assert!(!iter.peek_is_slice(b"inf", true));
+    assert!(iter.read_if_slice(b"INF", false));
+    assert!(iter.is_buffer_empty());
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn skip_iterator_digit_separators_test() {
+    use core::num;
+
+    use lexical_util::format::NumberFormatBuilder;
+
+    pub const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(num::NonZeroU8::new(b'_'))
+        .digit_separator_flags(true)
+        .build_strict();
+
+    let digits = b"1_2 3, 4";
+    let mut byte = digits.bytes::<{ FORMAT }>();
+    assert!(byte.is_digit_separator(b'_'));
+    assert!(!byte.is_digit_separator(b' '));
+    assert_eq!(byte.integer_iter().copied().collect::<Vec<u8>>(), b"12 3, 4");
+
+    let mut byte = digits.bytes::<{ FORMAT }>().with_digit_separators(b" ,");
+    assert!(byte.is_digit_separator(b' '));
+    assert!(byte.is_digit_separator(b','));
+    assert_eq!(byte.integer_iter().copied().collect::<Vec<u8>>(), b"1234");
+
+    // Extra separators are ignored without a digit separator in the format.
+    let byte = digits.bytes::<{ lexical_util::format::STANDARD }>().with_digit_separators(b" ");
+    assert!(!byte.is_digit_separator(b' '));
+}
+
+#[test]
+#[cfg(not(feature = "format"))]
+fn digits_iterator_digits_consumed_test() {
+    use lexical_util::format::STANDARD;
+
+    let digits = b"123.45";
+    let mut byte = digits.bytes::<{ STANDARD }>();
+    let mut iter = byte.integer_iter();
+    assert_eq!(iter.digits_consumed(), 0);
+    iter.next();
+    iter.next();
+    assert_eq!(iter.digits_consumed(), 2);
+    assert_eq!(iter.cursor(), 2);
+
+    // Only count from where the iterator was created.
+    unsafe { byte.set_cursor(4) };
+    let mut iter = byte.fraction_iter();
+    assert_eq!(iter.digits_consumed(), 0);
+    iter.next();
+    assert_eq!(iter.digits_consumed(), 1);
+    assert_eq!(iter.cursor(), 5);
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn skip_iterator_digits_consumed_test() {
+    use core::num;
+
+    use lexical_util::format::NumberFormatBuilder;
+
+    pub const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(num::NonZeroU8::new(b'_'))
+        .digit_separator_flags(true)
+        .build_strict();
+
+    let digits = b"1_2_3";
+    let mut byte = digits.bytes::<{ FORMAT }>();
+    let mut iter = byte.integer_iter();
+    assert_eq!(iter.digits_consumed(), 0);
+    assert_eq!(iter.next(), Some(&b'1'));
+    assert_eq!(iter.next(), Some(&b'2'));
+    assert_eq!(iter.digits_consumed(), 2);
+    assert_eq!(iter.cursor(), 3);
+    assert_eq!(iter.next(), Some(&b'3'));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.digits_consumed(), 3);
+    assert_eq!(iter.cursor(), 5);
+
+    let digits = b"1__2__3";
+    let mut byte = digits.bytes::<{ FORMAT }>();
+    let mut iter = byte.integer_iter();
+    while iter.next().is_some() {}
+    assert_eq!(iter.digits_consumed(), 3);
+    assert_eq!(iter.cursor(), 7);
+
+    // Iterators over the same component only count their own digits.
+    let digits = b"1_2_3";
+    let mut byte = digits.bytes::<{ FORMAT }>();
+    byte.integer_iter().next();
+    let mut iter = byte.integer_iter();
+    assert_eq!(iter.digits_consumed(), 0);
+    iter.next();
+    assert_eq!(iter.digits_consumed(), 1);
+    assert_eq!(iter.current_count(), 2);
+}
+
+/// Pack the digits in little-endian order, as a scalar reference.
+fn pack_digits(digits: &[u8]) -> u64 {
+    digits.iter().rev().fold(0, |acc, &digit| (acc << 8) | digit as u64)
+}
+
+#[test]
+#[cfg(not(feature = "format"))]
+fn digits_iterator_read_u64_test() {
+    use lexical_util::format::STANDARD;
+
+    let digits = b"123456789";
+    let mut byte = digits.bytes::<{ STANDARD }>();
+    let mut iter = byte.integer_iter();
+    let expected = u64::from_le(iter.peek_u64().unwrap());
+    assert_eq!(expected, pack_digits(b"12345678"));
+    assert_eq!(iter.read_u64(), Some(expected));
+    assert_eq!(iter.cursor(), 8);
+    assert_eq!(iter.read_u64(), None);
+    assert_eq!(iter.read_u32(), None);
+    assert_eq!(iter.cursor(), 8);
+
+    let digits = b"1234.567";
+    let mut byte = digits.bytes::<{ STANDARD }>();
+    let mut iter = byte.integer_iter();
+    assert_eq!(iter.read_u64(), None);
+    assert_eq!(iter.cursor(), 0);
+    assert_eq!(iter.read_u32(), Some(pack_digits(b"1234") as u32));
+    assert_eq!(iter.cursor(), 4);
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn skip_iterator_read_u64_test() {
+    use core::num;
+
+    use lexical_util::format::NumberFormatBuilder;
+
+    pub const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(num::NonZeroU8::new(b'_'))
+        .integer_internal_digit_separator(true)
+        .build_strict();
+
+    let digits = b"1_2_3_4_5_6_7_8";
+    let mut byte = digits.bytes::<{ FORMAT }>();
+    let mut iter = byte.integer_iter();
+    assert_eq!(iter.peek_u64(), None);
+    assert_eq!(iter.read_u64(), Some(pack_digits(b"12345678")));
+    assert_eq!(iter.cursor(), digits.len());
+    assert_eq!(iter.current_count(), 8);
+    assert_eq!(iter.read_u64(), None);
+
+    // Fewer than 8 digits restores the cursor and count.
+    let digits = b"1_2_3_4_5_6_7";
+    let mut byte = digits.bytes::<{ FORMAT }>();
+    let mut iter = byte.integer_iter();
+    assert_eq!(iter.read_u64(), None);
+    assert_eq!(iter.cursor(), 0);
+    assert_eq!(iter.current_count(), 0);
+    assert_eq!(iter.read_u32(), Some(pack_digits(b"1234") as u32));
+    assert_eq!(iter.cursor(), 7);
+    assert_eq!(iter.current_count(), 4);
+    let rest: Vec<u8> = iter.copied().collect();
+    assert_eq!(rest, b"567");
+
+    // Invalid separators are not skipped, and end the digits.
+    let digits = b"1234__5678";
+    let mut byte = digits.bytes::<{ FORMAT }>();
+    let mut iter = byte.integer_iter();
+    assert_eq!(iter.read_u64(), None);
+    assert_eq!(iter.cursor(), 0);
+    assert_eq!(iter.read_u32(), Some(pack_digits(b"1234") as u32));
+    assert_eq!(iter.read_u32(), None);
+
+    // The gathered digits match the contiguous digits.
+    let digits = b"98_765_432_1";
+    let mut byte = digits.bytes::<{ FORMAT }>();
+    let mut iter = byte.integer_iter();
+    assert_eq!(iter.read_u64(), Some(pack_digits(b"98765432")));
+    assert_eq!(iter.next(), Some(&b'1'));
+}
@@ -252,3 +252,54 @@ fn float_test() {
     assert_eq!(f.prev_positive().next_positive(), f);
     assert_ne!(f.round_positive_even(), f.next());
 }
+
+#[test]
+#[cfg(any(feature = "parse-floats", feature = "write-floats"))]
+fn signaling_nan_test() {
+    use lexical_util::num::Float;
+
+    assert_eq!(f32::signaling_nan().to_bits(), 0x7FA0_0000);
+    assert_eq!(f64::signaling_nan().to_bits(), 0x7FF4_0000_0000_0000);
+    assert!(f32::signaling_nan().is_signaling_nan());
+    assert!(f64::signaling_nan().is_signaling_nan());
+    assert!(f64::from_bits(0x7FF0_0000_0000_0001).is_signaling_nan());
+    assert!(f64::from_bits(0xFFF4_0000_0000_0000).is_signaling_nan());
+    assert!(!f32::NAN.is_signaling_nan());
+    assert!(!f64::NAN.is_signaling_nan());
+    assert!(!f64::INFINITY.is_signaling_nan());
+    assert!(!1.0f64.is_signaling_nan());
+}
+
+#[test]
+#[cfg(any(feature = "parse-floats", feature = "write-floats"))]
+fn decimal_sci_exp_test() {
+    use lexical_util::num::Float;
+
+    assert_eq!(1.0f64.decimal_sci_exp(), 0);
+    assert_eq!(9.9f64.decimal_sci_exp(), 0);
+    assert_eq!(10.0f64.decimal_sci_exp(), 1);
+    assert_eq!(100.0f64.decimal_sci_exp(), 2);
+    assert_eq!(99.99f64.decimal_sci_exp(), 1);
+    assert_eq!(0.001f64.decimal_sci_exp(), -3);
+    assert_eq!(0.001f64.prev().decimal_sci_exp(), -4);
+    assert_eq!((-0.001f64).decimal_sci_exp(), -3);
+    assert_eq!(1e22f64.decimal_sci_exp(), 22);
+    assert_eq!(1e23f64.decimal_sci_exp(), 23);
+    assert_eq!(1e23f64.prev().decimal_sci_exp(), 22);
+    assert_eq!(f64::MAX.decimal_sci_exp(), 308);
+    assert_eq!(f64::MIN_POSITIVE.decimal_sci_exp(), -308);
+    assert_eq!(5e-324f64.decimal_sci_exp(), -324);
+    assert_eq!(1e-323f64.decimal_sci_exp(), -323);
+    assert_eq!(3e-320f64.decimal_sci_exp(), -320);
+    assert_eq!(0.0f64.decimal_sci_exp(), 0);
+    assert_eq!(f64::INFINITY.decimal_sci_exp(), 0);
+    assert_eq!(f64::NAN.decimal_sci_exp(), 0);
+
+    assert_eq!(1.0f32.decimal_sci_exp(), 0);
+    assert_eq!(9.9f32.decimal_sci_exp(), 0);
+    assert_eq!(100.0f32.decimal_sci_exp(), 2);
+    assert_eq!(0.001f32.decimal_sci_exp(), -3);
+    assert_eq!(f32::MAX.decimal_sci_exp(), 38);
+    assert_eq!(1e-45f32.decimal_sci_exp(), -45);
+    assert_eq!(1e-40f32.decimal_sci_exp(), -40);
+}
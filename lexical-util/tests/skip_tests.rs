@@ -3,7 +3,7 @@
 use core::num;
 
 use lexical_util::format::NumberFormatBuilder;
-use lexical_util::iterator::AsBytes;
+use lexical_util::iterator::{AsBytes, DigitsIter, Iter};
 
 fn skip_iter_eq<const FORMAT: u128>(input: &[u8], output: &[u8]) {
     // next is done in terms of peek, so we're safe here.
@@ -588,3 +588,279 @@ fn test_skip_iter_iltc() {
     skip_iter_eq::<{ FORMAT }>(b"_4_5_.56", b"45.56");
     skip_iter_eq::<{ FORMAT }>(b"__4__5__.56", b"45.56");
 }
+
+/// Get the digits consumed by the integer iterator, and the final cursor.
+fn skip_iter_digits<const FORMAT: u128>(input: &[u8]) -> (Vec<u8>, usize) {
+    let mut byte = input.bytes::<{ FORMAT }>();
+    let mut iter = byte.integer_iter();
+    let mut digits = Vec::new();
+    while let Some(&c) = iter.peek() {
+        if !c.is_ascii_digit() {
+            break;
+        }
+        digits.push(c);
+        iter.next();
+    }
+    (digits, iter.cursor())
+}
+
+/// Get the digits consumed by the integer iterator after an optional sign,
+/// and the final cursor.
+fn skip_iter_signed_digits<const FORMAT: u128>(input: &[u8]) -> (Vec<u8>, usize) {
+    let mut byte = input.bytes::<{ FORMAT }>();
+    let mut iter = byte.integer_iter();
+    if let Some(b'+' | b'-') = iter.peek() {
+        iter.next();
+    }
+    let mut digits = Vec::new();
+    while let Some(&c) = iter.peek() {
+        if !c.is_ascii_digit() {
+            break;
+        }
+        digits.push(c);
+        iter.next();
+    }
+    (digits, iter.cursor())
+}
+
+const fn skip_format(internal: bool, leading: bool, trailing: bool, consecutive: bool) -> u128 {
+    NumberFormatBuilder::new()
+        .digit_separator(num::NonZeroU8::new(b'_'))
+        .integer_internal_digit_separator(internal)
+        .integer_leading_digit_separator(leading)
+        .integer_trailing_digit_separator(trailing)
+        .integer_consecutive_digit_separator(consecutive)
+        .build_strict()
+}
+
+/// Check each row of a truth table, with the expected digits and cursor
+/// for each format, in the same order as the formats.
+macro_rules! truth_table {
+    ($formats:tt { $($input:literal => $expected:tt,)* }) => {
+        $(truth_table!(@row skip_iter_digits $formats $input $expected);)*
+    };
+
+    (signed $formats:tt { $($input:literal => $expected:tt,)* }) => {
+        $(truth_table!(@row skip_iter_signed_digits $formats $input $expected);)*
+    };
+
+    (@row
+        $digits_fn:ident
+        [$($format:ident),*]
+        $input:literal
+        [$(($digits:literal, $cursor:literal)),*]
+    ) => {{
+        let mut expected = [$((&$digits[..], $cursor)),*].into_iter();
+        $(
+            let (digits, cursor) = expected.next().unwrap();
+            let actual = $digits_fn::<{ $format }>($input);
+            assert_eq!(
+                (actual.0.as_slice(), actual.1),
+                (digits, cursor),
+                "input={:?}, format={}",
+                String::from_utf8_lossy($input),
+                stringify!($format),
+            );
+        )*
+    }};
+}
+
+#[test]
+fn test_skip_iter_truth_table() {
+    // A lone separator, with no digits on either side, is both leading and
+    // trailing, but never internal. Adjacent separators are never skipped
+    // without the consecutive flag, including for all-separator buffers.
+    const I: u128 = skip_format(true, false, false, false);
+    const L: u128 = skip_format(false, true, false, false);
+    const T: u128 = skip_format(false, false, true, false);
+    const IL: u128 = skip_format(true, true, false, false);
+    const IT: u128 = skip_format(true, false, true, false);
+    const LT: u128 = skip_format(false, true, true, false);
+    const ILT: u128 = skip_format(true, true, true, false);
+
+    truth_table!([I, L, T, IL, IT, LT, ILT] {
+        // lone
+        b"_" => [(b"", 0), (b"", 1), (b"", 1), (b"", 1), (b"", 1), (b"", 1), (b"", 1)],
+        b"_." => [(b"", 0), (b"", 1), (b"", 1), (b"", 1), (b"", 1), (b"", 1), (b"", 1)],
+        // all separators
+        b"__" => [(b"", 0), (b"", 0), (b"", 0), (b"", 0), (b"", 0), (b"", 0), (b"", 0)],
+        b"___" => [(b"", 0), (b"", 0), (b"", 0), (b"", 0), (b"", 0), (b"", 0), (b"", 0)],
+        // leading
+        b"_1" => [(b"", 0), (b"1", 2), (b"", 0), (b"1", 2), (b"", 0), (b"1", 2), (b"1", 2)],
+        b"_.1" => [(b"", 0), (b"", 1), (b"", 1), (b"", 1), (b"", 1), (b"", 1), (b"", 1)],
+        b"__1" => [(b"", 0), (b"", 0), (b"", 0), (b"", 0), (b"", 0), (b"", 0), (b"", 0)],
+        // internal
+        b"1_1" => [(b"11", 3), (b"1", 1), (b"1", 1), (b"11", 3), (b"11", 3), (b"1", 1), (b"11", 3)],
+        b"1__1" => [(b"1", 1), (b"1", 1), (b"1", 1), (b"1", 1), (b"1", 1), (b"1", 1), (b"1", 1)],
+        // trailing
+        b"1_" => [(b"1", 1), (b"1", 1), (b"1", 2), (b"1", 1), (b"1", 2), (b"1", 2), (b"1", 2)],
+        b"1_." => [(b"1", 1), (b"1", 1), (b"1", 2), (b"1", 1), (b"1", 2), (b"1", 2), (b"1", 2)],
+        b"1__" => [(b"1", 1), (b"1", 1), (b"1", 1), (b"1", 1), (b"1", 1), (b"1", 1), (b"1", 1)],
+        // mixed
+        b"_1_" => [(b"", 0), (b"1", 2), (b"", 0), (b"1", 2), (b"", 0), (b"1", 3), (b"1", 3)],
+        b"1_1_" => [(b"11", 3), (b"1", 1), (b"1", 1), (b"11", 3), (b"11", 4), (b"1", 1), (b"11", 4)],
+        b"__1__" => [(b"", 0), (b"", 0), (b"", 0), (b"", 0), (b"", 0), (b"", 0), (b"", 0)],
+    });
+}
+
+#[test]
+fn test_skip_iter_consecutive_truth_table() {
+    // A lone run of separators is both leading and trailing, but never
+    // internal.
+    const IC: u128 = skip_format(true, false, false, true);
+    const LC: u128 = skip_format(false, true, false, true);
+    const TC: u128 = skip_format(false, false, true, true);
+    const ILC: u128 = skip_format(true, true, false, true);
+    const ITC: u128 = skip_format(true, false, true, true);
+    const LTC: u128 = skip_format(false, true, true, true);
+    const ILTC: u128 = skip_format(true, true, true, true);
+
+    truth_table!([IC, LC, TC, ILC, ITC, LTC, ILTC] {
+        // lone
+        b"_" => [(b"", 0), (b"", 1), (b"", 1), (b"", 1), (b"", 1), (b"", 1), (b"", 1)],
+        b"_." => [(b"", 0), (b"", 1), (b"", 1), (b"", 1), (b"", 1), (b"", 1), (b"", 1)],
+        // all separators
+        b"__" => [(b"", 0), (b"", 2), (b"", 2), (b"", 2), (b"", 2), (b"", 2), (b"", 2)],
+        b"___" => [(b"", 0), (b"", 3), (b"", 3), (b"", 3), (b"", 3), (b"", 3), (b"", 3)],
+        // leading
+        b"_1" => [(b"", 0), (b"1", 2), (b"", 0), (b"1", 2), (b"", 0), (b"1", 2), (b"1", 2)],
+        b"_.1" => [(b"", 0), (b"", 1), (b"", 1), (b"", 1), (b"", 1), (b"", 1), (b"", 1)],
+        b"__1" => [(b"", 0), (b"1", 3), (b"", 0), (b"1", 3), (b"", 0), (b"1", 3), (b"1", 3)],
+        // internal
+        b"1_1" => [(b"11", 3), (b"1", 1), (b"1", 1), (b"11", 3), (b"11", 3), (b"1", 1), (b"11", 3)],
+        b"1__1" => [(b"11", 4), (b"1", 1), (b"1", 1), (b"11", 4), (b"11", 4), (b"1", 1), (b"11", 4)],
+        // trailing
+        b"1_" => [(b"1", 1), (b"1", 1), (b"1", 2), (b"1", 1), (b"1", 2), (b"1", 2), (b"1", 2)],
+        b"1_." => [(b"1", 1), (b"1", 1), (b"1", 2), (b"1", 1), (b"1", 2), (b"1", 2), (b"1", 2)],
+        b"1__" => [(b"1", 1), (b"1", 1), (b"1", 3), (b"1", 1), (b"1", 3), (b"1", 3), (b"1", 3)],
+        // mixed
+        b"_1_" => [(b"", 0), (b"1", 2), (b"", 0), (b"1", 2), (b"", 0), (b"1", 3), (b"1", 3)],
+        b"1_1_" => [(b"11", 3), (b"1", 1), (b"1", 1), (b"11", 3), (b"11", 4), (b"1", 1), (b"11", 4)],
+        b"__1__" => [(b"", 0), (b"1", 3), (b"", 0), (b"1", 3), (b"", 0), (b"1", 5), (b"1", 5)],
+    });
+}
+
+#[test]
+fn test_skip_iter_signed_truth_table() {
+    // The sign is consumed before the digit separators are classified, so
+    // a separator directly after the sign is leading, not internal.
+    const I: u128 = skip_format(true, false, false, false);
+    const L: u128 = skip_format(false, true, false, false);
+    const IL: u128 = skip_format(true, true, false, false);
+    const ILC: u128 = skip_format(true, true, false, true);
+
+    truth_table!(signed [I, L, IL, ILC] {
+        b"-1_000" => [(b"1000", 6), (b"1", 2), (b"1000", 6), (b"1000", 6)],
+        b"+1_000" => [(b"1000", 6), (b"1", 2), (b"1000", 6), (b"1000", 6)],
+        b"-_1000" => [(b"", 1), (b"1000", 6), (b"1000", 6), (b"1000", 6)],
+        b"-__1000" => [(b"", 1), (b"", 1), (b"", 1), (b"1000", 7)],
+        b"-_1_000" => [(b"", 1), (b"1", 3), (b"1000", 7), (b"1000", 7)],
+        b"-_" => [(b"", 1), (b"", 2), (b"", 2), (b"", 2)],
+    });
+}
+
+#[test]
+fn test_skip_iter_take_n_boundary() {
+    // The integer digits are contiguous, so `take_n` is supported, but the
+    // fraction skips consecutive digit separators.
+    pub const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(num::NonZeroU8::new(b'_'))
+        .fraction_internal_digit_separator(true)
+        .fraction_trailing_digit_separator(true)
+        .fraction_consecutive_digit_separator(true)
+        .build_strict();
+
+    let input = b"1.2__3";
+    let mut byte = input.bytes::<{ FORMAT }>();
+    let mut sub = byte.integer_iter().take_n(4).unwrap();
+    assert_eq!(sub.get_buffer(), b"1.2_");
+    assert_eq!(byte.cursor(), 4);
+
+    // The separators at the boundary must not consume past the sub-buffer.
+    assert_eq!(sub.integer_iter().next(), Some(&b'1'));
+    // SAFETY: safe, since the next byte is the decimal point.
+    unsafe { sub.step_unchecked() };
+    let mut fraction = sub.fraction_iter();
+    assert_eq!(fraction.next(), Some(&b'2'));
+    assert_eq!(fraction.peek(), None);
+    assert_eq!(fraction.cursor(), 4);
+    assert!(fraction.is_buffer_empty());
+
+    // The full buffer consumes the internal separators.
+    let mut byte = input.bytes::<{ FORMAT }>();
+    assert_eq!(byte.integer_iter().next(), Some(&b'1'));
+    // SAFETY: safe, since the next byte is the decimal point.
+    unsafe { byte.step_unchecked() };
+    assert!(byte.fraction_iter().eq([b'2', b'3'].iter()));
+    assert_eq!(byte.cursor(), 6);
+}
+
+#[test]
+fn test_skip_iter_count() {
+    const I: u128 = skip_format(true, false, false, false);
+    const ILTC: u128 = skip_format(true, true, true, true);
+
+    // The digit separators are never counted.
+    let mut byte = b"1_2_3".bytes::<{ I }>();
+    assert_eq!(byte.integer_iter().count(), 3);
+    assert_eq!(byte.cursor(), 5);
+    assert_eq!(byte.integer_iter().current_count(), 3);
+
+    // Unskipped separators are counted, just like with `next()`.
+    for input in [&b"1_2_3"[..], b"123", b"1__2", b"_1_", b"__1__.2_3", b"", b"_"] {
+        let mut byte = input.bytes::<{ I }>();
+        let mut manual = input.bytes::<{ I }>();
+        let mut iter = manual.integer_iter();
+        let mut expected = 0;
+        while iter.next().is_some() {
+            expected += 1;
+        }
+        assert_eq!(byte.integer_iter().count(), expected);
+        assert_eq!(byte.cursor(), manual.cursor());
+
+        let mut byte = input.bytes::<{ ILTC }>();
+        let mut manual = input.bytes::<{ ILTC }>();
+        let mut iter = manual.integer_iter();
+        let mut expected = 0;
+        while iter.next().is_some() {
+            expected += 1;
+        }
+        assert_eq!(byte.integer_iter().count(), expected);
+        assert_eq!(byte.cursor(), manual.cursor());
+        assert_eq!(byte.integer_iter().current_count(), manual.integer_iter().current_count());
+    }
+
+    // Contiguous iterators count the remaining bytes.
+    const FRACTION: u128 = NumberFormatBuilder::new()
+        .digit_separator(num::NonZeroU8::new(b'_'))
+        .fraction_internal_digit_separator(true)
+        .build_strict();
+    let mut byte = b"12.3_4".bytes::<{ FRACTION }>();
+    assert_eq!(byte.integer_iter().count(), 6);
+    assert_eq!(byte.cursor(), 6);
+    let mut byte = b"3_4".bytes::<{ FRACTION }>();
+    assert_eq!(byte.fraction_iter().count(), 2);
+}
+
+#[test]
+fn test_skip_iter_special() {
+    const MANTISSA: u128 = skip_format(true, false, false, false);
+    const SPECIAL: u128 = NumberFormatBuilder::rebuild(MANTISSA)
+        .special_digit_separator(true)
+        .build_strict();
+
+    // Separators are only skipped in special values with the special flag.
+    let mut byte = b"i_nf".bytes::<{ MANTISSA }>();
+    assert!(byte.special_iter().eq(b"i_nf".iter()));
+    let mut byte = b"i_nf".bytes::<{ SPECIAL }>();
+    assert!(byte.special_iter().eq(b"inf".iter()));
+    let mut byte = b"1_0".bytes::<{ MANTISSA }>();
+    assert!(byte.integer_iter().eq(b"10".iter()));
+
+    // Special values are letters, even if they are not digits in the radix.
+    let mut byte = b"inf".bytes::<{ SPECIAL }>();
+    let iter = byte.special_iter();
+    assert!(iter.is_digit(b'i'));
+    assert!(iter.is_digit(b'N'));
+    assert!(!iter.is_digit(b'1'));
+}
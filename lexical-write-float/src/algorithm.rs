@@ -117,9 +117,12 @@ pub fn write_float_scientific<F: DragonboxFloat, const FORMAT: u128>(
         cursor = digit_count + 1;
     }
 
+    // Shift the decimal point if using engineering notation.
+    let sci_exp = shared::shift_engineering::<FORMAT>(bytes, &mut cursor, sci_exp, options);
+
     // Now, write our scientific notation.
     // Won't panic since bytes must be large enough to store all digits.
-    shared::write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp, options.exponent());
+    shared::write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp, options);
 
     cursor
 }
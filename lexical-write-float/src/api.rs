@@ -6,9 +6,19 @@
 use lexical_util::bf16::bf16;
 #[cfg(feature = "f16")]
 use lexical_util::f16::f16;
-use lexical_util::format::STANDARD;
+use lexical_util::digit::char_to_digit_const;
+use lexical_util::error::NeedCapacity;
+use lexical_util::format::{NumberFormat, STANDARD};
+use lexical_util::num::Float;
+#[cfg(feature = "alloc")]
+use lexical_util::{digit::digit_to_char, num::AsPrimitive};
 use lexical_util::{to_lexical, to_lexical_with_options};
 
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+#[cfg(feature = "std")]
+use crate::options::ThreadLocalOptions;
 use crate::options::Options;
 use crate::write::WriteFloat;
 
@@ -56,3 +66,517 @@ float_to_lexical! {
     f16 ;
     bf16 ;
 }
+
+// TRY
+
+/// Size of the intermediate buffer used to calculate the exact length.
+///
+/// This is large enough for any float using the default options, including
+/// fixed notation with power-of-two radixes, so only esoteric options, such
+/// as a very large number of minimum significant digits, exceed it.
+const SCRATCH_SIZE: usize = 2048;
+
+/// Write a float to a buffer, or return the required capacity.
+///
+/// Unlike [`to_lexical`][ToLexical::to_lexical], this does not panic if the
+/// buffer is too small, and instead returns the exact number of bytes
+/// required to write the value. The buffer is not modified on error.
+///
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Buffer to write number to.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_write_float::{try_to_lexical, NeedCapacity};
+///
+/// let mut buffer = [0u8; 4];
+/// assert_eq!(try_to_lexical(-1.25f64, &mut buffer), Err(NeedCapacity(5)));
+/// assert_eq!(try_to_lexical(1.25f64, &mut buffer), Ok(4));
+/// assert_eq!(&buffer, b"1.25");
+/// ```
+#[inline]
+pub fn try_to_lexical<T>(value: T, bytes: &mut [u8]) -> Result<usize, NeedCapacity>
+where
+    T: ToLexicalWithOptions<Options = Options>,
+{
+    try_to_lexical_with_options::<T, { STANDARD }>(value, bytes, &DEFAULT_OPTIONS)
+}
+
+/// Write a float to a buffer with custom options, or return the required
+/// capacity.
+///
+/// Unlike [`to_lexical_with_options`][ToLexicalWithOptions::to_lexical_with_options],
+/// this does not panic if the buffer is too small, and instead returns the
+/// number of bytes required to write the value. The buffer is not modified
+/// on error.
+///
+/// The required capacity is exact, unless [`Options::buffer_size_const`]
+/// is larger than 2048 bytes, in which case it is the same upper bound.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Buffer to write number to.
+/// * `options` - Options to customize number writing.
+///
+/// # Panics
+///
+/// Panics if the number format or options are invalid, as with
+/// [`to_lexical_with_options`][ToLexicalWithOptions::to_lexical_with_options].
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_write_float::{try_to_lexical_with_options, NeedCapacity, Options};
+/// use lexical_write_float::format::STANDARD;
+///
+/// const OPTIONS: Options = Options::builder().trim_floats(true).build_strict();
+/// let mut buffer = [0u8; 2];
+/// let result = try_to_lexical_with_options::<_, STANDARD>(100.0f64, &mut buffer, &OPTIONS);
+/// assert_eq!(result, Err(NeedCapacity(3)));
+///
+/// let result = try_to_lexical_with_options::<_, STANDARD>(10.0f64, &mut buffer, &OPTIONS);
+/// assert_eq!(result, Ok(2));
+/// assert_eq!(&buffer, b"10");
+/// ```
+#[inline]
+pub fn try_to_lexical_with_options<T, const FORMAT: u128>(
+    value: T,
+    bytes: &mut [u8],
+    options: &Options,
+) -> Result<usize, NeedCapacity>
+where
+    T: ToLexicalWithOptions<Options = Options>,
+{
+    let size = options.buffer_size_const::<T, FORMAT>();
+    if bytes.len() >= size {
+        Ok(value.to_lexical_with_options::<FORMAT>(bytes, options).len())
+    } else if size <= SCRATCH_SIZE {
+        try_to_lexical_scratch::<T, FORMAT>(value, bytes, options)
+    } else {
+        Err(NeedCapacity(size))
+    }
+}
+
+/// Write a float to an intermediate buffer, and copy it if it fits.
+///
+/// This is kept out-of-line so the large, intermediate buffer does not
+/// increase the stack usage of callers with sufficiently large buffers.
+#[inline(never)]
+fn try_to_lexical_scratch<T, const FORMAT: u128>(
+    value: T,
+    bytes: &mut [u8],
+    options: &Options,
+) -> Result<usize, NeedCapacity>
+where
+    T: ToLexicalWithOptions<Options = Options>,
+{
+    let mut buffer = [0u8; SCRATCH_SIZE];
+    let digits = value.to_lexical_with_options::<FORMAT>(&mut buffer, options);
+    match bytes.get_mut(..digits.len()) {
+        Some(dst) => {
+            dst.copy_from_slice(digits);
+            Ok(digits.len())
+        },
+        None => Err(NeedCapacity(digits.len())),
+    }
+}
+
+// THREAD-LOCAL DEFAULTS
+
+/// Write a float to a buffer with the default options for the current thread.
+///
+/// This is [`to_lexical`][ToLexical::to_lexical], but uses the options
+/// set by [`ThreadLocalOptions::set_default`] rather than the standard
+/// options, so the options only need to be configured once.
+///
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Buffer to write number to.
+///
+/// # Panics
+///
+/// Panics if the buffer is not large enough for the default options, as
+/// with [`to_lexical_with_options`][ToLexicalWithOptions::to_lexical_with_options].
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_write_float::{to_lexical_default, Options, ThreadLocalOptions, BUFFER_SIZE};
+///
+/// let mut buffer = [0u8; BUFFER_SIZE];
+/// assert_eq!(to_lexical_default(1.5f64, &mut buffer), b"1.5");
+/// let options = Options::builder().decimal_point(b',').build_strict();
+/// ThreadLocalOptions::set_default(options);
+/// assert_eq!(to_lexical_default(1.5f64, &mut buffer), b"1,5");
+/// ```
+#[cfg(feature = "std")]
+#[inline]
+pub fn to_lexical_default<T>(value: T, bytes: &mut [u8]) -> &mut [u8]
+where
+    T: ToLexicalWithOptions<Options = Options>,
+{
+    ThreadLocalOptions::with_default(move |options| {
+        value.to_lexical_with_options::<STANDARD>(bytes, options)
+    })
+}
+
+// SPLIT
+
+/// Write the integer and fraction digits of a float to separate buffers.
+///
+/// The float is written as with
+/// [`to_lexical_with_options`][ToLexicalWithOptions::to_lexical_with_options],
+/// and then split at the decimal point, which is not written. The sign and
+/// integer digits are left in `int_bytes`, the fraction digits are moved
+/// to `frac_bytes`, and the exponent is parsed from the exponent digits,
+/// rather than written. This returns the number of integer bytes, the
+/// number of fraction bytes, and the exponent, which is `0` if the value
+/// is written without one. Special values, such as NaN, are written to
+/// `int_bytes` without any fraction digits. The [`suffix`] is not included
+/// in either buffer.
+///
+/// * `FORMAT`      - Packed struct containing the number format.
+/// * `value`       - Number to serialize.
+/// * `int_bytes`   - Buffer to write the sign and integer digits to.
+/// * `frac_bytes`  - Buffer to write the fraction digits to.
+/// * `options`     - Options to customize number writing.
+///
+/// # Panics
+///
+/// Panics if the number format or options are invalid, or if `int_bytes`
+/// is smaller than [`Options::buffer_size_const`], as with
+/// [`to_lexical_with_options`][ToLexicalWithOptions::to_lexical_with_options].
+/// Also panics if `frac_bytes` is not large enough to hold the fraction
+/// digits.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_write_float::{to_lexical_split, BUFFER_SIZE, Options};
+/// use lexical_write_float::format::STANDARD;
+///
+/// const OPTIONS: Options = Options::new();
+/// let mut int_bytes = [0u8; BUFFER_SIZE];
+/// let mut frac_bytes = [0u8; BUFFER_SIZE];
+/// let split = |value: f64, int_bytes: &mut [u8], frac_bytes: &mut [u8]| {
+///     to_lexical_split::<_, STANDARD>(value, int_bytes, frac_bytes, &OPTIONS)
+/// };
+///
+/// let (int_len, frac_len, exp) = split(3.14, &mut int_bytes, &mut frac_bytes);
+/// assert_eq!((&int_bytes[..int_len], &frac_bytes[..frac_len], exp), (&b"3"[..], &b"14"[..], 0));
+///
+/// let (int_len, frac_len, exp) = split(-1.5e20, &mut int_bytes, &mut frac_bytes);
+/// assert_eq!((&int_bytes[..int_len], &frac_bytes[..frac_len], exp), (&b"-1"[..], &b"5"[..], 20));
+/// ```
+///
+/// [`suffix`]: Options::suffix
+#[inline]
+pub fn to_lexical_split<T, const FORMAT: u128>(
+    value: T,
+    int_bytes: &mut [u8],
+    frac_bytes: &mut [u8],
+    options: &Options,
+) -> (usize, usize, i32)
+where
+    T: Float + ToLexicalWithOptions<Options = Options>,
+{
+    // The suffix may contain the exponent or decimal point, so remove it first.
+    let count = value.to_lexical_with_options::<FORMAT>(int_bytes, options).len();
+    let count = count - options.suffix().len();
+    if value.is_special() {
+        return (count, 0, 0);
+    }
+
+    // Parse and remove the exponent, which is always after the digits.
+    let digits = &int_bytes[..count];
+    let (count, exp) = match digits.iter().position(|&c| c == options.exponent()) {
+        Some(index) => (index, parse_split_exponent::<FORMAT>(&digits[index + 1..])),
+        None => (count, 0),
+    };
+
+    // Move the fraction digits, if any, and remove the decimal point.
+    let digits = &int_bytes[..count];
+    match digits.iter().position(|&c| c == options.decimal_point()) {
+        Some(index) => {
+            let fraction = &digits[index + 1..];
+            frac_bytes[..fraction.len()].copy_from_slice(fraction);
+            (index, fraction.len(), exp)
+        },
+        None => (count, 0, exp),
+    }
+}
+
+/// Parse the signed exponent digits written after the exponent character.
+#[inline(always)]
+fn parse_split_exponent<const FORMAT: u128>(bytes: &[u8]) -> i32 {
+    let (is_negative, digits) = match bytes {
+        [b'-', digits @ ..] => (true, digits),
+        [b'+', digits @ ..] => (false, digits),
+        digits => (false, digits),
+    };
+    let radix = NumberFormat::<FORMAT>::EXPONENT_RADIX;
+    let mut exp = 0i32;
+    for &c in digits {
+        // NOTE: The exponent is always written with valid digits.
+        let digit = char_to_digit_const(c, radix).unwrap_or(0);
+        exp = exp * radix as i32 + digit as i32;
+    }
+    if is_negative {
+        -exp
+    } else {
+        exp
+    }
+}
+
+// MIXED RADIX
+
+/// Write a float using separate radices for the integer and fraction digits.
+///
+/// The integer digits are written in the mantissa radix, and the fraction
+/// digits in the exponent radix of the number format, always using
+/// positional (fixed) notation. For example, with a decimal mantissa radix
+/// and a duodecimal exponent radix, `12.5` is written as `12.6`, since
+/// `0.5` is `6/12`.
+///
+/// The integer digits are exact, as are the fraction digits for power-of-two
+/// radices. Otherwise, the fraction digits are rounded to the precision of
+/// the float. Trailing zeros in the fraction are trimmed, and only the
+/// decimal point, [`round_mode`], [`trim_floats`], and special value options
+/// are used.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Buffer to write number to.
+/// * `options` - Options to customize number writing.
+///
+/// # Panics
+///
+/// Panics if the number format is invalid, or if the buffer is not large
+/// enough to hold the digits. The integer and fraction digits can each
+/// require over 1000 bytes for binary radices, but the buffer only needs
+/// to be as large as the written digits.
+///
+/// [`round_mode`]: Options::round_mode
+/// [`trim_floats`]: Options::trim_floats
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "radix")] {
+/// use lexical_write_float::{to_lexical_mixed_radix, NumberFormatBuilder, Options};
+///
+/// const DUODECIMAL_FRACTION: u128 = NumberFormatBuilder::new()
+///     .exponent_radix(core::num::NonZeroU8::new(12))
+///     .build_strict();
+/// const OPTIONS: Options = Options::new();
+///
+/// let mut buffer = [0u8; 64];
+/// let digits = to_lexical_mixed_radix::<_, DUODECIMAL_FRACTION>(12.5f64, &mut buffer, &OPTIONS);
+/// assert_eq!(digits, b"12.6");
+/// # }
+/// ```
+#[cfg(feature = "power-of-two")]
+#[inline]
+pub fn to_lexical_mixed_radix<'a, T: Float, const FORMAT: u128>(
+    value: T,
+    bytes: &'a mut [u8],
+    options: &Options,
+) -> &'a mut [u8] {
+    let count = crate::mixed_radix::write_float::<T, FORMAT>(value, bytes, options);
+    &mut bytes[..count]
+}
+
+// ALLOC
+
+/// Write a float to a newly allocated [`Vec`].
+///
+/// * `value`   - Number to serialize.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_write_float::to_lexical_vec;
+///
+/// assert_eq!(to_lexical_vec(1.5f64), b"1.5");
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn to_lexical_vec<T: ToLexical>(value: T) -> Vec<u8> {
+    let mut vec = vec![0u8; T::FORMATTED_SIZE_DECIMAL];
+    let count = value.to_lexical(&mut vec).len();
+    vec.truncate(count);
+    vec
+}
+
+/// Write a float to a newly allocated [`String`].
+///
+/// * `value`   - Number to serialize.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_write_float::to_lexical_string;
+///
+/// assert_eq!(to_lexical_string(1.5f64), "1.5");
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn to_lexical_string<T: ToLexical>(value: T) -> String {
+    let vec = to_lexical_vec(value);
+    // SAFETY: safe since the written float is always valid ASCII.
+    unsafe { String::from_utf8_unchecked(vec) }
+}
+
+/// Write a float to a newly allocated [`Vec`] with custom options.
+///
+/// The allocation is sized using [`Options::buffer_size_const`], which
+/// accounts for the minimum significant digits, the exponent breaks, and
+/// whether scientific notation is disabled, so it is never too small.
+///
+/// If [`Options::debug_annotate`] is set, the raw bit decomposition of the
+/// float is appended to the written value.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `value`   - Number to serialize.
+/// * `options` - Options to customize number writing.
+///
+/// # Panics
+///
+/// Panics if the number format or options are invalid, as with
+/// [`to_lexical_with_options`][ToLexicalWithOptions::to_lexical_with_options].
+///
+/// # Examples
+///
+/// ```rust
+/// use core::num;
+///
+/// use lexical_write_float::{to_lexical_vec_with_options, Options};
+/// use lexical_write_float::format::STANDARD;
+///
+/// const OPTIONS: Options = Options::builder()
+///     .min_significant_digits(num::NonZeroUsize::new(5))
+///     .build_strict();
+/// let digits = to_lexical_vec_with_options::<_, STANDARD>(1.5f64, &OPTIONS);
+/// assert_eq!(digits, b"1.5000");
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn to_lexical_vec_with_options<T, const FORMAT: u128>(value: T, options: &Options) -> Vec<u8>
+where
+    T: ToLexicalWithOptions<Options = Options> + Float,
+{
+    let mut vec = vec![0u8; options.buffer_size_const::<T, FORMAT>()];
+    let count = value.to_lexical_with_options::<FORMAT>(&mut vec, options).len();
+    vec.truncate(count);
+    if options.debug_annotate() {
+        write_debug_annotation(value, &mut vec);
+    }
+    vec
+}
+
+/// Append the raw bit decomposition of a float, for [`Options::debug_annotate`].
+///
+/// This contains the bits in hexadecimal, the unbiased binary exponent,
+/// and the stored mantissa bits, without the hidden bit.
+#[cfg(feature = "alloc")]
+fn write_debug_annotation<T: Float>(value: T, vec: &mut Vec<u8>) {
+    use lexical_write_integer::{FormattedSize, ToLexical};
+
+    // Write the hex digits, padded to at least `width` digits.
+    let write_hex = |vec: &mut Vec<u8>, value: u64, width: usize| {
+        let count = (64 - value.leading_zeros() as usize + 3) / 4;
+        for index in (0..count.max(width)).rev() {
+            let digit = (value >> (4 * index)) & 0xF;
+            vec.push(digit_to_char(digit as u32));
+        }
+    };
+
+    // The denormal exponent already accounts for the missing hidden bit.
+    let exp = value.exponent() + T::MANTISSA_SIZE;
+    let mant = (value.mantissa() & T::MANTISSA_MASK).as_u64();
+    let mut buffer = [0u8; i32::FORMATTED_SIZE_DECIMAL];
+    vec.extend_from_slice(b" (0x");
+    write_hex(vec, value.to_bits().as_u64(), T::BITS / 4);
+    vec.extend_from_slice(b", exp=");
+    vec.extend_from_slice(exp.to_lexical(&mut buffer));
+    vec.extend_from_slice(b", mant=0x");
+    write_hex(vec, mant, 1);
+    vec.push(b')');
+}
+
+/// Write the decimal strings just below and just above the exact value of a
+/// float.
+///
+/// The exact value of a float is rarely a short decimal, so `0.1f64` is
+/// exactly `0.1000000000000000055511151231257827...`. This returns the
+/// closest strings with `digits` significant digits below and above the
+/// exact value, which are equal if the value is exactly representable with
+/// that many digits. This is useful to show the rounding uncertainty of
+/// a value.
+///
+/// The strings use scientific notation if the exponent is below `-4`, or
+/// is not below `digits`, and positional notation otherwise. Trailing zeros
+/// are kept, to show the number of significant digits.
+///
+/// * `value`   - Number to serialize.
+/// * `digits`  - Number of significant digits to write.
+///
+/// Returns [`None`] if the value is NaN or infinite.
+///
+/// # Panics
+///
+/// Panics if `digits` is `0`.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_write_float::to_lexical_bracket;
+///
+/// let (lower, upper) = to_lexical_bracket(0.1f64, 3).unwrap();
+/// assert_eq!((lower.as_str(), upper.as_str()), ("0.100", "0.101"));
+///
+/// let (lower, upper) = to_lexical_bracket(-2.5f64, 2).unwrap();
+/// assert_eq!((lower.as_str(), upper.as_str()), ("-2.5", "-2.5"));
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn to_lexical_bracket<T: Float>(value: T, digits: usize) -> Option<(String, String)> {
+    crate::bracket::write_bracket(value, digits)
+}
+
+/// Write a float to a newly allocated [`String`] with custom options.
+///
+/// The allocation is sized using [`Options::buffer_size_const`], which
+/// accounts for the minimum significant digits, the exponent breaks, and
+/// whether scientific notation is disabled, so it is never too small.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `value`   - Number to serialize.
+/// * `options` - Options to customize number writing.
+///
+/// # Panics
+///
+/// Panics if the number format or options are invalid, as with
+/// [`to_lexical_with_options`][ToLexicalWithOptions::to_lexical_with_options],
+/// or if the options contain non-ASCII characters.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_write_float::{to_lexical_string_with_options, Options};
+/// use lexical_write_float::format::STANDARD;
+///
+/// const OPTIONS: Options = Options::builder().trim_floats(true).build_strict();
+/// let digits = to_lexical_string_with_options::<_, STANDARD>(5.0f64, &OPTIONS);
+/// assert_eq!(digits, "5");
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn to_lexical_string_with_options<T, const FORMAT: u128>(value: T, options: &Options) -> String
+where
+    T: ToLexicalWithOptions<Options = Options> + Float,
+{
+    let vec = to_lexical_vec_with_options::<T, FORMAT>(value, options);
+    // Options built with `build_unchecked` may contain invalid UTF-8.
+    String::from_utf8(vec).expect("options must only contain valid UTF-8")
+}
@@ -62,7 +62,8 @@ where
     //      formatting write control.
     let mantissa = float.mantissa();
     let radix = format.mantissa_radix();
-    let (mantissa, mantissa_bits) = truncate_and_round(mantissa, radix, options);
+    let (mantissa, shifted_exp, mantissa_bits) =
+        truncate_and_round(mantissa, float.exponent(), radix, options);
 
     // See if we should use an exponent if the number was represented
     // in scientific notation, AKA, `I.FFFF^EEE`. If the exponent is above
@@ -96,7 +97,7 @@ where
         write_float_negative_exponent,
         generic => _,
         bytes => bytes,
-        args => mantissa, exp, sci_exp, options,
+        args => mantissa, shifted_exp, sci_exp, options,
     )
 }
 
@@ -163,9 +164,12 @@ where
         cursor = digits_end;
     }
 
-    // Now, write our scientific notation.
+    // Now, write our scientific notation, shifting the decimal point
+    // if using engineering notation.
     let scaled_sci_exp = scale_sci_exp(sci_exp, bits_per_digit);
-    shared::write_exponent::<FORMAT>(bytes, &mut cursor, scaled_sci_exp, options.exponent());
+    let scaled_sci_exp =
+        shared::shift_engineering::<FORMAT>(bytes, &mut cursor, scaled_sci_exp, options);
+    shared::write_power_of_two_exponent::<FORMAT>(bytes, &mut cursor, scaled_sci_exp, options);
 
     cursor
 }
@@ -717,9 +721,21 @@ pub fn scale_sci_exp(sci_exp: i32, bits_per_digit: i32) -> i32 {
 // ---------
 
 /// Round mantissa to the nearest value, returning only the number
-/// of significant digits. Also returns the number of bits of the mantissa.
+/// of significant digits. Also returns the adjusted binary exponent
+/// of the truncated mantissa, and the number of bits of the mantissa
+/// relative to the original exponent.
+///
+/// The digits are aligned to the exponent, so the leading digit
+/// may contain fewer bits than `log2(radix)`: the number of bits
+/// truncated is based on the number of digits written, not the number
+/// of significant bits.
 #[inline(always)]
-pub fn truncate_and_round<M>(mantissa: M, radix: u32, options: &Options) -> (M, usize)
+pub fn truncate_and_round<M>(
+    mantissa: M,
+    exp: i32,
+    radix: u32,
+    options: &Options,
+) -> (M, i32, usize)
 where
     M: UnsignedInteger,
 {
@@ -732,12 +748,24 @@ where
         max_digits = digits.get();
     }
     let max_bits = max_digits.saturating_mul(bits_per_digit as usize);
+
+    // Calculate the number of bits in the digits we would write, since
+    // the mantissa is shifted left so the exponent is a multiple of
+    // the bits per digit.
+    let shl = calculate_shl(exp, bits_per_digit) as usize;
+    let digit_bits = fast_ceildiv((mantissa_bits + shl) as i32, bits_per_digit) as usize
+        * bits_per_digit as usize;
     let mut shifted_mantissa = mantissa;
+    let mut shifted_exp = exp;
 
     // Need to truncate the number of significant digits.
-    if max_bits < mantissa_bits {
-        let shr = (mantissa_bits - max_bits) as i32;
+    if max_bits < digit_bits {
+        // Since we always keep at least 1 digit, `digit_bits - max_bits`
+        // is at least `bits_per_digit`, which is larger than `shl`, and
+        // smaller than `mantissa_bits`.
+        let shr = (digit_bits - max_bits - shl) as i32;
         shifted_mantissa = mantissa >> shr;
+        shifted_exp = exp + shr;
 
         // We need to round-nearest, tie-even, so we need to handle
         // the truncation **here**. If the representation is above
@@ -755,9 +783,9 @@ where
             let initial_bits = shifted_mantissa.leading_zeros();
             shifted_mantissa += as_cast((above_halfway || (is_odd & is_halfway)) as u32);
             let final_bits = shifted_mantissa.leading_zeros();
-            mantissa_bits += (final_bits - initial_bits) as usize;
+            mantissa_bits += (initial_bits - final_bits) as usize;
         }
     }
 
-    (shifted_mantissa, mantissa_bits)
+    (shifted_mantissa, shifted_exp, mantissa_bits)
 }
@@ -0,0 +1,147 @@
+//! Write the decimal strings bracketing the exact value of a float.
+//!
+//! The exact value of a float can require over 700 significant decimal
+//! digits, so this uses the exact big-integer arithmetic of the `exact`
+//! module to generate the leading digits. The digits are truncated to
+//! the requested number of significant digits, which gives the bound
+//! closer to zero, and the bound further from zero is found by incrementing
+//! the last digit if any truncated digits were non-zero.
+
+#![cfg(feature = "alloc")]
+#![doc(hidden)]
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use lexical_util::num::Float;
+use lexical_write_integer::{FormattedSize, ToLexical};
+
+use crate::exact::{mul_small, to_fixed_point, write_large_integer, MAX_INTEGER_DIGITS};
+
+/// Write the decimal strings just below and just above a float.
+///
+/// Returns [`None`] if the float is NaN or infinite.
+///
+/// # Panics
+///
+/// Panics if `count` is `0`.
+pub fn write_bracket<F: Float>(float: F, count: usize) -> Option<(String, String)> {
+    assert!(count != 0, "must write at least 1 significant digit");
+    if float.is_special() {
+        return None;
+    }
+
+    let value = float.as_f64().abs();
+    let is_negative = float.is_sign_negative() && value != 0.0;
+    let (mut digits, mut sci_exp, is_inexact) = if value == 0.0 {
+        (vec![0; count], 0, false)
+    } else {
+        truncated_digits(value, count)
+    };
+    let truncated = to_string(&digits, sci_exp, is_negative);
+    let rounded = if is_inexact {
+        increment(&mut digits, &mut sci_exp);
+        to_string(&digits, sci_exp, is_negative)
+    } else {
+        truncated.clone()
+    };
+
+    // The truncated digits are closer to zero, so are the upper bound if
+    // the float is negative.
+    if is_negative {
+        Some((rounded, truncated))
+    } else {
+        Some((truncated, rounded))
+    }
+}
+
+/// Get the leading significant digits of a positive float.
+///
+/// Returns the `count` leading digits, the exponent of the first digit in
+/// scientific notation, and if any truncated digits are non-zero.
+fn truncated_digits(value: f64, count: usize) -> (Vec<u8>, i32, bool) {
+    let mant = value.mantissa();
+    let exp = value.exponent();
+    let mut buffer = [0u8; MAX_INTEGER_DIGITS];
+    let integer = if exp >= 0 {
+        write_large_integer(mant, exp as u32, 10, &mut buffer)
+    } else if exp > -64 && mant >> -exp != 0 {
+        write_large_integer(mant >> -exp, 0, 10, &mut buffer)
+    } else {
+        &[]
+    };
+
+    let take = integer.len().min(count);
+    let mut digits: Vec<u8> = integer[..take].iter().map(|&c| c - b'0').collect();
+    let mut sci_exp = integer.len() as i32 - 1;
+    let mut is_inexact = integer[take..].iter().any(|&c| c != b'0');
+    if exp < 0 {
+        // Skip the leading zeros in the fraction, if there are no integer
+        // digits, adjusting the exponent for each.
+        let mut limbs = to_fixed_point(mant, exp);
+        while digits.len() < count && limbs.iter().any(|&x| x != 0) {
+            let digit = mul_small(&mut limbs, 10) as u8;
+            if digits.is_empty() && digit == 0 {
+                sci_exp -= 1;
+            } else {
+                digits.push(digit);
+            }
+        }
+        is_inexact |= limbs.iter().any(|&x| x != 0);
+    }
+    digits.resize(count, 0);
+
+    (digits, sci_exp, is_inexact)
+}
+
+/// Increment the last digit, carrying into the exponent if required.
+fn increment(digits: &mut [u8], sci_exp: &mut i32) {
+    for digit in digits.iter_mut().rev() {
+        if *digit == 9 {
+            *digit = 0;
+        } else {
+            *digit += 1;
+            return;
+        }
+    }
+    // Every digit was `9`, so the digits are now `100...`.
+    digits[0] = 1;
+    *sci_exp += 1;
+}
+
+/// Convert the digits to a string.
+///
+/// This uses scientific notation if the exponent is below `-4`, or if it
+/// is not below the number of digits, otherwise, it uses positional
+/// notation. Trailing zeros are kept, to show the number of digits.
+fn to_string(digits: &[u8], sci_exp: i32, is_negative: bool) -> String {
+    let mut bytes = Vec::with_capacity(digits.len() + 16);
+    if is_negative {
+        bytes.push(b'-');
+    }
+    let to_char = |&digit: &u8| digit + b'0';
+    let count = digits.len() as i32;
+    if sci_exp < -4 || sci_exp >= count {
+        bytes.push(to_char(&digits[0]));
+        if digits.len() > 1 {
+            bytes.push(b'.');
+            bytes.extend(digits[1..].iter().map(to_char));
+        }
+        let mut buffer = [0u8; i32::FORMATTED_SIZE_DECIMAL];
+        bytes.push(b'e');
+        bytes.extend_from_slice(sci_exp.to_lexical(&mut buffer));
+    } else if sci_exp < 0 {
+        bytes.extend_from_slice(b"0.");
+        bytes.extend((sci_exp + 1..0).map(|_| b'0'));
+        bytes.extend(digits.iter().map(to_char));
+    } else {
+        let (integer, fraction) = digits.split_at(sci_exp as usize + 1);
+        bytes.extend(integer.iter().map(to_char));
+        if !fraction.is_empty() {
+            bytes.push(b'.');
+            bytes.extend(fraction.iter().map(to_char));
+        }
+    }
+    // SAFETY: safe since the written digits are always valid ASCII.
+    unsafe { String::from_utf8_unchecked(bytes) }
+}
@@ -0,0 +1,89 @@
+//! Exact big-integer arithmetic for the digits of a float.
+//!
+//! Every float is `mant * 2^exp`, so the integer digits can be written by
+//! repeated division of a large integer, and the fraction digits by repeated
+//! multiplication of a fixed-point fraction, both of which are exact. This
+//! is simple rather than fast, since both the integer and fraction digits
+//! can require over a thousand digits in binary.
+
+#![cfg(any(feature = "power-of-two", feature = "alloc"))]
+#![doc(hidden)]
+
+use lexical_util::digit::digit_to_char_const;
+
+/// Number of 64-bit limbs in the fixed-point fraction.
+///
+/// The smallest denormal float is `2^-1074`, which fits in 1088 bits.
+pub(crate) const FRACTION_LIMBS: usize = 17;
+
+/// Number of bits in the fixed-point fraction.
+pub(crate) const FRACTION_BITS: i32 = 64 * FRACTION_LIMBS as i32;
+
+/// Number of 32-bit limbs for large integers.
+///
+/// The largest float is just below `2^1024`, which fits in 1056 bits.
+const INTEGER_LIMBS: usize = 33;
+
+/// Maximum number of integer digits, for the largest float in binary.
+pub(crate) const MAX_INTEGER_DIGITS: usize = 1024;
+
+/// Write a large integer, `mant * 2^exp`, to the buffer.
+///
+/// The digits are written to the end of the buffer, and the written digits
+/// are returned.
+pub(crate) fn write_large_integer(mant: u64, exp: u32, radix: u32, buffer: &mut [u8]) -> &[u8] {
+    // Store the integer in little-endian, 32-bit limbs.
+    let mut limbs = [0u32; INTEGER_LIMBS];
+    let (index, shift) = ((exp / 32) as usize, exp % 32);
+    let value = (mant as u128) << shift;
+    limbs[index] = value as u32;
+    limbs[index + 1] = (value >> 32) as u32;
+    limbs[index + 2] = (value >> 64) as u32;
+    let mut len = index + 3;
+
+    // Repeatedly divide by the radix, writing the remainder as a digit.
+    let mut start = buffer.len();
+    while len != 0 {
+        let mut rem = 0u64;
+        for limb in limbs[..len].iter_mut().rev() {
+            let value = (rem << 32) | *limb as u64;
+            *limb = (value / radix as u64) as u32;
+            rem = value % radix as u64;
+        }
+        start -= 1;
+        buffer[start] = digit_to_char_const(rem as u32, radix);
+        while len != 0 && limbs[len - 1] == 0 {
+            len -= 1;
+        }
+    }
+    &buffer[start..]
+}
+
+/// Convert the fraction of `mant * 2^exp` to little-endian, fixed-point limbs.
+///
+/// The fixed-point value is the fraction multiplied by `2^FRACTION_BITS`,
+/// which is exact for every float. Any integer bits are shifted out.
+pub(crate) fn to_fixed_point(mant: u64, exp: i32) -> [u64; FRACTION_LIMBS] {
+    debug_assert!((-FRACTION_BITS..0).contains(&exp), "exponent must be in the fraction");
+    let mut limbs = [0u64; FRACTION_LIMBS];
+    let shift = (FRACTION_BITS + exp) as u32;
+    let (index, shift) = ((shift / 64) as usize, shift % 64);
+    let value = (mant as u128) << shift;
+    limbs[index] = value as u64;
+    if index + 1 < FRACTION_LIMBS {
+        limbs[index + 1] = (value >> 64) as u64;
+    }
+    limbs
+}
+
+/// Multiply the fixed-point fraction by the radix, returning the carry.
+#[inline(always)]
+pub(crate) fn mul_small(limbs: &mut [u64; FRACTION_LIMBS], radix: u32) -> u32 {
+    let mut carry = 0u128;
+    for limb in limbs.iter_mut() {
+        let value = (*limb as u128) * radix as u128 + carry;
+        *limb = value as u64;
+        carry = value >> 64;
+    }
+    carry as u32
+}
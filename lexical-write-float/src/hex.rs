@@ -45,11 +45,14 @@ use crate::shared;
 /// Safe as long as the float isn't special (NaN or Infinity), and `bytes`
 /// is large enough to hold the significant digits.
 ///
+/// The exponent digits are written in the exponent radix of the
+/// format, which may be any valid radix.
+///
 /// # Panics
 ///
-/// Panics if the radix for the significant digits is not 16, if
-/// the exponent base is not 2, or if the radix for the exponent
-/// digits is not 10.
+/// Panics if the radix for the significant digits and the exponent
+/// base are not one of the supported combinations, IE, if the exponent
+/// cannot exactly represent every power of the significant digit radix.
 pub fn write_float<F: Float, const FORMAT: u128>(
     float: F,
     bytes: &mut [u8],
@@ -83,7 +86,8 @@ where
     //      formatting write control.
     let mantissa = float.mantissa();
     let radix = format.mantissa_radix();
-    let (mantissa, mantissa_bits) = truncate_and_round(mantissa, radix, options);
+    let (mantissa, shifted_exp, mantissa_bits) =
+        truncate_and_round(mantissa, float.exponent(), radix, options);
 
     // See if we should use an exponent if the number was represented
     // in scientific notation, AKA, `I.FFFF^EEE`. If the exponent is above
@@ -118,7 +122,7 @@ where
         write_float_negative_exponent,
         generic => _,
         bytes => bytes,
-        args => mantissa, exp, sci_exp, options,
+        args => mantissa, shifted_exp, sci_exp, options,
     )
 }
 
@@ -181,7 +185,9 @@ where
         // Need to trim floats from trailing zeros, and we have only a decimal.
         cursor -= 1;
     } else if exact_count < 2 {
-        // Need to have at least 1 digit, the trailing `.0`.
+        // Need to have at least 1 digit, the trailing `.0`. This is also
+        // used when trimming floats, if the format requires a fraction
+        // with the exponent.
         bytes[cursor] = b'0';
         cursor += 1;
     } else if exact_count > digit_count {
@@ -196,7 +202,7 @@ where
     // Now, write our scientific notation.
     // Won't panic safe if bytes is large enough to store all digits.
     let scaled_sci_exp = scale_sci_exp(sci_exp, bits_per_digit, bits_per_base);
-    shared::write_exponent::<FORMAT>(bytes, &mut cursor, scaled_sci_exp, options.exponent());
+    shared::write_power_of_two_exponent::<FORMAT>(bytes, &mut cursor, scaled_sci_exp, options);
 
     cursor
 }
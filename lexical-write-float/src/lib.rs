@@ -34,6 +34,10 @@
 //!
 //! [`FORMATTED_SIZE_DECIMAL`]: FormattedSize::FORMATTED_SIZE_DECIMAL
 //!
+//! If the buffer may be too small, such as when writing to a bounded
+//! network buffer, use [`try_to_lexical`], which returns the required
+//! capacity rather than panicking.
+//!
 //! # Options/Formatting API
 //!
 //! Each float formatter contains extensive formatting control, including
@@ -43,7 +47,8 @@
 //! notation), whether to force or disable scientific notation, the rounding
 //! mode for truncated float strings, and how to display non-finite floats.
 //! While using custom float options, you must use
-//! [`Options::buffer_size_const`] to determine the correct buffer size:
+//! [`Options::buffer_size_const`], or [`formatted_size`] for any float type,
+//! to determine the correct buffer size:
 //!
 //! ```rust
 //! # #[cfg(feature = "format")] {
@@ -86,6 +91,7 @@
 //! * `compact` - Reduce code size at the cost of performance.
 //! * `f16` - Enable support for half-precision [`f16`][`ieee-f16`] and
 //!   [`bf16`][`brain-float`] floats.
+//! * `alloc` - Add support for writing to an allocated [`String`] or [`Vec`].
 //! * `std` (Default) - Disable to allow use in a [`no_std`] environment.
 //!
 //! [`no_std`]: https://docs.rust-embedded.org/book/intro/no-std.html
@@ -244,6 +250,24 @@
 //! # }
 //! ```
 //!
+//! #### alloc
+//!
+//! Enable writing floats to a newly allocated [`String`] or [`Vec`], without
+//! a caller-provided buffer. The allocation is sized using
+//! [`Options::buffer_size_const`], so it is always large enough, even with
+//! many significant digits or with scientific notation disabled.
+//!
+//! ```rust
+//! # #[cfg(feature = "alloc")] {
+//! use lexical_write_float::{to_lexical_string_with_options, Notation, Options};
+//! use lexical_write_float::format::STANDARD;
+//!
+//! const OPTIONS: Options = Options::builder().notation(Notation::Fixed).build_strict();
+//! let digits = to_lexical_string_with_options::<_, STANDARD>(1e30f64, &OPTIONS);
+//! assert_eq!(digits, "1000000000000000000000000000000.0");
+//! # }
+//! ```
+//!
 //! #### std
 //!
 //! Enable use of the standard library. Currently, the standard library
@@ -324,7 +348,8 @@
 //! - Custom [`NaN`][f64::NAN] and [`Infinity`][f64::INFINITY] string
 //!   [`representations`][Options::nan_string].
 //! - Whether to [`trim`][Options::trim_floats] the fraction component from
-//!   integral floats.
+//!   integral floats, optionally only [`below`][Options::trim_floats_below] a
+//!   given magnitude.
 //! - The exponent [`break-point`][Options::positive_exponent_break] for
 //!   scientific notation.
 //! - The [`maximum`][Options::max_significant_digits] and
@@ -332,6 +357,10 @@
 //!   to write.
 //! - The rounding [`mode`][Options::round_mode] when truncating significant
 //!   digits while writing.
+//! - The [`minimum`][Options::exponent_min_digits] number of exponent digits,
+//!   padded with zeros.
+//! - The [`minimum`][Options::min_integer_digits] number of integer digits in
+//!   fixed-point notation, padded with a custom [`character`][Options::pad_char].
 //!
 //! In addition, pre-defined constants for each category of options may
 //! be found in their respective modules, for example, [`JSON`][`JSON-OPTS`].
@@ -435,6 +464,7 @@
 //!
 //! [`rust-1.63.0`]: https://blog.rust-lang.org/2022/08/11/Rust-1.63.0.html
 //! [`String`]: https://doc.rust-lang.org/alloc/string/struct.String.html
+//! [`Vec`]: https://doc.rust-lang.org/alloc/vec/struct.Vec.html
 //! [`to_string`]: https://doc.rust-lang.org/alloc/string/trait.ToString.html#tymethod.to_string
 
 // We want to have the same safety guarantees as Rust core,
@@ -469,6 +499,11 @@
     clippy::semicolon_inside_block,
 )]
 
+// Need an allocator for String/Vec.
+#[cfg(feature = "alloc")]
+#[macro_use(vec)]
+extern crate alloc;
+
 #[macro_use]
 mod index;
 #[macro_use]
@@ -479,12 +514,15 @@ pub mod binary;
 pub mod compact;
 pub mod float;
 pub mod hex;
+pub mod mixed_radix;
 pub mod options;
 pub mod radix;
 pub mod table;
 pub mod write;
 
 mod api;
+mod bracket;
+mod exact;
 mod table_dragonbox;
 mod table_grisu;
 
@@ -492,13 +530,40 @@ mod table_grisu;
 #[cfg(feature = "f16")]
 pub use lexical_util::bf16::bf16;
 pub use lexical_util::constants::{FormattedSize, BUFFER_SIZE};
-pub use lexical_util::error::Error;
+pub use lexical_util::error::{Error, NeedCapacity};
 #[cfg(feature = "f16")]
 pub use lexical_util::f16::f16;
 pub use lexical_util::format::{self, NumberFormat, NumberFormatBuilder};
 pub use lexical_util::options::WriteOptions;
 pub use lexical_util::result::Result;
 
-pub use self::api::{ToLexical, ToLexicalWithOptions};
+#[cfg(feature = "alloc")]
+pub use self::api::{
+    to_lexical_bracket,
+    to_lexical_string,
+    to_lexical_string_with_options,
+    to_lexical_vec,
+    to_lexical_vec_with_options,
+};
+#[cfg(feature = "power-of-two")]
+pub use self::api::to_lexical_mixed_radix;
+pub use self::api::{
+    to_lexical_split,
+    try_to_lexical,
+    try_to_lexical_with_options,
+    ToLexical,
+    ToLexicalWithOptions,
+};
 #[doc(inline)]
-pub use self::options::{Options, OptionsBuilder, RoundMode};
+pub use self::options::{
+    formatted_size,
+    ExponentZero,
+    Notation,
+    Options,
+    OptionsBuilder,
+    RoundMode,
+};
+#[cfg(feature = "std")]
+pub use self::api::to_lexical_default;
+#[cfg(feature = "std")]
+pub use self::options::ThreadLocalOptions;
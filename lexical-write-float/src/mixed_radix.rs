@@ -0,0 +1,195 @@
+//! Float writer with separate radices for the integer and fraction digits.
+//!
+//! This always uses positional (fixed) notation, writing the integer digits
+//! using the mantissa radix and the fraction digits using the exponent radix.
+//! For example, `12.5` with a decimal mantissa radix and an exponent radix of
+//! `12` is written as `12.6`, since `0.5` is `6/12`.
+//!
+//! The integer digits are always exact, as are the fraction digits for
+//! power-of-two radices. Otherwise, the fraction digits are rounded according
+//! to the [`RoundMode`] to the number of significant digits in the fraction
+//! radix that the float can represent, which is the number of digits in
+//! `2^(MANTISSA_SIZE + 1)`, less the bits used by the integer digits.
+//! Halfway cases are rounded up, and trailing zeros in the fraction are
+//! always trimmed.
+//!
+//! This uses the simple, exact big-integer arithmetic of the `exact` module
+//! rather than any optimized algorithm, since both the integer and fraction
+//! digits can require over a thousand digits in binary.
+
+#![cfg(feature = "power-of-two")]
+#![doc(hidden)]
+
+use lexical_util::constants::FormattedSize;
+use lexical_util::digit::digit_to_char_const;
+use lexical_util::format::NumberFormat;
+use lexical_util::num::Float;
+use lexical_write_integer::write::{write_sign, WriteInteger};
+
+use crate::exact::{
+    mul_small,
+    to_fixed_point,
+    write_large_integer,
+    FRACTION_BITS,
+    FRACTION_LIMBS,
+    MAX_INTEGER_DIGITS,
+};
+use crate::options::{Options, RoundMode};
+use crate::write::{write_inf, write_nan};
+
+/// Maximum number of fraction digits, for the smallest float in binary.
+const MAX_FRACTION_DIGITS: usize = FRACTION_BITS as usize;
+
+/// Write a float using separate radices for the integer and fraction digits.
+///
+/// Returns the number of bytes written.
+///
+/// # Panics
+///
+/// Panics if the number format is invalid, if the buffer is not large enough
+/// for the digits, or if asked to write a special value that the options do
+/// not support.
+pub fn write_float<F: Float, const FORMAT: u128>(
+    float: F,
+    bytes: &mut [u8],
+    options: &Options,
+) -> usize {
+    let format = NumberFormat::<FORMAT> {};
+    assert!(format.is_valid());
+
+    let buffer = bytes;
+    let required = cfg!(feature = "format") && format.required_mantissa_sign();
+    let count = write_sign(buffer, float.needs_negative_sign(), required);
+    let bytes = &mut buffer[count..];
+    if float.is_nan() {
+        return write_nan(bytes, options, count, float.is_signaling_nan());
+    } else if float.is_special() {
+        return write_inf(buffer, options, count, float.is_sign_negative());
+    }
+
+    // Split into the integer and fraction components, as `mant * 2^exp`.
+    let value = float.as_f64().abs();
+    let mant = value.mantissa();
+    let exp = value.exponent();
+    let precision = F::MANTISSA_SIZE as u32 + 1;
+    let mut fraction = [0u8; MAX_FRACTION_DIGITS];
+    let mut fraction_count = 0;
+    let integer_len = if exp >= 0 {
+        // No fraction digits, and the integer can be very large.
+        let mut integer = [0u8; MAX_INTEGER_DIGITS];
+        let digits = write_large_integer(mant, exp as u32, format.mantissa_radix(), &mut integer);
+        assert!(bytes.len() >= digits.len() + 2, "buffer is too small to write the float");
+        bytes[..digits.len()].copy_from_slice(digits);
+        digits.len()
+    } else {
+        let shift = (-exp) as u32;
+        let mut integer = if shift < 64 {
+            mant >> shift
+        } else {
+            0
+        };
+        let bits = precision.saturating_sub(64 - integer.leading_zeros());
+        let radix = format.exponent_radix();
+        let max_digits = if radix.is_power_of_two() {
+            MAX_FRACTION_DIGITS
+        } else {
+            digit_count(bits, radix)
+        };
+        let mut limbs = to_fixed_point(mant, exp);
+        let (digits, carry) =
+            write_fraction(&mut limbs, radix, max_digits, options.round_mode(), &mut fraction);
+        fraction_count = digits;
+        integer += carry as u64;
+
+        let mut buffer = [0u8; u64::FORMATTED_SIZE];
+        let len = integer.write_mantissa::<FORMAT>(&mut buffer);
+        assert!(
+            bytes.len() >= len + 1 + fraction_count.max(1),
+            "buffer is too small to write the float"
+        );
+        bytes[..len].copy_from_slice(&buffer[..len]);
+        len
+    };
+
+    // Write the decimal point and fraction digits, if any.
+    let mut index = integer_len;
+    if fraction_count != 0 {
+        bytes[index] = options.decimal_point();
+        bytes[index + 1..index + 1 + fraction_count].copy_from_slice(&fraction[..fraction_count]);
+        index += 1 + fraction_count;
+    } else if !options.trim_floats() {
+        bytes[index] = options.decimal_point();
+        bytes[index + 1] = b'0';
+        index += 2;
+    }
+
+    count + index
+}
+
+/// Get the number of digits in `2^bits` in the radix.
+///
+/// This is the number of significant digits required to represent every
+/// value with the given number of significant bits.
+#[inline(always)]
+fn digit_count(bits: u32, radix: u32) -> usize {
+    if bits == 0 {
+        return 0;
+    }
+    let mut value = 1u64 << bits;
+    let mut count = 0;
+    while value != 0 {
+        value /= radix as u64;
+        count += 1;
+    }
+    count
+}
+
+/// Write the fraction digits, returning the number of digits and the carry.
+///
+/// This writes up to `max_digits` significant digits, ignoring leading
+/// zeros, and rounds the last digit using the round mode. The carry is `1`
+/// if rounding overflows into the integer digits. Trailing zeros are trimmed.
+fn write_fraction(
+    limbs: &mut [u64; FRACTION_LIMBS],
+    radix: u32,
+    max_digits: usize,
+    round_mode: RoundMode,
+    buffer: &mut [u8],
+) -> (usize, u32) {
+    let mut count = 0;
+    let mut significant = 0;
+    while significant < max_digits && count < buffer.len() && limbs.iter().any(|&x| x != 0) {
+        let digit = mul_small(limbs, radix);
+        buffer[count] = digit as u8;
+        count += 1;
+        if significant != 0 || digit != 0 {
+            significant += 1;
+        }
+    }
+
+    // Round-up if the remainder is at least halfway.
+    let mut carry = 0;
+    let is_halfway = limbs[FRACTION_LIMBS - 1] >> 63 != 0;
+    if round_mode == RoundMode::Round && is_halfway {
+        carry = 1;
+        for digit in buffer[..count].iter_mut().rev() {
+            if *digit as u32 + 1 == radix {
+                *digit = 0;
+            } else {
+                *digit += 1;
+                carry = 0;
+                break;
+            }
+        }
+    }
+
+    // Trim trailing zeros, and convert the digits to characters.
+    while count != 0 && buffer[count - 1] == 0 {
+        count -= 1;
+    }
+    for digit in buffer[..count].iter_mut() {
+        *digit = digit_to_char_const(*digit as u32, radix);
+    }
+
+    (count, carry)
+}
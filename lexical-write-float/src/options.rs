@@ -41,6 +41,8 @@
 //!   exponent.
 //! - [`CARAT_EXPONENT`]: Numerical format where `^` is used as the exponent
 //!   notation character.
+//! - [`COMPACT_SCIENTIFIC`]: Numerical format for the shortest scientific
+//!   notation that round-trips.
 //! - [`RUST_LITERAL`]: Number format for a [`Rust`] literal floating-point
 //!   number.
 //! - [`PYTHON_LITERAL`]: Number format for a [`Python`] literal floating-point
@@ -198,11 +200,13 @@
 //! [`MongoDB`]: https://www.mongodb.com/
 
 use core::num;
+#[cfg(feature = "std")]
+use std::cell::RefCell;
 
 use lexical_util::ascii::{is_valid_ascii, is_valid_letter_slice};
 use lexical_util::constants::FormattedSize;
 use lexical_util::error::Error;
-use lexical_util::format::NumberFormat;
+use lexical_util::format::{validate_format, NumberFormat};
 use lexical_util::options::{self, WriteOptions};
 use lexical_util::result::Result;
 
@@ -217,6 +221,19 @@ pub type OptionUsize = Option<num::NonZeroUsize>;
 #[doc(hidden)]
 pub type OptionI32 = Option<num::NonZeroI32>;
 
+/// Float threshold compared by its bits, so the options can be [`Eq`].
+#[derive(Debug, Copy, Clone)]
+struct FloatThreshold(f64);
+
+impl PartialEq for FloatThreshold {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_bits() == other.0.to_bits()
+    }
+}
+
+impl Eq for FloatThreshold {}
+
 /// Const evaluation of `max` for integers.
 macro_rules! max {
     ($x:expr, $y:expr) => {{
@@ -258,6 +275,70 @@ pub enum RoundMode {
     Truncate,
 }
 
+/// Enumeration for the notation to use when writing floats.
+///
+/// This sets the intent for how to write a float once, rather than
+/// through the exponent breaks. [`Auto`][Notation::Auto] uses scientific
+/// notation only outside of the exponent breaks, while the other variants
+/// override them. The number format still has the final say: if the
+/// format does not allow exponent notation, or if it requires it, those
+/// requirements take precedence over the notation.
+///
+/// For example, `1234.5` is written as `1234.5` with [`Auto`][Notation::Auto]
+/// and [`Fixed`][Notation::Fixed], `1.2345e3` with
+/// [`Scientific`][Notation::Scientific] and `1.2345e3` with
+/// [`Engineering`][Notation::Engineering], while `0.0001` would be
+/// `1.0e-4` and `100.0e-6` for scientific and engineering notation,
+/// respectively.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Notation {
+    /// Use scientific notation only if the exponent is outside of the
+    /// [`negative_exponent_break`] and [`positive_exponent_break`].
+    ///
+    /// [`negative_exponent_break`]: OptionsBuilder::negative_exponent_break
+    /// [`positive_exponent_break`]: OptionsBuilder::positive_exponent_break
+    Auto,
+
+    /// Never use scientific notation.
+    ///
+    /// This writes every digit for all magnitudes, so `1e300` has over 300
+    /// integer digits and `5e-324` over 300 leading fractional zeros. A
+    /// buffer of [`formatted_size`] bytes always holds the result.
+    Fixed,
+
+    /// Always use scientific notation, with a single integral digit.
+    Scientific,
+
+    /// Always use scientific notation, with the exponent a multiple of 3.
+    ///
+    /// This is only supported when the exponent base is the same as the
+    /// mantissa radix: otherwise, this is identical to
+    /// [`Scientific`][Notation::Scientific].
+    Engineering,
+}
+
+/// Enumeration for how to write a scientific exponent of zero.
+///
+/// This only applies to the scientific writers for power-of-two radixes,
+/// such as hexadecimal floats. For example, `1.0` in hexadecimal with a
+/// `p` exponent is written as `1.0` with [`Omit`][ExponentZero::Omit],
+/// `1.0p+0` with [`Signed`][ExponentZero::Signed], and `1.0p0` with
+/// [`Unsigned`][ExponentZero::Unsigned].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ExponentZero {
+    /// Do not write the exponent, unless the number format requires
+    /// exponent notation.
+    Omit,
+
+    /// Always write the exponent with a positive sign, unless the number
+    /// format does not allow positive exponent signs.
+    Signed,
+
+    /// Write the exponent without a sign, unless the number format
+    /// requires an exponent sign.
+    Unsigned,
+}
+
 /// Maximum length for a special string.
 pub const MAX_SPECIAL_STRING_LENGTH: usize = 50;
 
@@ -307,6 +388,11 @@ pub struct OptionsBuilder {
     /// `0.100000000000000000000000`, which is still the nearest float.
     min_significant_digits: OptionUsize,
 
+    /// Relative resolution to write the fewest significant digits for.
+    ///
+    /// If not set, the digits are not limited by the resolution.
+    significant_resolution: Option<FloatThreshold>,
+
     /// Maximum exponent prior to using scientific notation.
     ///
     /// This is ignored if the exponent base is not the same as the mantissa
@@ -331,6 +417,39 @@ pub struct OptionsBuilder {
     /// [`min_significant_digits`]: Self::min_significant_digits
     trim_floats: bool,
 
+    /// Only trim the trailing ".0" from integral floats below this magnitude.
+    ///
+    /// If not set, integral floats are only trimmed if [`trim_floats`]
+    /// is enabled.
+    ///
+    /// [`trim_floats`]: Self::trim_floats
+    trim_floats_below: Option<FloatThreshold>,
+
+    /// The notation to use when writing floats.
+    notation: Notation,
+
+    /// How to write a scientific exponent of zero.
+    exponent_zero_behavior: ExponentZero,
+
+    /// Minimum number of digits to write for the exponent.
+    ///
+    /// If not set, the exponent is written without padding.
+    exponent_min_digits: OptionUsize,
+
+    /// Minimum number of integer digits to write in fixed-point notation.
+    ///
+    /// If not set, the integer digits are written without padding.
+    min_integer_digits: OptionUsize,
+
+    /// Character to pad the integer digits with.
+    pad_char: u8,
+
+    /// Write digits greater than `9` in uppercase, such as `FF`.
+    uppercase_digits: bool,
+
+    /// Append the raw bit decomposition to allocated output.
+    debug_annotate: bool,
+
     /// Character to designate the exponent component of a float.
     exponent: u8,
 
@@ -340,8 +459,20 @@ pub struct OptionsBuilder {
     /// String representation of Not A Number, aka `NaN`.
     nan_string: Option<&'static [u8]>,
 
+    /// String representation of a signaling `NaN`.
+    snan_string: Option<&'static [u8]>,
+
+    /// String representation of a quiet `NaN`.
+    qnan_string: Option<&'static [u8]>,
+
     /// String representation of `Infinity`.
     inf_string: Option<&'static [u8]>,
+
+    /// String representation of negative `Infinity`.
+    neg_inf_string: Option<&'static [u8]>,
+
+    /// Literal suffix written after the float, such as a unit.
+    suffix: &'static [u8],
 }
 
 impl OptionsBuilder {
@@ -352,14 +483,27 @@ impl OptionsBuilder {
         Self {
             max_significant_digits: None,
             min_significant_digits: None,
+            significant_resolution: None,
             positive_exponent_break: None,
             negative_exponent_break: None,
             round_mode: RoundMode::Round,
             trim_floats: false,
+            trim_floats_below: None,
+            notation: Notation::Auto,
+            exponent_zero_behavior: ExponentZero::Unsigned,
+            exponent_min_digits: None,
+            min_integer_digits: None,
+            pad_char: b'0',
+            uppercase_digits: true,
+            debug_annotate: false,
             exponent: b'e',
             decimal_point: b'.',
             nan_string: Some(b"NaN"),
+            snan_string: None,
+            qnan_string: None,
             inf_string: Some(b"inf"),
+            neg_inf_string: None,
+            suffix: &[],
         }
     }
 
@@ -407,6 +551,32 @@ impl OptionsBuilder {
         self.min_significant_digits
     }
 
+    /// Get the relative resolution to write the fewest significant digits
+    /// for.
+    ///
+    /// If provided, this writes the fewest significant digits such that the
+    /// written value is within `resolution * |value|` of the float. This is
+    /// further limited by [`max_significant_digits`], and only applies to
+    /// decimal floats. Defaults to [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_significant_resolution(), None);
+    /// ```
+    ///
+    /// [`max_significant_digits`]: Self::max_significant_digits
+    #[inline(always)]
+    pub const fn get_significant_resolution(&self) -> Option<f64> {
+        match self.significant_resolution {
+            Some(FloatThreshold(resolution)) => Some(resolution),
+            None => None,
+        }
+    }
+
     /// Get the maximum exponent prior to using scientific notation.
     ///
     /// If the value is set to `300`, then any value with magnitude `>= 1e300`
@@ -488,6 +658,162 @@ impl OptionsBuilder {
         self.trim_floats
     }
 
+    /// Get the magnitude below which a trailing `".0"` is trimmed from
+    /// integral floats.
+    ///
+    /// Only values where `|value| < threshold` are trimmed, so small
+    /// integral floats are written as integers while larger ones keep
+    /// the `".0"`. This has no effect if [`trim_floats`] is enabled.
+    /// Defaults to [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_trim_floats_below(), None);
+    /// ```
+    ///
+    /// [`trim_floats`]: Self::trim_floats
+    #[inline(always)]
+    pub const fn get_trim_floats_below(&self) -> Option<f64> {
+        match self.trim_floats_below {
+            Some(FloatThreshold(threshold)) => Some(threshold),
+            None => None,
+        }
+    }
+
+    /// Get the notation to use when writing floats.
+    ///
+    /// [`Notation::Auto`] uses scientific notation outside of the
+    /// exponent breaks, while the other notations override them.
+    /// Defaults to [`Notation::Auto`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::{Notation, Options};
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_notation(), Notation::Auto);
+    /// ```
+    #[inline(always)]
+    pub const fn get_notation(&self) -> Notation {
+        self.notation
+    }
+
+    /// Get how to write a scientific exponent of zero.
+    ///
+    /// This only applies to power-of-two radixes. Defaults to
+    /// [`ExponentZero::Unsigned`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::{ExponentZero, Options};
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_exponent_zero_behavior(), ExponentZero::Unsigned);
+    /// ```
+    #[inline(always)]
+    pub const fn get_exponent_zero_behavior(&self) -> ExponentZero {
+        self.exponent_zero_behavior
+    }
+
+    /// Get the minimum number of digits to write for the exponent.
+    ///
+    /// If the exponent has fewer digits, it is padded with leading zeros
+    /// after the exponent sign. Defaults to [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_exponent_min_digits(), None);
+    /// ```
+    #[inline(always)]
+    pub const fn get_exponent_min_digits(&self) -> OptionUsize {
+        self.exponent_min_digits
+    }
+
+    /// Get the minimum number of integer digits to write in fixed-point
+    /// notation.
+    ///
+    /// If the integer part has fewer digits, it is padded with
+    /// [`pad_char`][Self::get_pad_char]. Defaults to [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_min_integer_digits(), None);
+    /// ```
+    #[inline(always)]
+    pub const fn get_min_integer_digits(&self) -> OptionUsize {
+        self.min_integer_digits
+    }
+
+    /// Get the character to pad the integer digits with.
+    ///
+    /// Defaults to `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_pad_char(), b'0');
+    /// ```
+    #[inline(always)]
+    pub const fn get_pad_char(&self) -> u8 {
+        self.pad_char
+    }
+
+    /// Get if digits greater than `9` are written in uppercase.
+    ///
+    /// This only affects radixes greater than `10`, such as the digits of
+    /// hexadecimal floats, and not the exponent character or special
+    /// values. Defaults to [`true`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_uppercase_digits(), true);
+    /// ```
+    #[inline(always)]
+    pub const fn get_uppercase_digits(&self) -> bool {
+        self.uppercase_digits
+    }
+
+    /// Get if the raw bit decomposition is appended to allocated output.
+    ///
+    /// This is a verbose, debug-friendly form only used by
+    /// `to_lexical_vec_with_options` and `to_lexical_string_with_options`,
+    /// such as `3.14 (0x40091EB851EB851F, exp=1, mant=0x91EB851EB851F)`.
+    /// Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_debug_annotate(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn get_debug_annotate(&self) -> bool {
+        self.debug_annotate
+    }
+
     /// Get the character to designate the exponent component of a float.
     ///
     /// Any non-control character is valid, but `\t` to `\r` are also valid.
@@ -543,11 +869,55 @@ impl OptionsBuilder {
         self.nan_string
     }
 
+    /// Get the string representation for a signaling `NaN`.
+    ///
+    /// All characters must be valid ASCII letters (`A-Z` or `a-z`). If set
+    /// to `None`, then signaling NaNs are written using [`get_nan_string`].
+    /// Defaults to `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_snan_string(), None);
+    /// ```
+    ///
+    /// [`get_nan_string`]: Self::get_nan_string
+    #[inline(always)]
+    pub const fn get_snan_string(&self) -> Option<&'static [u8]> {
+        self.snan_string
+    }
+
+    /// Get the string representation for a quiet `NaN`.
+    ///
+    /// All characters must be valid ASCII letters (`A-Z` or `a-z`). If set
+    /// to `None`, then quiet NaNs are written using [`get_nan_string`].
+    /// Defaults to `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_qnan_string(), None);
+    /// ```
+    ///
+    /// [`get_nan_string`]: Self::get_nan_string
+    #[inline(always)]
+    pub const fn get_qnan_string(&self) -> Option<&'static [u8]> {
+        self.qnan_string
+    }
+
     /// Get the string representation for `Infinity`.
     ///
-    /// The first character must start with `I` or `i` and all characters must
-    /// be valid ASCII letters (`A-Z` or `a-z`). If set to `None`, then writing
-    /// [`Infinity`][f64::INFINITY] returns an error. Defaults to `inf`.
+    /// This must either be valid ASCII letters (`A-Z` or `a-z`) starting with
+    /// `I` or `i`, or a UTF-8 symbol such as `∞` without any ASCII
+    /// characters, since the sign is written separately. If set to `None`,
+    /// then writing [`Infinity`][f64::INFINITY] returns an error. Defaults
+    /// to `inf`.
     ///
     /// # Examples
     ///
@@ -565,9 +935,11 @@ impl OptionsBuilder {
     /// Get the string representation for `Infinity`. Alias for
     /// [`get_inf_string`].
     ///
-    /// The first character must start with `I` or `i` and all characters must
-    /// be valid ASCII letters (`A-Z` or `a-z`). If set to `None`, then writing
-    /// [`Infinity`][f64::INFINITY] returns an error. Defaults to `inf`.
+    /// This must either be valid ASCII letters (`A-Z` or `a-z`) starting with
+    /// `I` or `i`, or a UTF-8 symbol such as `∞` without any ASCII
+    /// characters, since the sign is written separately. If set to `None`,
+    /// then writing [`Infinity`][f64::INFINITY] returns an error. Defaults
+    /// to `inf`.
     ///
     /// [`get_inf_string`]: Self::get_inf_string
     ///
@@ -584,6 +956,47 @@ impl OptionsBuilder {
         self.inf_string
     }
 
+    /// Get the string representation for negative `Infinity`.
+    ///
+    /// If set, this is written in place of the sign and [`inf_string`] for
+    /// negative [`Infinity`][f64::NEG_INFINITY], so it should include any
+    /// sign. It has the same requirements as [`inf_string`], except it may
+    /// start with a leading `-`. Defaults to `None`, which writes `-` followed
+    /// by the [`inf_string`].
+    ///
+    /// [`inf_string`]: Self::inf_string
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_neg_inf_string(), None);
+    /// ```
+    #[inline(always)]
+    pub const fn get_neg_inf_string(&self) -> Option<&'static [u8]> {
+        self.neg_inf_string
+    }
+
+    /// Get the literal suffix written after the float.
+    ///
+    /// This is written after every value, including any exponent and
+    /// special values, such as a unit. Defaults to an empty suffix.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_suffix(), b"");
+    /// ```
+    #[inline(always)]
+    pub const fn get_suffix(&self) -> &'static [u8] {
+        self.suffix
+    }
+
     // SETTERS
 
     /// Set the maximum number of significant digits to write.
@@ -651,6 +1064,47 @@ impl OptionsBuilder {
         self
     }
 
+    /// Set the relative resolution to write the fewest significant digits
+    /// for.
+    ///
+    /// If provided, this writes the fewest significant digits such that the
+    /// written value is within `resolution * |value|` of the float, such as
+    /// `0.001` to be unambiguous at a 0.1% resolution. Values that differ by
+    /// more than twice the resolution are always written differently, while
+    /// closer values may be written the same. This is further limited by
+    /// [`max_significant_digits`], and only applies to decimal floats. A
+    /// `NaN` or non-positive resolution writes all the digits. Defaults to
+    /// [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::str;
+    ///
+    /// use lexical_write_float::{Options, ToLexicalWithOptions};
+    /// use lexical_write_float::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .significant_resolution(Some(0.001))
+    ///     .build_strict();
+    /// const SIZE: usize = OPTIONS.buffer_size_const::<f64, STANDARD>();
+    /// let mut buffer = [0u8; SIZE];
+    /// let digits = 1.23456f64.to_lexical_with_options::<STANDARD>(&mut buffer, &OPTIONS);
+    /// assert_eq!(str::from_utf8(digits), Ok("1.235"));
+    /// let digits = 9876.54f64.to_lexical_with_options::<STANDARD>(&mut buffer, &OPTIONS);
+    /// assert_eq!(str::from_utf8(digits), Ok("9880.0"));
+    /// ```
+    ///
+    /// [`max_significant_digits`]: Self::max_significant_digits
+    #[inline(always)]
+    pub const fn significant_resolution(mut self, resolution: Option<f64>) -> Self {
+        self.significant_resolution = match resolution {
+            Some(resolution) => Some(FloatThreshold(resolution)),
+            None => None,
+        };
+        self
+    }
+
     /// Set the maximum exponent prior to using scientific notation.
     ///
     /// If the value is set to `300`, then any value with magnitude `>= 1e300`
@@ -783,6 +1237,257 @@ impl OptionsBuilder {
         self
     }
 
+    /// Set the magnitude below which a trailing `".0"` is trimmed from
+    /// integral floats.
+    ///
+    /// Only values where `|value| < threshold` are trimmed, so small
+    /// integral floats are written as integers while larger ones keep
+    /// the `".0"`. This has no effect if [`trim_floats`] is enabled,
+    /// and a `NaN` threshold never trims. Defaults to [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::str;
+    ///
+    /// use lexical_write_float::{Options, ToLexicalWithOptions};
+    /// use lexical_write_float::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .trim_floats_below(Some(1e6))
+    ///     .build_strict();
+    /// const SIZE: usize = OPTIONS.buffer_size_const::<f64, STANDARD>();
+    /// let mut buffer = [0u8; SIZE];
+    /// let digits = 5.0f64.to_lexical_with_options::<STANDARD>(&mut buffer, &OPTIONS);
+    /// assert_eq!(str::from_utf8(digits), Ok("5"));
+    /// let digits = 1e15f64.to_lexical_with_options::<STANDARD>(&mut buffer, &OPTIONS);
+    /// assert_eq!(str::from_utf8(digits), Ok("1.0e15"));
+    /// ```
+    ///
+    /// [`trim_floats`]: Self::trim_floats
+    #[inline(always)]
+    pub const fn trim_floats_below(mut self, threshold: Option<f64>) -> Self {
+        self.trim_floats_below = match threshold {
+            Some(threshold) => Some(FloatThreshold(threshold)),
+            None => None,
+        };
+        self
+    }
+
+    /// Set the notation to use when writing floats.
+    ///
+    /// [`Notation::Auto`] uses scientific notation outside of the
+    /// exponent breaks, while the other notations override them.
+    /// Requirements of the number format, such as if exponent notation is
+    /// not allowed or is required, take precedence over the notation, and
+    /// [`trim_floats`] is still applied to the significant digits.
+    /// Defaults to [`Notation::Auto`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::str;
+    ///
+    /// use lexical_write_float::{Notation, Options, ToLexicalWithOptions};
+    /// use lexical_write_float::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .notation(Notation::Engineering)
+    ///     .build_strict();
+    /// const SIZE: usize = OPTIONS.buffer_size_const::<f64, STANDARD>();
+    /// let mut buffer = [0u8; SIZE];
+    /// let digits = 0.0001f64.to_lexical_with_options::<STANDARD>(&mut buffer, &OPTIONS);
+    /// assert_eq!(str::from_utf8(digits), Ok("100.0e-6"));
+    /// ```
+    ///
+    /// [`trim_floats`]: Self::trim_floats
+    #[inline(always)]
+    pub const fn notation(mut self, notation: Notation) -> Self {
+        self.notation = notation;
+        self
+    }
+
+    /// Set how to write a scientific exponent of zero.
+    ///
+    /// [`ExponentZero::Omit`] does not write the exponent, unless the
+    /// number format requires exponent notation, [`ExponentZero::Signed`]
+    /// writes it with a `+` sign, unless the number format disallows one,
+    /// and [`ExponentZero::Unsigned`] writes it without a sign, unless the
+    /// number format requires one.
+    /// This only applies to the scientific writers for power-of-two
+    /// radixes, such as hexadecimal floats. Defaults to
+    /// [`ExponentZero::Unsigned`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "power-of-two")] {
+    /// use core::str;
+    ///
+    /// use lexical_write_float::{ExponentZero, Notation, Options, ToLexicalWithOptions};
+    /// use lexical_write_float::format::NumberFormatBuilder;
+    ///
+    /// const BINARY: u128 = NumberFormatBuilder::binary();
+    /// const OPTIONS: Options = Options::builder()
+    ///     .notation(Notation::Scientific)
+    ///     .exponent_zero_behavior(ExponentZero::Signed)
+    ///     .build_strict();
+    /// const SIZE: usize = OPTIONS.buffer_size_const::<f64, BINARY>();
+    /// let mut buffer = [0u8; SIZE];
+    /// let digits = 1.5f64.to_lexical_with_options::<BINARY>(&mut buffer, &OPTIONS);
+    /// assert_eq!(str::from_utf8(digits), Ok("1.1e+0"));
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub const fn exponent_zero_behavior(mut self, exponent_zero_behavior: ExponentZero) -> Self {
+        self.exponent_zero_behavior = exponent_zero_behavior;
+        self
+    }
+
+    /// Set the minimum number of digits to write for the exponent.
+    ///
+    /// If the exponent has fewer digits, it is padded with leading zeros
+    /// after the exponent sign, so `1.5e3` is written as `1.5e003` with
+    /// a minimum of 3 digits. Exponents with more digits are never
+    /// truncated. Defaults to [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::{num, str};
+    ///
+    /// use lexical_write_float::{Options, ToLexicalWithOptions};
+    /// use lexical_write_float::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .exponent_min_digits(num::NonZeroUsize::new(3))
+    ///     .build_strict();
+    /// const SIZE: usize = OPTIONS.buffer_size_const::<f64, STANDARD>();
+    /// let mut buffer = [0u8; SIZE];
+    /// let digits = 1.5e-10f64.to_lexical_with_options::<STANDARD>(&mut buffer, &OPTIONS);
+    /// assert_eq!(str::from_utf8(digits), Ok("1.5e-010"));
+    /// ```
+    #[inline(always)]
+    pub const fn exponent_min_digits(mut self, exponent_min_digits: OptionUsize) -> Self {
+        self.exponent_min_digits = exponent_min_digits;
+        self
+    }
+
+    /// Set the minimum number of integer digits to write in fixed-point
+    /// notation.
+    ///
+    /// If the integer part has fewer digits, it is padded with
+    /// [`pad_char`][Self::pad_char], which aligns the decimal points of
+    /// floats written in a column. Zero padding is written after the sign,
+    /// and any other padding before it. Scientific notation is never
+    /// padded. Defaults to [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::{num, str};
+    ///
+    /// use lexical_write_float::{Options, ToLexicalWithOptions};
+    /// use lexical_write_float::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .min_integer_digits(num::NonZeroUsize::new(3))
+    ///     .pad_char(b' ')
+    ///     .build_strict();
+    /// const SIZE: usize = OPTIONS.buffer_size_const::<f64, STANDARD>();
+    /// let mut buffer = [0u8; SIZE];
+    /// let digits = 3.25f64.to_lexical_with_options::<STANDARD>(&mut buffer, &OPTIONS);
+    /// assert_eq!(str::from_utf8(digits), Ok("  3.25"));
+    /// ```
+    #[inline(always)]
+    pub const fn min_integer_digits(mut self, min_integer_digits: OptionUsize) -> Self {
+        self.min_integer_digits = min_integer_digits;
+        self
+    }
+
+    /// Set the character to pad the integer digits with.
+    ///
+    /// This must be `0`, or a printable ASCII character that is not
+    /// alphanumeric, a sign, or the decimal point. Defaults to `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// let builder = Options::builder()
+    ///     .pad_char(b' ');
+    /// assert_eq!(builder.get_pad_char(), b' ');
+    /// ```
+    #[inline(always)]
+    pub const fn pad_char(mut self, pad_char: u8) -> Self {
+        self.pad_char = pad_char;
+        self
+    }
+
+    /// Set if digits greater than `9` are written in uppercase.
+    ///
+    /// This only affects radixes greater than `10`, such as the digits of
+    /// hexadecimal floats, and not the exponent character or special
+    /// values. Defaults to [`true`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "power-of-two")] {
+    /// use core::num;
+    ///
+    /// use lexical_write_float::{NumberFormatBuilder, Options, ToLexicalWithOptions};
+    ///
+    /// const FORMAT: u128 = NumberFormatBuilder::new()
+    ///     .mantissa_radix(16)
+    ///     .exponent_base(num::NonZeroU8::new(2))
+    ///     .exponent_radix(num::NonZeroU8::new(10))
+    ///     .build_strict();
+    /// const OPTIONS: Options = Options::builder()
+    ///     .uppercase_digits(false)
+    ///     .exponent(b'P')
+    ///     .build_strict();
+    /// const BUFFER_SIZE: usize = OPTIONS.buffer_size_const::<f64, FORMAT>();
+    /// let mut buffer = [0u8; BUFFER_SIZE];
+    /// let digits = 1e300f64.to_lexical_with_options::<FORMAT>(&mut buffer, &OPTIONS);
+    /// assert_eq!(digits, b"1.7e43c8800759cP996");
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub const fn uppercase_digits(mut self, uppercase_digits: bool) -> Self {
+        self.uppercase_digits = uppercase_digits;
+        self
+    }
+
+    /// Set if the raw bit decomposition is appended to allocated output.
+    ///
+    /// The shortest representation is followed by the float's bits in
+    /// hexadecimal, the unbiased binary exponent, and the stored mantissa
+    /// bits. This only affects [`to_lexical_vec_with_options`] and
+    /// [`to_lexical_string_with_options`], and is meant for debugging
+    /// rather than round-tripping. Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::{to_lexical_string_with_options, Options};
+    /// use lexical_write_float::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder().debug_annotate(true).build_strict();
+    /// let digits = to_lexical_string_with_options::<_, STANDARD>(3.14f64, &OPTIONS);
+    /// assert_eq!(digits, "3.14 (0x40091EB851EB851F, exp=1, mant=0x91EB851EB851F)");
+    /// ```
+    ///
+    /// [`to_lexical_vec_with_options`]: crate::to_lexical_vec_with_options
+    /// [`to_lexical_string_with_options`]: crate::to_lexical_string_with_options
+    #[inline(always)]
+    #[cfg(feature = "alloc")]
+    pub const fn debug_annotate(mut self, debug_annotate: bool) -> Self {
+        self.debug_annotate = debug_annotate;
+        self
+    }
+
     /// Set the character to designate the exponent component of a float.
     ///
     /// Any non-control character is valid, but `\t` to `\r` are also valid.
@@ -854,11 +1559,85 @@ impl OptionsBuilder {
         self
     }
 
+    /// Set the string representation for a signaling `NaN`.
+    ///
+    /// All characters must be valid ASCII letters (`A-Z` or `a-z`). If set
+    /// to `None`, then signaling NaNs are written using [`nan_string`].
+    /// Defaults to `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::str;
+    ///
+    /// use lexical_write_float::{Options, ToLexicalWithOptions};
+    /// use lexical_write_float::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .snan_string(Some(b"sNaN"))
+    ///     .build_strict();
+    /// const SIZE: usize = OPTIONS.buffer_size_const::<f64, STANDARD>();
+    /// let mut buffer = [0u8; SIZE];
+    /// let snan = f64::from_bits(0x7FF4_0000_0000_0000);
+    /// let digits = snan.to_lexical_with_options::<STANDARD>(&mut buffer, &OPTIONS);
+    /// assert_eq!(str::from_utf8(digits), Ok("sNaN"));
+    /// let digits = f64::NAN.to_lexical_with_options::<STANDARD>(&mut buffer, &OPTIONS);
+    /// assert_eq!(str::from_utf8(digits), Ok("NaN"));
+    /// ```
+    ///
+    /// Panics
+    ///
+    /// Setting a value with more than 50 elements will panic at runtime. You
+    /// should always build the format using [`build_strict`] or checking
+    /// [`is_valid`] prior to using the format, to avoid unexpected panics.
+    ///
+    /// [`nan_string`]: Self::nan_string
+    /// [`build_strict`]: Self::build_strict
+    /// [`is_valid`]: Self::is_valid
+    #[inline(always)]
+    pub const fn snan_string(mut self, snan_string: Option<&'static [u8]>) -> Self {
+        self.snan_string = snan_string;
+        self
+    }
+
+    /// Set the string representation for a quiet `NaN`.
+    ///
+    /// All characters must be valid ASCII letters (`A-Z` or `a-z`). If set
+    /// to `None`, then quiet NaNs are written using [`nan_string`].
+    /// Defaults to `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// let builder = Options::builder()
+    ///     .qnan_string(Some(b"qNaN"));
+    /// assert_eq!(builder.get_qnan_string(), Some(b"qNaN".as_ref()));
+    /// ```
+    ///
+    /// Panics
+    ///
+    /// Setting a value with more than 50 elements will panic at runtime. You
+    /// should always build the format using [`build_strict`] or checking
+    /// [`is_valid`] prior to using the format, to avoid unexpected panics.
+    ///
+    /// [`nan_string`]: Self::nan_string
+    /// [`build_strict`]: Self::build_strict
+    /// [`is_valid`]: Self::is_valid
+    #[inline(always)]
+    pub const fn qnan_string(mut self, qnan_string: Option<&'static [u8]>) -> Self {
+        self.qnan_string = qnan_string;
+        self
+    }
+
     /// Set the string representation for `Infinity`.
     ///
-    /// The first character must start with `I` or `i` and all characters must
-    /// be valid ASCII letters (`A-Z` or `a-z`). If set to `None`, then writing
-    /// [`Infinity`][f64::INFINITY] returns an error. Defaults to `inf`.
+    /// This must either be valid ASCII letters (`A-Z` or `a-z`) starting with
+    /// `I` or `i`, or a UTF-8 symbol such as `∞` without any ASCII
+    /// characters, since the sign is written separately. If set to `None`,
+    /// then writing [`Infinity`][f64::INFINITY] returns an error. Defaults
+    /// to `inf`.
     ///
     /// # Examples
     ///
@@ -887,9 +1666,11 @@ impl OptionsBuilder {
 
     /// Set the string representation for `Infinity`. Alias for [`inf_string`].
     ///
-    /// The first character must start with `I` or `i` and all characters must
-    /// be valid ASCII letters (`A-Z` or `a-z`). If set to `None`, then writing
-    /// [`Infinity`][f64::INFINITY] returns an error. Defaults to `inf`.
+    /// This must either be valid ASCII letters (`A-Z` or `a-z`) starting with
+    /// `I` or `i`, or a UTF-8 symbol such as `∞` without any ASCII
+    /// characters, since the sign is written separately. If set to `None`,
+    /// then writing [`Infinity`][f64::INFINITY] returns an error. Defaults
+    /// to `inf`.
     ///
     /// [`inf_string`]: Self::inf_string
     ///
@@ -917,6 +1698,71 @@ impl OptionsBuilder {
         self.inf_string(inf_string)
     }
 
+    /// Set the string representation for negative `Infinity`.
+    ///
+    /// If set, this is written in place of the sign and [`inf_string`] for
+    /// negative [`Infinity`][f64::NEG_INFINITY], so it should include any
+    /// sign. It has the same requirements as [`inf_string`], except it may
+    /// start with a leading `-`. Defaults to `None`, which writes `-` followed
+    /// by the [`inf_string`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// let builder = Options::builder()
+    ///     .inf_string(Some("∞".as_bytes()))
+    ///     .neg_inf_string(Some("-∞".as_bytes()));
+    /// assert_eq!(builder.get_neg_inf_string(), Some("-∞".as_bytes()));
+    /// ```
+    ///
+    /// Panics
+    ///
+    /// Setting a value with more than 50 elements will panic at runtime. You
+    /// should always build the format using [`build_strict`] or checking
+    /// [`is_valid`] prior to using the format, to avoid unexpected panics.
+    ///
+    /// [`inf_string`]: Self::inf_string
+    /// [`build_strict`]: Self::build_strict
+    /// [`is_valid`]: Self::is_valid
+    #[inline(always)]
+    pub const fn neg_inf_string(mut self, neg_inf_string: Option<&'static [u8]>) -> Self {
+        self.neg_inf_string = neg_inf_string;
+        self
+    }
+
+    /// Set the literal suffix written after the float.
+    ///
+    /// This is written after every value, including any exponent and
+    /// special values, so it can hold a unit, such as `" V"` or `"°C"`.
+    /// The suffix is included in the [`buffer_size_const`]. Defaults to an
+    /// empty suffix, which writes nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::str;
+    ///
+    /// use lexical_write_float::{Options, ToLexicalWithOptions};
+    /// use lexical_write_float::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .suffix(b" V")
+    ///     .build_strict();
+    /// const BUFFER_SIZE: usize = OPTIONS.buffer_size_const::<f64, STANDARD>();
+    /// let mut buffer = [0u8; BUFFER_SIZE];
+    /// let digits = 3.14f64.to_lexical_with_options::<STANDARD>(&mut buffer, &OPTIONS);
+    /// assert_eq!(str::from_utf8(digits), Ok("3.14 V"));
+    /// ```
+    ///
+    /// [`buffer_size_const`]: Options::buffer_size_const
+    #[inline(always)]
+    pub const fn suffix(mut self, suffix: &'static [u8]) -> Self {
+        self.suffix = suffix;
+        self
+    }
+
     // BUILDERS
 
     /// Determine if [`nan_string`][`Self::nan_string`] is valid.
@@ -941,22 +1787,42 @@ impl OptionsBuilder {
         }
     }
 
+    /// Determine if [`snan_string`][`Self::snan_string`] and
+    /// [`qnan_string`][`Self::qnan_string`] are valid.
+    #[doc(hidden)]
+    #[inline(always)]
+    pub const fn nan_kind_str_is_valid(&self) -> bool {
+        nan_kind_is_valid(self.snan_string) && nan_kind_is_valid(self.qnan_string)
+    }
+
+    /// Determine if [`pad_char`][`Self::pad_char`] is valid.
+    #[doc(hidden)]
+    #[inline(always)]
+    pub const fn pad_char_is_valid(&self) -> bool {
+        let c = self.pad_char;
+        c == b'0'
+            || ((c == b' ' || c.is_ascii_punctuation())
+                && !matches!(c, b'+' | b'-')
+                && c != self.decimal_point)
+    }
+
     /// Determine if [`inf_string`][`Self::inf_string`] is valid.
     #[doc(hidden)]
     #[inline(always)]
     #[allow(clippy::if_same_then_else, clippy::needless_bool)] // reason="more logical"
     pub const fn inf_str_is_valid(&self) -> bool {
         if self.inf_string.is_none() {
-            return true;
+            // The negative string is never used without the positive one.
+            return self.neg_inf_string.is_none();
         }
 
         let inf = unwrap_str(self.inf_string);
-        let length = inf.len();
-        if length == 0 || length > MAX_SPECIAL_STRING_LENGTH {
+        let neg_inf = unwrap_str(self.neg_inf_string);
+        if inf.len() > MAX_SPECIAL_STRING_LENGTH || neg_inf.len() > MAX_SPECIAL_STRING_LENGTH {
             false
-        } else if !matches!(inf[0], b'I' | b'i') {
+        } else if !is_valid_inf_slice(inf) {
             false
-        } else if !is_valid_letter_slice(inf) {
+        } else if self.neg_inf_string.is_some() && !is_valid_neg_inf_slice(neg_inf) {
             false
         } else {
             true
@@ -973,8 +1839,16 @@ impl OptionsBuilder {
             false
         } else if !self.nan_str_is_valid() {
             false
+        } else if !self.nan_kind_str_is_valid() {
+            false
         } else if !self.inf_str_is_valid() {
             false
+        } else if !self.pad_char_is_valid() {
+            false
+        } else if unwrap_or_max_usize(self.max_significant_digits)
+            < unwrap_or_zero_usize(self.min_significant_digits)
+        {
+            false
         } else {
             true
         }
@@ -999,14 +1873,27 @@ impl OptionsBuilder {
         Options {
             max_significant_digits: self.max_significant_digits,
             min_significant_digits: self.min_significant_digits,
+            significant_resolution: self.significant_resolution,
             positive_exponent_break: self.positive_exponent_break,
             negative_exponent_break: self.negative_exponent_break,
             round_mode: self.round_mode,
             trim_floats: self.trim_floats,
+            trim_floats_below: self.trim_floats_below,
+            notation: self.notation,
+            exponent_zero_behavior: self.exponent_zero_behavior,
+            exponent_min_digits: self.exponent_min_digits,
+            min_integer_digits: self.min_integer_digits,
+            pad_char: self.pad_char,
+            uppercase_digits: self.uppercase_digits,
+            debug_annotate: self.debug_annotate,
             exponent: self.exponent,
             decimal_point: self.decimal_point,
             nan_string: self.nan_string,
+            snan_string: self.snan_string,
+            qnan_string: self.qnan_string,
             inf_string: self.inf_string,
+            neg_inf_string: self.neg_inf_string,
+            suffix: self.suffix,
         }
     }
 
@@ -1041,17 +1928,34 @@ impl OptionsBuilder {
             }
         }
 
+        if self.snan_string.is_some() || self.qnan_string.is_some() {
+            let snan = unwrap_str(self.snan_string);
+            let qnan = unwrap_str(self.qnan_string);
+            if snan.len() > MAX_SPECIAL_STRING_LENGTH || qnan.len() > MAX_SPECIAL_STRING_LENGTH {
+                return Err(Error::NanStringTooLong);
+            } else if !self.nan_kind_str_is_valid() {
+                return Err(Error::InvalidNanString);
+            }
+        }
+
         if self.inf_string.is_some() {
             let inf = unwrap_str(self.inf_string);
-            if inf.is_empty() || !matches!(inf[0], b'I' | b'i') {
-                return Err(Error::InvalidInfString);
-            } else if !is_valid_letter_slice(inf) {
+            if !is_valid_inf_slice(inf) {
                 return Err(Error::InvalidInfString);
             } else if inf.len() > MAX_SPECIAL_STRING_LENGTH {
                 return Err(Error::InfStringTooLong);
             }
         }
 
+        if self.neg_inf_string.is_some() {
+            let neg_inf = unwrap_str(self.neg_inf_string);
+            if self.inf_string.is_none() || !is_valid_neg_inf_slice(neg_inf) {
+                return Err(Error::InvalidInfString);
+            } else if neg_inf.len() > MAX_SPECIAL_STRING_LENGTH {
+                return Err(Error::InfStringTooLong);
+            }
+        }
+
         let min_digits = unwrap_or_zero_usize(self.min_significant_digits);
         let max_digits = unwrap_or_max_usize(self.max_significant_digits);
         if max_digits < min_digits {
@@ -1064,6 +1968,8 @@ impl OptionsBuilder {
             Err(Error::InvalidExponentSymbol)
         } else if !is_valid_ascii(self.decimal_point) {
             Err(Error::InvalidDecimalPoint)
+        } else if !self.pad_char_is_valid() {
+            Err(Error::InvalidPadCharacter)
         } else {
             Ok(self.build_unchecked())
         }
@@ -1121,6 +2027,10 @@ pub struct Options {
     /// If not set, it defaults to the algorithm's default.
     min_significant_digits: OptionUsize,
 
+    /// Relative resolution to write the fewest significant digits for.
+    /// If not set, the digits are not limited by the resolution.
+    significant_resolution: Option<FloatThreshold>,
+
     /// Maximum exponent prior to using scientific notation.
     /// This is ignored if the exponent base is not the same as the mantissa
     /// radix. If not provided, use the algorithm's default.
@@ -1143,6 +2053,39 @@ pub struct Options {
     /// [`min_significant_digits`]: Self::min_significant_digits
     trim_floats: bool,
 
+    /// Only trim the trailing ".0" from integral floats below this magnitude.
+    ///
+    /// If not set, integral floats are only trimmed if [`trim_floats`]
+    /// is enabled.
+    ///
+    /// [`trim_floats`]: Self::trim_floats
+    trim_floats_below: Option<FloatThreshold>,
+
+    /// The notation to use when writing floats.
+    notation: Notation,
+
+    /// How to write a scientific exponent of zero.
+    exponent_zero_behavior: ExponentZero,
+
+    /// Minimum number of digits to write for the exponent.
+    ///
+    /// If not set, the exponent is written without padding.
+    exponent_min_digits: OptionUsize,
+
+    /// Minimum number of integer digits to write in fixed-point notation.
+    ///
+    /// If not set, the integer digits are written without padding.
+    min_integer_digits: OptionUsize,
+
+    /// Character to pad the integer digits with.
+    pad_char: u8,
+
+    /// Write digits greater than `9` in uppercase, such as `FF`.
+    uppercase_digits: bool,
+
+    /// Append the raw bit decomposition to allocated output.
+    debug_annotate: bool,
+
     /// Character to designate the exponent component of a float.
     exponent: u8,
 
@@ -1152,8 +2095,20 @@ pub struct Options {
     /// String representation of Not A Number, aka `NaN`.
     nan_string: Option<&'static [u8]>,
 
+    /// String representation of a signaling `NaN`.
+    snan_string: Option<&'static [u8]>,
+
+    /// String representation of a quiet `NaN`.
+    qnan_string: Option<&'static [u8]>,
+
     /// String representation of `Infinity`.
     inf_string: Option<&'static [u8]>,
+
+    /// String representation of negative `Infinity`.
+    neg_inf_string: Option<&'static [u8]>,
+
+    /// Literal suffix written after the float, such as a unit.
+    suffix: &'static [u8],
 }
 
 impl Options {
@@ -1283,7 +2238,8 @@ impl Options {
 
         // First need to calculate maximum number of digits from leading or
         // trailing zeros, IE, the exponent break.
-        if !format.no_exponent_notation() {
+        let is_fixed = matches!(self.notation(), Notation::Fixed);
+        if !format.no_exponent_notation() && (!is_fixed || format.required_exponent_notation()) {
             let min_exp = match self.negative_exponent_break() {
                 Some(v) => v.get(),
                 None => -5,
@@ -1296,9 +2252,11 @@ impl Options {
             if cfg!(feature = "power-of-two") && exp < 13 {
                 // 11 for the exponent digits in binary, 1 for the sign, 1 for the symbol
                 count += 13;
-            } else if exp < 5 {
-                // 3 for the exponent digits in decimal, 1 for the sign, 1 for the symbol
-                count += 5;
+            } else if exp < 12 {
+                // 3 for the exponent digits in decimal, 1 for the sign, 1 for the symbol.
+                // However, writing the decimal digits requires room for any `u32`,
+                // which is up to 10 digits.
+                count += 12;
             } else {
                 // More leading or trailing zeros than the exponent digits.
                 count += exp;
@@ -1341,11 +2299,22 @@ impl Options {
         };
         count += digits;
 
+        // Add any padding for the exponent digits.
+        if let Some(min_digits) = self.exponent_min_digits() {
+            count += min_digits.get();
+        }
+
+        // Add any padding for the integer digits.
+        if let Some(min_digits) = self.min_integer_digits() {
+            count += min_digits.get();
+        }
+
         // we need to make sure we have at least enough room for the
         // default formatting size, no matter what, just as a precaution.
         count = max!(count, formatted_size);
 
-        count
+        // Add the suffix, which is written after any value.
+        count + self.suffix.len()
     }
 
     // GETTERS
@@ -1356,6 +2325,43 @@ impl Options {
         self.rebuild().is_valid()
     }
 
+    /// Check if a packed number format is valid for writing.
+    ///
+    /// The writers panic on invalid formats, so this can be used to
+    /// check a format up front and get the specific error instead. On top
+    /// of [`validate_format`], this returns [`InvalidExponentBase`] if the
+    /// exponent base differs from the mantissa radix, unless floats can be
+    /// written with both: a radix of `4`, `8`, `16` or `32` with a base of
+    /// `2`, or a radix of `16` with a base of `4`.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "format")] {
+    /// use lexical_util::error::Error;
+    /// use lexical_util::format::{NumberFormatBuilder, STANDARD};
+    /// use lexical_write_float::Options;
+    ///
+    /// assert_eq!(Options::validate_format::<STANDARD>(), Ok(()));
+    /// const FORMAT: u128 = NumberFormatBuilder::new()
+    ///     .no_positive_mantissa_sign(true)
+    ///     .required_mantissa_sign(true)
+    ///     .build_unchecked();
+    /// assert_eq!(Options::validate_format::<FORMAT>(), Err(Error::InvalidMantissaSign));
+    /// # }
+    /// ```
+    ///
+    /// [`validate_format`]: lexical_util::format::validate_format
+    /// [`InvalidExponentBase`]: Error::InvalidExponentBase
+    #[inline(always)]
+    pub const fn validate_format<const FORMAT: u128>() -> Result<()> {
+        let format = NumberFormat::<FORMAT> {};
+        match validate_format(FORMAT) {
+            Ok(()) if !is_valid_exponent_base(format.mantissa_radix(), format.exponent_base()) => {
+                Err(Error::InvalidExponentBase)
+            },
+            result => result,
+        }
+    }
+
     /// Get the maximum number of significant digits to write.
     ///
     /// This limits the total number of written digits, truncating based
@@ -1408,6 +2414,34 @@ impl Options {
         self.min_significant_digits
     }
 
+    /// Get the relative resolution to write the fewest significant digits
+    /// for.
+    ///
+    /// If provided, this writes the fewest significant digits such that the
+    /// written value is within `resolution * |value|` of the float. This is
+    /// further limited by [`max_significant_digits`], and only applies to
+    /// decimal floats. Defaults to [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .significant_resolution(Some(0.001))
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.significant_resolution(), Some(0.001));
+    /// ```
+    ///
+    /// [`max_significant_digits`]: Self::max_significant_digits
+    #[inline(always)]
+    pub const fn significant_resolution(&self) -> Option<f64> {
+        match self.significant_resolution {
+            Some(FloatThreshold(resolution)) => Some(resolution),
+            None => None,
+        }
+    }
+
     /// Get the maximum exponent prior to using scientific notation.
     ///
     /// If the value is set to `300`, then any value with magnitude `>= 1e300`
@@ -1503,6 +2537,173 @@ impl Options {
         self.trim_floats
     }
 
+    /// Get the magnitude below which a trailing `".0"` is trimmed from
+    /// integral floats.
+    ///
+    /// Only values where `|value| < threshold` are trimmed, so small
+    /// integral floats are written as integers while larger ones keep
+    /// the `".0"`. This has no effect if [`trim_floats`] is enabled.
+    /// Defaults to [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .trim_floats_below(Some(1e6))
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.trim_floats_below(), Some(1e6));
+    /// ```
+    ///
+    /// [`trim_floats`]: Self::trim_floats
+    #[inline(always)]
+    pub const fn trim_floats_below(&self) -> Option<f64> {
+        match self.trim_floats_below {
+            Some(FloatThreshold(threshold)) => Some(threshold),
+            None => None,
+        }
+    }
+
+    /// Get the notation to use when writing floats.
+    ///
+    /// [`Notation::Auto`] uses scientific notation outside of the
+    /// exponent breaks, while the other notations override them.
+    /// Defaults to [`Notation::Auto`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::{Notation, Options};
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .notation(Notation::Scientific)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.notation(), Notation::Scientific);
+    /// ```
+    #[inline(always)]
+    pub const fn notation(&self) -> Notation {
+        self.notation
+    }
+
+    /// Get how to write a scientific exponent of zero.
+    ///
+    /// This only applies to power-of-two radixes. Defaults to
+    /// [`ExponentZero::Unsigned`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::{ExponentZero, Options};
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .exponent_zero_behavior(ExponentZero::Omit)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.exponent_zero_behavior(), ExponentZero::Omit);
+    /// ```
+    #[inline(always)]
+    pub const fn exponent_zero_behavior(&self) -> ExponentZero {
+        self.exponent_zero_behavior
+    }
+
+    /// Get the minimum number of digits to write for the exponent.
+    ///
+    /// If the exponent has fewer digits, it is padded with leading zeros
+    /// after the exponent sign. Defaults to [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::num;
+    ///
+    /// use lexical_write_float::Options;
+    ///
+    /// const MIN_DIGITS: Option<num::NonZeroUsize> = num::NonZeroUsize::new(3);
+    /// const OPTIONS: Options = Options::builder()
+    ///     .exponent_min_digits(MIN_DIGITS)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.exponent_min_digits(), MIN_DIGITS);
+    /// ```
+    #[inline(always)]
+    pub const fn exponent_min_digits(&self) -> OptionUsize {
+        self.exponent_min_digits
+    }
+
+    /// Get the minimum number of integer digits to write in fixed-point
+    /// notation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::num;
+    ///
+    /// use lexical_write_float::Options;
+    ///
+    /// const MIN_DIGITS: Option<num::NonZeroUsize> = num::NonZeroUsize::new(3);
+    /// const OPTIONS: Options = Options::builder()
+    ///     .min_integer_digits(MIN_DIGITS)
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.min_integer_digits(), MIN_DIGITS);
+    /// ```
+    #[inline(always)]
+    pub const fn min_integer_digits(&self) -> OptionUsize {
+        self.min_integer_digits
+    }
+
+    /// Get the character to pad the integer digits with.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .pad_char(b' ')
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.pad_char(), b' ');
+    /// ```
+    #[inline(always)]
+    pub const fn pad_char(&self) -> u8 {
+        self.pad_char
+    }
+
+    /// Get if digits greater than `9` are written in uppercase.
+    ///
+    /// This only affects radixes greater than `10`, such as the digits of
+    /// hexadecimal floats, and not the exponent character or special
+    /// values. Defaults to [`true`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// assert_eq!(Options::new().uppercase_digits(), true);
+    /// ```
+    #[inline(always)]
+    pub const fn uppercase_digits(&self) -> bool {
+        self.uppercase_digits
+    }
+
+    /// Get if the raw bit decomposition is appended to allocated output.
+    ///
+    /// This is a verbose, debug-friendly form only used by
+    /// `to_lexical_vec_with_options` and `to_lexical_string_with_options`,
+    /// such as `3.14 (0x40091EB851EB851F, exp=1, mant=0x91EB851EB851F)`.
+    /// Defaults to [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// assert_eq!(Options::new().debug_annotate(), false);
+    /// ```
+    #[inline(always)]
+    pub const fn debug_annotate(&self) -> bool {
+        self.debug_annotate
+    }
+
     /// Get the character to designate the exponent component of a float.
     ///
     /// Any non-control character is valid, but `\t` to `\r` are also valid.
@@ -1564,11 +2765,59 @@ impl Options {
         self.nan_string
     }
 
+    /// Get the string representation for a signaling `NaN`.
+    ///
+    /// All characters must be valid ASCII letters (`A-Z` or `a-z`). If set
+    /// to `None`, then signaling NaNs are written using [`nan_string`].
+    /// Defaults to `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .snan_string(Some(b"sNaN"))
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.snan_string(), Some(b"sNaN".as_ref()));
+    /// ```
+    ///
+    /// [`nan_string`]: Self::nan_string
+    #[inline(always)]
+    pub const fn snan_string(&self) -> Option<&'static [u8]> {
+        self.snan_string
+    }
+
+    /// Get the string representation for a quiet `NaN`.
+    ///
+    /// All characters must be valid ASCII letters (`A-Z` or `a-z`). If set
+    /// to `None`, then quiet NaNs are written using [`nan_string`].
+    /// Defaults to `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .qnan_string(Some(b"qNaN"))
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.qnan_string(), Some(b"qNaN".as_ref()));
+    /// ```
+    ///
+    /// [`nan_string`]: Self::nan_string
+    #[inline(always)]
+    pub const fn qnan_string(&self) -> Option<&'static [u8]> {
+        self.qnan_string
+    }
+
     /// Get the string representation for `Infinity`.
     ///
-    /// The first character must start with `I` or `i` and all characters must
-    /// be valid ASCII letters (`A-Z` or `a-z`). If set to `None`, then writing
-    /// [`Infinity`][f64::INFINITY] returns an error. Defaults to `inf`.
+    /// This must either be valid ASCII letters (`A-Z` or `a-z`) starting with
+    /// `I` or `i`, or a UTF-8 symbol such as `∞` without any ASCII
+    /// characters, since the sign is written separately. If set to `None`,
+    /// then writing [`Infinity`][f64::INFINITY] returns an error. Defaults
+    /// to `inf`.
     ///
     /// # Examples
     ///
@@ -1587,9 +2836,11 @@ impl Options {
 
     /// Get the string representation for `Infinity`. Alias for [`inf_string`].
     ///
-    /// The first character must start with `I` or `i` and all characters must
-    /// be valid ASCII letters (`A-Z` or `a-z`). If set to `None`, then writing
-    /// [`Infinity`][f64::INFINITY] returns an error. Defaults to `inf`.
+    /// This must either be valid ASCII letters (`A-Z` or `a-z`) starting with
+    /// `I` or `i`, or a UTF-8 symbol such as `∞` without any ASCII
+    /// characters, since the sign is written separately. If set to `None`,
+    /// then writing [`Infinity`][f64::INFINITY] returns an error. Defaults
+    /// to `inf`.
     ///
     /// [`inf_string`]: Self::inf_string
     ///
@@ -1608,6 +2859,50 @@ impl Options {
         self.inf_string
     }
 
+    /// Get the string representation for negative `Infinity`.
+    ///
+    /// If set, this is written in place of the sign and [`inf_string`] for
+    /// negative [`Infinity`][f64::NEG_INFINITY]. Defaults to `None`, which
+    /// writes `-` followed by the [`inf_string`].
+    ///
+    /// [`inf_string`]: Self::inf_string
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .inf_string(Some("∞".as_bytes()))
+    ///     .neg_inf_string(Some("-∞".as_bytes()))
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.neg_inf_string(), Some("-∞".as_bytes()));
+    /// ```
+    #[inline(always)]
+    pub const fn neg_inf_string(&self) -> Option<&'static [u8]> {
+        self.neg_inf_string
+    }
+
+    /// Get the literal suffix written after the float.
+    ///
+    /// This is written after every value, including any exponent and
+    /// special values, such as a unit. Defaults to an empty suffix.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_float::Options;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .suffix(b" V")
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.suffix(), b" V");
+    /// ```
+    #[inline(always)]
+    pub const fn suffix(&self) -> &'static [u8] {
+        self.suffix
+    }
+
     // SETTERS
 
     /// Set the maximum number of significant digits to write.
@@ -1757,14 +3052,27 @@ impl Options {
         OptionsBuilder {
             max_significant_digits: self.max_significant_digits,
             min_significant_digits: self.min_significant_digits,
+            significant_resolution: self.significant_resolution,
             positive_exponent_break: self.positive_exponent_break,
             negative_exponent_break: self.negative_exponent_break,
             round_mode: self.round_mode,
             trim_floats: self.trim_floats,
+            trim_floats_below: self.trim_floats_below,
+            notation: self.notation,
+            exponent_zero_behavior: self.exponent_zero_behavior,
+            exponent_min_digits: self.exponent_min_digits,
+            min_integer_digits: self.min_integer_digits,
+            pad_char: self.pad_char,
+            uppercase_digits: self.uppercase_digits,
+            debug_annotate: self.debug_annotate,
             exponent: self.exponent,
             decimal_point: self.decimal_point,
             nan_string: self.nan_string,
+            snan_string: self.snan_string,
+            qnan_string: self.qnan_string,
             inf_string: self.inf_string,
+            neg_inf_string: self.neg_inf_string,
+            suffix: self.suffix,
         }
     }
 }
@@ -1789,6 +3097,100 @@ impl WriteOptions for Options {
     }
 }
 
+// BUFFER SIZE
+// -----------
+
+/// Calculate the upper bound on the number of bytes written for any float.
+///
+/// This is [`Options::buffer_size_const`] for the largest supported float,
+/// so a buffer of this size can hold any [`f32`] or [`f64`] written with
+/// the format and options. This accounts for the sign, decimal point,
+/// minimum significant and integer digits, exponent padding, and the leading
+/// or trailing zeros from the exponent breaks or disabling scientific notation.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `options` - Options to customize number writing.
+///
+/// # Examples
+///
+/// ```rust
+/// use core::num;
+///
+/// use lexical_write_float::{formatted_size, FormattedSize, Notation, Options};
+/// use lexical_write_float::format::STANDARD;
+///
+/// assert_eq!(formatted_size::<STANDARD>(&Options::new()), f64::FORMATTED_SIZE_DECIMAL);
+///
+/// const FIXED: Options = Options::builder()
+///     .notation(Notation::Fixed)
+///     .min_significant_digits(num::NonZeroUsize::new(40))
+///     .build_strict();
+/// const SIZE: usize = formatted_size::<STANDARD>(&FIXED);
+/// let buffer = [0u8; SIZE];
+/// assert!(buffer.len() > 324);
+/// ```
+#[inline(always)]
+pub const fn formatted_size<const FORMAT: u128>(options: &Options) -> usize {
+    options.buffer_size_const::<f64, FORMAT>()
+}
+
+// THREAD-LOCAL DEFAULTS
+// ---------------------
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static DEFAULT_OPTIONS: RefCell<Options> = const { RefCell::new(Options::new()) };
+}
+
+/// Ambient, per-thread default options for writing floats.
+///
+/// This allows configuring the options once and then using them from
+/// code that does not have access to the [`Options`], without threading
+/// them through every call. Each thread starts with [`Options::new`].
+///
+/// The defaults are used by [`to_lexical_default`]. [`to_lexical`] always
+/// uses the standard options and never reads the thread-local defaults,
+/// so it is not slowed down by the lookup.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_write_float::{to_lexical_default, Options, ThreadLocalOptions, BUFFER_SIZE};
+///
+/// let options = Options::builder().decimal_point(b',').build_strict();
+/// ThreadLocalOptions::set_default(options);
+/// let mut buffer = [0u8; BUFFER_SIZE];
+/// assert_eq!(to_lexical_default(1.5f64, &mut buffer), b"1,5");
+/// ```
+///
+/// [`to_lexical`]: crate::ToLexical::to_lexical
+/// [`to_lexical_default`]: crate::to_lexical_default
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThreadLocalOptions;
+
+#[cfg(feature = "std")]
+impl ThreadLocalOptions {
+    /// Set the default options for the current thread.
+    ///
+    /// Returns the previous default options, which can be used to restore
+    /// them later.
+    #[inline]
+    pub fn set_default(options: Options) -> Options {
+        DEFAULT_OPTIONS.with(|default| default.replace(options))
+    }
+
+    /// Call `f` with the default options for the current thread.
+    ///
+    /// The options are copied before calling `f`, so `f` may change the
+    /// defaults without affecting the options it was given.
+    #[inline]
+    pub fn with_default<R, F: FnOnce(&Options) -> R>(f: F) -> R {
+        let options = DEFAULT_OPTIONS.with(|default| default.borrow().clone());
+        f(&options)
+    }
+}
+
 /// Define `unwrap_or_zero` for a custom type.
 macro_rules! unwrap_or_zero {
     ($name:ident, $opt:ident, $t:ident) => {
@@ -1824,6 +3226,77 @@ const fn unwrap_str(option: Option<&'static [u8]>) -> &'static [u8] {
     }
 }
 
+/// Determine if floats can be written with the exponent base and mantissa radix.
+#[inline(always)]
+const fn is_valid_exponent_base(radix: u32, base: u32) -> bool {
+    radix == base || matches!((radix, base), (4, 2) | (8, 2) | (16, 2) | (32, 2) | (16, 4))
+}
+
+/// Determine if a signaling or quiet `NaN` string is valid.
+#[inline(always)]
+const fn nan_kind_is_valid(option: Option<&'static [u8]>) -> bool {
+    match option {
+        Some(nan) => {
+            !nan.is_empty() && nan.len() <= MAX_SPECIAL_STRING_LENGTH && is_valid_letter_slice(nan)
+        },
+        None => true,
+    }
+}
+
+/// Determine if an `Infinity` string is valid.
+///
+/// This is either ASCII letters starting with `I` or `i`, or valid UTF-8
+/// without any ASCII characters. Signs are rejected, since the sign is
+/// written separately, and would be duplicated for negative values.
+#[inline(always)]
+const fn is_valid_inf_slice(inf: &[u8]) -> bool {
+    if inf.is_empty() {
+        return false;
+    } else if matches!(inf[0], b'I' | b'i') {
+        return is_valid_letter_slice(inf);
+    }
+
+    let mut index = 0;
+    while index < inf.len() {
+        // Get the width of the character and the valid range of the second
+        // byte, which rejects overlong encodings and surrogates.
+        let (width, lower, upper) = match inf[index] {
+            0xC2..=0xDF => (2, 0x80, 0xBF),
+            0xE0 => (3, 0xA0, 0xBF),
+            0xED => (3, 0x80, 0x9F),
+            0xE1..=0xEF => (3, 0x80, 0xBF),
+            0xF0 => (4, 0x90, 0xBF),
+            0xF1..=0xF3 => (4, 0x80, 0xBF),
+            0xF4 => (4, 0x80, 0x8F),
+            _ => return false,
+        };
+        let is_short = index + width > inf.len();
+        if is_short || inf[index + 1] < lower || inf[index + 1] > upper {
+            return false;
+        }
+        let mut offset = 2;
+        while offset < width {
+            if inf[index + offset] & 0xC0 != 0x80 {
+                return false;
+            }
+            offset += 1;
+        }
+        index += width;
+    }
+    true
+}
+
+/// Determine if a negative `Infinity` string is valid.
+///
+/// This is the same as [`is_valid_inf_slice`], with an optional leading `-`.
+#[inline(always)]
+const fn is_valid_neg_inf_slice(neg_inf: &[u8]) -> bool {
+    match neg_inf {
+        [b'-', rest @ ..] => is_valid_inf_slice(rest),
+        _ => is_valid_inf_slice(neg_inf),
+    }
+}
+
 // PRE-DEFINED CONSTANTS
 // ---------------------
 
@@ -1855,6 +3328,17 @@ pub const CARAT_EXPONENT: Options = Options::builder()
     .exponent(b'^')
     .build_strict();
 
+/// Numerical format for the shortest scientific notation that round-trips.
+///
+/// This always uses scientific notation, with the shortest digits that
+/// round-trip, no trailing `.0`, and an exponent without padding or a
+/// `+` sign, such as `1e5` or `1.5e-7`.
+#[rustfmt::skip]
+pub const COMPACT_SCIENTIFIC: Options = Options::builder()
+    .notation(Notation::Scientific)
+    .trim_floats(true)
+    .build_strict();
+
 /// Number format for a [`Rust`] literal floating-point number.
 ///
 /// [`Rust`]: https://www.rust-lang.org/
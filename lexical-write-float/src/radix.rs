@@ -250,8 +250,11 @@ pub fn write_float_scientific<const FORMAT: u128>(
         cursor = digits_end;
     }
 
+    // Shift the decimal point if using engineering notation.
+    let sci_exp = shared::shift_engineering::<FORMAT>(bytes, &mut cursor, sci_exp, options);
+
     // Now, write our scientific notation.
-    shared::write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp, options.exponent());
+    shared::write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp, options);
 
     cursor
 }
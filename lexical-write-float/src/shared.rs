@@ -2,9 +2,11 @@
 
 use lexical_util::digit::{char_to_valid_digit_const, digit_to_char_const};
 use lexical_util::format::NumberFormat;
-use lexical_write_integer::write::WriteInteger;
+use lexical_write_integer::write::{write_sign, WriteInteger};
 
-use crate::options::{Options, RoundMode};
+#[cfg(feature = "power-of-two")]
+use crate::options::ExponentZero;
+use crate::options::{Notation, Options, RoundMode};
 
 /// Get the exact number of digits from a minimum bound.
 #[inline(always)]
@@ -46,6 +48,39 @@ pub fn round_up(digits: &mut [u8], count: usize, radix: u32) -> (usize, bool) {
     (1, true)
 }
 
+/// Get the fewest decimal digits within the relative resolution.
+///
+/// The digits are treated as a fraction in `[0.1, 1)`, since the exponent
+/// does not affect the relative error. This returns the number of digits
+/// such that rounding, or truncating, to them has an error of at most
+/// `resolution` times the value.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn resolution_digits(digits: &[u8], resolution: f64, round_mode: RoundMode) -> usize {
+    let mut value = 0.0;
+    let mut unit = 1.0;
+    for &digit in digits {
+        unit /= 10.0;
+        value += (digit - b'0') as f64 * unit;
+    }
+
+    let mut prefix = 0.0;
+    let mut unit = 1.0;
+    for (index, &digit) in digits.iter().enumerate() {
+        unit /= 10.0;
+        prefix += (digit - b'0') as f64 * unit;
+        let remainder = value - prefix;
+        let error = if round_mode == RoundMode::Round && 2.0 * remainder >= unit {
+            unit - remainder
+        } else {
+            remainder
+        };
+        if error <= resolution * value {
+            return index + 1;
+        }
+    }
+    digits.len()
+}
+
 /// Round the number of digits based on the maximum digits, for decimal digits.
 ///
 /// `digits` is a mutable buffer of the current digits, `digit_count` is the
@@ -61,11 +96,15 @@ pub fn truncate_and_round_decimal(
 ) -> (usize, bool) {
     debug_assert!(digit_count <= digits.len());
 
-    let max_digits = if let Some(digits) = options.max_significant_digits() {
+    let mut max_digits = if let Some(digits) = options.max_significant_digits() {
         digits.get()
     } else {
-        return (digit_count, false);
+        digit_count
     };
+    if let Some(resolution) = options.significant_resolution() {
+        let digits = &digits[..digit_count];
+        max_digits = max_digits.min(resolution_digits(digits, resolution, options.round_mode()));
+    }
     if max_digits >= digit_count {
         return (digit_count, false);
     }
@@ -115,31 +154,117 @@ pub fn write_exponent_sign<const FORMAT: u128>(
     exp: i32,
 ) -> u32 {
     let format = NumberFormat::<{ FORMAT }> {};
-    if exp < 0 {
-        bytes[*cursor] = b'-';
-        *cursor += 1;
-        exp.wrapping_neg() as u32
-    } else if cfg!(feature = "format") && format.required_exponent_sign() {
-        bytes[*cursor] = b'+';
-        *cursor += 1;
-        exp as u32
-    } else {
-        exp as u32
-    }
+    let required = cfg!(feature = "format") && format.required_exponent_sign();
+    *cursor += write_sign(&mut bytes[*cursor..], exp < 0, required);
+    exp.unsigned_abs()
 }
 
 /// Write the symbol, sign, and digits for the exponent.
+///
+/// The exponent digits are padded with leading zeros, after the sign,
+/// to [`exponent_min_digits`][Options::exponent_min_digits].
 #[cfg_attr(not(feature = "compact"), inline(always))]
 pub fn write_exponent<const FORMAT: u128>(
     bytes: &mut [u8],
     cursor: &mut usize,
     exp: i32,
-    exponent_character: u8,
+    options: &Options,
 ) {
-    bytes[*cursor] = exponent_character;
+    bytes[*cursor] = options.exponent();
     *cursor += 1;
     let positive_exp: u32 = write_exponent_sign::<FORMAT>(bytes, cursor, exp);
-    *cursor += positive_exp.write_exponent_signed::<FORMAT>(&mut bytes[*cursor..]);
+    write_exponent_digits::<FORMAT>(bytes, cursor, positive_exp, options);
+}
+
+/// Write the symbol, sign, and digits for the exponent of a power-of-two
+/// radix.
+///
+/// This is identical to [`write_exponent`], except an exponent of `0` is
+/// written according to
+/// [`exponent_zero_behavior`][Options::exponent_zero_behavior].
+#[cfg(feature = "power-of-two")]
+#[cfg_attr(not(feature = "compact"), inline(always))]
+pub fn write_power_of_two_exponent<const FORMAT: u128>(
+    bytes: &mut [u8],
+    cursor: &mut usize,
+    exp: i32,
+    options: &Options,
+) {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let no_positive = cfg!(feature = "format") && format.no_positive_exponent_sign();
+    match options.exponent_zero_behavior() {
+        ExponentZero::Omit if exp == 0 && !format.required_exponent_notation() => (),
+        ExponentZero::Signed if exp == 0 && !no_positive => {
+            bytes[*cursor] = options.exponent();
+            bytes[*cursor + 1] = b'+';
+            *cursor += 2;
+            write_exponent_digits::<FORMAT>(bytes, cursor, 0, options);
+        },
+        _ => write_exponent::<FORMAT>(bytes, cursor, exp, options),
+    }
+}
+
+/// Write the digits for the exponent, padded with leading zeros.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn write_exponent_digits<const FORMAT: u128>(
+    bytes: &mut [u8],
+    cursor: &mut usize,
+    positive_exp: u32,
+    options: &Options,
+) {
+    let count = positive_exp.write_exponent_signed::<FORMAT>(&mut bytes[*cursor..]);
+    let min_digits = options.exponent_min_digits().map_or(0, |x| x.get());
+    if count < min_digits {
+        let zeros = min_digits - count;
+        bytes.copy_within(*cursor..*cursor + count, *cursor + zeros);
+        bytes[*cursor..*cursor + zeros].fill(b'0');
+        *cursor += min_digits;
+    } else {
+        *cursor += count;
+    }
+}
+
+/// Shift the decimal point for engineering notation.
+///
+/// This expects the digits to already be written in scientific notation,
+/// with the first digit at index `0`, and if `cursor > 1`, the decimal
+/// point at index `1` followed by the fraction digits. The decimal point
+/// is shifted so the exponent is a multiple of 3, padding with zeros if
+/// there are not enough digits, and the shifted exponent is returned. If
+/// not using [`Notation::Engineering`], this is a no-op.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+pub fn shift_engineering<const FORMAT: u128>(
+    bytes: &mut [u8],
+    cursor: &mut usize,
+    sci_exp: i32,
+    options: &Options,
+) -> i32 {
+    let shift = sci_exp.rem_euclid(3) as usize;
+    if options.notation() != Notation::Engineering || shift == 0 {
+        return sci_exp;
+    }
+
+    let format = NumberFormat::<{ FORMAT }> {};
+    let decimal_point = options.decimal_point();
+    let fraction_count = cursor.saturating_sub(2);
+    if fraction_count > shift {
+        // Have enough digits, just move the decimal point.
+        bytes.copy_within(2..2 + shift, 1);
+        bytes[1 + shift] = decimal_point;
+    } else {
+        // Need to pad the integral digits with zeros, and then
+        // write the trailing `.0`, unless we're trimming floats.
+        bytes.copy_within(2..2 + fraction_count, 1);
+        bytes[1 + fraction_count..1 + shift].fill(b'0');
+        *cursor = 1 + shift;
+        if format.no_exponent_without_fraction() || !options.trim_floats() {
+            bytes[*cursor] = decimal_point;
+            bytes[*cursor + 1] = b'0';
+            *cursor += 2;
+        }
+    }
+
+    sci_exp - shift as i32
 }
 
 /// Detect the notation to use for the float formatter and call the appropriate
@@ -172,14 +297,20 @@ macro_rules! write_float {
     ) => {{
         use lexical_util::format::NumberFormat;
 
+        use crate::options::Notation;
+
         debug_assert!($float.is_sign_positive());
 
         let format = NumberFormat::<{ $format }> {};
         let min_exp = $options.negative_exponent_break().map_or(-5, |x| x.get());
         let max_exp = $options.positive_exponent_break().map_or(9, |x| x.get());
 
-        let outside_break = $sci_exp < min_exp || $sci_exp > max_exp;
-        let require_exponent = format.required_exponent_notation() || outside_break;
+        let use_exponent = match $options.notation() {
+            Notation::Auto => $sci_exp < min_exp || $sci_exp > max_exp,
+            Notation::Fixed => false,
+            Notation::Scientific | Notation::Engineering => true,
+        };
+        let require_exponent = format.required_exponent_notation() || use_exponent;
         if !format.no_exponent_notation() && require_exponent {
             // Write digits in scientific notation.
             $write_scientific::<$($generic,)? FORMAT>($bytes, $($args,)*)
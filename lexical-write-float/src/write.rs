@@ -8,7 +8,7 @@ use lexical_util::bf16::bf16;
 use lexical_util::f16::f16;
 use lexical_util::format::NumberFormat;
 use lexical_util::{algorithm::copy_to_dst, constants::FormattedSize};
-use lexical_write_integer::write::WriteInteger;
+use lexical_write_integer::write::{write_sign, WriteInteger};
 
 /// Select the back-end.
 #[cfg(not(feature = "compact"))]
@@ -38,25 +38,103 @@ fn write_special(bytes: &mut [u8], special: Option<&[u8]>, error: &'static str)
 }
 
 /// Write an NaN string to the buffer.
-fn write_nan(bytes: &mut [u8], options: &Options, count: usize) -> usize {
+///
+/// Uses the signaling or quiet NaN string if provided, falling back to the
+/// NaN string otherwise.
+pub(crate) fn write_nan(
+    bytes: &mut [u8],
+    options: &Options,
+    count: usize,
+    is_signaling: bool,
+) -> usize {
+    let nan_string = if is_signaling {
+        options.snan_string().or(options.nan_string())
+    } else {
+        options.qnan_string().or(options.nan_string())
+    };
     count
         + write_special(
             bytes,
-            options.nan_string(),
+            nan_string,
             "NaN explicitly disabled but asked to write NaN as string.",
         )
 }
 
 /// Write an Inf string to the buffer.
-fn write_inf(bytes: &mut [u8], options: &Options, count: usize) -> usize {
+///
+/// The buffer must start at the sign, which occupies the first `count`
+/// bytes. For negative infinity, the negative Inf string replaces the sign
+/// if provided.
+pub(crate) fn write_inf(
+    buffer: &mut [u8],
+    options: &Options,
+    count: usize,
+    is_negative: bool,
+) -> usize {
+    if let (true, Some(neg_inf_string)) = (is_negative, options.neg_inf_string()) {
+        debug_assert!(neg_inf_string.len() <= 50, "neg_inf_string.len() must be <= 50");
+        return copy_to_dst(buffer, neg_inf_string);
+    }
     count
         + write_special(
-            bytes,
+            &mut buffer[count..],
             options.inf_string(),
             "Inf explicitly disabled but asked to write Inf as string.",
         )
 }
 
+/// Pad the integer digits of fixed-point notation to the minimum width.
+///
+/// Zero padding is written after the sign, and any other padding before it.
+/// Scientific notation is never padded. Returns the new length of the float.
+#[inline(always)]
+fn pad_integer_digits(bytes: &mut [u8], len: usize, sign: usize, options: &Options) -> usize {
+    let min_digits = match options.min_integer_digits() {
+        Some(min_digits) => min_digits.get(),
+        None => return len,
+    };
+    let digits = &bytes[sign..len];
+    if digits.contains(&options.exponent()) {
+        return len;
+    }
+    let integer_digits =
+        digits.iter().position(|&c| c == options.decimal_point()).unwrap_or(digits.len());
+    if integer_digits >= min_digits {
+        return len;
+    }
+
+    let padding = min_digits - integer_digits;
+    let pad_char = options.pad_char();
+    let start = if pad_char == b'0' {
+        sign
+    } else {
+        0
+    };
+    bytes.copy_within(start..len, start + padding);
+    bytes[start..start + padding].fill(pad_char);
+    len + padding
+}
+
+/// Write the suffix after the float. Returns the new length of the float.
+#[inline(always)]
+fn write_suffix(bytes: &mut [u8], len: usize, options: &Options) -> usize {
+    let suffix = options.suffix();
+    bytes[len..len + suffix.len()].copy_from_slice(suffix);
+    len + suffix.len()
+}
+
+/// Convert the digits greater than `9` to lowercase.
+///
+/// The exponent character is left as-is, so its case is set by the options.
+#[inline(always)]
+fn lowercase_digits(bytes: &mut [u8], options: &Options) {
+    for c in bytes.iter_mut() {
+        if c.is_ascii_uppercase() && *c != options.exponent() {
+            *c = c.to_ascii_lowercase();
+        }
+    }
+}
+
 /// Check if a buffer is sufficiently large.
 #[inline(always)]
 fn check_buffer<T, const FORMAT: u128>(len: usize, options: &Options) -> bool
@@ -107,57 +185,71 @@ pub trait WriteFloat: RawFloat + FormattedSize {
             }
         }
 
-        let (float, count, bytes) = if self.needs_negative_sign() {
-            bytes[0] = b'-';
-            (-self, 1, &mut bytes[1..])
-        } else if cfg!(feature = "format") && format.required_mantissa_sign() {
-            bytes[0] = b'+';
-            (self, 1, &mut bytes[1..])
-        } else {
-            (self, 0, bytes)
+        let buffer = bytes;
+        let is_negative = self.needs_negative_sign();
+        let required = cfg!(feature = "format") && format.required_mantissa_sign();
+        let count = write_sign(buffer, is_negative, required);
+        let float = if is_negative { -self } else { self };
+        let bytes = &mut buffer[count..];
+
+        // Only trim small, integral floats if requested.
+        let trim_options;
+        let options = match options.trim_floats_below() {
+            Some(threshold) if !options.trim_floats() && float.as_f64() < threshold => {
+                trim_options = options.rebuild().trim_floats(true).build_unchecked();
+                &trim_options
+            },
+            _ => options,
         };
 
         // Handle special values.
-        if !self.is_special() {
-            #[cfg(all(feature = "power-of-two", not(feature = "radix")))]
-            {
-                let radix = format.radix();
-                let exponent_base = format.exponent_base();
-                count
-                    + if radix == 10 {
-                        write_float_decimal::<_, FORMAT>(float, bytes, options)
-                    } else if radix != exponent_base {
-                        hex::write_float::<_, FORMAT>(float, bytes, options)
-                    } else {
-                        binary::write_float::<_, FORMAT>(float, bytes, options)
-                    }
-            }
+        let len = if !self.is_special() {
+            let len = {
+                #[cfg(all(feature = "power-of-two", not(feature = "radix")))]
+                {
+                    let radix = format.radix();
+                    let exponent_base = format.exponent_base();
+                    count
+                        + if radix == 10 {
+                            write_float_decimal::<_, FORMAT>(float, bytes, options)
+                        } else if radix != exponent_base {
+                            hex::write_float::<_, FORMAT>(float, bytes, options)
+                        } else {
+                            binary::write_float::<_, FORMAT>(float, bytes, options)
+                        }
+                }
 
-            #[cfg(feature = "radix")]
-            {
-                let radix = format.radix();
-                let exponent_base = format.exponent_base();
-                count
-                    + if radix == 10 {
-                        write_float_decimal::<_, FORMAT>(float, bytes, options)
-                    } else if radix != exponent_base {
-                        hex::write_float::<_, FORMAT>(float, bytes, options)
-                    } else if matches!(radix, 2 | 4 | 8 | 16 | 32) {
-                        binary::write_float::<_, FORMAT>(float, bytes, options)
-                    } else {
-                        radix::write_float::<_, FORMAT>(float, bytes, options)
-                    }
-            }
+                #[cfg(feature = "radix")]
+                {
+                    let radix = format.radix();
+                    let exponent_base = format.exponent_base();
+                    count
+                        + if radix == 10 {
+                            write_float_decimal::<_, FORMAT>(float, bytes, options)
+                        } else if radix != exponent_base {
+                            hex::write_float::<_, FORMAT>(float, bytes, options)
+                        } else if matches!(radix, 2 | 4 | 8 | 16 | 32) {
+                            binary::write_float::<_, FORMAT>(float, bytes, options)
+                        } else {
+                            radix::write_float::<_, FORMAT>(float, bytes, options)
+                        }
+                }
 
-            #[cfg(not(feature = "power-of-two"))]
-            {
-                count + write_float_decimal::<_, FORMAT>(float, bytes, options)
+                #[cfg(not(feature = "power-of-two"))]
+                {
+                    count + write_float_decimal::<_, FORMAT>(float, bytes, options)
+                }
+            };
+            if !options.uppercase_digits() {
+                lowercase_digits(&mut buffer[count..len], options);
             }
+            pad_integer_digits(buffer, len, count, options)
         } else if self.is_nan() {
-            write_nan(bytes, options, count)
+            write_nan(bytes, options, count, self.is_signaling_nan())
         } else {
-            write_inf(bytes, options, count)
-        }
+            write_inf(buffer, options, count, self.is_sign_negative())
+        };
+        write_suffix(buffer, len, options)
     }
 }
 
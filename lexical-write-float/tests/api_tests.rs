@@ -1,6 +1,6 @@
 use lexical_util::constants::BUFFER_SIZE;
 use lexical_util::format::STANDARD;
-use lexical_write_float::{Options, ToLexical, ToLexicalWithOptions};
+use lexical_write_float::{Notation, Options, RoundMode, ToLexical, ToLexicalWithOptions};
 
 #[test]
 fn error_tests() {
@@ -43,6 +43,65 @@ fn special_test() {
     assert_eq!(actual, "Infinity");
 }
 
+#[test]
+fn signaling_nan_test() {
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let snan = f64::from_bits(0x7FF4_0000_0000_0000);
+    let write = |float: f64, buffer: &mut [u8], options: &Options| -> String {
+        let bytes = float.to_lexical_with_options::<{ STANDARD }>(buffer, options);
+        String::from_utf8(bytes.to_vec()).unwrap()
+    };
+
+    // By default, both kinds are written using the NaN string.
+    const DEFAULT: Options = Options::new();
+    assert_eq!(write(snan, &mut buffer, &DEFAULT), "NaN");
+    assert_eq!(write(f64::NAN, &mut buffer, &DEFAULT), "NaN");
+
+    const OPTIONS: Options =
+        Options::builder().snan_string(Some(b"sNaN")).qnan_string(Some(b"qNaN")).build_strict();
+    assert_eq!(write(snan, &mut buffer, &OPTIONS), "sNaN");
+    assert_eq!(write(-snan, &mut buffer, &OPTIONS), "sNaN");
+    assert_eq!(write(f64::from_bits(0x7FF0_0000_0000_0001), &mut buffer, &OPTIONS), "sNaN");
+    assert_eq!(write(f64::NAN, &mut buffer, &OPTIONS), "qNaN");
+
+    const SIGNALING: Options = Options::builder().snan_string(Some(b"sNaN")).build_strict();
+    assert_eq!(write(snan, &mut buffer, &SIGNALING), "sNaN");
+    assert_eq!(write(f64::NAN, &mut buffer, &SIGNALING), "NaN");
+
+    let snan = f32::from_bits(0x7FA0_0000);
+    let bytes = snan.to_lexical_with_options::<STANDARD>(&mut buffer, &OPTIONS);
+    assert_eq!(bytes, b"sNaN");
+}
+
+#[test]
+fn neg_inf_string_test() {
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let write = |float: f64, buffer: &mut [u8], options: &Options| -> String {
+        let bytes = float.to_lexical_with_options::<{ STANDARD }>(buffer, options);
+        String::from_utf8(bytes.to_vec()).unwrap()
+    };
+
+    // Without a negative string, the sign prefixes the infinity string.
+    const SYMBOL: Options = Options::builder().inf_string(Some("∞".as_bytes())).build_strict();
+    assert_eq!(write(f64::INFINITY, &mut buffer, &SYMBOL), "∞");
+    assert_eq!(write(f64::NEG_INFINITY, &mut buffer, &SYMBOL), "-∞");
+
+    const OPTIONS: Options = Options::builder()
+        .inf_string(Some("∞".as_bytes()))
+        .neg_inf_string(Some("−∞".as_bytes()))
+        .build_strict();
+    assert_eq!(write(f64::INFINITY, &mut buffer, &OPTIONS), "∞");
+    assert_eq!(write(f64::NEG_INFINITY, &mut buffer, &OPTIONS), "−∞");
+    assert_eq!(write(-1.5, &mut buffer, &OPTIONS), "-1.5");
+
+    const ASCII: Options = Options::builder().neg_inf_string(Some(b"-Infinity")).build_strict();
+    assert_eq!(write(f64::INFINITY, &mut buffer, &ASCII), "inf");
+    assert_eq!(write(f64::NEG_INFINITY, &mut buffer, &ASCII), "-Infinity");
+
+    let bytes = f32::NEG_INFINITY.to_lexical_with_options::<STANDARD>(&mut buffer, &OPTIONS);
+    assert_eq!(bytes, "−∞".as_bytes());
+}
+
 #[test]
 #[should_panic]
 fn invalid_nan_test() {
@@ -78,3 +137,792 @@ fn hex_test() {
     let result = float.to_lexical_with_options::<BASE16_2_10>(&mut buffer, &HEX_OPTIONS);
     assert_eq!(result, b"3.039^12");
 }
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn exponent_zero_behavior_test() {
+    use core::num;
+
+    use lexical_util::format::NumberFormatBuilder;
+    use lexical_write_float::ExponentZero;
+
+    const BINARY: u128 = NumberFormatBuilder::binary();
+    const BASE16_2_10: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(16)
+        .exponent_base(num::NonZeroU8::new(2))
+        .exponent_radix(num::NonZeroU8::new(10))
+        .build_strict();
+    const SCIENTIFIC: Options = Options::builder().notation(Notation::Scientific).build_strict();
+    const HEX: Options = SCIENTIFIC.rebuild().exponent(b'p').build_unchecked();
+
+    let write = |float: f64, behavior: ExponentZero| {
+        let mut buffer = [b'\x00'; 512];
+        let options = SCIENTIFIC.rebuild().exponent_zero_behavior(behavior).build_strict();
+        float.to_lexical_with_options::<BINARY>(&mut buffer, &options).to_vec()
+    };
+    assert_eq!(write(1.5, ExponentZero::Unsigned), b"1.1e0");
+    assert_eq!(write(1.5, ExponentZero::Signed), b"1.1e+0");
+    assert_eq!(write(1.5, ExponentZero::Omit), b"1.1");
+    assert_eq!(write(-1.0, ExponentZero::Omit), b"-1.0");
+    assert_eq!(write(3.0, ExponentZero::Omit), b"1.1e1");
+    assert_eq!(write(3.0, ExponentZero::Signed), b"1.1e1");
+
+    let write = |float: f64, behavior: ExponentZero| {
+        let mut buffer = [b'\x00'; 512];
+        let options = HEX.rebuild().exponent_zero_behavior(behavior).build_unchecked();
+        float.to_lexical_with_options::<BASE16_2_10>(&mut buffer, &options).to_vec()
+    };
+    assert_eq!(write(1.5, ExponentZero::Unsigned), b"1.8p0");
+    assert_eq!(write(1.5, ExponentZero::Signed), b"1.8p+0");
+    assert_eq!(write(1.5, ExponentZero::Omit), b"1.8");
+    assert_eq!(write(0.5, ExponentZero::Omit), b"8.0p-4");
+}
+
+#[test]
+#[cfg(all(feature = "power-of-two", feature = "format"))]
+fn exponent_zero_behavior_format_test() {
+    use lexical_util::format::NumberFormatBuilder;
+    use lexical_write_float::ExponentZero;
+
+    const SIGNED: u128 = NumberFormatBuilder::rebuild(NumberFormatBuilder::binary())
+        .required_exponent_sign(true)
+        .build_strict();
+    const REQUIRED: u128 = NumberFormatBuilder::rebuild(NumberFormatBuilder::binary())
+        .required_exponent_notation(true)
+        .build_strict();
+    const NO_POSITIVE: u128 = NumberFormatBuilder::rebuild(NumberFormatBuilder::binary())
+        .no_positive_exponent_sign(true)
+        .build_strict();
+    const UNSIGNED: Options = Options::builder()
+        .notation(Notation::Scientific)
+        .exponent_zero_behavior(ExponentZero::Unsigned)
+        .build_strict();
+    const OMIT: Options =
+        UNSIGNED.rebuild().exponent_zero_behavior(ExponentZero::Omit).build_strict();
+    const SIGNED_ZERO: Options =
+        UNSIGNED.rebuild().exponent_zero_behavior(ExponentZero::Signed).build_strict();
+
+    // The number format takes precedence over the behavior.
+    let mut buffer = [b'\x00'; 512];
+    assert_eq!(1.5f64.to_lexical_with_options::<SIGNED>(&mut buffer, &UNSIGNED), b"1.1e+0");
+    assert_eq!(1.5f64.to_lexical_with_options::<REQUIRED>(&mut buffer, &OMIT), b"1.1e0");
+    assert_eq!(1.5f64.to_lexical_with_options::<NO_POSITIVE>(&mut buffer, &SIGNED_ZERO), b"1.1e0");
+}
+
+#[test]
+fn compact_scientific_test() {
+    use lexical_write_float::options::COMPACT_SCIENTIFIC;
+
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let cases: [(f64, &str); 10] = [
+        (1e5, "1e5"),
+        (1.5e-7, "1.5e-7"),
+        (0.1, "1e-1"),
+        (1.0, "1e0"),
+        (-2.5, "-2.5e0"),
+        (123.456, "1.23456e2"),
+        (1e300, "1e300"),
+        (5e-324, "5e-324"),
+        (f64::MIN_POSITIVE, "2.2250738585072014e-308"),
+        (f64::MAX, "1.7976931348623157e308"),
+    ];
+    for (value, expected) in cases {
+        let actual = value.to_lexical_with_options::<STANDARD>(&mut buffer, &COMPACT_SCIENTIFIC);
+        let actual = core::str::from_utf8(actual).unwrap();
+        assert_eq!(actual, expected);
+        assert_eq!(actual.parse::<f64>(), Ok(value));
+    }
+
+    let actual = 3.4e38f32.to_lexical_with_options::<STANDARD>(&mut buffer, &COMPACT_SCIENTIFIC);
+    assert_eq!(actual, b"3.4e38");
+    let actual = 0.0f64.to_lexical_with_options::<STANDARD>(&mut buffer, &COMPACT_SCIENTIFIC);
+    assert_eq!(actual, b"0e0");
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn uppercase_digits_test() {
+    use lexical_util::format::NumberFormatBuilder;
+
+    const HEX: u128 = NumberFormatBuilder::hexadecimal();
+    const BASE16_2_16: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(16)
+        .exponent_base(core::num::NonZeroU8::new(2))
+        .exponent_radix(core::num::NonZeroU8::new(16))
+        .build_strict();
+    const UPPER: Options = Options::builder().exponent(b'P').build_strict();
+    const LOWER: Options = Options::builder().exponent(b'P').uppercase_digits(false).build_strict();
+
+    let mut buffer = [b'\x00'; 2 * BUFFER_SIZE];
+    assert_eq!(255.0f64.to_lexical_with_options::<HEX>(&mut buffer, &UPPER), b"FF.0");
+    assert_eq!(255.0f64.to_lexical_with_options::<HEX>(&mut buffer, &LOWER), b"ff.0");
+    let result = 0.0001f64.to_lexical_with_options::<HEX>(&mut buffer, &LOWER);
+    assert_eq!(result, b"6.8db8bac710cb4P-4");
+    let result = 1e300f64.to_lexical_with_options::<BASE16_2_16>(&mut buffer, &UPPER);
+    assert_eq!(result, b"1.7E43C8800759CP3E4");
+    let result = 1e300f64.to_lexical_with_options::<BASE16_2_16>(&mut buffer, &LOWER);
+    assert_eq!(result, b"1.7e43c8800759cP3e4");
+
+    // The exponent character keeps its own case, as do special values.
+    const LOWER_EXP: Options =
+        Options::builder().exponent(b'p').uppercase_digits(false).build_strict();
+    let result = (-1e300f64).to_lexical_with_options::<BASE16_2_16>(&mut buffer, &LOWER_EXP);
+    assert_eq!(result, b"-1.7e43c8800759cp3e4");
+    assert_eq!(f64::INFINITY.to_lexical_with_options::<HEX>(&mut buffer, &LOWER), b"inf");
+    assert_eq!(f64::NAN.to_lexical_with_options::<HEX>(&mut buffer, &LOWER), b"NaN");
+}
+
+fn write_notation<const FORMAT: u128>(float: f64, options: &Options, expected: &str) {
+    let mut buffer = vec![b'\x00'; options.buffer_size_const::<f64, FORMAT>()];
+    let bytes = float.to_lexical_with_options::<FORMAT>(&mut buffer, options);
+    let actual = unsafe { std::str::from_utf8_unchecked(bytes) };
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn notation_test() {
+    const AUTO: Options = Options::builder().notation(Notation::Auto).build_strict();
+    write_notation::<STANDARD>(1234.5, &AUTO, "1234.5");
+    write_notation::<STANDARD>(0.0001, &AUTO, "0.0001");
+    write_notation::<STANDARD>(1e30, &AUTO, "1.0e30");
+
+    const FIXED: Options = Options::builder().notation(Notation::Fixed).build_strict();
+    write_notation::<STANDARD>(1234.5, &FIXED, "1234.5");
+    write_notation::<STANDARD>(0.0001, &FIXED, "0.0001");
+    write_notation::<STANDARD>(1e30, &FIXED, "1000000000000000000000000000000.0");
+
+    const SCIENTIFIC: Options = Options::builder().notation(Notation::Scientific).build_strict();
+    write_notation::<STANDARD>(1234.5, &SCIENTIFIC, "1.2345e3");
+    write_notation::<STANDARD>(0.0001, &SCIENTIFIC, "1.0e-4");
+    write_notation::<STANDARD>(1e30, &SCIENTIFIC, "1.0e30");
+
+    const ENGINEERING: Options = Options::builder().notation(Notation::Engineering).build_strict();
+    write_notation::<STANDARD>(1234.5, &ENGINEERING, "1.2345e3");
+    write_notation::<STANDARD>(0.0001, &ENGINEERING, "100.0e-6");
+    write_notation::<STANDARD>(1e30, &ENGINEERING, "1.0e30");
+    write_notation::<STANDARD>(12345.0, &ENGINEERING, "12.345e3");
+    write_notation::<STANDARD>(123456.0, &ENGINEERING, "123.456e3");
+    write_notation::<STANDARD>(0.0, &ENGINEERING, "0.0e0");
+}
+
+#[test]
+fn notation_fixed_extremes_test() {
+    use lexical_write_float::formatted_size;
+
+    const FIXED: Options = Options::builder().notation(Notation::Fixed).build_strict();
+    let mut buffer = [b'\x00'; formatted_size::<STANDARD>(&FIXED)];
+    let values = [1e300, 1e-300, f64::MAX, -f64::MAX, f64::MIN_POSITIVE, 5e-324, -5e-324];
+    for value in values {
+        let digits = value.to_lexical_with_options::<STANDARD>(&mut buffer, &FIXED);
+        let digits = core::str::from_utf8(digits).unwrap();
+        assert!(!digits.contains('e'));
+        assert_eq!(digits.parse::<f64>(), Ok(value));
+    }
+
+    let digits = 1e300f64.to_lexical_with_options::<STANDARD>(&mut buffer, &FIXED);
+    assert_eq!(digits.len(), 303);
+    assert!(digits.starts_with(b"10000") && digits.ends_with(b"000.0"));
+    let digits = 1e-300f64.to_lexical_with_options::<STANDARD>(&mut buffer, &FIXED);
+    assert_eq!(digits.len(), 302);
+    assert!(digits.starts_with(b"0.000") && digits.ends_with(b"0001"));
+
+    let digits = 1e38f32.to_lexical_with_options::<STANDARD>(&mut buffer, &FIXED);
+    assert_eq!(core::str::from_utf8(digits).unwrap().parse::<f32>(), Ok(1e38));
+
+    // Disabling exponent notation in the format has the same worst case.
+    #[cfg(feature = "format")]
+    {
+        use lexical_util::format::NumberFormatBuilder;
+
+        const FORMAT: u128 = NumberFormatBuilder::new().no_exponent_notation(true).build_strict();
+        const OPTIONS: Options = Options::new();
+        let mut buffer = [b'\x00'; formatted_size::<FORMAT>(&OPTIONS)];
+        for value in values {
+            let digits = value.to_lexical_with_options::<FORMAT>(&mut buffer, &OPTIONS);
+            let digits = core::str::from_utf8(digits).unwrap();
+            assert_eq!(digits.parse::<f64>(), Ok(value));
+        }
+    }
+}
+
+#[test]
+fn notation_trim_floats_test() {
+    const FIXED: Options =
+        Options::builder().notation(Notation::Fixed).trim_floats(true).build_strict();
+    write_notation::<STANDARD>(1e30, &FIXED, "1000000000000000000000000000000");
+
+    const SCIENTIFIC: Options =
+        Options::builder().notation(Notation::Scientific).trim_floats(true).build_strict();
+    write_notation::<STANDARD>(1234.5, &SCIENTIFIC, "1.2345e3");
+    write_notation::<STANDARD>(0.0001, &SCIENTIFIC, "1e-4");
+    write_notation::<STANDARD>(1e30, &SCIENTIFIC, "1e30");
+
+    const ENGINEERING: Options =
+        Options::builder().notation(Notation::Engineering).trim_floats(true).build_strict();
+    write_notation::<STANDARD>(1234.5, &ENGINEERING, "1.2345e3");
+    write_notation::<STANDARD>(0.0001, &ENGINEERING, "100e-6");
+    write_notation::<STANDARD>(1e30, &ENGINEERING, "1e30");
+    write_notation::<STANDARD>(12000.0, &ENGINEERING, "12e3");
+}
+
+#[test]
+fn trim_floats_below_test() {
+    const OPTIONS: Options = Options::builder().trim_floats_below(Some(1e6)).build_strict();
+    write_notation::<STANDARD>(5.0, &OPTIONS, "5");
+    write_notation::<STANDARD>(-5.0, &OPTIONS, "-5");
+    write_notation::<STANDARD>(999999.0, &OPTIONS, "999999");
+    write_notation::<STANDARD>(1e6, &OPTIONS, "1000000.0");
+    write_notation::<STANDARD>(1e15, &OPTIONS, "1.0e15");
+    write_notation::<STANDARD>(-1e15, &OPTIONS, "-1.0e15");
+    write_notation::<STANDARD>(5.5, &OPTIONS, "5.5");
+
+    const FIXED: Options = Options::builder()
+        .trim_floats_below(Some(1e6))
+        .notation(Notation::Fixed)
+        .build_strict();
+    write_notation::<STANDARD>(5.0, &FIXED, "5");
+    write_notation::<STANDARD>(1e15, &FIXED, "1000000000000000.0");
+
+    // Trimming all floats takes precedence.
+    const TRIM: Options =
+        Options::builder().trim_floats(true).trim_floats_below(Some(1e6)).build_strict();
+    write_notation::<STANDARD>(1e15, &TRIM, "1e15");
+
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let bytes = 5.0f32.to_lexical_with_options::<STANDARD>(&mut buffer, &OPTIONS);
+    assert_eq!(bytes, b"5");
+    let bytes = 1e15f32.to_lexical_with_options::<STANDARD>(&mut buffer, &OPTIONS);
+    assert_eq!(bytes, b"1.0e15");
+}
+
+#[test]
+fn significant_resolution_test() {
+    const OPTIONS: Options = Options::builder().significant_resolution(Some(0.001)).build_strict();
+    write_notation::<STANDARD>(1.23456, &OPTIONS, "1.235");
+    write_notation::<STANDARD>(-1.23456, &OPTIONS, "-1.235");
+    write_notation::<STANDARD>(9876.54, &OPTIONS, "9880.0");
+    write_notation::<STANDARD>(0.000123456, &OPTIONS, "0.0001235");
+    write_notation::<STANDARD>(1.5e30, &OPTIONS, "1.5e30");
+    write_notation::<STANDARD>(0.0, &OPTIONS, "0.0");
+
+    // Values closer than the resolution may collapse.
+    write_notation::<STANDARD>(1.0, &OPTIONS, "1.0");
+    write_notation::<STANDARD>(1.0004, &OPTIONS, "1.0");
+    write_notation::<STANDARD>(0.9996, &OPTIONS, "1.0");
+
+    // Values further apart than the resolution are written differently.
+    write_notation::<STANDARD>(1.01, &OPTIONS, "1.01");
+    write_notation::<STANDARD>(1.002, &OPTIONS, "1.002");
+    write_notation::<STANDARD>(0.998, &OPTIONS, "0.998");
+
+    // The written value is always within the resolution.
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let mut value = 0.001f64;
+    while value < 1e6 {
+        for resolution in [0.1, 0.01, 0.001, 1e-6] {
+            let options =
+                Options::builder().significant_resolution(Some(resolution)).build_strict();
+            let bytes = value.to_lexical_with_options::<STANDARD>(&mut buffer, &options);
+            let parsed: f64 = std::str::from_utf8(bytes).unwrap().parse().unwrap();
+            assert!((parsed - value).abs() <= resolution * value);
+        }
+        value *= 1.37;
+    }
+
+    // Truncating uses more digits to stay within the resolution.
+    const TRUNCATE: Options = Options::builder()
+        .significant_resolution(Some(0.001))
+        .round_mode(RoundMode::Truncate)
+        .build_strict();
+    write_notation::<STANDARD>(1.23456, &TRUNCATE, "1.234");
+    write_notation::<STANDARD>(0.9996, &TRUNCATE, "0.999");
+
+    // The maximum significant digits still apply.
+    const MAX_DIGITS: Options = Options::builder()
+        .significant_resolution(Some(1e-6))
+        .max_significant_digits(core::num::NonZeroUsize::new(3))
+        .build_strict();
+    write_notation::<STANDARD>(1.23456, &MAX_DIGITS, "1.23");
+
+    // Invalid resolutions write all the digits.
+    const NAN: Options = Options::builder().significant_resolution(Some(f64::NAN)).build_strict();
+    write_notation::<STANDARD>(1.23456, &NAN, "1.23456");
+    const NEGATIVE: Options = Options::builder().significant_resolution(Some(-1.0)).build_strict();
+    write_notation::<STANDARD>(1.23456, &NEGATIVE, "1.23456");
+}
+
+#[test]
+fn notation_significant_digits_test() {
+    use core::num;
+
+    const ENGINEERING: Options = Options::builder()
+        .notation(Notation::Engineering)
+        .min_significant_digits(num::NonZeroUsize::new(4))
+        .build_strict();
+    write_notation::<STANDARD>(0.0001, &ENGINEERING, "100.0e-6");
+    write_notation::<STANDARD>(12000.0, &ENGINEERING, "12.00e3");
+}
+
+#[test]
+fn notation_integral_significant_digits_test() {
+    use core::num;
+
+    // Integral values must keep the trailing zeros as significant digits.
+    const SCIENTIFIC: Options = Options::builder()
+        .notation(Notation::Scientific)
+        .min_significant_digits(num::NonZeroUsize::new(4))
+        .build_strict();
+    write_notation::<STANDARD>(1000.0, &SCIENTIFIC, "1.000e3");
+    write_notation::<STANDARD>(1.0, &SCIENTIFIC, "1.000e0");
+    write_notation::<STANDARD>(10.0, &SCIENTIFIC, "1.000e1");
+    write_notation::<STANDARD>(1200.0, &SCIENTIFIC, "1.200e3");
+    write_notation::<STANDARD>(1e30, &SCIENTIFIC, "1.000e30");
+    write_notation::<STANDARD>(12345.0, &SCIENTIFIC, "1.2345e4");
+
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let bytes = 1000.0f32.to_lexical_with_options::<STANDARD>(&mut buffer, &SCIENTIFIC);
+    assert_eq!(bytes, b"1.000e3");
+
+    // Trimming floats still trims all the significant digits.
+    const TRIMMED: Options = Options::builder()
+        .notation(Notation::Scientific)
+        .min_significant_digits(num::NonZeroUsize::new(4))
+        .trim_floats(true)
+        .build_strict();
+    write_notation::<STANDARD>(1000.0, &TRIMMED, "1e3");
+    write_notation::<STANDARD>(1200.0, &TRIMMED, "1.200e3");
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn notation_format_precedence_test() {
+    use lexical_util::format::NumberFormatBuilder;
+
+    const NO_EXPONENT: u128 = NumberFormatBuilder::new().no_exponent_notation(true).build_strict();
+    const REQUIRED_EXPONENT: u128 =
+        NumberFormatBuilder::new().required_exponent_notation(true).build_strict();
+
+    const SCIENTIFIC: Options = Options::builder().notation(Notation::Scientific).build_strict();
+    write_notation::<NO_EXPONENT>(1234.5, &SCIENTIFIC, "1234.5");
+
+    const FIXED: Options = Options::builder().notation(Notation::Fixed).build_strict();
+    write_notation::<REQUIRED_EXPONENT>(1234.5, &FIXED, "1.2345e3");
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn notation_radix_test() {
+    use lexical_util::format::NumberFormatBuilder;
+
+    const BINARY: u128 = NumberFormatBuilder::binary();
+    const HEX: u128 = NumberFormatBuilder::hexadecimal();
+    const BASE16_2_10: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(16)
+        .exponent_base(core::num::NonZeroU8::new(2))
+        .exponent_radix(core::num::NonZeroU8::new(10))
+        .build_strict();
+
+    const FIXED: Options =
+        Options::builder().notation(Notation::Fixed).exponent(b'^').build_strict();
+    write_notation::<BINARY>(
+        1e30,
+        &FIXED,
+        "1100100111110010110010011100110100000100011001110101000000000000000000000000000000000000000000000000.0",
+    );
+    write_notation::<HEX>(0.0001, &FIXED, "0.00068DB8BAC710CB4");
+
+    const SCIENTIFIC: Options =
+        Options::builder().notation(Notation::Scientific).exponent(b'^').build_strict();
+    write_notation::<BINARY>(0.5, &SCIENTIFIC, "1.0^-1");
+    write_notation::<HEX>(1234.5, &SCIENTIFIC, "4.D28^2");
+    write_notation::<BASE16_2_10>(1234.5, &SCIENTIFIC, "4.D28^8");
+
+    const ENGINEERING: Options =
+        Options::builder().notation(Notation::Engineering).exponent(b'^').build_strict();
+    write_notation::<BINARY>(0.5, &ENGINEERING, "100.0^-11");
+    write_notation::<BINARY>(4.0, &ENGINEERING, "100.0^0");
+    write_notation::<HEX>(1234.5, &ENGINEERING, "4D2.8^0");
+    write_notation::<BASE16_2_10>(1234.5, &ENGINEERING, "4.D28^8");
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn notation_radix_integral_significant_digits_test() {
+    use lexical_util::format::NumberFormatBuilder;
+
+    const BINARY: u128 = NumberFormatBuilder::binary();
+    const HEX: u128 = NumberFormatBuilder::hexadecimal();
+
+    const SCIENTIFIC: Options = Options::builder()
+        .notation(Notation::Scientific)
+        .exponent(b'^')
+        .min_significant_digits(core::num::NonZeroUsize::new(4))
+        .build_strict();
+    write_notation::<BINARY>(8.0, &SCIENTIFIC, "1.000^11");
+    write_notation::<BINARY>(1.0, &SCIENTIFIC, "1.000^0");
+    write_notation::<HEX>(4096.0, &SCIENTIFIC, "1.000^3");
+}
+
+#[test]
+fn buffer_size_significant_digits_test() {
+    use core::num;
+
+    // The exponent must fit after the significant digits.
+    const OPTIONS: Options =
+        Options::builder().min_significant_digits(num::NonZeroUsize::new(100)).build_strict();
+    let digits = "1.".to_string() + &"0".repeat(99);
+    write_notation::<STANDARD>(1e-300, &OPTIONS, &(digits.clone() + "e-300"));
+    write_notation::<STANDARD>(1e300, &OPTIONS, &(digits + "e300"));
+}
+
+#[test]
+fn min_integer_digits_test() {
+    use core::num;
+
+    const SPACE: Options = Options::builder()
+        .min_integer_digits(num::NonZeroUsize::new(3))
+        .pad_char(b' ')
+        .build_strict();
+    write_notation::<STANDARD>(3.25, &SPACE, "  3.25");
+    write_notation::<STANDARD>(12.7, &SPACE, " 12.7");
+    write_notation::<STANDARD>(123.0, &SPACE, "123.0");
+    write_notation::<STANDARD>(1234.5, &SPACE, "1234.5");
+    write_notation::<STANDARD>(-3.25, &SPACE, "  -3.25");
+    write_notation::<STANDARD>(0.001, &SPACE, "  0.001");
+    write_notation::<STANDARD>(1e300, &SPACE, "1.0e300");
+    write_notation::<STANDARD>(f64::NAN, &SPACE, "NaN");
+    write_notation::<STANDARD>(f64::NEG_INFINITY, &SPACE, "-inf");
+
+    const ZERO: Options = Options::builder()
+        .min_integer_digits(num::NonZeroUsize::new(3))
+        .trim_floats(true)
+        .build_strict();
+    write_notation::<STANDARD>(3.25, &ZERO, "003.25");
+    write_notation::<STANDARD>(-3.25, &ZERO, "-003.25");
+    write_notation::<STANDARD>(12.0, &ZERO, "012");
+    write_notation::<STANDARD>(-1e-300, &ZERO, "-1e-300");
+
+    const FIXED: Options = Options::builder()
+        .min_integer_digits(num::NonZeroUsize::new(400))
+        .notation(Notation::Fixed)
+        .build_strict();
+    let mut buffer = vec![b'\x00'; FIXED.buffer_size_const::<f64, STANDARD>()];
+    let digits = 1e300f64.to_lexical_with_options::<STANDARD>(&mut buffer, &FIXED);
+    assert_eq!(digits.len(), 402);
+    assert!(digits[..99].iter().all(|&c| c == b'0'));
+    assert!(digits[99..].starts_with(b"1000") && digits.ends_with(b".0"));
+}
+
+#[test]
+fn suffix_test() {
+    use core::num;
+
+    const VOLTS: Options = Options::builder().suffix(b" V").build_strict();
+    write_notation::<STANDARD>(3.3, &VOLTS, "3.3 V");
+    write_notation::<STANDARD>(-12.0, &VOLTS, "-12.0 V");
+    write_notation::<STANDARD>(1e300, &VOLTS, "1.0e300 V");
+    write_notation::<STANDARD>(f64::NAN, &VOLTS, "NaN V");
+    write_notation::<STANDARD>(f64::NEG_INFINITY, &VOLTS, "-inf V");
+
+    const CELSIUS: Options = Options::builder().suffix("°C".as_bytes()).build_strict();
+    write_notation::<STANDARD>(25.0, &CELSIUS, "25.0°C");
+
+    // The suffix is written after any rounding and padding.
+    const PADDED: Options = Options::builder()
+        .max_significant_digits(num::NonZeroUsize::new(3))
+        .min_integer_digits(num::NonZeroUsize::new(4))
+        .pad_char(b' ')
+        .suffix(b" ms")
+        .build_strict();
+    write_notation::<STANDARD>(12.3456, &PADDED, "  12.3 ms");
+    write_notation::<STANDARD>(-1234.5, &PADDED, "-1230.0 ms");
+
+    // The buffer size includes the suffix.
+    const LONG: Options = Options::builder().suffix(&[b'x'; 100]).build_strict();
+    let size = Options::new().buffer_size_const::<f64, STANDARD>();
+    assert_eq!(LONG.buffer_size_const::<f64, STANDARD>(), size + 100);
+    let mut buffer = vec![b'\x00'; size + 100];
+    let digits = f64::MIN.to_lexical_with_options::<STANDARD>(&mut buffer, &LONG);
+    assert!(digits.starts_with(b"-1.7976931348623157e308x"));
+    assert_eq!(digits.len(), 23 + 100);
+}
+
+#[test]
+fn exponent_min_digits_test() {
+    use core::num;
+
+    const OPTIONS: Options = Options::builder()
+        .exponent_min_digits(num::NonZeroUsize::new(3))
+        .notation(Notation::Scientific)
+        .build_strict();
+    write_notation::<STANDARD>(1500.0, &OPTIONS, "1.5e003");
+    write_notation::<STANDARD>(1e-5, &OPTIONS, "1.0e-005");
+    write_notation::<STANDARD>(1e30, &OPTIONS, "1.0e030");
+    write_notation::<STANDARD>(1e300, &OPTIONS, "1.0e300");
+    write_notation::<STANDARD>(5e-324, &OPTIONS, "5.0e-324");
+
+    // Only the exponent is padded, not the significant digits.
+    const AUTO: Options =
+        Options::builder().exponent_min_digits(num::NonZeroUsize::new(3)).build_strict();
+    write_notation::<STANDARD>(1234.5, &AUTO, "1234.5");
+    write_notation::<STANDARD>(1e30, &AUTO, "1.0e030");
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn exponent_min_digits_sign_test() {
+    use core::num;
+
+    use lexical_util::format::NumberFormatBuilder;
+
+    const FORMAT: u128 = NumberFormatBuilder::new().required_exponent_sign(true).build_strict();
+    const OPTIONS: Options = Options::builder()
+        .exponent_min_digits(num::NonZeroUsize::new(3))
+        .notation(Notation::Scientific)
+        .build_strict();
+    write_notation::<FORMAT>(1500.0, &OPTIONS, "1.5e+003");
+    write_notation::<FORMAT>(1e-5, &OPTIONS, "1.0e-005");
+    write_notation::<FORMAT>(1e300, &OPTIONS, "1.0e+300");
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn independent_signs_test() {
+    use lexical_util::format::NumberFormatBuilder;
+
+    const OPTIONS: Options = Options::builder().notation(Notation::Scientific).build_strict();
+    const F1: u128 = NumberFormatBuilder::new()
+        .required_exponent_sign(true)
+        .no_positive_mantissa_sign(true)
+        .build_strict();
+    write_notation::<F1>(1500.0, &OPTIONS, "1.5e+3");
+    write_notation::<F1>(-1e-5, &OPTIONS, "-1.0e-5");
+
+    const F2: u128 = NumberFormatBuilder::new()
+        .required_mantissa_sign(true)
+        .no_positive_exponent_sign(true)
+        .build_strict();
+    write_notation::<F2>(1500.0, &OPTIONS, "+1.5e3");
+    write_notation::<F2>(-1e-5, &OPTIONS, "-1.0e-5");
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn to_lexical_bracket_test() {
+    use lexical_write_float::to_lexical_bracket;
+
+    let bracket = |value: f64, digits: usize| {
+        let (lower, upper) = to_lexical_bracket(value, digits).unwrap();
+        (lower.parse::<f64>().unwrap(), upper.parse::<f64>().unwrap())
+    };
+    let strings = |value: f64, digits: usize| to_lexical_bracket(value, digits).unwrap();
+
+    // `0.1` is exactly `0.1000000000000000055511151231257827...`.
+    assert_eq!(strings(0.1, 3), ("0.100".into(), "0.101".into()));
+    let (lower, upper) = bracket(0.1, 3);
+    assert!(lower <= 0.1 && 0.1 <= upper);
+    assert_eq!(strings(0.1, 17), ("0.10000000000000000".into(), "0.10000000000000001".into()));
+    assert_eq!(strings(-0.1, 3), ("-0.101".into(), "-0.100".into()));
+    assert_eq!(to_lexical_bracket(0.1f32, 9), Some(("0.100000001".into(), "0.100000002".into())));
+
+    // Exactly representable values have equal bounds.
+    assert_eq!(strings(0.5, 3), ("0.500".into(), "0.500".into()));
+    assert_eq!(strings(1.0, 1), ("1".into(), "1".into()));
+    assert_eq!(strings(0.0, 2), ("0.0".into(), "0.0".into()));
+    assert_eq!(strings(-0.0, 2), ("0.0".into(), "0.0".into()));
+
+    // Carries into the next power of ten.
+    assert_eq!(strings(9.999, 2), ("9.9".into(), "10".into()));
+    assert_eq!(strings(0.0999, 1), ("0.09".into(), "0.1".into()));
+
+    // Large and small values use scientific notation.
+    assert_eq!(strings(12345.0, 3), ("1.23e4".into(), "1.24e4".into()));
+    assert_eq!(strings(1e300, 3), ("1.00e300".into(), "1.01e300".into()));
+    assert_eq!(strings(1.5e-7, 2), ("1.4e-7".into(), "1.5e-7".into()));
+    assert_eq!(strings(5e-324, 2), ("4.9e-324".into(), "5.0e-324".into()));
+    assert_eq!(strings(f64::MAX, 3), ("1.79e308".into(), "1.80e308".into()));
+    for value in [0.1, 1.5e-7, 12345.678, 1e300, 5e-324, f64::MAX, 1.0 / 3.0] {
+        let (lower, upper) = bracket(value, 5);
+        assert!(lower <= value && value <= upper);
+    }
+
+    assert_eq!(to_lexical_bracket(f64::NAN, 3), None);
+    assert_eq!(to_lexical_bracket(f64::INFINITY, 3), None);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn to_lexical_vec_test() {
+    use lexical_write_float::{to_lexical_string, to_lexical_vec};
+
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    for value in [0.0f64, -1.5, 1e300, 5e-324, f64::MAX, f64::NAN, f64::NEG_INFINITY] {
+        let expected = value.to_lexical(&mut buffer).to_vec();
+        assert_eq!(to_lexical_vec(value), expected);
+        assert_eq!(to_lexical_string(value).as_bytes(), &expected[..]);
+    }
+    assert_eq!(to_lexical_string(1.5f32), "1.5");
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn to_lexical_vec_with_options_test() {
+    use core::num;
+
+    use lexical_write_float::{to_lexical_string_with_options, to_lexical_vec_with_options};
+
+    fn check(value: f64, options: &Options) {
+        let mut buffer = vec![b'\x00'; 4096];
+        let expected = value.to_lexical_with_options::<STANDARD>(&mut buffer, options).to_vec();
+        assert_eq!(to_lexical_vec_with_options::<_, STANDARD>(value, options), expected);
+        let actual = to_lexical_string_with_options::<_, STANDARD>(value, options);
+        assert_eq!(actual.as_bytes(), &expected[..]);
+    }
+
+    // Many significant digits.
+    const DIGITS: Options =
+        Options::builder().min_significant_digits(num::NonZeroUsize::new(500)).build_strict();
+    check(1.5, &DIGITS);
+    check(1e-300, &DIGITS);
+    check(f64::MAX, &DIGITS);
+    let digits = to_lexical_string_with_options::<_, STANDARD>(1.5f64, &DIGITS);
+    assert_eq!(digits.len(), 501);
+
+    // Scientific notation disabled.
+    const FIXED: Options = Options::builder().notation(Notation::Fixed).build_strict();
+    check(f64::MAX, &FIXED);
+    check(5e-324, &FIXED);
+    check(-1e-300, &FIXED);
+    let digits = to_lexical_string_with_options::<_, STANDARD>(5e-324f64, &FIXED);
+    assert!(digits.starts_with("0.000") && digits.ends_with('5'));
+
+    // Both combined.
+    const FIXED_DIGITS: Options = Options::builder()
+        .notation(Notation::Fixed)
+        .min_significant_digits(num::NonZeroUsize::new(500))
+        .build_strict();
+    check(f64::MAX, &FIXED_DIGITS);
+    check(-5e-324, &FIXED_DIGITS);
+    check(1.0, &FIXED_DIGITS);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn debug_annotate_test() {
+    use lexical_write_float::{to_lexical_string_with_options, to_lexical_vec_with_options};
+
+    const OPTIONS: Options = Options::builder().debug_annotate(true).build_strict();
+    let actual = to_lexical_string_with_options::<_, STANDARD>(1.1f64, &OPTIONS);
+    assert_eq!(actual, "1.1 (0x3FF199999999999A, exp=0, mant=0x199999999999A)");
+    let actual = to_lexical_vec_with_options::<_, STANDARD>(-1.5f32, &OPTIONS);
+    assert_eq!(actual, b"-1.5 (0xBFC00000, exp=0, mant=0x400000)");
+    let actual = to_lexical_string_with_options::<_, STANDARD>(5e-324f64, &OPTIONS);
+    assert_eq!(actual, "5.0e-324 (0x0000000000000001, exp=-1022, mant=0x1)");
+    let actual = to_lexical_string_with_options::<_, STANDARD>(f32::INFINITY, &OPTIONS);
+    assert_eq!(actual, "inf (0x7F800000, exp=128, mant=0x0)");
+
+    // The shortest form is unchanged without the option.
+    let actual = to_lexical_string_with_options::<_, STANDARD>(1.1f64, &Options::new());
+    assert_eq!(actual, "1.1");
+}
+
+#[test]
+#[cfg(all(feature = "alloc", feature = "format"))]
+fn to_lexical_vec_no_exponent_test() {
+    use lexical_util::format::NumberFormatBuilder;
+    use lexical_write_float::to_lexical_string_with_options;
+
+    const FORMAT: u128 = NumberFormatBuilder::new().no_exponent_notation(true).build_strict();
+    const OPTIONS: Options = Options::new();
+    let mut buffer = vec![b'\x00'; 4096];
+    for value in [f64::MAX, 5e-324, 1.5] {
+        let expected = value.to_lexical_with_options::<FORMAT>(&mut buffer, &OPTIONS);
+        let actual = to_lexical_string_with_options::<_, FORMAT>(value, &OPTIONS);
+        assert_eq!(actual.as_bytes(), expected);
+    }
+}
+
+#[test]
+fn try_to_lexical_test() {
+    use lexical_write_float::{try_to_lexical, try_to_lexical_with_options, NeedCapacity};
+
+    fn check<T: ToLexical + ToLexicalWithOptions<Options = Options> + Copy>(
+        value: T,
+        options: &Options,
+    ) {
+        let mut buffer = [b'\x00'; 2048];
+        let expected = value.to_lexical_with_options::<STANDARD>(&mut buffer, options).to_vec();
+        let len = expected.len();
+
+        // One byte too small must not modify the buffer.
+        let mut small = vec![b'\x00'; len - 1];
+        let result = try_to_lexical_with_options::<_, STANDARD>(value, &mut small, options);
+        assert_eq!(result, Err(NeedCapacity(len)));
+        assert!(small.iter().all(|&x| x == b'\x00'));
+
+        let mut exact = vec![b'\x00'; len];
+        let result = try_to_lexical_with_options::<_, STANDARD>(value, &mut exact, options);
+        assert_eq!(result, Ok(len));
+        assert_eq!(exact, expected);
+        let result = try_to_lexical_with_options::<_, STANDARD>(value, &mut buffer, options);
+        assert_eq!(result, Ok(len));
+        assert_eq!(&buffer[..len], &expected[..]);
+    }
+
+    const DEFAULT: Options = Options::new();
+    const FIXED: Options = Options::builder().notation(Notation::Fixed).build_strict();
+    check(0.0f64, &DEFAULT);
+    check(-1.5f64, &DEFAULT);
+    check(1.2345678901234567e-300f64, &DEFAULT);
+    check(f64::MAX, &DEFAULT);
+    check(f64::NAN, &DEFAULT);
+    check(f64::NEG_INFINITY, &DEFAULT);
+    check(3.25f32, &DEFAULT);
+    check(f32::MIN_POSITIVE, &DEFAULT);
+    check(f64::MAX, &FIXED);
+    check(-5e-324f64, &FIXED);
+
+    let mut buffer = [b'\x00'; 3];
+    assert_eq!(try_to_lexical(1.25f64, &mut buffer), Err(NeedCapacity(4)));
+    assert_eq!(try_to_lexical(1.5f64, &mut buffer), Ok(3));
+    assert_eq!(&buffer, b"1.5");
+}
+
+#[test]
+fn to_lexical_split_test() {
+    use lexical_write_float::to_lexical_split;
+
+    fn split(value: f64, options: &Options) -> (String, String, i32) {
+        let mut int_bytes = vec![b'\x00'; options.buffer_size_const::<f64, STANDARD>()];
+        let mut frac_bytes = [b'\x00'; BUFFER_SIZE];
+        let (int_len, frac_len, exp) =
+            to_lexical_split::<_, STANDARD>(value, &mut int_bytes, &mut frac_bytes, options);
+        let int_digits = core::str::from_utf8(&int_bytes[..int_len]).unwrap();
+        let frac_digits = core::str::from_utf8(&frac_bytes[..frac_len]).unwrap();
+        (int_digits.into(), frac_digits.into(), exp)
+    }
+
+    const DEFAULT: Options = Options::new();
+    assert_eq!(split(3.25, &DEFAULT), ("3".into(), "25".into(), 0));
+    assert_eq!(split(-3.25, &DEFAULT), ("-3".into(), "25".into(), 0));
+    assert_eq!(split(1.5e20, &DEFAULT), ("1".into(), "5".into(), 20));
+    assert_eq!(split(1e100, &DEFAULT), ("1".into(), "0".into(), 100));
+    assert_eq!(split(-2.5e-10, &DEFAULT), ("-2".into(), "5".into(), -10));
+    assert_eq!(split(f64::NAN, &DEFAULT), ("NaN".into(), "".into(), 0));
+    assert_eq!(split(f64::NEG_INFINITY, &DEFAULT), ("-inf".into(), "".into(), 0));
+
+    const TRIM: Options = Options::builder().trim_floats(true).build_strict();
+    assert_eq!(split(12.0, &TRIM), ("12".into(), "".into(), 0));
+
+    const CUSTOM: Options = Options::builder()
+        .decimal_point(b',')
+        .exponent(b'^')
+        .notation(Notation::Scientific)
+        .build_strict();
+    assert_eq!(split(1234.5, &CUSTOM), ("1".into(), "2345".into(), 3));
+
+    const SUFFIX: Options = Options::builder().suffix(b" sec").build_strict();
+    assert_eq!(split(3.25, &SUFFIX), ("3".into(), "25".into(), 0));
+    assert_eq!(split(1.5e20, &SUFFIX), ("1".into(), "5".into(), 20));
+    assert_eq!(split(f64::NAN, &SUFFIX), ("NaN".into(), "".into(), 0));
+}
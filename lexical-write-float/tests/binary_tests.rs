@@ -143,16 +143,16 @@ fn truncate_and_round_test() {
         .build_strict();
 
     // Above halfway
-    assert_eq!(binary::truncate_and_round(6602499140956772u64, 2, &ROUND), (12, 53));
-    assert_eq!(binary::truncate_and_round(6602499140956772u64, 2, &TRUNCATE), (11, 53));
+    assert_eq!(binary::truncate_and_round(6602499140956772u64, -52, 2, &ROUND), (12, -3, 53));
+    assert_eq!(binary::truncate_and_round(6602499140956772u64, -52, 2, &TRUNCATE), (11, -3, 53));
 
     // At halfway
-    assert_eq!(binary::truncate_and_round(6473924464345088u64, 2, &ROUND), (12, 53));
-    assert_eq!(binary::truncate_and_round(6473924464345088u64, 2, &TRUNCATE), (11, 53));
+    assert_eq!(binary::truncate_and_round(6473924464345088u64, -52, 2, &ROUND), (12, -3, 53));
+    assert_eq!(binary::truncate_and_round(6473924464345088u64, -52, 2, &TRUNCATE), (11, -3, 53));
 
     // Below halfway.
-    assert_eq!(binary::truncate_and_round(6473924464345087u64, 2, &ROUND), (11, 53));
-    assert_eq!(binary::truncate_and_round(6473924464345087u64, 2, &TRUNCATE), (11, 53));
+    assert_eq!(binary::truncate_and_round(6473924464345087u64, -52, 2, &ROUND), (11, -3, 53));
+    assert_eq!(binary::truncate_and_round(6473924464345087u64, -52, 2, &TRUNCATE), (11, -3, 53));
 }
 
 // NOTE: This doesn't handle float rounding or truncation.
@@ -176,6 +176,20 @@ where
     assert_eq!(actual, expected);
 }
 
+#[test]
+fn write_float_scientific_exponent_min_digits_test() {
+    const OPTIONS: Options =
+        Options::builder().exponent_min_digits(num::NonZeroUsize::new(3)).build_strict();
+    write_float_scientific::<_, BINARY>(0.0f64, &OPTIONS, "0.0e000");
+    write_float_scientific::<_, BINARY>(2.0f64, &OPTIONS, "1.0e001");
+    write_float_scientific::<_, BINARY>(0.5f64, &OPTIONS, "1.0e-001");
+    write_float_scientific::<_, HEX>(1.0f64, &OPTIONS, "1.0e000");
+    write_float_scientific::<_, HEX>(1.5e-300f64, &OPTIONS, "1.01297D23AB683e-0F9");
+
+    // Larger exponents are not truncated.
+    write_float_scientific::<_, BINARY>(5e-324f64, &OPTIONS, "1.0e-10000110010");
+}
+
 #[test]
 fn write_float_scientific_test() {
     // Positive exponent
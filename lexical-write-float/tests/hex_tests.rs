@@ -5,7 +5,7 @@ use core::num;
 use lexical_util::constants::{FormattedSize, BUFFER_SIZE};
 use lexical_util::format::NumberFormatBuilder;
 use lexical_util::num::{Float, Integer};
-use lexical_write_float::{binary, hex, Options};
+use lexical_write_float::{binary, hex, Options, RoundMode};
 use lexical_write_integer::write::WriteInteger;
 
 const BASE4_2_10: u128 = NumberFormatBuilder::new()
@@ -33,6 +33,11 @@ const BASE16_4_10: u128 = NumberFormatBuilder::new()
     .exponent_base(num::NonZeroU8::new(4))
     .exponent_radix(num::NonZeroU8::new(10))
     .build_strict();
+const BASE16_2_16: u128 = NumberFormatBuilder::new()
+    .mantissa_radix(16)
+    .exponent_base(num::NonZeroU8::new(2))
+    .exponent_radix(num::NonZeroU8::new(16))
+    .build_strict();
 const HEX_OPTIONS: Options = Options::builder().exponent(b'^').build_unchecked();
 
 // NOTE: This doesn't handle float rounding or truncation.
@@ -380,6 +385,64 @@ fn write_float_scientific_test() {
     write_float_scientific::<_, BASE16_4_10>(1.2345678901234567890f32, &OPTS4, "1.3C0CA4e0");
 }
 
+#[test]
+fn write_float_scientific_exponent_min_digits_test() {
+    const OPTIONS: Options =
+        Options::builder().exponent_min_digits(num::NonZeroUsize::new(3)).build_strict();
+    write_float_scientific::<_, BASE16_2_10>(0.0f64, &OPTIONS, "0.0e000");
+    write_float_scientific::<_, BASE16_2_10>(1.0f64, &OPTIONS, "1.0e000");
+    write_float_scientific::<_, BASE16_2_10>(0.5f64, &OPTIONS, "8.0e-004");
+    write_float_scientific::<_, BASE16_2_10>(1.5e-300f64, &OPTIONS, "1.01297D23AB683e-996");
+    write_float_scientific::<_, BASE16_4_10>(0.5f64, &OPTIONS, "8.0e-002");
+    write_float_scientific::<_, BASE16_4_10>(1.4e-45f32, &OPTIONS, "8.0e-076");
+
+    // Larger exponents are not truncated.
+    write_float_scientific::<_, BASE16_2_10>(5e-324f64, &OPTIONS, "4.0e-1076");
+    write_float_scientific::<_, BASE16_2_10>(1.5e300f64, &OPTIONS, "2.3D65ACC00B06Ae996");
+
+    const TRIM: Options = Options::builder()
+        .exponent_min_digits(num::NonZeroUsize::new(3))
+        .trim_floats(true)
+        .exponent(b'^')
+        .build_strict();
+    write_float_scientific::<_, BASE16_2_10>(2.0f64, &TRIM, "2^000");
+    write_float_scientific::<_, BASE16_4_10>(1.4e-45f32, &TRIM, "8^-076");
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn write_float_scientific_no_exponent_without_fraction_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(16)
+        .exponent_base(num::NonZeroU8::new(2))
+        .exponent_radix(num::NonZeroU8::new(10))
+        .no_exponent_without_fraction(true)
+        .build_strict();
+
+    // The fraction is always kept, even when trimming floats.
+    write_float_scientific::<_, FORMAT>(2f64.powi(100), &HEX_OPTIONS, "1.0^100");
+    write_float_scientific::<_, FORMAT>(2f64.powi(-100), &HEX_OPTIONS, "1.0^-100");
+    write_float_scientific::<_, FORMAT>(3f64 * 2f64.powi(80), &HEX_OPTIONS, "3.0^80");
+    write_float_scientific::<_, FORMAT>(1e300f64, &HEX_OPTIONS, "1.7E43C8800759C^996");
+
+    const TRIM: Options = Options::builder().exponent(b'^').trim_floats(true).build_strict();
+    write_float_scientific::<_, FORMAT>(2f64.powi(100), &TRIM, "1.0^100");
+    write_float_scientific::<_, FORMAT>(2f64.powi(-100), &TRIM, "1.0^-100");
+    write_float_scientific::<_, FORMAT>(3f64 * 2f64.powi(80), &TRIM, "3.0^80");
+    write_float_scientific::<_, FORMAT>(1e300f64, &TRIM, "1.7E43C8800759C^996");
+
+    const MIN_DIGITS: Options = Options::builder()
+        .exponent(b'^')
+        .trim_floats(true)
+        .min_significant_digits(num::NonZeroUsize::new(3))
+        .build_strict();
+    write_float_scientific::<_, FORMAT>(2f64.powi(100), &MIN_DIGITS, "1.00^100");
+
+    // Without the format flag, the fraction is trimmed.
+    write_float_scientific::<_, BASE16_2_10>(2f64.powi(100), &TRIM, "1^100");
+    write_float_scientific::<_, BASE16_2_10>(2f64.powi(-100), &TRIM, "1^-100");
+}
+
 // NOTE: This doesn't handle float rounding or truncation.
 // It assumes this has already been done.
 fn write_float<T: Float, const FORMAT: u128>(f: T, options: &Options, expected: &str)
@@ -409,4 +472,59 @@ fn write_float_test() {
         &OPTIONS,
         "3.3000302210022030112133232e-8",
     );
+
+    // Check max digits, which rounds to the nearest, tie-even.
+    const MAX2: Options =
+        Options::builder().max_significant_digits(num::NonZeroUsize::new(2)).build_strict();
+    write_float::<_, BASE16_2_10>(1.15625f64, &MAX2, "1.2");
+    write_float::<_, BASE16_2_10>(1.21875f64, &MAX2, "1.4");
+    write_float::<_, BASE16_2_10>(1.171875f64, &MAX2, "1.3");
+    write_float::<_, BASE16_2_10>(0.1f64, &MAX2, "0.1A");
+    write_float::<_, BASE16_2_10>(255.5f64, &MAX2, "100.0");
+    write_float::<_, BASE16_2_10>(1.5e300f64, &MAX2, "2.4e996");
+    write_float::<_, BASE16_4_10>(1.5e300f64, &MAX2, "2.4e498");
+    write_float::<_, BASE16_2_10>(0.0011444091796875f64, &MAX2, "4.Be-12");
+
+    const MAX3: Options =
+        Options::builder().max_significant_digits(num::NonZeroUsize::new(3)).build_strict();
+    write_float::<_, BASE16_2_10>(1.171875f64, &MAX3, "1.2C");
+    write_float::<_, BASE16_2_10>(255.5f64, &MAX3, "FF.8");
+    write_float::<_, BASE16_2_10>(65535.5f64, &MAX3, "1.0e16");
+    write_float::<_, BASE8_2_10>(255.5f64, &MAX3, "400.0");
+    write_float::<_, BASE4_2_10>(0.1f64, &MAX3, "0.0122");
+
+    const TRUNCATE: Options = Options::builder()
+        .max_significant_digits(num::NonZeroUsize::new(2))
+        .round_mode(RoundMode::Truncate)
+        .build_strict();
+    write_float::<_, BASE16_2_10>(1.21875f64, &TRUNCATE, "1.3");
+    write_float::<_, BASE16_2_10>(255.5f64, &TRUNCATE, "FF.0");
+
+    // Check max digits with padding to the min digits.
+    const PAD: Options = Options::builder()
+        .min_significant_digits(num::NonZeroUsize::new(4))
+        .max_significant_digits(num::NonZeroUsize::new(4))
+        .build_strict();
+    write_float::<_, BASE16_2_10>(1.5f64, &PAD, "1.800");
+    write_float::<_, BASE16_2_10>(0.1f64, &PAD, "0.199A");
+    write_float::<_, BASE16_2_10>(65535.5f64, &PAD, "1.000e16");
+    write_float::<_, BASE16_4_10>(1.5e300f64, &PAD, "2.3D6e498");
+}
+
+#[test]
+fn write_float_exponent_radix_test() {
+    const OPTIONS: Options = Options::builder().exponent(b'p').build_strict();
+    write_float::<_, BASE16_2_16>(1.5f64, &OPTIONS, "1.8");
+    write_float::<_, BASE16_2_16>(12345.0f64, &OPTIONS, "3.039pC");
+    write_float::<_, BASE16_2_16>(1e300f64, &OPTIONS, "1.7E43C8800759Cp3E4");
+    write_float::<_, BASE16_2_16>(1e-300f64, &OPTIONS, "A.B70FE17C79AC8p-3E8");
+    write_float::<_, BASE16_2_16>(5e-324f64, &OPTIONS, "4.0p-434");
+    write_float::<_, BASE16_2_10>(5e-324f64, &OPTIONS, "4.0p-1076");
+
+    const PADDED: Options = Options::builder()
+        .exponent(b'p')
+        .exponent_min_digits(num::NonZeroUsize::new(3))
+        .build_strict();
+    write_float::<_, BASE16_2_16>(12345.0f64, &PADDED, "3.039p00C");
+    write_float::<_, BASE16_2_16>(5e-324f64, &PADDED, "4.0p-434");
 }
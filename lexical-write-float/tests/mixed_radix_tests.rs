@@ -0,0 +1,144 @@
+#![cfg(feature = "power-of-two")]
+
+use core::num;
+use core::str::from_utf8;
+
+use lexical_util::format::NumberFormatBuilder;
+use lexical_util::num::Float;
+use lexical_write_float::{mixed_radix, Options, RoundMode};
+
+const DECIMAL: u128 = NumberFormatBuilder::new().build_strict();
+const BASE10_2: u128 = NumberFormatBuilder::new()
+    .exponent_radix(num::NonZeroU8::new(2))
+    .build_strict();
+const BASE10_16: u128 = NumberFormatBuilder::new()
+    .exponent_radix(num::NonZeroU8::new(16))
+    .build_strict();
+const BASE2_10: u128 = NumberFormatBuilder::new()
+    .mantissa_radix(2)
+    .exponent_radix(num::NonZeroU8::new(10))
+    .build_strict();
+
+fn write<F: Float, const FORMAT: u128>(value: F, options: &Options) -> String {
+    let mut buffer = [b'\x00'; 2048];
+    let count = mixed_radix::write_float::<_, FORMAT>(value, &mut buffer, options);
+    from_utf8(&buffer[..count]).unwrap().to_string()
+}
+
+#[test]
+fn decimal_test() {
+    const OPTIONS: Options = Options::new();
+    assert_eq!(write::<_, DECIMAL>(0.0f64, &OPTIONS), "0.0");
+    assert_eq!(write::<_, DECIMAL>(-0.0f64, &OPTIONS), "-0.0");
+    assert_eq!(write::<_, DECIMAL>(1.0f64, &OPTIONS), "1.0");
+    assert_eq!(write::<_, DECIMAL>(12.5f64, &OPTIONS), "12.5");
+    assert_eq!(write::<_, DECIMAL>(-123.456f64, &OPTIONS), "-123.456");
+    assert_eq!(write::<_, DECIMAL>(0.1f64, &OPTIONS), "0.1");
+    assert_eq!(write::<_, DECIMAL>(0.3f64, &OPTIONS), "0.3");
+    assert_eq!(write::<_, DECIMAL>(1e-10f64, &OPTIONS), "0.0000000001");
+    assert_eq!(write::<_, DECIMAL>(0.1f32, &OPTIONS), "0.1");
+    assert_eq!(write::<_, DECIMAL>(3.25f32, &OPTIONS), "3.25");
+    assert_eq!(write::<_, DECIMAL>(1e15f64, &OPTIONS), "1000000000000000.0");
+
+    // The integer digits are exact, even for the largest floats.
+    let max = write::<_, DECIMAL>(f64::MAX, &OPTIONS);
+    assert_eq!(max.len(), 311);
+    assert!(max.starts_with("17976931348623157081452742373170435679"));
+    assert!(max.ends_with("858368.0"));
+    assert_eq!(max.parse::<f64>(), Ok(f64::MAX));
+
+    // The fraction digits are rounded, unless truncated.
+    const TRUNCATE: Options = Options::builder().round_mode(RoundMode::Truncate).build_strict();
+    assert_eq!(write::<_, DECIMAL>(0.3f64, &TRUNCATE), "0.2999999999999999");
+    assert_eq!(write::<_, DECIMAL>(0.1f64, &TRUNCATE), "0.1");
+}
+
+#[test]
+fn options_test() {
+    const TRIM: Options = Options::builder().trim_floats(true).build_strict();
+    assert_eq!(write::<_, DECIMAL>(3.0f64, &TRIM), "3");
+    assert_eq!(write::<_, DECIMAL>(3.5f64, &TRIM), "3.5");
+    assert_eq!(write::<_, DECIMAL>(f64::MAX, &TRIM).len(), 309);
+
+    const COMMA: Options = Options::builder().decimal_point(b',').build_strict();
+    assert_eq!(write::<_, BASE10_2>(2.5f64, &COMMA), "2,1");
+
+    const OPTIONS: Options = Options::new();
+    assert_eq!(write::<_, DECIMAL>(f64::NAN, &OPTIONS), "NaN");
+    assert_eq!(write::<_, DECIMAL>(f64::NEG_INFINITY, &OPTIONS), "-inf");
+    assert_eq!(write::<_, BASE10_16>(f32::INFINITY, &OPTIONS), "inf");
+}
+
+#[test]
+fn binary_fraction_test() {
+    const OPTIONS: Options = Options::new();
+    assert_eq!(write::<_, BASE10_2>(12.5f64, &OPTIONS), "12.1");
+    assert_eq!(write::<_, BASE10_2>(10.75f64, &OPTIONS), "10.11");
+    assert_eq!(write::<_, BASE10_2>(-0.0625f64, &OPTIONS), "-0.0001");
+
+    // Binary fractions are always exact, since the float is binary.
+    let expected = "0.0001100110011001100110011001100110011001100110011001101";
+    assert_eq!(write::<_, BASE10_2>(0.1f64, &OPTIONS), expected);
+    let min = write::<_, BASE10_2>(5e-324f64, &OPTIONS);
+    assert_eq!(min.len(), 1076);
+    assert!(min.starts_with("0.000"));
+    assert!(min.ends_with("0001"));
+}
+
+#[test]
+fn hexadecimal_fraction_test() {
+    const OPTIONS: Options = Options::new();
+    assert_eq!(write::<_, BASE10_16>(12.5f64, &OPTIONS), "12.8");
+    assert_eq!(write::<_, BASE10_16>(255.9375f64, &OPTIONS), "255.F");
+    assert_eq!(write::<_, BASE10_16>(0.1f64, &OPTIONS), "0.1999999999999A");
+    assert_eq!(write::<_, BASE10_16>(0.1f32, &OPTIONS), "0.199999A");
+}
+
+#[test]
+fn binary_integer_test() {
+    const OPTIONS: Options = Options::new();
+    assert_eq!(write::<_, BASE2_10>(12.5f64, &OPTIONS), "1100.5");
+    assert_eq!(write::<_, BASE2_10>(-5.25f64, &OPTIONS), "-101.25");
+
+    let max = write::<_, BASE2_10>(f64::MAX, &OPTIONS);
+    let expected = "1".repeat(53) + &"0".repeat(971) + ".0";
+    assert_eq!(max, expected);
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn radix_fraction_test() {
+    const BASE10_12: u128 = NumberFormatBuilder::new()
+        .exponent_radix(num::NonZeroU8::new(12))
+        .build_strict();
+    const BASE10_3: u128 = NumberFormatBuilder::new()
+        .exponent_radix(num::NonZeroU8::new(3))
+        .build_strict();
+    const BASE12_10: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(12)
+        .exponent_radix(num::NonZeroU8::new(10))
+        .build_strict();
+    const OPTIONS: Options = Options::new();
+
+    // Duodecimal fractions, such as for hours and minutes.
+    assert_eq!(write::<_, BASE10_12>(12.5f64, &OPTIONS), "12.6");
+    assert_eq!(write::<_, BASE10_12>(1.25f64, &OPTIONS), "1.3");
+    assert_eq!(write::<_, BASE10_12>(-2.75f64, &OPTIONS), "-2.9");
+    assert_eq!(write::<_, BASE10_12>(1.0f64 / 3.0, &OPTIONS), "0.4");
+    assert_eq!(write::<_, BASE10_12>(0.1f64, &OPTIONS), "0.124972497249725");
+
+    // The fraction is rounded to the precision left after the integer digits.
+    assert_eq!(write::<_, BASE10_12>(1.1f64, &OPTIONS), "1.124972497249726");
+    assert_eq!(write::<_, BASE10_12>(1000.1f64, &OPTIONS), "1000.12497249724A");
+
+    // Ternary fractions are rounded to the 34 ternary digits in `2^53`.
+    let third = "0.0".to_string() + &"2".repeat(34);
+    assert_eq!(write::<_, BASE10_3>(1.0f64 / 3.0, &OPTIONS), third);
+    let half = "0.".to_string() + &"1".repeat(33) + "2";
+    assert_eq!(write::<_, BASE10_3>(0.5f64, &OPTIONS), half);
+    let half = "4.".to_string() + &"1".repeat(31) + "2";
+    assert_eq!(write::<_, BASE10_3>(4.5f64, &OPTIONS), half);
+
+    // Duodecimal integers with a decimal fraction.
+    assert_eq!(write::<_, BASE12_10>(143.5f64, &OPTIONS), "BB.5");
+}
@@ -1,5 +1,6 @@
 use core::num;
 
+use lexical_util::error::Error;
 use lexical_write_float::options::{self, Options, OptionsBuilder};
 
 #[test]
@@ -45,6 +46,45 @@ fn invalid_nan_test() {
     assert!(builder.is_valid());
 }
 
+#[test]
+fn invalid_nan_kind_test() {
+    let mut builder = OptionsBuilder::new();
+    builder = builder.snan_string(Some(b"snaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaan"));
+    assert!(!builder.is_valid());
+    assert_eq!(builder.build(), Err(Error::NanStringTooLong));
+    builder = builder.snan_string(Some(b"s-nan"));
+    assert!(!builder.is_valid());
+    assert_eq!(builder.build(), Err(Error::InvalidNanString));
+    builder = builder.snan_string(Some(b""));
+    assert!(!builder.is_valid());
+    builder = builder.snan_string(Some(b"sNaN"));
+    assert!(builder.is_valid());
+    builder = builder.qnan_string(Some(b"qN4N"));
+    assert!(!builder.is_valid());
+    assert_eq!(builder.build(), Err(Error::InvalidNanString));
+    builder = builder.qnan_string(Some(b"qNaN"));
+    assert!(builder.is_valid());
+    assert!(builder.build().is_ok());
+}
+
+#[test]
+fn invalid_pad_char_test() {
+    let mut builder = OptionsBuilder::new();
+    for c in [b'1', b'a', b'+', b'-', b'.', b'\t', b'\x00'] {
+        builder = builder.pad_char(c);
+        assert!(!builder.is_valid());
+        assert_eq!(builder.build(), Err(Error::InvalidPadCharacter));
+    }
+    for c in [b'0', b' ', b'*', b'_'] {
+        builder = builder.pad_char(c);
+        assert!(builder.is_valid());
+    }
+    builder = builder.decimal_point(b',').pad_char(b'.');
+    assert!(builder.is_valid());
+    builder = builder.pad_char(b',');
+    assert!(!builder.is_valid());
+}
+
 #[test]
 fn invalid_inf_test() {
     let mut builder = OptionsBuilder::new();
@@ -60,6 +100,59 @@ fn invalid_inf_test() {
     assert!(builder.build().is_ok());
     builder = builder.inf_string(None);
     assert!(builder.is_valid());
+
+    builder = builder.inf_string(Some("∞".as_bytes()));
+    assert!(builder.is_valid());
+    builder = builder.inf_string(Some("+∞".as_bytes()));
+    assert!(!builder.is_valid());
+    assert_eq!(builder.build(), Err(Error::InvalidInfString));
+    builder = builder.inf_string(Some("∞-".as_bytes()));
+    assert!(!builder.is_valid());
+    builder = builder.inf_string(Some("∞x".as_bytes()));
+    assert!(!builder.is_valid());
+    assert_eq!(builder.build(), Err(Error::InvalidInfString));
+    builder = builder.inf_string(Some(b"+-"));
+    assert!(!builder.is_valid());
+    builder = builder.inf_string(Some(b"\xE2\x88"));
+    assert!(!builder.is_valid());
+    builder = builder.inf_string(Some(b"\xED\xA0\x80"));
+    assert!(!builder.is_valid());
+}
+
+#[test]
+fn invalid_neg_inf_test() {
+    let mut builder = OptionsBuilder::new().inf_string(Some("∞".as_bytes()));
+    builder = builder.neg_inf_string(Some("-∞".as_bytes()));
+    assert!(builder.is_valid());
+    assert!(builder.build().is_ok());
+    builder = builder.neg_inf_string(Some(b"-inf"));
+    assert!(builder.is_valid());
+    builder = builder.neg_inf_string(Some(b"-"));
+    assert!(!builder.is_valid());
+    assert_eq!(builder.build(), Err(Error::InvalidInfString));
+    builder = builder.neg_inf_string(Some(b"-nan"));
+    assert!(!builder.is_valid());
+    builder = builder.neg_inf_string(Some(b"-innnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnf"));
+    assert!(!builder.is_valid());
+    assert_eq!(builder.build(), Err(Error::InfStringTooLong));
+
+    // A negative string requires a positive one.
+    builder = builder.inf_string(None).neg_inf_string(Some("-∞".as_bytes()));
+    assert!(!builder.is_valid());
+    assert_eq!(builder.build(), Err(Error::InvalidInfString));
+}
+
+#[test]
+fn invalid_significant_digits_test() {
+    let mut builder = OptionsBuilder::new();
+    builder = builder
+        .min_significant_digits(num::NonZeroUsize::new(5))
+        .max_significant_digits(num::NonZeroUsize::new(4));
+    assert!(!builder.is_valid());
+    assert_eq!(builder.build(), Err(lexical_util::error::Error::InvalidFloatPrecision));
+    builder = builder.max_significant_digits(num::NonZeroUsize::new(5));
+    assert!(builder.is_valid());
+    assert!(builder.build().is_ok());
 }
 
 #[test]
@@ -68,25 +161,45 @@ fn builder_test() {
 
     builder = builder.max_significant_digits(num::NonZeroUsize::new(10));
     builder = builder.min_significant_digits(num::NonZeroUsize::new(5));
+    builder = builder.significant_resolution(Some(0.001));
     builder = builder.positive_exponent_break(num::NonZeroI32::new(9));
     builder = builder.negative_exponent_break(num::NonZeroI32::new(-9));
     builder = builder.round_mode(options::RoundMode::Truncate);
     builder = builder.trim_floats(true);
+    builder = builder.trim_floats_below(Some(1e6));
+    builder = builder.exponent_min_digits(num::NonZeroUsize::new(3));
+    builder = builder.exponent_zero_behavior(options::ExponentZero::Signed);
+    builder = builder.min_integer_digits(num::NonZeroUsize::new(4));
+    builder = builder.pad_char(b' ');
+    builder = builder.uppercase_digits(false);
     builder = builder.exponent(b'^');
     builder = builder.decimal_point(b',');
     builder = builder.nan_string(Some(b"nan"));
+    builder = builder.snan_string(Some(b"sNaN"));
+    builder = builder.qnan_string(Some(b"qNaN"));
     builder = builder.inf_string(Some(b"Infinity"));
+    builder = builder.suffix(b" m/s");
 
     assert_eq!(builder.get_max_significant_digits().unwrap().get(), 10);
     assert_eq!(builder.get_min_significant_digits().unwrap().get(), 5);
+    assert_eq!(builder.get_significant_resolution(), Some(0.001));
     assert_eq!(builder.get_positive_exponent_break().unwrap().get(), 9);
     assert_eq!(builder.get_negative_exponent_break().unwrap().get(), -9);
     assert_eq!(builder.get_round_mode(), options::RoundMode::Truncate);
     assert_eq!(builder.get_trim_floats(), true);
+    assert_eq!(builder.get_trim_floats_below(), Some(1e6));
+    assert_eq!(builder.get_exponent_min_digits().unwrap().get(), 3);
+    assert_eq!(builder.get_exponent_zero_behavior(), options::ExponentZero::Signed);
+    assert_eq!(builder.get_min_integer_digits().unwrap().get(), 4);
+    assert_eq!(builder.get_pad_char(), b' ');
+    assert_eq!(builder.get_uppercase_digits(), false);
     assert_eq!(builder.get_exponent(), b'^');
     assert_eq!(builder.get_decimal_point(), b',');
     assert_eq!(builder.get_nan_string(), Some("nan".as_bytes()));
+    assert_eq!(builder.get_snan_string(), Some("sNaN".as_bytes()));
+    assert_eq!(builder.get_qnan_string(), Some("qNaN".as_bytes()));
     assert_eq!(builder.get_inf_string(), Some("Infinity".as_bytes()));
+    assert_eq!(builder.get_suffix(), b" m/s");
 
     assert!(builder.is_valid());
     assert_eq!(builder.build(), Ok(builder.build_unchecked()));
@@ -123,3 +236,136 @@ fn options_test() {
     assert_eq!(Options::builder(), OptionsBuilder::new());
     assert_eq!(opts.rebuild().build(), Ok(opts));
 }
+
+#[test]
+#[cfg(feature = "std")]
+fn thread_local_options_test() {
+    use lexical_write_float::{to_lexical_default, ThreadLocalOptions, ToLexical, BUFFER_SIZE};
+
+    fn write(value: f64) -> Vec<u8> {
+        let mut buffer = [b'\x00'; BUFFER_SIZE];
+        to_lexical_default(value, &mut buffer).to_vec()
+    }
+
+    assert_eq!(write(1.5), b"1.5");
+    let comma = Options::builder().decimal_point(b',').build().unwrap();
+    let previous = ThreadLocalOptions::set_default(comma.clone());
+    assert_eq!(previous, Options::new());
+    assert_eq!(write(1.5), b"1,5");
+    assert_eq!(ThreadLocalOptions::with_default(|options| options.clone()), comma);
+
+    // The defaults are not used by `to_lexical`.
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    assert_eq!(1.5f64.to_lexical(&mut buffer), b"1.5");
+
+    // Other threads keep the standard defaults.
+    let result = std::thread::spawn(|| write(1.5)).join().unwrap();
+    assert_eq!(result, b"1.5");
+
+    assert_eq!(ThreadLocalOptions::set_default(previous), comma);
+    assert_eq!(write(1.5), b"1.5");
+}
+
+#[test]
+fn formatted_size_test() {
+    use lexical_util::format::STANDARD;
+    use lexical_write_float::{formatted_size, FormattedSize, Notation, ToLexicalWithOptions};
+
+    fn check<const FORMAT: u128>(options: &Options) {
+        let size = formatted_size::<FORMAT>(options);
+        assert!(size >= options.buffer_size_const::<f32, FORMAT>());
+        let mut buffer = [b'\x00'; 4096];
+        let values = [
+            0.0,
+            -0.0,
+            1.0,
+            -1.2345678901234567,
+            f64::MAX,
+            f64::MIN,
+            f64::MIN_POSITIVE,
+            -f64::MIN_POSITIVE,
+            5e-324,
+            -5e-324,
+            -1.2345678901234567e-300,
+            -1.2345678901234567e300,
+            f64::NAN,
+            f64::NEG_INFINITY,
+        ];
+        for value in values {
+            let digits = value.to_lexical_with_options::<FORMAT>(&mut buffer, options);
+            assert!(digits.len() <= size, "{value} needs {} > {size}", digits.len());
+            let digits = (value as f32).to_lexical_with_options::<FORMAT>(&mut buffer, options);
+            assert!(digits.len() <= size, "{value} needs {} > {size}", digits.len());
+        }
+    }
+
+    const FIXED: Options = Options::builder().notation(Notation::Fixed).build_strict();
+    const SCIENTIFIC: Options = Options::builder()
+        .notation(Notation::Scientific)
+        .min_significant_digits(num::NonZeroUsize::new(100))
+        .exponent_min_digits(num::NonZeroUsize::new(8))
+        .build_strict();
+    const MIN_DIGITS: Options = Options::builder()
+        .notation(Notation::Fixed)
+        .min_significant_digits(num::NonZeroUsize::new(500))
+        .min_integer_digits(num::NonZeroUsize::new(20))
+        .build_strict();
+    const BREAKS: Options = Options::builder()
+        .min_significant_digits(num::NonZeroUsize::new(300))
+        .positive_exponent_break(num::NonZeroI32::new(300))
+        .negative_exponent_break(num::NonZeroI32::new(-300))
+        .build_strict();
+    const SPECIAL: Options = Options::builder()
+        .nan_string(Some(b"NotANumberNotANumberNotANumberNotANumberNotANumber"))
+        .inf_string(Some(b"InfinityInfinityInfinityInfinityInfinityInfinityIn"))
+        .build_strict();
+
+    assert_eq!(formatted_size::<STANDARD>(&options::STANDARD), f64::FORMATTED_SIZE_DECIMAL);
+    for options in [options::STANDARD, FIXED, SCIENTIFIC, MIN_DIGITS, BREAKS, SPECIAL] {
+        check::<STANDARD>(&options);
+    }
+
+    #[cfg(feature = "radix")]
+    {
+        use lexical_util::format::NumberFormatBuilder;
+
+        const BASE3: u128 = NumberFormatBuilder::from_radix(3);
+        const BASE36: u128 = NumberFormatBuilder::from_radix(36);
+        const RADIX_OPTIONS: Options = Options::builder().exponent(b'^').build_strict();
+        const RADIX_FIXED: Options =
+            Options::builder().exponent(b'^').notation(Notation::Fixed).build_strict();
+        for options in [RADIX_OPTIONS, RADIX_FIXED] {
+            check::<BASE3>(&options);
+            check::<BASE36>(&options);
+        }
+    }
+}
+
+#[test]
+#[cfg(all(feature = "power-of-two", feature = "format"))]
+fn validate_format_exponent_base_test() {
+    use lexical_util::format::{self, NumberFormatBuilder};
+
+    const fn exponent_format(radix: u8, base: u8) -> u128 {
+        NumberFormatBuilder::new()
+            .mantissa_radix(radix)
+            .exponent_base(num::NonZeroU8::new(base))
+            .build_unchecked()
+    }
+
+    // Only some exponent bases can be written with a different radix.
+    assert_eq!(Options::validate_format::<{ exponent_format(16, 2) }>(), Ok(()));
+    assert_eq!(Options::validate_format::<{ exponent_format(16, 4) }>(), Ok(()));
+    assert_eq!(Options::validate_format::<{ exponent_format(32, 2) }>(), Ok(()));
+    assert_eq!(
+        Options::validate_format::<{ exponent_format(8, 4) }>(),
+        Err(Error::InvalidExponentBase)
+    );
+    assert_eq!(
+        Options::validate_format::<{ exponent_format(2, 16) }>(),
+        Err(Error::InvalidExponentBase)
+    );
+
+    // The shared validator only checks the format itself.
+    assert_eq!(format::validate_format(exponent_format(8, 4)), Ok(()));
+}
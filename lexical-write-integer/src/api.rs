@@ -2,12 +2,28 @@
 
 #![doc(hidden)]
 
+use core::cmp;
+
+use lexical_util::constants::FormattedSize;
+use lexical_util::error::{Error, NeedCapacity};
 use lexical_util::format::{NumberFormat, STANDARD};
 use lexical_util::num::SignedInteger;
+#[cfg(feature = "compact")]
+use lexical_util::num::UnsignedInteger;
 use lexical_util::{to_lexical, to_lexical_with_options};
 
-use crate::options::Options;
-use crate::write::WriteInteger;
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+#[cfg(feature = "smallvec")]
+use smallvec::SmallVec;
+
+#[cfg(not(feature = "compact"))]
+use crate::digit_count::DigitCount;
+use crate::options::{group_separator_count, group_size_for_radix, GroupingScheme, Options};
+#[cfg(feature = "std")]
+use crate::options::ThreadLocalOptions;
+use crate::write::{write_sign, WriteInteger};
 
 // UNSIGNED
 
@@ -23,13 +39,9 @@ where
     T: WriteInteger,
 {
     let format = NumberFormat::<FORMAT> {};
-    if cfg!(feature = "format") && format.required_mantissa_sign() {
-        buffer[0] = b'+';
-        let buffer = &mut buffer[1..];
-        value.write_mantissa::<FORMAT>(buffer) + 1
-    } else {
-        value.write_mantissa::<FORMAT>(buffer)
-    }
+    let required = cfg!(feature = "format") && format.required_mantissa_sign();
+    let count = write_sign(buffer, false, required);
+    value.write_mantissa::<FORMAT>(&mut buffer[count..]) + count
 }
 
 // SIGNED
@@ -47,24 +59,237 @@ where
     Unsigned: WriteInteger,
 {
     let format = NumberFormat::<FORMAT> {};
-    if value < Signed::ZERO {
+    let is_negative = value < Signed::ZERO;
+    let required = cfg!(feature = "format") && format.required_mantissa_sign();
+    let count = write_sign(buffer, is_negative, required);
+    let unsigned = if is_negative {
         // Need to cast the value to the same size as unsigned type, since if
         // the value is **exactly** `Narrow::MIN`, and it it is then cast
         // as the wrapping negative as the unsigned value, a wider type
         // will have a very different value.
-        let unsigned = Unsigned::as_cast(value.wrapping_neg());
-        buffer[0] = b'-';
-        let buffer = &mut buffer[1..];
-        unsigned.write_mantissa_signed::<FORMAT>(buffer) + 1
-    } else if cfg!(feature = "format") && format.required_mantissa_sign() {
-        let unsigned = Unsigned::as_cast(value);
-        buffer[0] = b'+';
-        let buffer = &mut buffer[1..];
-        unsigned.write_mantissa_signed::<FORMAT>(buffer) + 1
+        Unsigned::as_cast(value.wrapping_neg())
+    } else {
+        Unsigned::as_cast(value)
+    };
+    unsigned.write_mantissa_signed::<FORMAT>(&mut buffer[count..]) + count
+}
+
+// GROUPING
+
+/// Write the group separators between the integer digits, if any.
+///
+/// The digits are written from `0..len`, after any sign, and are shifted
+/// right in place to insert the separators. Returns the new length.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn write_grouping<const FORMAT: u128>(buffer: &mut [u8], len: usize, options: &Options) -> usize {
+    let (separator, size) = match options.integer_grouping() {
+        Some((separator, size)) => {
+            (separator, group_size_for_radix(size, NumberFormat::<FORMAT>::RADIX))
+        },
+        None => return len,
+    };
+    let scheme = options.grouping_scheme();
+    let start = matches!(buffer[0], b'+' | b'-') as usize;
+    let new_len = len + group_separator_count(len - start, size, scheme);
+
+    // Shift the digits from the right, writing a separator after every group.
+    let mut src = len;
+    let mut dst = new_len;
+    let mut count = 0;
+    let mut group = size;
+    while src > start && dst > src {
+        src -= 1;
+        dst -= 1;
+        buffer[dst] = buffer[src];
+        count += 1;
+        if count == group && src > start {
+            dst -= 1;
+            buffer[dst] = separator;
+            count = 0;
+            if scheme == GroupingScheme::Indian {
+                group = 2;
+            }
+        }
+    }
+    new_len
+}
+
+// EXACT DIGITS
+
+/// Pad the integer digits with leading zeros to the exact number of digits.
+///
+/// The digits are written from `0..len`, after any sign, and are shifted
+/// right in place to insert the zeros. Returns the new length.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn write_exact_digits(buffer: &mut [u8], len: usize, options: &Options) -> usize {
+    let exact_digits = match options.exact_digits() {
+        Some(exact_digits) => exact_digits.get() as usize,
+        None => return len,
+    };
+    let start = matches!(buffer[0], b'+' | b'-') as usize;
+    let count = len - start;
+    if count >= exact_digits {
+        return len;
+    }
+    let padding = exact_digits - count;
+    buffer.copy_within(start..len, start + padding);
+    buffer[start..start + padding].fill(b'0');
+    len + padding
+}
+
+// MIN WIDTH
+
+/// Pad the integer to the minimum width with the pad character.
+///
+/// Zero padding is written after any sign, and any other padding before
+/// it, shifting the written bytes right in place. Returns the new length.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn write_min_width(buffer: &mut [u8], len: usize, options: &Options) -> usize {
+    let width = match options.integer_min_width() {
+        Some(width) => width.get(),
+        None => return len,
+    };
+    if len >= width {
+        return len;
+    }
+    let padding = width - len;
+    let pad_char = options.pad_char();
+    let start = if pad_char == b'0' {
+        matches!(buffer[0], b'+' | b'-') as usize
     } else {
-        let unsigned = Unsigned::as_cast(value);
-        unsigned.write_mantissa_signed::<FORMAT>(buffer)
+        0
+    };
+    buffer.copy_within(start..len, start + padding);
+    buffer[start..start + padding].fill(pad_char);
+    width
+}
+
+// DIGIT COUNT
+
+/// Get the number of digits in an unsigned value.
+#[cfg(not(feature = "compact"))]
+#[inline(always)]
+fn unsigned_digit_count<T: DigitCount>(value: T, radix: u32) -> usize {
+    value.digit_count(radix)
+}
+
+/// Get the number of digits in an unsigned value.
+#[cfg(feature = "compact")]
+#[inline(always)]
+fn unsigned_digit_count<T: UnsignedInteger>(mut value: T, radix: u32) -> usize {
+    assert!((2..=36).contains(&radix), "radix must be >= 2 and <= 36");
+    let radix = T::as_cast(radix);
+    let mut count = 1;
+    while value >= radix {
+        value /= radix;
+        count += 1;
     }
+    count
+}
+
+/// Get the number of bytes written with options, from the number of digits.
+///
+/// This includes the sign, any padding digits, any group separators, and
+/// any padding to the minimum width.
+#[inline(always)]
+fn digit_count_with_options<const FORMAT: u128>(
+    is_negative: bool,
+    count: usize,
+    options: &Options,
+) -> usize {
+    let format = NumberFormat::<FORMAT> {};
+    let required = cfg!(feature = "format") && format.required_mantissa_sign();
+    let sign = (is_negative || required) as usize;
+    let count = match options.exact_digits() {
+        Some(exact_digits) => cmp::max(count, exact_digits.get() as usize),
+        None => count,
+    };
+    let separators = match options.integer_grouping() {
+        Some((_, size)) => {
+            let size = group_size_for_radix(size, format.radix());
+            group_separator_count(count, size, options.grouping_scheme())
+        },
+        None => 0,
+    };
+    let len = sign + count + separators;
+    match options.integer_min_width() {
+        Some(width) => cmp::max(len, width.get()),
+        None => len,
+    }
+}
+
+/// Get the number of bytes an integer is written with, without writing it.
+///
+/// This is useful to align values in tables, or to size a buffer exactly,
+/// rather than writing each value to measure it.
+pub trait ToDigitCount: ToLexical {
+    /// Get the number of bytes written for a const radix.
+    ///
+    /// This includes the `-` sign for negative values. The radix must be
+    /// from `2` to `36`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radix is not from `2` to `36`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_integer::ToDigitCount;
+    ///
+    /// assert_eq!(999u32.digit_count::<10>(), 3);
+    /// assert_eq!(1000u32.digit_count::<10>(), 4);
+    /// assert_eq!((-255i32).digit_count::<16>(), 3);
+    /// ```
+    #[inline(always)]
+    fn digit_count<const RADIX: u32>(self) -> usize {
+        self.digit_count_radix(RADIX)
+    }
+
+    /// Get the number of bytes written for a radix known at runtime.
+    ///
+    /// This includes the `-` sign for negative values. The radix must be
+    /// from `2` to `36`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radix is not from `2` to `36`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_integer::ToDigitCount;
+    ///
+    /// assert_eq!(i32::MIN.digit_count_radix(10), 11);
+    /// assert_eq!(255u8.digit_count_radix(2), 8);
+    /// ```
+    fn digit_count_radix(self, radix: u32) -> usize;
+
+    /// Get the number of bytes written by
+    /// [`to_lexical_with_options`][ToLexicalWithOptions::to_lexical_with_options].
+    ///
+    /// This includes the sign, if the value is negative or the format
+    /// requires a sign, any padding digits from
+    /// [`exact_digits`][Options::exact_digits], any group separators
+    /// from [`integer_grouping`][Options::integer_grouping], and any padding
+    /// to the [`integer_min_width`][Options::integer_min_width].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number format is not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_integer::{Options, ToDigitCount};
+    /// use lexical_write_integer::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .integer_grouping(Some((b',', 3)))
+    ///     .build_strict();
+    /// assert_eq!((-1234567i32).digit_count_with_options::<STANDARD>(&OPTIONS), 10);
+    /// ```
+    fn digit_count_with_options<const FORMAT: u128>(self, options: &Options) -> usize;
 }
 
 // API
@@ -92,17 +317,173 @@ macro_rules! unsigned_to_lexical {
                 options: &Self::Options,
             ) -> &'a mut [u8]
             {
-                _ = options;
                 assert!(NumberFormat::<{ FORMAT }> {}.is_valid());
                 let len = unsigned::<$t, FORMAT>(self, bytes);
+                if !options.uppercase_digits() {
+                    bytes[..len].make_ascii_lowercase();
+                }
+                let len = write_exact_digits(bytes, len, options);
+                let len = write_grouping::<FORMAT>(bytes, len, options);
+                let len = write_min_width(bytes, len, options);
                 &mut bytes[..len]
             }
         }
+
+        impl ToSignAndDigits for $t {
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn write_sign_and_digits(self, sign_out: &mut u8, digits: &mut [u8]) -> (bool, usize) {
+                *sign_out = b'+';
+                (false, self.write_mantissa::<{ STANDARD }>(digits))
+            }
+        }
+
+        impl ToDigitCount for $t {
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn digit_count_radix(self, radix: u32) -> usize {
+                unsigned_digit_count(self, radix)
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn digit_count_with_options<const FORMAT: u128>(self, options: &Options) -> usize {
+                let format = NumberFormat::<{ FORMAT }> {};
+                assert!(format.is_valid());
+                let count = unsigned_digit_count(self, format.mantissa_radix());
+                digit_count_with_options::<FORMAT>(false, count, options)
+            }
+        }
     )*)
 }
 
 to_lexical!("lexical_write_integer", 1234, u64);
 to_lexical_with_options!("lexical_write_integer", 1234, u64, Options);
+
+/// Write the sign and the digits of an integer separately.
+///
+/// This is useful for structured output where the sign is stored
+/// out-of-band, such as for database numeric types.
+pub trait ToSignAndDigits: ToLexical {
+    /// Write the sign and the decimal digits of the magnitude separately.
+    ///
+    /// The sign is written to `sign_out` as `b'-'` if the value is negative,
+    /// and `b'+'` otherwise, and is never written to `digits`. Returns if
+    /// the value is negative and the number of digits written.
+    ///
+    /// * `sign_out` - Byte to write the sign to.
+    /// * `digits`   - Buffer to write the digits to.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough to hold the digits. A
+    /// buffer of [`FORMATTED_SIZE_DECIMAL`] bytes is always large enough.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_integer::ToSignAndDigits;
+    ///
+    /// let mut sign = 0u8;
+    /// let mut digits = [0u8; 20];
+    /// assert_eq!((-1234i32).write_sign_and_digits(&mut sign, &mut digits), (true, 4));
+    /// assert_eq!(sign, b'-');
+    /// assert_eq!(&digits[..4], b"1234");
+    /// ```
+    ///
+    /// [`FORMATTED_SIZE_DECIMAL`]: FormattedSize::FORMATTED_SIZE_DECIMAL
+    fn write_sign_and_digits(self, sign_out: &mut u8, digits: &mut [u8]) -> (bool, usize);
+}
+
+/// Write the magnitude of a signed integer to a string, with a separate sign.
+///
+/// This is useful for sinks that cannot store negative values, and
+/// instead store the absolute value and a sign flag.
+pub trait ToUnsignedMagnitude: ToSignAndDigits {
+    /// Write the decimal digits of the magnitude to an inline string.
+    ///
+    /// Returns if the value is negative and the digits of the magnitude,
+    /// which never contain a sign. The magnitude of `MIN` is written
+    /// correctly, even though it cannot be represented by the signed type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_integer::ToUnsignedMagnitude;
+    ///
+    /// let (is_negative, digits) = (-1234i32).to_lexical_unsigned_magnitude();
+    /// assert!(is_negative);
+    /// assert_eq!(digits.as_str(), "1234");
+    /// ```
+    fn to_lexical_unsigned_magnitude(self) -> (bool, ArrayString);
+}
+
+/// Inline string large enough to hold the digits of any decimal integer.
+///
+/// This can only be created by this crate, so it always contains ASCII digits.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ArrayString {
+    buffer: [u8; i128::FORMATTED_SIZE_DECIMAL],
+    length: usize,
+}
+
+impl ArrayString {
+    /// Create an empty string.
+    #[inline(always)]
+    const fn new() -> Self {
+        Self {
+            buffer: [0u8; i128::FORMATTED_SIZE_DECIMAL],
+            length: 0,
+        }
+    }
+
+    /// Get the written digits as a string slice.
+    #[inline(always)]
+    pub fn as_str(&self) -> &str {
+        // SAFETY: safe since the string is only written by our own integer
+        // writers in `to_lexical_unsigned_magnitude`, which write ASCII digits.
+        unsafe { core::str::from_utf8_unchecked(self.as_bytes()) }
+    }
+
+    /// Get the written digits as a byte slice.
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buffer[..self.length]
+    }
+}
+
+impl core::ops::Deref for ArrayString {
+    type Target = str;
+
+    #[inline(always)]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for ArrayString {
+    #[inline(always)]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<[u8]> for ArrayString {
+    #[inline(always)]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl core::fmt::Debug for ArrayString {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl core::fmt::Display for ArrayString {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 unsigned_to_lexical! { u8 u16 u32 u64 u128 usize }
 
 // Implement `ToLexical` for numeric type.
@@ -127,12 +508,61 @@ macro_rules! signed_to_lexical {
                 options: &Self::Options,
             ) -> &'a mut [u8]
             {
-                _ = options;
                 assert!(NumberFormat::<{ FORMAT }> {}.is_valid());
                 let len = signed::<$signed, $unsigned, FORMAT>(self, bytes);
+                if !options.uppercase_digits() {
+                    bytes[..len].make_ascii_lowercase();
+                }
+                let len = write_exact_digits(bytes, len, options);
+                let len = write_grouping::<FORMAT>(bytes, len, options);
+                let len = write_min_width(bytes, len, options);
                 &mut bytes[..len]
             }
         }
+
+        impl ToSignAndDigits for $signed {
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn write_sign_and_digits(self, sign_out: &mut u8, digits: &mut [u8]) -> (bool, usize) {
+                // NOTE: This must be the wrapping negative for `MIN`, as in `signed`.
+                let is_negative = self < 0;
+                let unsigned = if is_negative {
+                    self.wrapping_neg() as $unsigned
+                } else {
+                    self as $unsigned
+                };
+                *sign_out = if is_negative { b'-' } else { b'+' };
+                (is_negative, unsigned.write_mantissa_signed::<{ STANDARD }>(digits))
+            }
+        }
+
+        impl ToUnsignedMagnitude for $signed {
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn to_lexical_unsigned_magnitude(self) -> (bool, ArrayString) {
+                let mut string = ArrayString::new();
+                let mut sign = 0u8;
+                let (is_negative, count) =
+                    self.write_sign_and_digits(&mut sign, &mut string.buffer);
+                string.length = count;
+                (is_negative, string)
+            }
+        }
+
+        impl ToDigitCount for $signed {
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn digit_count_radix(self, radix: u32) -> usize {
+                let count = unsigned_digit_count(self.wrapping_abs() as $unsigned, radix);
+                count + (self < 0) as usize
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn digit_count_with_options<const FORMAT: u128>(self, options: &Options) -> usize {
+                let format = NumberFormat::<{ FORMAT }> {};
+                assert!(format.is_valid());
+                let unsigned = self.wrapping_abs() as $unsigned;
+                let count = unsigned_digit_count(unsigned, format.mantissa_radix());
+                digit_count_with_options::<FORMAT>(self < 0, count, options)
+            }
+        }
     )*)
 }
 
@@ -152,3 +582,345 @@ signed_to_lexical! { isize u32 ; }
 
 #[cfg(target_pointer_width = "64")]
 signed_to_lexical! { isize u64 ; }
+
+// TRY
+
+/// Write a decimal integer to a buffer, or return the required capacity.
+///
+/// Unlike [`to_lexical`][ToLexical::to_lexical], this does not panic if the
+/// buffer is too small, and instead returns the exact number of bytes
+/// required to write the value. The buffer is not modified on error.
+///
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Buffer to write number to.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_write_integer::{try_to_lexical, NeedCapacity};
+///
+/// let mut buffer = [0u8; 4];
+/// assert_eq!(try_to_lexical(-1234i32, &mut buffer), Err(NeedCapacity(5)));
+/// assert_eq!(try_to_lexical(1234i32, &mut buffer), Ok(4));
+/// assert_eq!(&buffer, b"1234");
+/// ```
+#[inline]
+pub fn try_to_lexical<T: ToLexical>(value: T, bytes: &mut [u8]) -> Result<usize, NeedCapacity> {
+    if bytes.len() >= T::FORMATTED_SIZE_DECIMAL {
+        return Ok(value.to_lexical(bytes).len());
+    }
+    let mut buffer = [0u8; i128::FORMATTED_SIZE];
+    copy_if_fits(value.to_lexical(&mut buffer), bytes)
+}
+
+/// Write an integer to a buffer with custom options, or return the required
+/// capacity.
+///
+/// Unlike [`to_lexical_with_options`][ToLexicalWithOptions::to_lexical_with_options],
+/// this does not panic if the buffer is too small, and instead returns the
+/// exact number of bytes required to write the value. The buffer is not
+/// modified on error.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Buffer to write number to.
+/// * `options` - Options to customize number writing.
+///
+/// # Panics
+///
+/// Panics if the number format is invalid, as with
+/// [`to_lexical_with_options`][ToLexicalWithOptions::to_lexical_with_options].
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_write_integer::{try_to_lexical_with_options, NeedCapacity, Options};
+/// use lexical_write_integer::format::STANDARD;
+///
+/// const OPTIONS: Options = Options::new();
+/// let mut buffer = [0u8; 2];
+/// let result = try_to_lexical_with_options::<_, STANDARD>(255u8, &mut buffer, &OPTIONS);
+/// assert_eq!(result, Err(NeedCapacity(3)));
+/// ```
+#[inline]
+pub fn try_to_lexical_with_options<T, const FORMAT: u128>(
+    value: T,
+    bytes: &mut [u8],
+    options: &Options,
+) -> Result<usize, NeedCapacity>
+where
+    T: ToLexicalWithOptions<Options = Options>,
+{
+    let size = options.buffer_size_const::<T, FORMAT>();
+    if bytes.len() >= size {
+        return Ok(value.to_lexical_with_options::<FORMAT>(bytes, options).len());
+    }
+    // Large enough for the digits and a separator between every digit.
+    let mut buffer = [0u8; 2 * (u8::MAX as usize + 1)];
+    if size > buffer.len() {
+        // Only the padding can be this large, so the value is always
+        // padded to exactly the minimum width.
+        return Err(NeedCapacity(size));
+    }
+    copy_if_fits(value.to_lexical_with_options::<FORMAT>(&mut buffer, options), bytes)
+}
+
+/// Write an integer with an exact number of digits to a buffer.
+///
+/// Unlike [`to_lexical_with_options`][ToLexicalWithOptions::to_lexical_with_options],
+/// this returns [`Error::FieldOverflow`] if the value has more digits than
+/// [`exact_digits`][Options::exact_digits], rather than writing all the
+/// digits. Values with fewer digits are padded with leading zeros.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Buffer to write number to.
+/// * `options` - Options to customize number writing.
+///
+/// # Panics
+///
+/// Panics if the number format is invalid or the buffer is too small, as
+/// with [`to_lexical_with_options`][ToLexicalWithOptions::to_lexical_with_options].
+///
+/// # Examples
+///
+/// ```rust
+/// use core::num;
+///
+/// use lexical_write_integer::{to_lexical_exact_digits, Error, Options};
+/// use lexical_write_integer::format::STANDARD;
+///
+/// const OPTIONS: Options = Options::builder()
+///     .exact_digits(num::NonZeroU8::new(4))
+///     .build_strict();
+/// const BUFFER_SIZE: usize = OPTIONS.buffer_size_const::<u32, STANDARD>();
+/// let mut buffer = [0u8; BUFFER_SIZE];
+/// let result = to_lexical_exact_digits::<_, STANDARD>(42u32, &mut buffer, &OPTIONS);
+/// assert_eq!(result.as_deref(), Ok(&b"0042"[..]));
+/// let result = to_lexical_exact_digits::<_, STANDARD>(12345u32, &mut buffer, &OPTIONS);
+/// assert_eq!(result, Err(Error::FieldOverflow));
+/// ```
+#[inline]
+pub fn to_lexical_exact_digits<'a, T, const FORMAT: u128>(
+    value: T,
+    bytes: &'a mut [u8],
+    options: &Options,
+) -> Result<&'a mut [u8], Error>
+where
+    T: ToLexicalWithOptions<Options = Options>,
+{
+    let digits = value.to_lexical_with_options::<FORMAT>(bytes, options);
+    if let Some(exact_digits) = options.exact_digits() {
+        // Skip the sign, any group separators, and any leading zeros, which
+        // are all padding.
+        let digits = digits.iter().filter(|&c| c.is_ascii_alphanumeric());
+        let count = digits.skip_while(|&&c| c == b'0').count();
+        if count > exact_digits.get() as usize {
+            return Err(Error::FieldOverflow);
+        }
+    }
+    Ok(digits)
+}
+
+/// Copy the written digits to the buffer, if it is large enough.
+#[inline(always)]
+fn copy_if_fits(digits: &[u8], bytes: &mut [u8]) -> Result<usize, NeedCapacity> {
+    match bytes.get_mut(..digits.len()) {
+        Some(dst) => {
+            dst.copy_from_slice(digits);
+            Ok(digits.len())
+        },
+        None => Err(NeedCapacity(digits.len())),
+    }
+}
+
+// THREAD-LOCAL DEFAULTS
+
+/// Write an integer to a buffer with the default options for the current
+/// thread.
+///
+/// This is [`to_lexical`][ToLexical::to_lexical], but uses the options
+/// set by [`ThreadLocalOptions::set_default`] rather than the standard
+/// options, so the options only need to be configured once.
+///
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Buffer to write number to.
+///
+/// # Panics
+///
+/// Panics if the buffer is not large enough for the default options, as
+/// with [`to_lexical_with_options`][ToLexicalWithOptions::to_lexical_with_options].
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_write_integer::{to_lexical_default, Options, ThreadLocalOptions, BUFFER_SIZE};
+///
+/// let mut buffer = [0u8; BUFFER_SIZE];
+/// assert_eq!(to_lexical_default(1234567u32, &mut buffer), b"1234567");
+/// let options = Options::builder().integer_grouping(Some((b',', 3))).build_strict();
+/// ThreadLocalOptions::set_default(options);
+/// assert_eq!(to_lexical_default(1234567u32, &mut buffer), b"1,234,567");
+/// ```
+#[cfg(feature = "std")]
+#[inline]
+pub fn to_lexical_default<T>(value: T, bytes: &mut [u8]) -> &mut [u8]
+where
+    T: ToLexicalWithOptions<Options = Options>,
+{
+    ThreadLocalOptions::with_default(move |options| {
+        value.to_lexical_with_options::<STANDARD>(bytes, options)
+    })
+}
+
+// SMALLVEC
+
+/// Write a decimal integer to a [`SmallVec`] with an inline buffer.
+///
+/// The inline buffer holds 24 bytes, which is enough for any 64-bit integer
+/// including the sign, so only larger values, such as large 128-bit
+/// integers, are written to the heap.
+///
+/// * `value`   - Number to serialize.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_write_integer::to_lexical_smallvec;
+///
+/// let digits = to_lexical_smallvec(u64::MAX);
+/// assert_eq!(&digits[..], b"18446744073709551615");
+/// assert!(!digits.spilled());
+///
+/// let digits = to_lexical_smallvec(u128::MAX);
+/// assert_eq!(&digits[..], b"340282366920938463463374607431768211455");
+/// assert!(digits.spilled());
+/// ```
+#[cfg(feature = "smallvec")]
+#[inline]
+pub fn to_lexical_smallvec<T: ToLexical>(value: T) -> SmallVec<[u8; 24]> {
+    // Write directly to the inline buffer if possible, otherwise use an
+    // intermediate buffer so small values of larger types stay inline.
+    if T::FORMATTED_SIZE_DECIMAL <= 24 {
+        let mut vec = SmallVec::from_buf([0u8; 24]);
+        let count = value.to_lexical(&mut vec).len();
+        vec.truncate(count);
+        vec
+    } else {
+        let mut buffer = [0u8; i128::FORMATTED_SIZE_DECIMAL];
+        SmallVec::from_slice(value.to_lexical(&mut buffer))
+    }
+}
+
+// ALLOC
+
+/// Write a decimal integer to a newly allocated [`Vec`].
+///
+/// * `value`   - Number to serialize.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_write_integer::to_lexical_vec;
+///
+/// assert_eq!(to_lexical_vec(-1234i32), b"-1234");
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn to_lexical_vec<T: ToLexical>(value: T) -> Vec<u8> {
+    let mut vec = vec![0u8; T::FORMATTED_SIZE_DECIMAL];
+    let count = value.to_lexical(&mut vec).len();
+    vec.truncate(count);
+    vec
+}
+
+/// Write a decimal integer to a newly allocated [`String`].
+///
+/// * `value`   - Number to serialize.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_write_integer::to_lexical_string;
+///
+/// assert_eq!(to_lexical_string(-1234i32), "-1234");
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn to_lexical_string<T: ToLexical>(value: T) -> String {
+    let vec = to_lexical_vec(value);
+    // SAFETY: safe since the written integer is always valid ASCII.
+    unsafe { String::from_utf8_unchecked(vec) }
+}
+
+/// Write an integer to a newly allocated [`Vec`] with custom options.
+///
+/// The allocation is sized using [`Options::buffer_size_const`], so it
+/// is always large enough for the radix of the format.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `value`   - Number to serialize.
+/// * `options` - Options to customize number writing.
+///
+/// # Panics
+///
+/// Panics if the number format is invalid, as with
+/// [`to_lexical_with_options`][ToLexicalWithOptions::to_lexical_with_options].
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_write_integer::{to_lexical_vec_with_options, Options};
+/// use lexical_write_integer::format::STANDARD;
+///
+/// const OPTIONS: Options = Options::new();
+/// let digits = to_lexical_vec_with_options::<_, STANDARD>(1234u64, &OPTIONS);
+/// assert_eq!(digits, b"1234");
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn to_lexical_vec_with_options<T, const FORMAT: u128>(value: T, options: &Options) -> Vec<u8>
+where
+    T: ToLexicalWithOptions<Options = Options>,
+{
+    let mut vec = vec![0u8; options.buffer_size_const::<T, FORMAT>()];
+    let count = value.to_lexical_with_options::<FORMAT>(&mut vec, options).len();
+    vec.truncate(count);
+    vec
+}
+
+/// Write an integer to a newly allocated [`String`] with custom options.
+///
+/// The allocation is sized using [`Options::buffer_size_const`], so it
+/// is always large enough for the radix of the format.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `value`   - Number to serialize.
+/// * `options` - Options to customize number writing.
+///
+/// # Panics
+///
+/// Panics if the number format is invalid, as with
+/// [`to_lexical_with_options`][ToLexicalWithOptions::to_lexical_with_options],
+/// or if the options contain non-ASCII characters.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_write_integer::{to_lexical_string_with_options, Options};
+/// use lexical_write_integer::format::STANDARD;
+///
+/// const OPTIONS: Options = Options::new();
+/// let digits = to_lexical_string_with_options::<_, STANDARD>(-1234i64, &OPTIONS);
+/// assert_eq!(digits, "-1234");
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn to_lexical_string_with_options<T, const FORMAT: u128>(value: T, options: &Options) -> String
+where
+    T: ToLexicalWithOptions<Options = Options>,
+{
+    let vec = to_lexical_vec_with_options::<T, FORMAT>(value, options);
+    // Options built with `build_unchecked` may contain invalid UTF-8.
+    String::from_utf8(vec).expect("options must only contain valid UTF-8")
+}
@@ -6,7 +6,8 @@
 #![cfg(feature = "compact")]
 #![doc(hidden)]
 
-use lexical_util::algorithm::copy_to_dst;
+use core::mem::MaybeUninit;
+
 use lexical_util::constants::FormattedSize;
 use lexical_util::digit::digit_to_char;
 use lexical_util::num::{AsCast, UnsignedInteger};
@@ -33,35 +34,45 @@ use lexical_util::num::{AsCast, UnsignedInteger};
 pub trait Compact: UnsignedInteger + FormattedSize {
     /// Write our integer to string without optimizations.
     ///
-    /// This iterates over the buffer in reverse, and ensures that at least
-    /// 128 elements exist in the temporary buffer. This ensures that the digits
-    /// can never overflow, even in base 2. The buffer then must be able to hold
-    /// at least 128 digits as well, after subslicing the data. This guarantee
-    /// is likely already made.
+    /// This writes the digits directly into the buffer, which must be
+    /// large enough to hold all the digits, otherwise this panics.
     fn compact(self, radix: u32, buffer: &mut [u8]) -> usize {
-        // NOTE: We do not have to validate the buffer length because `copy_to_dst` is
-        // safe.
-        assert!(Self::BITS <= 128);
-        let mut digits: [u8; 128] = [0u8; 128];
-        let mut index = digits.len();
+        // SAFETY: `MaybeUninit<u8>` has the same layout as `u8`, and
+        // `compact_into` only writes initialized digits to the buffer.
+        let buffer = unsafe { &mut *(buffer as *mut [u8] as *mut [MaybeUninit<u8>]) };
+        compact_into(self, radix, buffer)
+    }
+}
 
-        // Decode all but the last digit.
-        let radix = Self::from_u32(radix);
-        let mut value = self;
-        while value >= radix {
-            let r = value % radix;
-            value /= radix;
-            index -= 1;
-            digits[index] = digit_to_char(u32::as_cast(r));
-        }
+/// Write an integer to a possibly uninitialized buffer.
+///
+/// This writes the digits in reverse directly to the end of the buffer,
+/// avoiding any temporary buffer, and then shifts them to the start.
+/// Returns the number of digits written, which are all initialized.
+/// Panics if the buffer is too small to hold the digits.
+///
+/// This is a free function, rather than a trait method, so it cannot be
+/// overridden by an implementation that leaves the digits uninitialized.
+pub fn compact_into<T: Compact>(value: T, radix: u32, buffer: &mut [MaybeUninit<u8>]) -> usize {
+    assert!(T::BITS <= 128);
+    let mut index = buffer.len();
 
-        // Decode last digit.
-        let r = value;
+    // Decode all but the last digit.
+    let radix = T::from_u32(radix);
+    let mut value = value;
+    while value >= radix {
+        let r = value % radix;
+        value /= radix;
         index -= 1;
-        digits[index] = digit_to_char(u32::as_cast(r));
-        let slc = &digits[index..];
-        copy_to_dst(buffer, slc)
+        buffer[index] = MaybeUninit::new(digit_to_char(u32::as_cast(r)));
     }
+
+    // Decode last digit.
+    index -= 1;
+    buffer[index] = MaybeUninit::new(digit_to_char(u32::as_cast(value)));
+    let count = buffer.len() - index;
+    buffer.copy_within(index.., 0);
+    count
 }
 
 macro_rules! compact_impl {
@@ -24,7 +24,9 @@
 //!
 //! Using [`FormattedSize::FORMATTED_SIZE_DECIMAL`] guarantees the buffer
 //! will be large enough to write the digits for all numbers of that
-//! type.
+//! type. If the buffer may be too small, such as when writing to a bounded
+//! network buffer, use [`try_to_lexical`], which returns the required
+//! capacity rather than panicking.
 //!
 //! # Features
 //!
@@ -33,9 +35,12 @@
 //! * `power-of-two` - Add support for writing power-of-two integer strings.
 //! * `radix` - Add support for strings of any radix.
 //! * `compact` - Reduce code size at the cost of performance.
+//! * `smallvec` - Add support for writing to an inline [`SmallVec`] buffer.
+//! * `alloc` - Add support for writing to an allocated [`String`] or [`Vec`].
 //! * `std` (Default) - Disable to allow use in a [`no_std`] environment.
 //!
 //! [`no_std`]: https://docs.rust-embedded.org/book/intro/no-std.html
+//! [`SmallVec`]: https://docs.rs/smallvec/latest/smallvec/struct.SmallVec.html
 //!
 //! A complete description of supported features includes:
 //!
@@ -98,6 +103,36 @@
 //! the number of static tables, inlining, and generics used, drastically
 //! reducing the size of the generated binaries.
 //!
+//! #### smallvec
+//!
+//! Enable writing decimal integers to a [`SmallVec`] with an inline buffer
+//! of 24 bytes, large enough for any 64-bit integer including the sign,
+//! without a heap allocation or a caller-provided buffer. Larger values,
+//! such as large 128-bit integers, spill to the heap.
+//!
+//! ```rust
+//! # #[cfg(feature = "smallvec")] {
+//! use lexical_write_integer::to_lexical_smallvec;
+//!
+//! let digits = to_lexical_smallvec(-1234i64);
+//! assert_eq!(&digits[..], b"-1234");
+//! assert!(!digits.spilled());
+//! # }
+//! ```
+//!
+//! #### alloc
+//!
+//! Enable writing integers to a newly allocated [`String`] or [`Vec`],
+//! without a caller-provided buffer.
+//!
+//! ```rust
+//! # #[cfg(feature = "alloc")] {
+//! use lexical_write_integer::to_lexical_string;
+//!
+//! assert_eq!(to_lexical_string(-1234i64), "-1234");
+//! # }
+//! ```
+//!
 //! #### std
 //!
 //! Enable use of the standard library. Currently, the standard library
@@ -119,6 +154,14 @@
 //! const generic support. Older versions of lexical support older Rust
 //! versions.
 //!
+//! # Platform Support
+//!
+//! [`usize`] and [`isize`] are written as the fixed-width integer with the
+//! same size as the target pointer, so `usize` is at most 5 digits
+//! on 16-bit targets, such as MSP430. The algorithms select their
+//! optimizations from the size of the integer type and not the target,
+//! and only assume `usize` is at least 16 bits, which Rust guarantees.
+//!
 //! # Algorithm
 //!
 //! We use 3 algorithms for serializing numbers:
@@ -313,6 +356,7 @@
 //! [`Jeaiii Algorithm`]: https://jk-jeon.github.io/posts/2022/02/jeaiii-algorithm/
 //! [`rust-1.63.0`]: https://blog.rust-lang.org/2022/08/11/Rust-1.63.0.html
 //! [`String`]: https://doc.rust-lang.org/alloc/string/struct.String.html
+//! [`Vec`]: https://doc.rust-lang.org/alloc/vec/struct.Vec.html
 //! [`to_string`]: https://doc.rust-lang.org/alloc/string/trait.ToString.html#tymethod.to_string
 //! [`log2_lz`]: https://github.com/Alexhuszagh/rust-lexical/blob/c6c5052/lexical-write-integer/src/digit_count.rs#L119
 
@@ -348,6 +392,11 @@
     clippy::semicolon_inside_block,
 )]
 
+// Need an allocator for String/Vec.
+#[cfg(feature = "alloc")]
+#[macro_use(vec)]
+extern crate alloc;
+
 pub mod algorithm;
 pub mod compact;
 pub mod decimal;
@@ -365,11 +414,34 @@ mod table_radix;
 
 // Re-exports
 pub use lexical_util::constants::{FormattedSize, BUFFER_SIZE};
-pub use lexical_util::error::Error;
+pub use lexical_util::error::{Error, NeedCapacity};
 pub use lexical_util::format::{self, NumberFormat, NumberFormatBuilder};
 pub use lexical_util::options::WriteOptions;
 pub use lexical_util::result::Result;
 
-pub use self::api::{ToLexical, ToLexicalWithOptions};
+#[cfg(feature = "smallvec")]
+pub use self::api::to_lexical_smallvec;
+#[cfg(feature = "alloc")]
+pub use self::api::{
+    to_lexical_string,
+    to_lexical_string_with_options,
+    to_lexical_vec,
+    to_lexical_vec_with_options,
+};
+pub use self::api::{
+    to_lexical_exact_digits,
+    try_to_lexical,
+    try_to_lexical_with_options,
+    ArrayString,
+    ToDigitCount,
+    ToLexical,
+    ToLexicalWithOptions,
+    ToSignAndDigits,
+    ToUnsignedMagnitude,
+};
 #[doc(inline)]
-pub use self::options::{Options, OptionsBuilder};
+pub use self::options::{GroupingScheme, Options, OptionsBuilder};
+#[cfg(feature = "std")]
+pub use self::api::to_lexical_default;
+#[cfg(feature = "std")]
+pub use self::options::ThreadLocalOptions;
@@ -1,7 +1,9 @@
 //! Configuration options for writing integers.
 //!
-//! This currently has no functionality, since we do not
-//! support any features for writing integers at this time.
+//! This currently supports grouping the integer digits, such as
+//! writing `1234567` as `1,234,567`, writing an exact number of
+//! digits, such as writing `42` as `0042`, and padding to a minimum
+//! width, such as writing `-5` as `-005`.
 //!
 //! # Examples
 //!
@@ -20,11 +22,29 @@
 //! assert_eq!(str::from_utf8(digits), Ok("1234"));
 //! ```
 
+use core::num;
+#[cfg(feature = "std")]
+use std::cell::RefCell;
+
 use lexical_util::constants::FormattedSize;
-use lexical_util::format::NumberFormat;
+use lexical_util::error::Error;
+use lexical_util::format::{validate_format, NumberFormat, OptionU8};
 use lexical_util::options::WriteOptions;
 use lexical_util::result::Result;
 
+/// How the integer digits are split into groups.
+///
+/// The group separator and the size of the least-significant group are
+/// set by [`integer_grouping`][OptionsBuilder::integer_grouping].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum GroupingScheme {
+    /// Every group has the same size, such as `1,234,567`.
+    Uniform,
+    /// The least-significant group has the group size, and every other
+    /// group has 2 digits, such as the Indian `12,34,567`.
+    Indian,
+}
+
 /// Builder for [`Options`].
 ///
 /// # Examples
@@ -45,21 +65,343 @@ use lexical_util::result::Result;
 /// assert_eq!(str::from_utf8(digits), Ok("1234"));
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub struct OptionsBuilder {}
+pub struct OptionsBuilder {
+    /// The separator and the number of digits per group for the integer
+    /// digits.
+    integer_grouping: Option<(u8, usize)>,
+    /// How the integer digits are split into groups.
+    grouping_scheme: GroupingScheme,
+    /// Write digits greater than `9` in uppercase, such as `FF`.
+    uppercase_digits: bool,
+    /// The exact number of digits to write, padding with leading zeros.
+    exact_digits: OptionU8,
+    /// The minimum number of bytes to write, including the sign.
+    integer_min_width: Option<num::NonZeroUsize>,
+    /// Character to pad the integer to the minimum width with.
+    pad_char: u8,
+}
 
 impl OptionsBuilder {
     /// Create new options builder with default options.
     #[inline(always)]
     pub const fn new() -> Self {
-        Self {}
+        Self {
+            integer_grouping: None,
+            grouping_scheme: GroupingScheme::Uniform,
+            uppercase_digits: true,
+            exact_digits: None,
+            integer_min_width: None,
+            pad_char: b'0',
+        }
+    }
+
+    // GETTERS
+
+    /// Get the separator and the number of digits per group for the integer
+    /// digits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_integer::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_integer_grouping(), None);
+    /// ```
+    #[inline(always)]
+    pub const fn get_integer_grouping(&self) -> Option<(u8, usize)> {
+        self.integer_grouping
+    }
+
+    /// Get how the integer digits are split into groups.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_integer::{GroupingScheme, Options};
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_grouping_scheme(), GroupingScheme::Uniform);
+    /// ```
+    #[inline(always)]
+    pub const fn get_grouping_scheme(&self) -> GroupingScheme {
+        self.grouping_scheme
+    }
+
+    /// Get if digits greater than `9` are written in uppercase.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_integer::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_uppercase_digits(), true);
+    /// ```
+    #[inline(always)]
+    pub const fn get_uppercase_digits(&self) -> bool {
+        self.uppercase_digits
+    }
+
+    /// Get the exact number of digits to write.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_integer::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_exact_digits(), None);
+    /// ```
+    #[inline(always)]
+    pub const fn get_exact_digits(&self) -> OptionU8 {
+        self.exact_digits
+    }
+
+    /// Get the minimum number of bytes to write, including the sign.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_integer::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_integer_min_width(), None);
+    /// ```
+    #[inline(always)]
+    pub const fn get_integer_min_width(&self) -> Option<num::NonZeroUsize> {
+        self.integer_min_width
+    }
+
+    /// Get the character to pad the integer to the minimum width with.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_integer::Options;
+    ///
+    /// let builder = Options::builder();
+    /// assert_eq!(builder.get_pad_char(), b'0');
+    /// ```
+    #[inline(always)]
+    pub const fn get_pad_char(&self) -> u8 {
+        self.pad_char
+    }
+
+    // SETTERS
+
+    /// Set the separator and the number of digits per group for the integer
+    /// digits.
+    ///
+    /// The separator is written every `size` digits, counting from the
+    /// least-significant digit, so `1234567` with `(b',', 3)` is written as
+    /// `1,234,567`. The separator is never written next to the sign, or if
+    /// there are no more than `size` digits. The separator must be a space or
+    /// ASCII punctuation other than a sign. A size of `0` uses the default for
+    /// the radix, which is `4` for binary and hexadecimal, such as
+    /// `dead beef`, and `3` otherwise. Defaults to [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::str;
+    ///
+    /// use lexical_write_integer::{Options, ToLexicalWithOptions};
+    /// use lexical_write_integer::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .integer_grouping(Some((b',', 3)))
+    ///     .build_strict();
+    /// const BUFFER_SIZE: usize = OPTIONS.buffer_size_const::<i64, STANDARD>();
+    /// let mut buffer = [0u8; BUFFER_SIZE];
+    /// let digits = (-1234567i64).to_lexical_with_options::<STANDARD>(&mut buffer, &OPTIONS);
+    /// assert_eq!(str::from_utf8(digits), Ok("-1,234,567"));
+    ///
+    /// const DEFAULT: Options = Options::builder()
+    ///     .integer_grouping(Some((b' ', 0)))
+    ///     .build_strict();
+    /// let digits = 1234567u32.to_lexical_with_options::<STANDARD>(&mut buffer, &DEFAULT);
+    /// assert_eq!(str::from_utf8(digits), Ok("1 234 567"));
+    /// ```
+    #[inline(always)]
+    pub const fn integer_grouping(mut self, integer_grouping: Option<(u8, usize)>) -> Self {
+        self.integer_grouping = integer_grouping;
+        self
+    }
+
+    /// Set how the integer digits are split into groups.
+    ///
+    /// This only has an effect with
+    /// [`integer_grouping`][Self::integer_grouping]. With
+    /// [`GroupingScheme::Indian`], the least-significant group has the group
+    /// size and every other group has 2 digits, so `12345678` with
+    /// `(b',', 3)` is written as `1,23,45,678`. Defaults to
+    /// [`GroupingScheme::Uniform`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::str;
+    ///
+    /// use lexical_write_integer::{GroupingScheme, Options, ToLexicalWithOptions};
+    /// use lexical_write_integer::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .integer_grouping(Some((b',', 3)))
+    ///     .grouping_scheme(GroupingScheme::Indian)
+    ///     .build_strict();
+    /// const BUFFER_SIZE: usize = OPTIONS.buffer_size_const::<i64, STANDARD>();
+    /// let mut buffer = [0u8; BUFFER_SIZE];
+    /// let digits = (-12345678i64).to_lexical_with_options::<STANDARD>(&mut buffer, &OPTIONS);
+    /// assert_eq!(str::from_utf8(digits), Ok("-1,23,45,678"));
+    /// ```
+    #[inline(always)]
+    pub const fn grouping_scheme(mut self, grouping_scheme: GroupingScheme) -> Self {
+        self.grouping_scheme = grouping_scheme;
+        self
+    }
+
+    /// Set if digits greater than `9` are written in uppercase.
+    ///
+    /// This only affects radixes greater than `10`, so `255` in radix `16`
+    /// is written as `FF` if set, and `ff` otherwise. Defaults to [`true`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "power-of-two")] {
+    /// use lexical_write_integer::{NumberFormatBuilder, Options, ToLexicalWithOptions};
+    ///
+    /// const FORMAT: u128 = NumberFormatBuilder::from_radix(16);
+    /// const OPTIONS: Options = Options::builder()
+    ///     .uppercase_digits(false)
+    ///     .build_strict();
+    /// const BUFFER_SIZE: usize = OPTIONS.buffer_size_const::<u32, FORMAT>();
+    /// let mut buffer = [0u8; BUFFER_SIZE];
+    /// assert_eq!(255u32.to_lexical_with_options::<FORMAT>(&mut buffer, &OPTIONS), b"ff");
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub const fn uppercase_digits(mut self, uppercase_digits: bool) -> Self {
+        self.uppercase_digits = uppercase_digits;
+        self
+    }
+
+    /// Set the exact number of digits to write.
+    ///
+    /// Values with fewer digits are padded with leading zeros after the
+    /// sign, so `42` with `4` digits is written as `0042`. Values with more
+    /// digits are written in full, and
+    /// [`to_lexical_exact_digits`][crate::to_lexical_exact_digits] returns
+    /// [`Error::FieldOverflow`] for them instead. Defaults to [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::num;
+    /// use core::str;
+    ///
+    /// use lexical_write_integer::{Options, ToLexicalWithOptions};
+    /// use lexical_write_integer::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .exact_digits(num::NonZeroU8::new(4))
+    ///     .build_strict();
+    /// const BUFFER_SIZE: usize = OPTIONS.buffer_size_const::<i32, STANDARD>();
+    /// let mut buffer = [0u8; BUFFER_SIZE];
+    /// let digits = (-42i32).to_lexical_with_options::<STANDARD>(&mut buffer, &OPTIONS);
+    /// assert_eq!(str::from_utf8(digits), Ok("-0042"));
+    /// ```
+    #[inline(always)]
+    pub const fn exact_digits(mut self, exact_digits: OptionU8) -> Self {
+        self.exact_digits = exact_digits;
+        self
+    }
+
+    /// Set the minimum number of bytes to write, including the sign.
+    ///
+    /// Shorter values are padded with [`pad_char`][Self::pad_char] to the
+    /// width, so `-5` with a width of `4` is written as `-005`. Zero padding
+    /// is written after the sign, and any other padding before it. Longer
+    /// values are written in full. The padding is applied after any group
+    /// separators, and is never grouped. Defaults to [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::num;
+    /// use core::str;
+    ///
+    /// use lexical_write_integer::{Options, ToLexicalWithOptions};
+    /// use lexical_write_integer::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .integer_min_width(num::NonZeroUsize::new(4))
+    ///     .build_strict();
+    /// const BUFFER_SIZE: usize = OPTIONS.buffer_size_const::<i32, STANDARD>();
+    /// let mut buffer = [0u8; BUFFER_SIZE];
+    /// let digits = (-5i32).to_lexical_with_options::<STANDARD>(&mut buffer, &OPTIONS);
+    /// assert_eq!(str::from_utf8(digits), Ok("-005"));
+    /// ```
+    #[inline(always)]
+    pub const fn integer_min_width(mut self, integer_min_width: Option<num::NonZeroUsize>) -> Self {
+        self.integer_min_width = integer_min_width;
+        self
+    }
+
+    /// Set the character to pad the integer to the minimum width with.
+    ///
+    /// This must be `0`, or a space or ASCII punctuation that is not a
+    /// sign. Defaults to `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::num;
+    /// use core::str;
+    ///
+    /// use lexical_write_integer::{Options, ToLexicalWithOptions};
+    /// use lexical_write_integer::format::STANDARD;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .integer_min_width(num::NonZeroUsize::new(4))
+    ///     .pad_char(b' ')
+    ///     .build_strict();
+    /// const BUFFER_SIZE: usize = OPTIONS.buffer_size_const::<i32, STANDARD>();
+    /// let mut buffer = [0u8; BUFFER_SIZE];
+    /// let digits = (-5i32).to_lexical_with_options::<STANDARD>(&mut buffer, &OPTIONS);
+    /// assert_eq!(str::from_utf8(digits), Ok("  -5"));
+    /// ```
+    #[inline(always)]
+    pub const fn pad_char(mut self, pad_char: u8) -> Self {
+        self.pad_char = pad_char;
+        self
     }
 
     // BUILDERS
 
+    /// Determine if [`integer_grouping`][Self::integer_grouping] is valid.
+    #[doc(hidden)]
+    #[inline(always)]
+    pub const fn integer_grouping_is_valid(&self) -> bool {
+        match self.integer_grouping {
+            Some((c, _)) => (c == b' ' || c.is_ascii_punctuation()) && !matches!(c, b'+' | b'-'),
+            None => true,
+        }
+    }
+
+    /// Determine if [`pad_char`][Self::pad_char] is valid.
+    #[doc(hidden)]
+    #[inline(always)]
+    pub const fn pad_char_is_valid(&self) -> bool {
+        let c = self.pad_char;
+        c == b'0' || ((c == b' ' || c.is_ascii_punctuation()) && !matches!(c, b'+' | b'-'))
+    }
+
     /// Check if the builder state is valid.
     #[inline(always)]
     pub const fn is_valid(&self) -> bool {
-        true
+        self.integer_grouping_is_valid() && self.pad_char_is_valid()
     }
 
     /// Build the [`Options`] struct without validation.
@@ -75,10 +417,21 @@ impl OptionsBuilder {
     /// [`is_valid`]: Self::is_valid
     #[inline(always)]
     pub const fn build_unchecked(&self) -> Options {
-        Options {}
+        Options {
+            integer_grouping: self.integer_grouping,
+            grouping_scheme: self.grouping_scheme,
+            uppercase_digits: self.uppercase_digits,
+            exact_digits: self.exact_digits,
+            integer_min_width: self.integer_min_width,
+            pad_char: self.pad_char,
+        }
     }
 
-    /// Build the [`Options`] struct. This can never panic.
+    /// Build the [`Options`] struct, panicking if the builder is invalid.
+    ///
+    /// # Panics
+    ///
+    /// If the built options are not valid.
     #[inline(always)]
     pub const fn build_strict(&self) -> Options {
         match self.build() {
@@ -90,7 +443,13 @@ impl OptionsBuilder {
     /// Build the [`Options`] struct.
     #[inline(always)]
     pub const fn build(&self) -> Result<Options> {
-        Ok(self.build_unchecked())
+        if !self.integer_grouping_is_valid() {
+            Err(Error::InvalidIntegerGrouping)
+        } else if !self.pad_char_is_valid() {
+            Err(Error::InvalidPadCharacter)
+        } else {
+            Ok(self.build_unchecked())
+        }
     }
 }
 
@@ -123,13 +482,34 @@ impl Default for OptionsBuilder {
 // FIXME: Add phantom data for private fields.
 //  This is a BREAKING change so requires a major API release.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub struct Options {}
+pub struct Options {
+    /// The separator and the number of digits per group for the integer
+    /// digits.
+    integer_grouping: Option<(u8, usize)>,
+    /// How the integer digits are split into groups.
+    grouping_scheme: GroupingScheme,
+    /// Write digits greater than `9` in uppercase, such as `FF`.
+    uppercase_digits: bool,
+    /// The exact number of digits to write, padding with leading zeros.
+    exact_digits: OptionU8,
+    /// The minimum number of bytes to write, including the sign.
+    integer_min_width: Option<num::NonZeroUsize>,
+    /// Character to pad the integer to the minimum width with.
+    pad_char: u8,
+}
 
 impl Options {
     /// Create options with default values.
     #[inline(always)]
     pub const fn new() -> Self {
-        Self {}
+        Self {
+            integer_grouping: None,
+            grouping_scheme: GroupingScheme::Uniform,
+            uppercase_digits: true,
+            exact_digits: None,
+            integer_min_width: None,
+            pad_char: b'0',
+        }
     }
 
     /// Create the default options for a given radix.
@@ -142,23 +522,175 @@ impl Options {
     /// Check if the options state is valid.
     #[inline(always)]
     pub const fn is_valid(&self) -> bool {
-        true
+        self.rebuild().is_valid()
+    }
+
+    /// Check if a packed number format is valid for writing.
+    ///
+    /// The writers panic on invalid formats, so this can be used to
+    /// check a format up front and get the specific error instead.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "format")] {
+    /// use lexical_util::error::Error;
+    /// use lexical_util::format::{NumberFormatBuilder, STANDARD};
+    /// use lexical_write_integer::Options;
+    ///
+    /// assert_eq!(Options::validate_format::<STANDARD>(), Ok(()));
+    /// const FORMAT: u128 = NumberFormatBuilder::new()
+    ///     .no_positive_mantissa_sign(true)
+    ///     .required_mantissa_sign(true)
+    ///     .build_unchecked();
+    /// assert_eq!(Options::validate_format::<FORMAT>(), Err(Error::InvalidMantissaSign));
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub const fn validate_format<const FORMAT: u128>() -> Result<()> {
+        validate_format(FORMAT)
     }
 
     /// Get an upper bound on the required buffer size.
     ///
-    /// This is always [`FORMATTED_SIZE`][FormattedSize::FORMATTED_SIZE]
+    /// This is [`FORMATTED_SIZE`][FormattedSize::FORMATTED_SIZE]
     /// or [`FORMATTED_SIZE_DECIMAL`][FormattedSize::FORMATTED_SIZE_DECIMAL],
-    /// depending on the radix.
+    /// depending on the radix, plus room for any padding digits and group
+    /// separators, and at least the minimum width.
     #[inline(always)]
     pub const fn buffer_size_const<T: FormattedSize, const FORMAT: u128>(&self) -> usize {
-        if (NumberFormat::<FORMAT> {}.radix()) == 10 {
+        let radix = NumberFormat::<FORMAT> {}.radix();
+        let mut size = if radix == 10 {
             T::FORMATTED_SIZE_DECIMAL
         } else {
             T::FORMATTED_SIZE
+        };
+        if let Some(exact_digits) = self.exact_digits {
+            // Add room for the sign.
+            let exact_size = exact_digits.get() as usize + 1;
+            if exact_size > size {
+                size = exact_size;
+            }
+        }
+        let size = match self.integer_grouping {
+            Some((_, group_size)) => {
+                let group_size = group_size_for_radix(group_size, radix);
+                size + group_separator_count(size, group_size, self.grouping_scheme)
+            },
+            None => size,
+        };
+        match self.integer_min_width {
+            Some(width) if width.get() > size => width.get(),
+            _ => size,
         }
     }
 
+    // GETTERS
+
+    /// Get the separator and the number of digits per group for the integer
+    /// digits.
+    ///
+    /// The separator is written every `size` digits, counting from the
+    /// least-significant digit. A size of `0` uses the default for the radix.
+    /// Defaults to [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_integer::Options;
+    ///
+    /// const OPTIONS: Options = Options::builder()
+    ///     .integer_grouping(Some((b'_', 4)))
+    ///     .build_strict();
+    /// assert_eq!(OPTIONS.integer_grouping(), Some((b'_', 4)));
+    /// ```
+    #[inline(always)]
+    pub const fn integer_grouping(&self) -> Option<(u8, usize)> {
+        self.integer_grouping
+    }
+
+    /// Get how the integer digits are split into groups.
+    ///
+    /// This only has an effect with
+    /// [`integer_grouping`][Self::integer_grouping]. Defaults to
+    /// [`GroupingScheme::Uniform`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_integer::{GroupingScheme, Options};
+    ///
+    /// assert_eq!(Options::new().grouping_scheme(), GroupingScheme::Uniform);
+    /// ```
+    #[inline(always)]
+    pub const fn grouping_scheme(&self) -> GroupingScheme {
+        self.grouping_scheme
+    }
+
+    /// Get if digits greater than `9` are written in uppercase.
+    ///
+    /// This only affects radixes greater than `10`. Defaults to [`true`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_integer::Options;
+    ///
+    /// assert_eq!(Options::new().uppercase_digits(), true);
+    /// ```
+    #[inline(always)]
+    pub const fn uppercase_digits(&self) -> bool {
+        self.uppercase_digits
+    }
+
+    /// Get the exact number of digits to write.
+    ///
+    /// Values with fewer digits are padded with leading zeros after the
+    /// sign. Defaults to [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_integer::Options;
+    ///
+    /// assert_eq!(Options::new().exact_digits(), None);
+    /// ```
+    #[inline(always)]
+    pub const fn exact_digits(&self) -> OptionU8 {
+        self.exact_digits
+    }
+
+    /// Get the minimum number of bytes to write, including the sign.
+    ///
+    /// Shorter values are padded with [`pad_char`][Self::pad_char].
+    /// Defaults to [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_integer::Options;
+    ///
+    /// assert_eq!(Options::new().integer_min_width(), None);
+    /// ```
+    #[inline(always)]
+    pub const fn integer_min_width(&self) -> Option<num::NonZeroUsize> {
+        self.integer_min_width
+    }
+
+    /// Get the character to pad the integer to the minimum width with.
+    ///
+    /// Defaults to `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lexical_write_integer::Options;
+    ///
+    /// assert_eq!(Options::new().pad_char(), b'0');
+    /// ```
+    #[inline(always)]
+    pub const fn pad_char(&self) -> u8 {
+        self.pad_char
+    }
+
     // BUILDERS
 
     /// Get [`OptionsBuilder`] as a static function.
@@ -170,7 +702,14 @@ impl Options {
     /// Create [`OptionsBuilder`] using existing values.
     #[inline(always)]
     pub const fn rebuild(&self) -> OptionsBuilder {
-        OptionsBuilder {}
+        OptionsBuilder {
+            integer_grouping: self.integer_grouping,
+            grouping_scheme: self.grouping_scheme,
+            uppercase_digits: self.uppercase_digits,
+            exact_digits: self.exact_digits,
+            integer_min_width: self.integer_min_width,
+            pad_char: self.pad_char,
+        }
     }
 }
 
@@ -194,9 +733,96 @@ impl WriteOptions for Options {
     }
 }
 
+// THREAD-LOCAL DEFAULTS
+// ---------------------
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static DEFAULT_OPTIONS: RefCell<Options> = const { RefCell::new(Options::new()) };
+}
+
+/// Ambient, per-thread default options for writing integers.
+///
+/// This allows configuring the options once and then using them from
+/// code that does not have access to the [`Options`], without threading
+/// them through every call. Each thread starts with [`Options::new`].
+///
+/// The defaults are used by [`to_lexical_default`]. [`to_lexical`] always
+/// uses the standard options and never reads the thread-local defaults,
+/// so it is not slowed down by the lookup.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_write_integer::{to_lexical_default, Options, ThreadLocalOptions, BUFFER_SIZE};
+///
+/// let options = Options::builder().integer_grouping(Some((b',', 3))).build_strict();
+/// ThreadLocalOptions::set_default(options);
+/// let mut buffer = [0u8; BUFFER_SIZE];
+/// assert_eq!(to_lexical_default(1234567u32, &mut buffer), b"1,234,567");
+/// ```
+///
+/// [`to_lexical`]: crate::ToLexical::to_lexical
+/// [`to_lexical_default`]: crate::to_lexical_default
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThreadLocalOptions;
+
+#[cfg(feature = "std")]
+impl ThreadLocalOptions {
+    /// Set the default options for the current thread.
+    ///
+    /// Returns the previous default options, which can be used to restore
+    /// them later.
+    #[inline]
+    pub fn set_default(options: Options) -> Options {
+        DEFAULT_OPTIONS.with(|default| default.replace(options))
+    }
+
+    /// Call `f` with the default options for the current thread.
+    ///
+    /// The options are copied before calling `f`, so `f` may change the
+    /// defaults without affecting the options it was given.
+    #[inline]
+    pub fn with_default<R, F: FnOnce(&Options) -> R>(f: F) -> R {
+        let options = DEFAULT_OPTIONS.with(|default| default.borrow().clone());
+        f(&options)
+    }
+}
+
 // PRE-DEFINED CONSTANTS
 // ---------------------
 
 /// Standard number format.
 #[rustfmt::skip]
 pub const STANDARD: Options = Options::new();
+
+/// Get the number of digits per group, using the default for the radix if
+/// the size is `0`.
+///
+/// The default is `4` for binary and hexadecimal, such as `dead beef`,
+/// and `3` otherwise, such as `1,234,567`.
+#[inline(always)]
+pub(crate) const fn group_size_for_radix(size: usize, radix: u32) -> usize {
+    match (size, radix) {
+        (0, 2 | 16) => 4,
+        (0, _) => 3,
+        _ => size,
+    }
+}
+
+/// Get the number of group separators written between `digits` digits.
+///
+/// This requires at least 1 digit and a non-zero group size.
+#[inline(always)]
+pub(crate) const fn group_separator_count(
+    digits: usize,
+    size: usize,
+    scheme: GroupingScheme,
+) -> usize {
+    match scheme {
+        GroupingScheme::Uniform => (digits - 1) / size,
+        GroupingScheme::Indian if digits > size => 1 + (digits - size - 1) / 2,
+        GroupingScheme::Indian => 0,
+    }
+}
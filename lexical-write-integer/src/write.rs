@@ -193,3 +193,21 @@ macro_rules! write_integer_impl {
 }
 
 write_integer_impl! { u8 u16 u32 u64 u128 usize }
+
+/// Write the sign for the mantissa or the exponent.
+///
+/// Writes `-` if the value is negative, and `+` if the sign is `required`,
+/// which is the `required_mantissa_sign` or `required_exponent_sign` flag
+/// of the format. Returns the number of bytes written.
+#[inline(always)]
+pub fn write_sign(buffer: &mut [u8], is_negative: bool, required: bool) -> usize {
+    if is_negative {
+        buffer[0] = b'-';
+        1
+    } else if required {
+        buffer[0] = b'+';
+        1
+    } else {
+        0
+    }
+}
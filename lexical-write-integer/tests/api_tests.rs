@@ -1,6 +1,7 @@
 mod util;
 
 use core::fmt::Debug;
+use core::num;
 use core::str::{from_utf8_unchecked, FromStr};
 
 #[cfg(feature = "radix")]
@@ -8,7 +9,15 @@ use lexical_util::constants::BUFFER_SIZE;
 #[cfg(feature = "format")]
 use lexical_util::format::NumberFormatBuilder;
 use lexical_util::format::STANDARD;
-use lexical_write_integer::{Options, ToLexical, ToLexicalWithOptions};
+use lexical_write_integer::{
+    to_lexical_exact_digits,
+    Error,
+    GroupingScheme,
+    Options,
+    ToDigitCount,
+    ToLexical,
+    ToLexicalWithOptions,
+};
 #[cfg(feature = "radix")]
 use util::from_radix;
 
@@ -181,6 +190,33 @@ fn i128_test() {
     assert_eq!(b"-1", (-1i128).to_lexical(&mut buffer));
 }
 
+#[test]
+#[cfg(target_pointer_width = "16")]
+fn size_test() {
+    let mut buffer = [b'\x00'; 16];
+    assert_eq!(b"65535", usize::MAX.to_lexical(&mut buffer));
+    assert_eq!(b"32767", isize::MAX.to_lexical(&mut buffer));
+    assert_eq!(b"-32768", isize::MIN.to_lexical(&mut buffer));
+}
+
+#[test]
+#[cfg(target_pointer_width = "32")]
+fn size_test() {
+    let mut buffer = [b'\x00'; 16];
+    assert_eq!(b"4294967295", usize::MAX.to_lexical(&mut buffer));
+    assert_eq!(b"2147483647", isize::MAX.to_lexical(&mut buffer));
+    assert_eq!(b"-2147483648", isize::MIN.to_lexical(&mut buffer));
+}
+
+#[test]
+#[cfg(target_pointer_width = "64")]
+fn size_test() {
+    let mut buffer = [b'\x00'; 32];
+    assert_eq!(b"18446744073709551615", usize::MAX.to_lexical(&mut buffer));
+    assert_eq!(b"9223372036854775807", isize::MAX.to_lexical(&mut buffer));
+    assert_eq!(b"-9223372036854775808", isize::MIN.to_lexical(&mut buffer));
+}
+
 #[test]
 #[cfg(feature = "radix")]
 fn proptest_failures_radix() {
@@ -215,6 +251,327 @@ fn options_radix_test() {
     assert_eq!(b"A8", 128u8.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &OPTIONS));
 }
 
+#[test]
+fn integer_grouping_test() {
+    const THREE: Options = Options::builder().integer_grouping(Some((b',', 3))).build_strict();
+    let mut buffer = [b'\x00'; THREE.buffer_size_const::<i128, STANDARD>()];
+    let mut write = |value: i64, options: &Options| {
+        value.to_lexical_with_options::<{ STANDARD }>(&mut buffer, options).to_vec()
+    };
+    assert_eq!(write(1234567, &THREE), b"1,234,567");
+    assert_eq!(write(-1234567, &THREE), b"-1,234,567");
+    assert_eq!(write(123456, &THREE), b"123,456");
+    assert_eq!(write(-123456, &THREE), b"-123,456");
+    assert_eq!(write(1234, &THREE), b"1,234");
+
+    // Values shorter than one group have no separators.
+    assert_eq!(write(0, &THREE), b"0");
+    assert_eq!(write(7, &THREE), b"7");
+    assert_eq!(write(-7, &THREE), b"-7");
+    assert_eq!(write(123, &THREE), b"123");
+    assert_eq!(write(-123, &THREE), b"-123");
+
+    const TWO: Options = Options::builder().integer_grouping(Some((b' ', 2))).build_strict();
+    assert_eq!(write(1234567, &TWO), b"1 23 45 67");
+    assert_eq!(write(-123456, &TWO), b"-12 34 56");
+    assert_eq!(write(12, &TWO), b"12");
+    assert_eq!(write(-1, &TWO), b"-1");
+
+    // The buffer size includes the separators for the largest values.
+    let max = i128::MIN.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &THREE);
+    assert_eq!(max, b"-170,141,183,460,469,231,731,687,303,715,884,105,728");
+    const ONE: Options = Options::builder().integer_grouping(Some((b'_', 1))).build_strict();
+    let mut buffer = [b'\x00'; ONE.buffer_size_const::<u128, STANDARD>()];
+    let max = u128::MAX.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &ONE);
+    assert_eq!(max.len(), 2 * 39 - 1);
+    assert_eq!(u8::MAX.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &ONE), b"2_5_5");
+}
+
+#[test]
+fn integer_grouping_default_test() {
+    const DEFAULT: Options = Options::builder().integer_grouping(Some((b' ', 0))).build_strict();
+    let mut buffer = [b'\x00'; DEFAULT.buffer_size_const::<u32, STANDARD>()];
+    let value = u32::MAX.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &DEFAULT);
+    assert_eq!(b"4 294 967 295", value);
+    assert_eq!(buffer.len(), 13);
+}
+
+#[test]
+fn grouping_scheme_indian_test() {
+    const INDIAN: Options = Options::builder()
+        .integer_grouping(Some((b',', 3)))
+        .grouping_scheme(GroupingScheme::Indian)
+        .build_strict();
+    let mut buffer = [b'\x00'; INDIAN.buffer_size_const::<i64, STANDARD>()];
+    let mut write = |value: i64| {
+        value.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &INDIAN).to_vec()
+    };
+    assert_eq!(write(12345678), b"1,23,45,678");
+    assert_eq!(write(-12345678), b"-1,23,45,678");
+    assert_eq!(write(123456789), b"12,34,56,789");
+    assert_eq!(write(100000), b"1,00,000");
+    assert_eq!(write(12345), b"12,345");
+    assert_eq!(write(1234), b"1,234");
+    assert_eq!(write(-1234), b"-1,234");
+    assert_eq!(write(123), b"123");
+    assert_eq!(write(-7), b"-7");
+    assert_eq!(write(0), b"0");
+
+    // The buffer size includes the extra separators for the largest values.
+    let mut buffer = [b'\x00'; INDIAN.buffer_size_const::<u64, STANDARD>()];
+    let max = u64::MAX.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &INDIAN);
+    assert_eq!(max, b"1,84,46,74,40,73,70,95,51,615");
+    let mut buffer = [b'\x00'; INDIAN.buffer_size_const::<i128, STANDARD>()];
+    let min = i128::MIN.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &INDIAN);
+    assert_eq!(min.len(), 1 + 39 + 18);
+
+    // The scheme has no effect without a group separator.
+    const NONE: Options = Options::builder().grouping_scheme(GroupingScheme::Indian).build_strict();
+    let value = 12345678u32.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &NONE);
+    assert_eq!(value, b"12345678");
+}
+
+#[test]
+fn exact_digits_test() {
+    const FOUR: Options = Options::builder().exact_digits(num::NonZeroU8::new(4)).build_strict();
+    let mut buffer = [b'\x00'; FOUR.buffer_size_const::<i64, STANDARD>()];
+    let mut write = |value: i64, options: &Options| {
+        to_lexical_exact_digits::<_, STANDARD>(value, &mut buffer, options).map(|x| x.to_vec())
+    };
+    assert_eq!(write(42, &FOUR), Ok(b"0042".to_vec()));
+    assert_eq!(write(-42, &FOUR), Ok(b"-0042".to_vec()));
+    assert_eq!(write(1234, &FOUR), Ok(b"1234".to_vec()));
+    assert_eq!(write(-9999, &FOUR), Ok(b"-9999".to_vec()));
+    assert_eq!(write(12345, &FOUR), Err(Error::FieldOverflow));
+    assert_eq!(write(-12345, &FOUR), Err(Error::FieldOverflow));
+
+    const THREE: Options = Options::builder().exact_digits(num::NonZeroU8::new(3)).build_strict();
+    assert_eq!(write(0, &THREE), Ok(b"000".to_vec()));
+
+    // Values with too many digits are written in full without the check.
+    assert_eq!(b"12345", 12345u32.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &FOUR));
+
+    // The padding digits are grouped like any other digit.
+    const GROUPED: Options = Options::builder()
+        .exact_digits(num::NonZeroU8::new(7))
+        .integer_grouping(Some((b',', 3)))
+        .build_strict();
+    let mut buffer = [b'\x00'; GROUPED.buffer_size_const::<u8, STANDARD>()];
+    assert_eq!(b"0,000,042", 42u8.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &GROUPED));
+
+    // Padding to a minimum width is not counted as digits.
+    const PADDED: Options = Options::builder()
+        .exact_digits(num::NonZeroU8::new(4))
+        .integer_min_width(num::NonZeroUsize::new(8))
+        .build_strict();
+    let mut buffer = [b'\x00'; PADDED.buffer_size_const::<i64, STANDARD>()];
+    let mut write = |value: i64| {
+        to_lexical_exact_digits::<_, STANDARD>(value, &mut buffer, &PADDED).map(|x| x.to_vec())
+    };
+    assert_eq!(write(42), Ok(b"00000042".to_vec()));
+    assert_eq!(write(-42), Ok(b"-0000042".to_vec()));
+    assert_eq!(write(12345), Err(Error::FieldOverflow));
+}
+
+#[test]
+fn integer_min_width_test() {
+    const ZERO: Options =
+        Options::builder().integer_min_width(num::NonZeroUsize::new(4)).build_strict();
+    let mut buffer = [b'\x00'; ZERO.buffer_size_const::<i64, STANDARD>()];
+    assert_eq!(b"0007", 7u32.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &ZERO));
+    assert_eq!(b"-005", (-5i32).to_lexical_with_options::<{ STANDARD }>(&mut buffer, &ZERO));
+    assert_eq!(b"0000", 0u8.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &ZERO));
+    assert_eq!(b"-128", i8::MIN.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &ZERO));
+
+    // Values at or above the width are left untouched.
+    assert_eq!(b"1234", 1234u32.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &ZERO));
+    assert_eq!(b"123456", 123456u32.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &ZERO));
+    assert_eq!(b"-12345", (-12345i64).to_lexical_with_options::<{ STANDARD }>(&mut buffer, &ZERO));
+
+    // Other padding is written before the sign.
+    const SPACE: Options = Options::builder()
+        .integer_min_width(num::NonZeroUsize::new(4))
+        .pad_char(b' ')
+        .build_strict();
+    assert_eq!(b"   7", 7u32.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &SPACE));
+    assert_eq!(b"  -5", (-5i32).to_lexical_with_options::<{ STANDARD }>(&mut buffer, &SPACE));
+    assert_eq!(b"12345", 12345u32.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &SPACE));
+
+    // The width includes any group separators, and the padding is not grouped.
+    const GROUPED: Options = Options::builder()
+        .integer_min_width(num::NonZeroUsize::new(8))
+        .integer_grouping(Some((b',', 3)))
+        .build_strict();
+    let mut buffer = [b'\x00'; GROUPED.buffer_size_const::<i16, STANDARD>()];
+    let mut write = |value: i16| {
+        value.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &GROUPED).to_vec()
+    };
+    assert_eq!(write(1234), b"0001,234");
+    assert_eq!(write(-1234), b"-001,234");
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "alloc")]
+fn integer_min_width_invalid_pad_char_test() {
+    use lexical_write_integer::to_lexical_string_with_options;
+
+    // A non-ASCII pad character is rejected by `build`, but not `build_unchecked`.
+    const OPTIONS: Options = Options::builder()
+        .integer_min_width(num::NonZeroUsize::new(4))
+        .pad_char(0xFF)
+        .build_unchecked();
+    to_lexical_string_with_options::<_, STANDARD>(7u32, &OPTIONS);
+}
+
+#[test]
+fn digit_count_test() {
+    assert_eq!(1, 0u32.digit_count::<10>());
+    assert_eq!(3, 999u32.digit_count::<10>());
+    assert_eq!(4, 1000u32.digit_count::<10>());
+    assert_eq!(4, (-999i32).digit_count::<10>());
+    assert_eq!(5, (-1000i32).digit_count::<10>());
+    assert_eq!(11, i32::MIN.digit_count::<10>());
+    assert_eq!(10, i32::MAX.digit_count::<10>());
+    assert_eq!(4, i8::MIN.digit_count::<10>());
+    assert_eq!(40, i128::MIN.digit_count::<10>());
+    assert_eq!(39, u128::MAX.digit_count::<10>());
+
+    // Other radixes, at and around the powers of the radix.
+    assert_eq!(8, 255u8.digit_count::<2>());
+    assert_eq!(9, 256u16.digit_count::<2>());
+    assert_eq!(33, i32::MIN.digit_count::<2>());
+    assert_eq!(2, 0xFFu32.digit_count::<16>());
+    assert_eq!(3, 0x100u32.digit_count::<16>());
+    assert_eq!(3, (-0x80i8).digit_count::<16>());
+    assert_eq!(3, 511u32.digit_count_radix(8));
+    assert_eq!(4, 512u32.digit_count_radix(8));
+    assert_eq!(3, 26u32.digit_count_radix(3));
+    assert_eq!(4, 27u32.digit_count_radix(3));
+    assert_eq!(2, 1295u32.digit_count_radix(36));
+    assert_eq!(3, 1296u32.digit_count_radix(36));
+    assert_eq!(13, u64::MAX.digit_count_radix(36));
+
+    // The count matches the number of bytes written.
+    let mut buffer = [b'\x00'; 64];
+    for value in [0, 1, 9, 10, 99, 100, 12345, -1, -10, -12345, i64::MIN, i64::MAX] {
+        assert_eq!(value.to_lexical(&mut buffer).len(), value.digit_count::<10>());
+    }
+}
+
+#[test]
+fn digit_count_with_options_test() {
+    const OPTIONS: Options = Options::new();
+    assert_eq!(4, 1000u32.digit_count_with_options::<{ STANDARD }>(&OPTIONS));
+    assert_eq!(11, i32::MIN.digit_count_with_options::<{ STANDARD }>(&OPTIONS));
+
+    // The count includes any padding digits and group separators.
+    const GROUPED: Options = Options::builder()
+        .exact_digits(num::NonZeroU8::new(7))
+        .integer_grouping(Some((b',', 3)))
+        .build_strict();
+    assert_eq!(9, 42u8.digit_count_with_options::<{ STANDARD }>(&GROUPED));
+    assert_eq!(14, i32::MIN.digit_count_with_options::<{ STANDARD }>(&GROUPED));
+    const INDIAN: Options = Options::builder()
+        .integer_grouping(Some((b',', 3)))
+        .grouping_scheme(GroupingScheme::Indian)
+        .build_strict();
+    assert_eq!(11, 12345678u32.digit_count_with_options::<{ STANDARD }>(&INDIAN));
+    assert_eq!(6, (-1234i32).digit_count_with_options::<{ STANDARD }>(&INDIAN));
+    const WIDE: Options = Options::builder()
+        .integer_min_width(num::NonZeroUsize::new(8))
+        .pad_char(b' ')
+        .build_strict();
+    assert_eq!(8, (-5i32).digit_count_with_options::<{ STANDARD }>(&WIDE));
+    assert_eq!(10, 1234567890u32.digit_count_with_options::<{ STANDARD }>(&WIDE));
+
+    // The count matches the number of bytes written.
+    let mut buffer = [b'\x00'; 64];
+    for options in [OPTIONS, GROUPED, INDIAN, WIDE] {
+        for value in [0, 7, 999, 1000, 123456, -1, -1000, -12345678, i64::MIN, i64::MAX] {
+            let count = value.digit_count_with_options::<{ STANDARD }>(&options);
+            let digits = value.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+            assert_eq!(digits.len(), count);
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn digit_count_radix_test() {
+    const DEFAULT: Options = Options::new();
+    const OPTIONS: Options = Options::builder().integer_grouping(Some((b' ', 0))).build_strict();
+    let mut buffer = [b'\x00'; 128];
+    macro_rules! check {
+        ($($radix:literal)*) => ($({
+            const FORMAT: u128 = from_radix($radix);
+            for value in [0, 1, $radix - 1, $radix, i32::MIN, i32::MAX, -1234567] {
+                let digits = value.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &OPTIONS);
+                assert_eq!(digits.len(), value.digit_count_with_options::<{ FORMAT }>(&OPTIONS));
+                let digits = value.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &DEFAULT);
+                assert_eq!(digits.len(), value.digit_count::<$radix>());
+            }
+        })*);
+    }
+    check! { 2 3 5 7 8 10 12 16 31 32 36 }
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn integer_grouping_sign_test() {
+    let mut buffer = [b'\x00'; 32];
+    const OPTIONS: Options = Options::builder().integer_grouping(Some((b',', 3))).build_strict();
+    const FORMAT: u128 = NumberFormatBuilder::new().required_mantissa_sign(true).build_strict();
+    assert_eq!(b"+1,234", 1234i32.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &OPTIONS));
+    assert_eq!(b"+123", 123u32.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &OPTIONS));
+    assert_eq!(b"-1,234", (-1234i32).to_lexical_with_options::<{ FORMAT }>(&mut buffer, &OPTIONS));
+    assert_eq!(6, 1234i32.digit_count_with_options::<{ FORMAT }>(&OPTIONS));
+    assert_eq!(4, 123u32.digit_count_with_options::<{ FORMAT }>(&OPTIONS));
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn integer_grouping_radix_test() {
+    use lexical_write_integer::{try_to_lexical_with_options, NeedCapacity};
+
+    const BINARY: u128 = from_radix(2);
+    const OPTIONS: Options = Options::builder().integer_grouping(Some((b'_', 4))).build_strict();
+    let mut buffer = [b'\x00'; OPTIONS.buffer_size_const::<i128, BINARY>()];
+    assert_eq!(b"1010_1010", 0xAAu8.to_lexical_with_options::<{ BINARY }>(&mut buffer, &OPTIONS));
+    assert_eq!(b"-1_0000", (-16i8).to_lexical_with_options::<{ BINARY }>(&mut buffer, &OPTIONS));
+
+    let mut small = [b'\x00'; 128];
+    let result = try_to_lexical_with_options::<_, BINARY>(i128::MIN, &mut small, &OPTIONS);
+    assert_eq!(result, Err(NeedCapacity(129 + 31)));
+    let result = try_to_lexical_with_options::<_, BINARY>(i128::MIN, &mut buffer, &OPTIONS);
+    assert_eq!(result, Ok(129 + 31));
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn uppercase_digits_test() {
+    const HEX: u128 = from_radix(16);
+    const LOWER: Options = Options::builder().uppercase_digits(false).build_strict();
+    let mut buffer = [b'\x00'; 64];
+    assert_eq!(b"FF", 255u8.to_lexical_with_options::<{ HEX }>(&mut buffer, &Options::new()));
+    assert_eq!(b"ff", 255u8.to_lexical_with_options::<{ HEX }>(&mut buffer, &LOWER));
+    assert_eq!(b"-7f", (-127i8).to_lexical_with_options::<{ HEX }>(&mut buffer, &LOWER));
+    assert_eq!(b"1234", 0x1234u32.to_lexical_with_options::<{ HEX }>(&mut buffer, &LOWER));
+
+    const GROUPED: Options =
+        Options::builder().uppercase_digits(false).integer_grouping(Some((b'_', 4))).build_strict();
+    let value = 0xDEADBEEFu64.to_lexical_with_options::<{ HEX }>(&mut buffer, &GROUPED);
+    assert_eq!(b"dead_beef", value);
+
+    // A group size of 0 groups hexadecimal digits by 4.
+    const DEFAULT: Options =
+        Options::builder().uppercase_digits(false).integer_grouping(Some((b' ', 0))).build_strict();
+    let mut buffer = [b'\x00'; DEFAULT.buffer_size_const::<u32, HEX>()];
+    let value = 0xDEADBEEFu32.to_lexical_with_options::<{ HEX }>(&mut buffer, &DEFAULT);
+    assert_eq!(b"dead beef", value);
+    assert_eq!(b"1 0000", 0x10000u32.to_lexical_with_options::<{ HEX }>(&mut buffer, &DEFAULT));
+}
+
 fn roundtrip<T>(x: T) -> T
 where
     T: Roundtrip,
@@ -1216,3 +1573,228 @@ fn usize_buffer_test() {
     let mut buffer = [b'\x00'; 5];
     123456usize.to_lexical(&mut buffer);
 }
+
+#[test]
+#[cfg(feature = "smallvec")]
+fn to_lexical_smallvec_test() {
+    use lexical_write_integer::to_lexical_smallvec;
+
+    // Small values stay inline.
+    let digits = to_lexical_smallvec(0u8);
+    assert_eq!(&digits[..], b"0");
+    assert!(!digits.spilled());
+    let digits = to_lexical_smallvec(-128i8);
+    assert_eq!(&digits[..], b"-128");
+    assert!(!digits.spilled());
+    let digits = to_lexical_smallvec(u64::MAX);
+    assert_eq!(&digits[..], b"18446744073709551615");
+    assert!(!digits.spilled());
+    let digits = to_lexical_smallvec(i64::MIN);
+    assert_eq!(&digits[..], b"-9223372036854775808");
+    assert!(!digits.spilled());
+    let digits = to_lexical_smallvec(usize::MAX);
+    assert_eq!(digits.len(), usize::MAX.to_string().len());
+    assert!(!digits.spilled());
+
+    // Small 128-bit values stay inline, large values spill to the heap.
+    let digits = to_lexical_smallvec(1234u128);
+    assert_eq!(&digits[..], b"1234");
+    assert!(!digits.spilled());
+    let digits = to_lexical_smallvec(-1234i128);
+    assert_eq!(&digits[..], b"-1234");
+    assert!(!digits.spilled());
+    let digits = to_lexical_smallvec(999_999_999_999_999_999_999_999u128);
+    assert_eq!(&digits[..], b"999999999999999999999999");
+    assert!(!digits.spilled());
+    let digits = to_lexical_smallvec(1_000_000_000_000_000_000_000_000u128);
+    assert_eq!(&digits[..], b"1000000000000000000000000");
+    assert!(digits.spilled());
+    let digits = to_lexical_smallvec(u128::MAX);
+    assert_eq!(&digits[..], b"340282366920938463463374607431768211455");
+    assert!(digits.spilled());
+    let digits = to_lexical_smallvec(i128::MIN);
+    assert_eq!(&digits[..], b"-170141183460469231731687303715884105728");
+    assert!(digits.spilled());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn to_lexical_vec_test() {
+    use lexical_write_integer::{
+        to_lexical_string,
+        to_lexical_string_with_options,
+        to_lexical_vec,
+        to_lexical_vec_with_options,
+    };
+
+    fn check<T: ToLexical + ToLexicalWithOptions<Options = Options> + Copy>(value: T) {
+        let mut buffer = [b'\x00'; 64];
+        let expected = value.to_lexical(&mut buffer).to_vec();
+        assert_eq!(to_lexical_vec(value), expected);
+        assert_eq!(to_lexical_string(value).as_bytes(), &expected[..]);
+
+        const OPTIONS: Options = Options::new();
+        let expected = value.to_lexical_with_options::<STANDARD>(&mut buffer, &OPTIONS).to_vec();
+        assert_eq!(to_lexical_vec_with_options::<_, STANDARD>(value, &OPTIONS), expected);
+        let actual = to_lexical_string_with_options::<_, STANDARD>(value, &OPTIONS);
+        assert_eq!(actual.as_bytes(), &expected[..]);
+    }
+
+    check(0u8);
+    check(i8::MIN);
+    check(u64::MAX);
+    check(i64::MIN);
+    check(u128::MAX);
+    check(i128::MIN);
+    assert_eq!(to_lexical_string(-1234i32), "-1234");
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "alloc")]
+fn to_lexical_string_invalid_grouping_test() {
+    use lexical_write_integer::to_lexical_string_with_options;
+
+    // A non-ASCII separator is rejected by `build`, but not `build_unchecked`.
+    const OPTIONS: Options = Options::builder().integer_grouping(Some((0xC3, 3))).build_unchecked();
+    to_lexical_string_with_options::<_, STANDARD>(1234567u32, &OPTIONS);
+}
+
+#[test]
+#[cfg(all(feature = "alloc", feature = "radix"))]
+fn to_lexical_vec_radix_test() {
+    use lexical_write_integer::to_lexical_string_with_options;
+
+    const BINARY: u128 = from_radix(2);
+    const OPTIONS: Options = Options::new();
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    for value in [0u128, 1234, u128::MAX] {
+        let expected = value.to_lexical_with_options::<BINARY>(&mut buffer, &OPTIONS);
+        let actual = to_lexical_string_with_options::<_, BINARY>(value, &OPTIONS);
+        assert_eq!(actual.as_bytes(), expected);
+    }
+    let actual = to_lexical_string_with_options::<_, BINARY>(i128::MIN, &OPTIONS);
+    assert_eq!(actual.len(), 129);
+}
+
+#[test]
+fn try_to_lexical_test() {
+    use lexical_write_integer::{try_to_lexical, try_to_lexical_with_options, NeedCapacity};
+
+    fn check<T: ToLexical + ToLexicalWithOptions<Options = Options> + Copy>(value: T) {
+        let mut buffer = [b'\x00'; 64];
+        let expected = value.to_lexical(&mut buffer).to_vec();
+        let len = expected.len();
+
+        // One byte too small must not modify the buffer.
+        let mut small = vec![b'\x00'; len - 1];
+        assert_eq!(try_to_lexical(value, &mut small), Err(NeedCapacity(len)));
+        assert!(small.iter().all(|&x| x == b'\x00'));
+
+        let mut exact = vec![b'\x00'; len];
+        assert_eq!(try_to_lexical(value, &mut exact), Ok(len));
+        assert_eq!(exact, expected);
+        assert_eq!(try_to_lexical(value, &mut buffer), Ok(len));
+        assert_eq!(&buffer[..len], &expected[..]);
+
+        const OPTIONS: Options = Options::new();
+        let result = try_to_lexical_with_options::<_, STANDARD>(value, &mut small, &OPTIONS);
+        assert_eq!(result, Err(NeedCapacity(len)));
+        let result = try_to_lexical_with_options::<_, STANDARD>(value, &mut exact, &OPTIONS);
+        assert_eq!(result, Ok(len));
+        assert_eq!(exact, expected);
+    }
+
+    check(1u8);
+    check(255u8);
+    check(-128i8);
+    check(10000u16);
+    check(-1i32);
+    check(u64::MAX);
+    check(i64::MIN);
+    check(u128::MAX);
+    check(i128::MIN);
+
+    let mut buffer = [b'\x00'; 0];
+    assert_eq!(try_to_lexical(0u32, &mut buffer), Err(NeedCapacity(1)));
+    assert_eq!(NeedCapacity(5).required(), 5);
+
+    // The padding may be wider than any digits, including separators.
+    const WIDE: Options =
+        Options::builder().integer_min_width(num::NonZeroUsize::new(600)).build_strict();
+    let mut buffer = [b'\x00'; 10];
+    let result = try_to_lexical_with_options::<_, STANDARD>(7u32, &mut buffer, &WIDE);
+    assert_eq!(result, Err(NeedCapacity(600)));
+    assert!(buffer.iter().all(|&x| x == b'\x00'));
+    let mut buffer = [b'\x00'; 600];
+    let result = try_to_lexical_with_options::<_, STANDARD>(7u32, &mut buffer, &WIDE);
+    assert_eq!(result, Ok(600));
+    assert_eq!(buffer[599], b'7');
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn try_to_lexical_radix_test() {
+    use lexical_write_integer::{try_to_lexical_with_options, NeedCapacity};
+
+    const BINARY: u128 = from_radix(2);
+    const OPTIONS: Options = Options::new();
+    let mut buffer = [b'\x00'; 128];
+    let result = try_to_lexical_with_options::<_, BINARY>(i128::MIN, &mut buffer, &OPTIONS);
+    assert_eq!(result, Err(NeedCapacity(129)));
+    let result = try_to_lexical_with_options::<_, BINARY>(u128::MAX, &mut buffer, &OPTIONS);
+    assert_eq!(result, Ok(128));
+    assert!(buffer.iter().all(|&x| x == b'1'));
+}
+
+#[test]
+fn write_sign_and_digits_test() {
+    use lexical_write_integer::ToSignAndDigits;
+
+    let mut sign = 0u8;
+    let mut digits = [b'\x00'; 40];
+    assert_eq!(1234i32.write_sign_and_digits(&mut sign, &mut digits), (false, 4));
+    assert_eq!((sign, &digits[..4]), (b'+', &b"1234"[..]));
+    assert_eq!((-1234i32).write_sign_and_digits(&mut sign, &mut digits), (true, 4));
+    assert_eq!((sign, &digits[..4]), (b'-', &b"1234"[..]));
+    assert_eq!(0i8.write_sign_and_digits(&mut sign, &mut digits), (false, 1));
+    assert_eq!((sign, &digits[..1]), (b'+', &b"0"[..]));
+
+    // The magnitude of `MIN` does not fit in the signed type.
+    assert_eq!(i8::MIN.write_sign_and_digits(&mut sign, &mut digits), (true, 3));
+    assert_eq!((sign, &digits[..3]), (b'-', &b"128"[..]));
+    assert_eq!(i64::MIN.write_sign_and_digits(&mut sign, &mut digits), (true, 19));
+    assert_eq!((sign, &digits[..19]), (b'-', &b"9223372036854775808"[..]));
+    let count = i128::MIN.write_sign_and_digits(&mut sign, &mut digits).1;
+    assert_eq!(&digits[..count], b"170141183460469231731687303715884105728");
+    assert!(isize::MIN.write_sign_and_digits(&mut sign, &mut digits).0);
+
+    // Unsigned values are never negative.
+    assert_eq!(u64::MAX.write_sign_and_digits(&mut sign, &mut digits), (false, 20));
+    assert_eq!((sign, &digits[..20]), (b'+', &b"18446744073709551615"[..]));
+    let count = u128::MAX.write_sign_and_digits(&mut sign, &mut digits).1;
+    assert_eq!(&digits[..count], b"340282366920938463463374607431768211455");
+}
+
+#[test]
+fn to_lexical_unsigned_magnitude_test() {
+    use lexical_write_integer::ToUnsignedMagnitude;
+
+    let (is_negative, digits) = 1234i32.to_lexical_unsigned_magnitude();
+    assert_eq!((is_negative, digits.as_str()), (false, "1234"));
+    let (is_negative, digits) = (-1234i32).to_lexical_unsigned_magnitude();
+    assert_eq!((is_negative, digits.as_str()), (true, "1234"));
+    let (is_negative, digits) = 0i64.to_lexical_unsigned_magnitude();
+    assert_eq!((is_negative, digits.as_bytes()), (false, &b"0"[..]));
+
+    // The magnitude of `MIN` does not fit in the signed type.
+    let (is_negative, digits) = i8::MIN.to_lexical_unsigned_magnitude();
+    assert_eq!((is_negative, digits.as_str()), (true, "128"));
+    let (is_negative, digits) = i64::MIN.to_lexical_unsigned_magnitude();
+    assert_eq!((is_negative, digits.as_str()), (true, "9223372036854775808"));
+    let (is_negative, digits) = i128::MIN.to_lexical_unsigned_magnitude();
+    assert_eq!((is_negative, &*digits), (true, "170141183460469231731687303715884105728"));
+    let (is_negative, digits) = i128::MAX.to_lexical_unsigned_magnitude();
+    assert_eq!((is_negative, digits.len()), (false, 39));
+    assert!(isize::MIN.to_lexical_unsigned_magnitude().0);
+}
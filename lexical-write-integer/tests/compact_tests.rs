@@ -0,0 +1,62 @@
+#![cfg(feature = "compact")]
+
+use core::mem::MaybeUninit;
+use core::str::from_utf8;
+
+use lexical_write_integer::compact::{compact_into, Compact};
+
+// Enough room for a 128-bit integer in binary.
+const BUFFER_SIZE: usize = 128;
+
+fn compact<T: Compact>(value: T, radix: u32, buffer: &mut [u8]) -> &str {
+    let count = value.compact(radix, buffer);
+    from_utf8(&buffer[..count]).unwrap()
+}
+
+fn compact_uninit<T: Compact>(value: T, radix: u32) -> Vec<u8> {
+    let mut buffer = [MaybeUninit::<u8>::uninit(); BUFFER_SIZE];
+    let count = compact_into(value, radix, &mut buffer);
+    buffer[..count].iter().map(|x| unsafe { x.assume_init() }).collect()
+}
+
+macro_rules! compact_test {
+    ($name:ident, $t:ty) => {
+        #[test]
+        fn $name() {
+            let values: [$t; 8] = [0, 1, 7, 8, 10, 15, 16, <$t>::MAX];
+            let mut buffer = [b'\x00'; BUFFER_SIZE];
+            for radix in [2, 8, 10, 16] {
+                for &value in values.iter().chain([<$t>::MAX / 3, <$t>::MAX - 1].iter()) {
+                    let expected = compact(value, radix, &mut buffer).to_string();
+                    assert_eq!(<$t>::from_str_radix(&expected, radix), Ok(value));
+                    assert_eq!(compact_uninit(value, radix), expected.as_bytes());
+                }
+            }
+        }
+    };
+}
+
+compact_test!(u8_test, u8);
+compact_test!(u16_test, u16);
+compact_test!(u32_test, u32);
+compact_test!(u64_test, u64);
+compact_test!(u128_test, u128);
+compact_test!(usize_test, usize);
+
+#[test]
+fn compact_into_exact_test() {
+    let mut buffer = [MaybeUninit::<u8>::uninit(); 3];
+    assert_eq!(compact_into(255u8, 10, &mut buffer), 3);
+    assert_eq!(buffer.map(|x| unsafe { x.assume_init() }), *b"255");
+
+    let mut buffer = [b'\x00'; 8];
+    assert_eq!(255u8.compact(2, &mut buffer), 8);
+    assert_eq!(&buffer, b"11111111");
+}
+
+#[test]
+#[should_panic]
+fn compact_into_small_buffer_test() {
+    let mut buffer = [MaybeUninit::<u8>::uninit(); 2];
+    compact_into(255u8, 10, &mut buffer);
+}
@@ -1,4 +1,8 @@
-use lexical_write_integer::options::{Options, OptionsBuilder};
+use core::num;
+
+use lexical_util::error::Error;
+use lexical_util::format::STANDARD;
+use lexical_write_integer::options::{GroupingScheme, Options, OptionsBuilder};
 
 #[test]
 fn options_tests() {
@@ -10,3 +14,125 @@ fn options_tests() {
     assert!(OptionsBuilder::default().is_valid());
     assert_eq!(X.rebuild(), Options::builder());
 }
+
+#[test]
+fn uppercase_digits_tests() {
+    assert!(Options::new().uppercase_digits());
+    assert!(OptionsBuilder::new().get_uppercase_digits());
+    const X: Options = Options::builder().uppercase_digits(false).build_strict();
+    assert!(!X.uppercase_digits());
+    assert!(!X.rebuild().get_uppercase_digits());
+    assert_eq!(X.rebuild().build_strict(), X);
+}
+
+#[test]
+fn exact_digits_tests() {
+    assert_eq!(Options::new().exact_digits(), None);
+    assert_eq!(OptionsBuilder::new().get_exact_digits(), None);
+    const X: Options = Options::builder().exact_digits(num::NonZeroU8::new(4)).build_strict();
+    assert_eq!(X.exact_digits(), num::NonZeroU8::new(4));
+    assert_eq!(X.rebuild().get_exact_digits(), num::NonZeroU8::new(4));
+    assert_eq!(X.rebuild().build_strict(), X);
+
+    // The buffer has room for the padding digits and the sign.
+    const MAX: Options = Options::builder().exact_digits(num::NonZeroU8::new(255)).build_strict();
+    assert_eq!(MAX.buffer_size_const::<u8, STANDARD>(), 256);
+    assert_eq!(X.buffer_size_const::<u8, STANDARD>(), 5);
+    assert_eq!(X.buffer_size_const::<u64, STANDARD>(), 20);
+}
+
+#[test]
+fn integer_min_width_tests() {
+    assert_eq!(Options::new().integer_min_width(), None);
+    assert_eq!(OptionsBuilder::new().get_integer_min_width(), None);
+    assert_eq!(Options::new().pad_char(), b'0');
+    assert_eq!(OptionsBuilder::new().get_pad_char(), b'0');
+    const X: Options = Options::builder()
+        .integer_min_width(num::NonZeroUsize::new(30))
+        .pad_char(b' ')
+        .build_strict();
+    assert_eq!(X.integer_min_width(), num::NonZeroUsize::new(30));
+    assert_eq!(X.pad_char(), b' ');
+    assert_eq!(X.rebuild().get_integer_min_width(), num::NonZeroUsize::new(30));
+    assert_eq!(X.rebuild().get_pad_char(), b' ');
+    assert_eq!(X.rebuild().build_strict(), X);
+
+    // The buffer has room for the width, but is never smaller.
+    assert_eq!(X.buffer_size_const::<u8, STANDARD>(), 30);
+    assert_eq!(X.buffer_size_const::<u128, STANDARD>(), 39);
+
+    // The pad character must not be a digit, letter, or sign.
+    let error = Err(Error::InvalidPadCharacter);
+    assert_eq!(OptionsBuilder::new().pad_char(b'1').build(), error);
+    assert_eq!(OptionsBuilder::new().pad_char(b'a').build(), error);
+    assert_eq!(OptionsBuilder::new().pad_char(b'-').build(), error);
+    assert!(!OptionsBuilder::new().pad_char(b'+').is_valid());
+    assert!(OptionsBuilder::new().pad_char(b'*').is_valid());
+    assert_eq!(OptionsBuilder::new().pad_char(0xFF).build(), error);
+}
+
+#[test]
+fn integer_grouping_tests() {
+    const X: Options = Options::builder().integer_grouping(Some((b',', 3))).build_strict();
+    assert_eq!(X.integer_grouping(), Some((b',', 3)));
+    assert_eq!(X.rebuild().get_integer_grouping(), Some((b',', 3)));
+    assert!(X.is_valid());
+    assert!(OptionsBuilder::new().integer_grouping(Some((b' ', 1))).is_valid());
+
+    // A group size of 0 selects the default size for the radix.
+    assert!(OptionsBuilder::new().integer_grouping(Some((b',', 0))).is_valid());
+
+    // The separator must not be a digit or a sign.
+    let error = Err(Error::InvalidIntegerGrouping);
+    assert_eq!(OptionsBuilder::new().integer_grouping(Some((b'1', 3))).build(), error);
+    assert_eq!(OptionsBuilder::new().integer_grouping(Some((b'a', 3))).build(), error);
+    assert_eq!(OptionsBuilder::new().integer_grouping(Some((b'-', 3))).build(), error);
+    assert!(!OptionsBuilder::new().integer_grouping(Some((b'+', 3))).is_valid());
+    assert_eq!(OptionsBuilder::new().integer_grouping(Some((0xC3, 3))).build(), error);
+}
+
+#[test]
+fn grouping_scheme_tests() {
+    assert_eq!(Options::new().grouping_scheme(), GroupingScheme::Uniform);
+    assert_eq!(OptionsBuilder::new().get_grouping_scheme(), GroupingScheme::Uniform);
+    const X: Options = Options::builder()
+        .integer_grouping(Some((b',', 3)))
+        .grouping_scheme(GroupingScheme::Indian)
+        .build_strict();
+    assert_eq!(X.grouping_scheme(), GroupingScheme::Indian);
+    assert_eq!(X.rebuild().get_grouping_scheme(), GroupingScheme::Indian);
+    assert_eq!(X.rebuild().build_strict(), X);
+
+    // The buffer has room for the extra separators of the smaller groups.
+    assert_eq!(X.buffer_size_const::<u32, STANDARD>(), 14);
+    assert_eq!(X.buffer_size_const::<i128, STANDARD>(), 40 + 19);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn thread_local_options_test() {
+    use lexical_write_integer::{to_lexical_default, ThreadLocalOptions, ToLexical, BUFFER_SIZE};
+
+    fn write(value: u32) -> Vec<u8> {
+        let mut buffer = [b'\x00'; BUFFER_SIZE];
+        to_lexical_default(value, &mut buffer).to_vec()
+    }
+
+    assert_eq!(write(1234567), b"1234567");
+    let comma = Options::builder().integer_grouping(Some((b',', 3))).build().unwrap();
+    let previous = ThreadLocalOptions::set_default(comma.clone());
+    assert_eq!(previous, Options::new());
+    assert_eq!(write(1234567), b"1,234,567");
+    assert_eq!(ThreadLocalOptions::with_default(|options| options.clone()), comma);
+
+    // The defaults are not used by `to_lexical`.
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    assert_eq!(1234567u32.to_lexical(&mut buffer), b"1234567");
+
+    // Other threads keep the standard defaults.
+    let result = std::thread::spawn(|| write(1234567)).join().unwrap();
+    assert_eq!(result, b"1234567");
+
+    assert_eq!(ThreadLocalOptions::set_default(previous), comma);
+    assert_eq!(write(1234567), b"1234567");
+}